@@ -0,0 +1,19 @@
+"""Python bindings for the ladderz collection of math and tech concepts.
+
+Functions are grouped by subject, as in the Rust library:
+
+>>> from ladderz.prealgebra import get_factors
+>>> get_factors(12)
+[1, 2, 3, 4, 6, 12]
+"""
+
+import sys
+
+from ._ladderz import dsa, prealgebra
+
+# Native submodules aren't importable by their dotted names on their own, so register them to
+# allow `import ladderz.prealgebra` and `from ladderz.dsa import two_sum`.
+sys.modules[__name__ + ".prealgebra"] = prealgebra
+sys.modules[__name__ + ".dsa"] = dsa
+
+__all__ = ["dsa", "prealgebra"]
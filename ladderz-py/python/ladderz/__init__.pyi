@@ -0,0 +1,4 @@
+from . import dsa as dsa
+from . import prealgebra as prealgebra
+
+__all__ = ["dsa", "prealgebra"]
@@ -0,0 +1,2 @@
+from . import dsa as dsa
+from . import prealgebra as prealgebra
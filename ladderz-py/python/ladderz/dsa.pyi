@@ -0,0 +1,104 @@
+"""Data structures and algorithms functions such as k-sum, string searching and dynamic
+programming.
+
+Results that are unordered in Rust are returned sorted.
+"""
+
+from typing import Optional
+
+def contains_duplicate(nums: list[int]) -> bool:
+    """Returns whether `nums` contains any value more than once."""
+
+def is_anagram(a: str, b: str) -> bool:
+    """Returns whether `a` and `b` are anagrams of each other, comparing characters exactly."""
+
+def is_anagram_unicode(a: str, b: str) -> bool:
+    """Returns whether `a` and `b` are anagrams of each other, ignoring case and comparing
+    grapheme clusters after Unicode normalization."""
+
+def two_sum(nums: list[int], target: int) -> list[int]:
+    """Returns the indices of two numbers in `nums` that add up to `target`, or an empty list."""
+
+def all_two_sum_pairs(nums: list[int], target: int) -> list[tuple[int, int]]:
+    """Returns every pair of indices `(i, j)` with `i < j` whose numbers add up to `target`,
+    sorted."""
+
+def three_sum(nums: list[int], target: int = 0) -> list[list[int]]:
+    """Returns the unique triplets in `nums` that add up to `target`, sorted."""
+
+def four_sum(nums: list[int], target: int = 0) -> list[list[int]]:
+    """Returns the unique quadruplets in `nums` that add up to `target`, sorted."""
+
+def k_sum(nums: list[int], k: int, target: int) -> list[list[int]]:
+    """Returns the unique combinations of `k` numbers in `nums` that add up to `target`, sorted."""
+
+def subarray_sum_equals_k(nums: list[int], k: int) -> int:
+    """Returns the number of contiguous subarrays of `nums` that add up to `k`."""
+
+def max_subarray(nums: list[int]) -> Optional[tuple[int, int, int]]:
+    """Returns the largest sum of a non-empty contiguous subarray with its `start` and `end`
+    indices, where `end` is exclusive, or `None` if `nums` is empty."""
+
+def kmp_search(text: str, pattern: str) -> list[int]:
+    """Returns the character indices where `pattern` starts in `text`, using Knuth-Morris-Pratt."""
+
+def z_search(text: str, pattern: str) -> list[int]:
+    """Returns the character indices where `pattern` starts in `text`, using the Z-function."""
+
+def rabin_karp(text: str, pattern: str) -> list[int]:
+    """Returns the character indices where `pattern` starts in `text`, using Rabin-Karp."""
+
+def longest_common_subsequence(a: str, b: str) -> str:
+    """Returns a longest common subsequence of `a` and `b`."""
+
+def longest_common_substring(a: str, b: str) -> str:
+    """Returns a longest common substring of `a` and `b`."""
+
+def longest_palindromic_substring(s: str) -> str:
+    """Returns a longest palindromic substring of `s`."""
+
+def levenshtein(a: str, b: str) -> int:
+    """Returns the Levenshtein distance between `a` and `b`."""
+
+def damerau_levenshtein(a: str, b: str) -> int:
+    """Returns the Damerau-Levenshtein (optimal string alignment) distance between `a` and `b`."""
+
+def group_anagrams(words: list[str]) -> list[list[str]]:
+    """Groups `words` that are anagrams of each other. Each group and the list of groups are
+    sorted."""
+
+def suffix_array(s: str) -> list[int]:
+    """Returns the starting character indices of the suffixes of `s` in sorted order."""
+
+def lcp_array(s: str, suffix_array: list[int]) -> list[int]:
+    """Returns the longest common prefix lengths of adjacent suffixes in `suffix_array`."""
+
+def knapsack_01(weights: list[int], values: list[int], capacity: int) -> int:
+    """Returns the largest total value of items that fit in `capacity`, using each item at most
+    once. Raises `ValueError` if `weights` and `values` have different lengths."""
+
+def unbounded_knapsack(weights: list[int], values: list[int], capacity: int) -> int:
+    """Returns the largest total value of items that fit in `capacity`, using each item any number
+    of times. Raises `ValueError` if `weights` and `values` have different lengths."""
+
+def coin_change_ways(coins: list[int], amount: int) -> int:
+    """Returns the number of ways to make `amount` from `coins`."""
+
+def coin_change_min_coins(coins: list[int], amount: int) -> Optional[int]:
+    """Returns the fewest `coins` that make `amount`, or `None` if it can't be made."""
+
+def longest_increasing_subsequence(nums: list[int]) -> list[int]:
+    """Returns the first longest strictly increasing subsequence of `nums`."""
+
+def matrix_chain_multiplication(dims: list[int]) -> int:
+    """Returns the fewest scalar multiplications needed to multiply a chain of matrices, where
+    matrix `i` is `dims[i]` by `dims[i + 1]`. Raises `ValueError` if `dims` has fewer than 2
+    numbers."""
+
+def matrix_chain_order(dims: list[int]) -> str:
+    """Returns the optimal parenthesization of a chain of matrices, such as `((A1A2)A3)`. Raises
+    `ValueError` if `dims` has fewer than 2 numbers."""
+
+def rod_cutting(prices: list[int], length: int) -> int:
+    """Returns the largest revenue from cutting a rod of `length`, where `prices[i]` is the price
+    of a piece of length `i + 1`."""
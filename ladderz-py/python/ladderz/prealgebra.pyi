@@ -0,0 +1,44 @@
+"""Pre-algebra functions such as factors, multiples, primes and number bases.
+
+Results that are sets or maps in Rust are returned as sorted lists and dicts.
+"""
+
+def get_factor_pairs(n: int) -> list[tuple[int, int]]:
+    """Returns the factor pairs of `n`, sorted by their first factor."""
+
+def get_factors(n: int) -> list[int]:
+    """Returns the factors of `n` in ascending order."""
+
+def is_factor(x: int, y: int) -> bool:
+    """Returns whether `x` is a factor of `y`."""
+
+def is_multiple(x: int, y: int) -> bool:
+    """Returns whether `x` is a multiple of `y`."""
+
+def get_multiples_in_range(n: int, start: int, end: int) -> list[int]:
+    """Returns the multiples of `n` in the range `[start, end]` in ascending order."""
+
+def is_prime(n: int) -> bool:
+    """Returns whether `n` is prime."""
+
+def is_composite(n: int) -> bool:
+    """Returns whether `n` is composite."""
+
+def get_primes_in_range(start: int, end: int) -> list[int]:
+    """Returns the primes in the range `[start, end]` in ascending order."""
+
+def get_prime_factorization(n: int) -> dict[int, int]:
+    """Returns the prime factorization of `n` as a map from each prime to its exponent, in
+    ascending order of the primes."""
+
+def to_base(n: int, base: int) -> str:
+    """Writes `n` in a base from 2 to 36. Raises `ValueError` for other bases."""
+
+def from_base(s: str, base: int) -> int:
+    """Reads an integer written in a base from 2 to 36. Raises `ValueError` for invalid digits."""
+
+def to_roman(n: int) -> str:
+    """Writes a number from 1 to 3999 in Roman numerals. Raises `ValueError` otherwise."""
+
+def from_roman(s: str) -> int:
+    """Reads a Roman numeral in standard form. Raises `ValueError` for invalid numerals."""
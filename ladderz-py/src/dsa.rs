@@ -0,0 +1,301 @@
+use crate::value_error;
+use ladderz::dsa;
+use pyo3::prelude::*;
+
+/// Returns whether `nums` contains any value more than once.
+#[pyfunction]
+pub fn contains_duplicate(nums: Vec<i64>) -> bool {
+    dsa::contains_duplicate(&nums)
+}
+
+/// Returns whether `a` and `b` are anagrams of each other, comparing characters exactly.
+#[pyfunction]
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    dsa::is_anagram(a, b)
+}
+
+/// Returns whether `a` and `b` are anagrams of each other, ignoring case and comparing grapheme
+/// clusters after Unicode normalization.
+#[pyfunction]
+pub fn is_anagram_unicode(a: &str, b: &str) -> bool {
+    dsa::is_anagram_unicode(a, b)
+}
+
+/// Returns the indices of two numbers in `nums` that add up to `target`, or an empty list.
+#[pyfunction]
+pub fn two_sum(nums: Vec<i64>, target: i64) -> Vec<usize> {
+    dsa::two_sum(&nums, target)
+}
+
+/// Returns every pair of indices `(i, j)` with `i < j` whose numbers add up to `target`, sorted.
+#[pyfunction]
+pub fn all_two_sum_pairs(nums: Vec<i64>, target: i64) -> Vec<(usize, usize)> {
+    let mut pairs = dsa::all_two_sum_pairs(&nums, target);
+    for pair in pairs.iter_mut() {
+        *pair = (pair.0.min(pair.1), pair.0.max(pair.1));
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Returns the unique triplets in `nums` that add up to `target`, sorted.
+#[pyfunction]
+#[pyo3(signature = (nums, target = 0))]
+pub fn three_sum(nums: Vec<i64>, target: i64) -> Vec<[i64; 3]> {
+    let mut triplets = dsa::three_sum(&nums, target);
+    triplets.sort_unstable();
+    triplets
+}
+
+/// Returns the unique quadruplets in `nums` that add up to `target`, sorted.
+#[pyfunction]
+#[pyo3(signature = (nums, target = 0))]
+pub fn four_sum(nums: Vec<i64>, target: i64) -> Vec<[i64; 4]> {
+    let mut quadruplets = dsa::four_sum(&nums, target);
+    quadruplets.sort_unstable();
+    quadruplets
+}
+
+/// Returns the unique combinations of `k` numbers in `nums` that add up to `target`, sorted.
+#[pyfunction]
+pub fn k_sum(nums: Vec<i64>, k: usize, target: i64) -> Vec<Vec<i64>> {
+    let mut combinations = dsa::k_sum(&nums, k, target);
+    combinations.sort_unstable();
+    combinations
+}
+
+/// Returns the number of contiguous subarrays of `nums` that add up to `k`.
+#[pyfunction]
+pub fn subarray_sum_equals_k(nums: Vec<i64>, k: i64) -> usize {
+    dsa::subarray_sum_equals_k(&nums, k)
+}
+
+/// Returns the largest sum of a non-empty contiguous subarray with its `start` and `end`
+/// indices, where `end` is exclusive, or `None` if `nums` is empty.
+#[pyfunction]
+pub fn max_subarray(nums: Vec<i64>) -> Option<(i128, usize, usize)> {
+    dsa::max_subarray(&nums).map(|(sum, range)| (sum, range.start, range.end))
+}
+
+/// Returns the character indices where `pattern` starts in `text`, using Knuth-Morris-Pratt.
+#[pyfunction]
+pub fn kmp_search(text: &str, pattern: &str) -> Vec<usize> {
+    dsa::kmp_search(text, pattern)
+}
+
+/// Returns the character indices where `pattern` starts in `text`, using the Z-function.
+#[pyfunction]
+pub fn z_search(text: &str, pattern: &str) -> Vec<usize> {
+    dsa::z_search(text, pattern)
+}
+
+/// Returns the character indices where `pattern` starts in `text`, using Rabin-Karp.
+#[pyfunction]
+pub fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> {
+    dsa::rabin_karp(text, pattern)
+}
+
+/// Returns a longest common subsequence of `a` and `b`.
+#[pyfunction]
+pub fn longest_common_subsequence(a: &str, b: &str) -> String {
+    dsa::longest_common_subsequence(a, b)
+}
+
+/// Returns a longest common substring of `a` and `b`.
+#[pyfunction]
+pub fn longest_common_substring(a: &str, b: &str) -> String {
+    dsa::longest_common_substring(a, b)
+}
+
+/// Returns a longest palindromic substring of `s`.
+#[pyfunction]
+pub fn longest_palindromic_substring(s: &str) -> String {
+    dsa::longest_palindromic_substring(s)
+}
+
+/// Returns the Levenshtein distance between `a` and `b`.
+#[pyfunction]
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    dsa::levenshtein(a, b)
+}
+
+/// Returns the Damerau-Levenshtein (optimal string alignment) distance between `a` and `b`.
+#[pyfunction]
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    dsa::damerau_levenshtein(a, b)
+}
+
+/// Groups `words` that are anagrams of each other. Each group and the list of groups are sorted.
+#[pyfunction]
+pub fn group_anagrams(words: Vec<String>) -> Vec<Vec<String>> {
+    let mut groups = dsa::group_anagrams(&words);
+    for group in groups.iter_mut() {
+        group.sort_unstable();
+    }
+    groups.sort_unstable();
+    groups
+}
+
+/// Returns the starting character indices of the suffixes of `s` in sorted order.
+#[pyfunction]
+pub fn suffix_array(s: &str) -> Vec<usize> {
+    dsa::suffix_array(s)
+}
+
+/// Returns the longest common prefix lengths of adjacent suffixes in `suffix_array`.
+#[pyfunction]
+pub fn lcp_array(s: &str, suffix_array: Vec<usize>) -> Vec<usize> {
+    dsa::lcp_array(s, &suffix_array)
+}
+
+/// Checks that every knapsack item has both a weight and a value, which the library asserts.
+fn check_items(weights: &[usize], values: &[u64]) -> PyResult<()> {
+    match weights.len() == values.len() {
+        true => Ok(()),
+        false => Err(value_error(format!(
+            "every item needs both a weight and a value, but weights has {} items and values \
+             has {}",
+            weights.len(),
+            values.len()
+        ))),
+    }
+}
+
+/// Checks that `dims` describes at least one matrix.
+fn check_dims(dims: &[u64]) -> PyResult<()> {
+    match dims.len() >= 2 {
+        true => Ok(()),
+        false => Err(value_error(
+            "a chain of matrices needs at least 2 dimensions",
+        )),
+    }
+}
+
+/// Returns the largest total value of items that fit in `capacity`, using each item at most once.
+#[pyfunction]
+pub fn knapsack_01(weights: Vec<usize>, values: Vec<u64>, capacity: usize) -> PyResult<u64> {
+    check_items(&weights, &values)?;
+    Ok(dsa::knapsack_01(&weights, &values, capacity))
+}
+
+/// Returns the largest total value of items that fit in `capacity`, using each item any number of
+/// times.
+#[pyfunction]
+pub fn unbounded_knapsack(weights: Vec<usize>, values: Vec<u64>, capacity: usize) -> PyResult<u64> {
+    check_items(&weights, &values)?;
+    Ok(dsa::unbounded_knapsack(&weights, &values, capacity))
+}
+
+/// Returns the number of ways to make `amount` from `coins`.
+#[pyfunction]
+pub fn coin_change_ways(coins: Vec<usize>, amount: usize) -> u64 {
+    dsa::coin_change_ways(&coins, amount)
+}
+
+/// Returns the fewest `coins` that make `amount`, or `None` if it can't be made.
+#[pyfunction]
+pub fn coin_change_min_coins(coins: Vec<usize>, amount: usize) -> Option<u64> {
+    dsa::coin_change_min_coins(&coins, amount)
+}
+
+/// Returns the first longest strictly increasing subsequence of `nums`.
+#[pyfunction]
+pub fn longest_increasing_subsequence(nums: Vec<i64>) -> Vec<i64> {
+    dsa::longest_increasing_subsequence(&nums)
+}
+
+/// Returns the fewest scalar multiplications needed to multiply a chain of matrices, where matrix
+/// `i` is `dims[i]` by `dims[i + 1]`.
+#[pyfunction]
+pub fn matrix_chain_multiplication(dims: Vec<u64>) -> PyResult<u64> {
+    check_dims(&dims)?;
+    Ok(dsa::matrix_chain_multiplication(&dims))
+}
+
+/// Returns the optimal parenthesization of a chain of matrices, such as `((A1A2)A3)`.
+#[pyfunction]
+pub fn matrix_chain_order(dims: Vec<u64>) -> PyResult<String> {
+    check_dims(&dims)?;
+    Ok(dsa::matrix_chain_order(&dims))
+}
+
+/// Returns the largest revenue from cutting a rod of `length`, where `prices[i]` is the price of
+/// a piece of length `i + 1`.
+#[pyfunction]
+pub fn rod_cutting(prices: Vec<u64>, length: usize) -> u64 {
+    dsa::rod_cutting(&prices, length)
+}
+
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(contains_duplicate, m)?)?;
+    m.add_function(wrap_pyfunction!(is_anagram, m)?)?;
+    m.add_function(wrap_pyfunction!(is_anagram_unicode, m)?)?;
+    m.add_function(wrap_pyfunction!(two_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(all_two_sum_pairs, m)?)?;
+    m.add_function(wrap_pyfunction!(three_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(four_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(k_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(subarray_sum_equals_k, m)?)?;
+    m.add_function(wrap_pyfunction!(max_subarray, m)?)?;
+    m.add_function(wrap_pyfunction!(kmp_search, m)?)?;
+    m.add_function(wrap_pyfunction!(z_search, m)?)?;
+    m.add_function(wrap_pyfunction!(rabin_karp, m)?)?;
+    m.add_function(wrap_pyfunction!(longest_common_subsequence, m)?)?;
+    m.add_function(wrap_pyfunction!(longest_common_substring, m)?)?;
+    m.add_function(wrap_pyfunction!(longest_palindromic_substring, m)?)?;
+    m.add_function(wrap_pyfunction!(levenshtein, m)?)?;
+    m.add_function(wrap_pyfunction!(damerau_levenshtein, m)?)?;
+    m.add_function(wrap_pyfunction!(group_anagrams, m)?)?;
+    m.add_function(wrap_pyfunction!(suffix_array, m)?)?;
+    m.add_function(wrap_pyfunction!(lcp_array, m)?)?;
+    m.add_function(wrap_pyfunction!(knapsack_01, m)?)?;
+    m.add_function(wrap_pyfunction!(unbounded_knapsack, m)?)?;
+    m.add_function(wrap_pyfunction!(coin_change_ways, m)?)?;
+    m.add_function(wrap_pyfunction!(coin_change_min_coins, m)?)?;
+    m.add_function(wrap_pyfunction!(longest_increasing_subsequence, m)?)?;
+    m.add_function(wrap_pyfunction!(matrix_chain_multiplication, m)?)?;
+    m.add_function(wrap_pyfunction!(matrix_chain_order, m)?)?;
+    m.add_function(wrap_pyfunction!(rod_cutting, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_are_sorted() {
+        assert_eq!(all_two_sum_pairs(vec![3, 1, 2, 2], 4), vec![(0, 1), (2, 3)]);
+        assert_eq!(
+            three_sum(vec![-1, 0, 1, 2, -1, -4], 0),
+            vec![[-1, -1, 2], [-1, 0, 1]]
+        );
+        let groups = group_anagrams(
+            ["eat", "tea", "tan", "ate", "nat", "bat"]
+                .map(String::from)
+                .to_vec(),
+        );
+        assert_eq!(
+            groups,
+            vec![vec!["ate", "eat", "tea"], vec!["bat"], vec!["nat", "tan"]]
+        );
+    }
+
+    #[test]
+    fn test_max_subarray_range() {
+        assert_eq!(
+            max_subarray(vec![-2, 1, -3, 4, -1, 2, 1, -5, 4]),
+            Some((6, 3, 7))
+        );
+        assert_eq!(max_subarray(vec![]), None);
+    }
+
+    #[test]
+    fn test_invalid_dp_inputs_are_errors() {
+        assert_eq!(knapsack_01(vec![1, 3, 4], vec![15, 20, 30], 4).unwrap(), 35);
+        assert!(knapsack_01(vec![1, 2], vec![3], 5).is_err());
+        assert!(unbounded_knapsack(vec![1], vec![], 5).is_err());
+        assert!(matrix_chain_multiplication(vec![10]).is_err());
+        assert!(matrix_chain_order(vec![]).is_err());
+    }
+}
@@ -0,0 +1,49 @@
+//! # ladderz-py
+//!
+//! Python bindings for the [ladderz](https://github.com/rzmk/ladderz) library, built with
+//! [PyO3](https://pyo3.rs) and [maturin](https://www.maturin.rs).
+//!
+//! Functions that return sets or maps in Rust return sorted lists and dicts in Python, so results
+//! are the same on every run.
+//!
+//! # Example
+//!
+//! ```python
+//! from ladderz.prealgebra import get_factors
+//!
+//! print(get_factors(12))
+//! ```
+//!
+//! ```console
+//! [1, 2, 3, 4, 6, 12]
+//! ```
+//!
+//! See `ladderz-py/README.md` for how to build a wheel and run the tests.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Python bindings for [`ladderz::prealgebra`].
+pub mod prealgebra;
+
+/// Python bindings for [`ladderz::dsa`].
+pub mod dsa;
+
+/// Converts an error from the ladderz library to a Python `ValueError`.
+fn value_error(e: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// The native module, which the `ladderz` Python package re-exports as `ladderz.prealgebra` and
+/// `ladderz.dsa`.
+#[pymodule]
+fn _ladderz(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let prealgebra = PyModule::new(m.py(), "prealgebra")?;
+    prealgebra::register(&prealgebra)?;
+    m.add_submodule(&prealgebra)?;
+
+    let dsa = PyModule::new(m.py(), "dsa")?;
+    dsa::register(&dsa)?;
+    m.add_submodule(&dsa)?;
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use crate::value_error;
+use ladderz::prealgebra;
+use pyo3::prelude::*;
+use std::collections::BTreeMap;
+
+/// Returns the factor pairs of `n`, sorted by their first factor.
+#[pyfunction]
+pub fn get_factor_pairs(n: u32) -> Vec<(u32, u32)> {
+    let mut pairs: Vec<_> = prealgebra::get_factor_pairs(n).into_iter().collect();
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Returns the factors of `n` in ascending order.
+#[pyfunction]
+pub fn get_factors(n: u32) -> Vec<u32> {
+    let mut factors: Vec<_> = prealgebra::get_factors(n).into_iter().collect();
+    factors.sort_unstable();
+    factors
+}
+
+/// Returns whether `x` is a factor of `y`.
+#[pyfunction]
+pub fn is_factor(x: u32, y: u32) -> bool {
+    prealgebra::is_factor(x, y)
+}
+
+/// Returns whether `x` is a multiple of `y`.
+#[pyfunction]
+pub fn is_multiple(x: u32, y: u32) -> bool {
+    prealgebra::is_multiple(x, y)
+}
+
+/// Returns the multiples of `n` in the range `[start, end]` in ascending order.
+#[pyfunction]
+pub fn get_multiples_in_range(n: u32, start: u32, end: u32) -> Vec<u32> {
+    let mut multiples: Vec<_> = prealgebra::get_multiples_in_range(n, start, end)
+        .into_iter()
+        .collect();
+    multiples.sort_unstable();
+    multiples
+}
+
+/// Returns whether `n` is prime.
+#[pyfunction]
+pub fn is_prime(n: u32) -> bool {
+    prealgebra::is_prime(n)
+}
+
+/// Returns whether `n` is composite.
+#[pyfunction]
+pub fn is_composite(n: u32) -> bool {
+    prealgebra::is_composite(n)
+}
+
+/// Returns the primes in the range `[start, end]` in ascending order.
+#[pyfunction]
+pub fn get_primes_in_range(start: u32, end: u32) -> Vec<u32> {
+    let mut primes: Vec<_> = prealgebra::get_primes_in_range(start, end)
+        .into_iter()
+        .collect();
+    primes.sort_unstable();
+    primes
+}
+
+/// Returns the prime factorization of `n` as a map from each prime to its exponent, in
+/// ascending order of the primes.
+#[pyfunction]
+pub fn get_prime_factorization(n: u32) -> BTreeMap<u32, u32> {
+    prealgebra::get_prime_factorization(n).into_iter().collect()
+}
+
+/// Writes `n` in a base from 2 to 36.
+#[pyfunction]
+pub fn to_base(n: i64, base: u32) -> PyResult<String> {
+    prealgebra::to_base(n, base).map_err(value_error)
+}
+
+/// Reads an integer written in a base from 2 to 36.
+#[pyfunction]
+pub fn from_base(s: &str, base: u32) -> PyResult<i64> {
+    prealgebra::from_base(s, base).map_err(value_error)
+}
+
+/// Writes a number from 1 to 3999 in Roman numerals.
+#[pyfunction]
+pub fn to_roman(n: u32) -> PyResult<String> {
+    prealgebra::to_roman(n).map_err(value_error)
+}
+
+/// Reads a Roman numeral in standard form.
+#[pyfunction]
+pub fn from_roman(s: &str) -> PyResult<u32> {
+    prealgebra::from_roman(s).map_err(value_error)
+}
+
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(get_factor_pairs, m)?)?;
+    m.add_function(wrap_pyfunction!(get_factors, m)?)?;
+    m.add_function(wrap_pyfunction!(is_factor, m)?)?;
+    m.add_function(wrap_pyfunction!(is_multiple, m)?)?;
+    m.add_function(wrap_pyfunction!(get_multiples_in_range, m)?)?;
+    m.add_function(wrap_pyfunction!(is_prime, m)?)?;
+    m.add_function(wrap_pyfunction!(is_composite, m)?)?;
+    m.add_function(wrap_pyfunction!(get_primes_in_range, m)?)?;
+    m.add_function(wrap_pyfunction!(get_prime_factorization, m)?)?;
+    m.add_function(wrap_pyfunction!(to_base, m)?)?;
+    m.add_function(wrap_pyfunction!(from_base, m)?)?;
+    m.add_function(wrap_pyfunction!(to_roman, m)?)?;
+    m.add_function(wrap_pyfunction!(from_roman, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_are_sorted() {
+        assert_eq!(get_factor_pairs(12), vec![(1, 12), (2, 6), (3, 4)]);
+        assert_eq!(get_factors(12), vec![1, 2, 3, 4, 6, 12]);
+        assert_eq!(get_multiples_in_range(3, 1, 10), vec![3, 6, 9]);
+        assert_eq!(get_primes_in_range(1, 20), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+        let factorization: Vec<_> = get_prime_factorization(360).into_iter().collect();
+        assert_eq!(factorization, vec![(2, 3), (3, 2), (5, 1)]);
+    }
+}
@@ -0,0 +1,92 @@
+import pytest
+
+from ladderz import dsa
+from ladderz.dsa import (
+    all_two_sum_pairs,
+    coin_change_min_coins,
+    coin_change_ways,
+    contains_duplicate,
+    four_sum,
+    group_anagrams,
+    is_anagram,
+    is_anagram_unicode,
+    k_sum,
+    kmp_search,
+    knapsack_01,
+    lcp_array,
+    levenshtein,
+    longest_common_subsequence,
+    longest_increasing_subsequence,
+    matrix_chain_multiplication,
+    matrix_chain_order,
+    max_subarray,
+    rod_cutting,
+    suffix_array,
+    three_sum,
+    two_sum,
+    unbounded_knapsack,
+)
+
+
+def test_submodule_import():
+    import ladderz.dsa
+
+    assert ladderz.dsa is dsa
+
+
+def test_duplicates_and_anagrams():
+    assert contains_duplicate([2, 3, 4, 2])
+    assert not contains_duplicate([])
+    assert is_anagram("listen", "silent")
+    assert not is_anagram("Listen", "silent")
+    assert is_anagram_unicode("Listen", "silent")
+    assert group_anagrams(["eat", "tea", "tan", "ate", "nat", "bat"]) == [
+        ["ate", "eat", "tea"],
+        ["bat"],
+        ["nat", "tan"],
+    ]
+
+
+def test_k_sum_results_are_sorted():
+    assert sorted(two_sum([2, 7, 11, 15], 9)) == [0, 1]
+    assert two_sum([1, 2], 10) == []
+    assert all_two_sum_pairs([3, 1, 2, 2], 4) == [(0, 1), (2, 3)]
+    assert three_sum([-1, 0, 1, 2, -1, -4]) == [[-1, -1, 2], [-1, 0, 1]]
+    assert four_sum([1, 0, -1, 0, -2, 2]) == [[-2, -1, 1, 2], [-2, 0, 0, 2], [-1, 0, 0, 1]]
+    assert k_sum([1, 2, 3, 4], 2, 5) == [[1, 4], [2, 3]]
+
+
+def test_max_subarray():
+    assert max_subarray([-2, 1, -3, 4, -1, 2, 1, -5, 4]) == (6, 3, 7)
+    assert max_subarray([]) is None
+
+
+def test_strings():
+    assert kmp_search("abababa", "aba") == [0, 2, 4]
+    assert levenshtein("kitten", "sitting") == 3
+    assert longest_common_subsequence("ABCBDAB", "BDCABA") in {"BCBA", "BDAB", "BCAB"}
+    sa = suffix_array("banana")
+    assert sa == [5, 3, 1, 0, 4, 2]
+    assert lcp_array("banana", sa) == [0, 1, 3, 0, 0, 2]
+
+
+def test_dynamic_programming():
+    assert knapsack_01([1, 3, 4, 5], [1, 4, 5, 7], 7) == 9
+    assert coin_change_ways([1, 2, 5], 5) == 4
+    assert coin_change_min_coins([1, 2, 5], 11) == 3
+    assert coin_change_min_coins([2], 3) is None
+    assert longest_increasing_subsequence([10, 9, 2, 5, 3, 7, 101, 18]) == [2, 5, 7, 101]
+    assert matrix_chain_multiplication([10, 30, 5, 60]) == 4500
+    assert matrix_chain_order([10, 30, 5, 60]) == "((A1A2)A3)"
+    assert rod_cutting([1, 5, 8, 9, 10, 17, 17, 20], 8) == 22
+
+
+def test_invalid_dynamic_programming_input_raises():
+    with pytest.raises(ValueError, match="weight and a value"):
+        knapsack_01([1, 2], [3], 5)
+    with pytest.raises(ValueError, match="weight and a value"):
+        unbounded_knapsack([1], [], 5)
+    with pytest.raises(ValueError, match="at least 2 dimensions"):
+        matrix_chain_multiplication([10])
+    with pytest.raises(ValueError, match="at least 2 dimensions"):
+        matrix_chain_order([])
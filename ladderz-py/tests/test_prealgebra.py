@@ -0,0 +1,71 @@
+import pytest
+
+from ladderz import prealgebra
+from ladderz.prealgebra import (
+    from_base,
+    from_roman,
+    get_factor_pairs,
+    get_factors,
+    get_multiples_in_range,
+    get_prime_factorization,
+    get_primes_in_range,
+    is_composite,
+    is_factor,
+    is_multiple,
+    is_prime,
+    to_base,
+    to_roman,
+)
+
+
+def test_submodule_import():
+    import ladderz.prealgebra
+
+    assert ladderz.prealgebra is prealgebra
+
+
+def test_factors_are_sorted():
+    assert get_factor_pairs(12) == [(1, 12), (2, 6), (3, 4)]
+    assert get_factors(12) == [1, 2, 3, 4, 6, 12]
+    assert get_factors(1) == [1]
+
+
+def test_factor_and_multiple():
+    assert is_factor(3, 12)
+    assert not is_factor(5, 12)
+    assert is_multiple(12, 3)
+    assert not is_multiple(12, 5)
+
+
+def test_ranges_are_sorted():
+    assert get_multiples_in_range(3, 1, 20) == [3, 6, 9, 12, 15, 18]
+    assert get_primes_in_range(1, 30) == [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+
+
+def test_primes():
+    assert is_prime(97)
+    assert not is_prime(1)
+    assert is_composite(91)
+    assert not is_composite(2)
+
+
+def test_prime_factorization_is_ordered_dict():
+    factorization = get_prime_factorization(360)
+    assert factorization == {2: 3, 3: 2, 5: 1}
+    assert list(factorization) == [2, 3, 5]
+
+
+def test_bases_and_roman_numerals():
+    assert to_base(255, 16) == "FF"
+    assert from_base("-1010", 2) == -10
+    assert to_roman(1994) == "MCMXCIV"
+    assert from_roman("mcmxciv") == 1994
+
+
+def test_invalid_input_raises():
+    with pytest.raises(ValueError, match="base 1"):
+        to_base(10, 1)
+    with pytest.raises(ValueError):
+        from_roman("IIII")
+    with pytest.raises(OverflowError):
+        get_factors(-1)
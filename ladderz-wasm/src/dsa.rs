@@ -0,0 +1,287 @@
+use crate::{to_integer, to_integers, to_js, to_number};
+use ladderz::dsa;
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// Returns whether `nums` contains any value more than once.
+#[wasm_bindgen(js_name = containsDuplicate)]
+pub fn contains_duplicate(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] nums: &[f64],
+) -> Result<bool, JsError> {
+    Ok(dsa::contains_duplicate(&to_integers(nums)?))
+}
+
+/// Returns whether `a` and `b` are anagrams of each other, comparing characters exactly.
+#[wasm_bindgen(js_name = isAnagram)]
+pub fn is_anagram(a: &str, b: &str) -> bool {
+    dsa::is_anagram(a, b)
+}
+
+/// Returns whether `a` and `b` are anagrams of each other, ignoring case and comparing grapheme
+/// clusters after Unicode normalization.
+#[wasm_bindgen(js_name = isAnagramUnicode)]
+pub fn is_anagram_unicode(a: &str, b: &str) -> bool {
+    dsa::is_anagram_unicode(a, b)
+}
+
+/// Returns the indices of two numbers in `nums` that add up to `target`, or an empty array.
+#[wasm_bindgen(js_name = twoSum, unchecked_return_type = "number[]")]
+pub fn two_sum(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] nums: &[f64],
+    target: f64,
+) -> Result<JsValue, JsError> {
+    let target = to_integer(target)?;
+    to_js(&dsa::two_sum(&to_integers(nums)?, target))
+}
+
+/// Returns every pair of indices `[i, j]` with `i < j` whose numbers add up to `target`, sorted.
+#[wasm_bindgen(js_name = allTwoSumPairs, unchecked_return_type = "[number, number][]")]
+pub fn all_two_sum_pairs(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] nums: &[f64],
+    target: f64,
+) -> Result<JsValue, JsError> {
+    let target = to_integer(target)?;
+    let mut pairs = dsa::all_two_sum_pairs(&to_integers(nums)?, target);
+    for pair in pairs.iter_mut() {
+        *pair = (pair.0.min(pair.1), pair.0.max(pair.1));
+    }
+    pairs.sort_unstable();
+    to_js(&pairs)
+}
+
+/// Returns the unique combinations of `k` numbers in `nums` that add up to `target`, sorted.
+#[wasm_bindgen(js_name = kSum, unchecked_return_type = "number[][]")]
+pub fn k_sum(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] nums: &[f64],
+    k: u32,
+    target: f64,
+) -> Result<JsValue, JsError> {
+    let target = to_integer(target)?;
+    let mut combinations = dsa::k_sum(&to_integers(nums)?, k as usize, target);
+    combinations.sort_unstable();
+    to_js(&combinations)
+}
+
+/// Returns the number of contiguous subarrays of `nums` that add up to `k`.
+#[wasm_bindgen(js_name = subarraySumEqualsK)]
+pub fn subarray_sum_equals_k(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] nums: &[f64],
+    k: f64,
+) -> Result<u32, JsError> {
+    let k = to_integer(k)?;
+    Ok(dsa::subarray_sum_equals_k(&to_integers(nums)?, k) as u32)
+}
+
+/// The largest sum of a contiguous subarray and where it is.
+#[derive(Serialize)]
+struct MaxSubarray {
+    sum: f64,
+    start: usize,
+    end: usize,
+}
+
+/// Returns the largest sum of a non-empty contiguous subarray as `{ sum, start, end }`, where
+/// `end` is exclusive, or `undefined` if `nums` is empty.
+#[wasm_bindgen(
+    js_name = maxSubarray,
+    unchecked_return_type = "{ sum: number, start: number, end: number } | undefined"
+)]
+pub fn max_subarray(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] nums: &[f64],
+) -> Result<JsValue, JsError> {
+    let max = match dsa::max_subarray(&to_integers(nums)?) {
+        Some((sum, range)) => Some(MaxSubarray {
+            sum: to_number(sum)?,
+            start: range.start,
+            end: range.end,
+        }),
+        None => None,
+    };
+    to_js(&max)
+}
+
+/// Returns the character indices where `pattern` starts in `text`, using Knuth-Morris-Pratt.
+#[wasm_bindgen(js_name = kmpSearch, unchecked_return_type = "number[]")]
+pub fn kmp_search(text: &str, pattern: &str) -> Result<JsValue, JsError> {
+    to_js(&dsa::kmp_search(text, pattern))
+}
+
+/// Returns the character indices where `pattern` starts in `text`, using the Z-function.
+#[wasm_bindgen(js_name = zSearch, unchecked_return_type = "number[]")]
+pub fn z_search(text: &str, pattern: &str) -> Result<JsValue, JsError> {
+    to_js(&dsa::z_search(text, pattern))
+}
+
+/// Returns the character indices where `pattern` starts in `text`, using Rabin-Karp.
+#[wasm_bindgen(js_name = rabinKarp, unchecked_return_type = "number[]")]
+pub fn rabin_karp(text: &str, pattern: &str) -> Result<JsValue, JsError> {
+    to_js(&dsa::rabin_karp(text, pattern))
+}
+
+/// Returns a longest common subsequence of `a` and `b`.
+#[wasm_bindgen(js_name = longestCommonSubsequence)]
+pub fn longest_common_subsequence(a: &str, b: &str) -> String {
+    dsa::longest_common_subsequence(a, b)
+}
+
+/// Returns a longest common substring of `a` and `b`.
+#[wasm_bindgen(js_name = longestCommonSubstring)]
+pub fn longest_common_substring(a: &str, b: &str) -> String {
+    dsa::longest_common_substring(a, b)
+}
+
+/// Returns a longest palindromic substring of `s`.
+#[wasm_bindgen(js_name = longestPalindromicSubstring)]
+pub fn longest_palindromic_substring(s: &str) -> String {
+    dsa::longest_palindromic_substring(s)
+}
+
+/// Returns the Levenshtein distance between `a` and `b`.
+#[wasm_bindgen]
+pub fn levenshtein(a: &str, b: &str) -> u32 {
+    dsa::levenshtein(a, b) as u32
+}
+
+/// Returns the Levenshtein alignment of `a` and `b` as three lines: `a` with gaps, a marker line,
+/// and `b` with gaps.
+#[wasm_bindgen(js_name = levenshteinAlignment)]
+pub fn levenshtein_alignment(a: &str, b: &str) -> String {
+    dsa::levenshtein_alignment(a, b).to_string()
+}
+
+/// Returns the Damerau-Levenshtein (optimal string alignment) distance between `a` and `b`.
+#[wasm_bindgen(js_name = damerauLevenshtein)]
+pub fn damerau_levenshtein(a: &str, b: &str) -> u32 {
+    dsa::damerau_levenshtein(a, b) as u32
+}
+
+/// Groups `words` that are anagrams of each other. Each group and the array of groups are
+/// sorted.
+#[wasm_bindgen(js_name = groupAnagrams, unchecked_return_type = "string[][]")]
+pub fn group_anagrams(words: Vec<String>) -> Result<JsValue, JsError> {
+    let mut groups = dsa::group_anagrams(&words);
+    for group in groups.iter_mut() {
+        group.sort_unstable();
+    }
+    groups.sort_unstable();
+    to_js(&groups)
+}
+
+/// Returns the starting character indices of the suffixes of `s` in sorted order.
+#[wasm_bindgen(js_name = suffixArray, unchecked_return_type = "number[]")]
+pub fn suffix_array(s: &str) -> Result<JsValue, JsError> {
+    to_js(&dsa::suffix_array(s))
+}
+
+/// Returns the longest common prefix lengths of adjacent suffixes of `s`, in suffix array order.
+#[wasm_bindgen(js_name = lcpArray, unchecked_return_type = "number[]")]
+pub fn lcp_array(s: &str) -> Result<JsValue, JsError> {
+    to_js(&dsa::lcp_array(s, &dsa::suffix_array(s)))
+}
+
+/// Converts JavaScript sizes to `usize`s.
+fn to_sizes(sizes: &[u32]) -> Vec<usize> {
+    sizes.iter().map(|&size| size as usize).collect()
+}
+
+/// Returns an error unless every knapsack item has both a weight and a value, which the library
+/// asserts.
+fn check_items(weights: usize, values: usize) -> Result<(), JsError> {
+    match weights == values {
+        true => Ok(()),
+        false => Err(JsError::new(&format!(
+            "every item needs both a weight and a value, but weights has {weights} items and \
+             values has {values}"
+        ))),
+    }
+}
+
+/// Returns the largest total value of items that fit in `capacity`, using each item at most once.
+#[wasm_bindgen(js_name = knapsack01)]
+pub fn knapsack_01(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] weights: &[u32],
+    #[wasm_bindgen(unchecked_param_type = "number[]")] values: &[u32],
+    capacity: u32,
+) -> Result<f64, JsError> {
+    check_items(weights.len(), values.len())?;
+    let values: Vec<u64> = values.iter().map(|&value| value as u64).collect();
+    Ok(dsa::knapsack_01(&to_sizes(weights), &values, capacity as usize) as f64)
+}
+
+/// Returns the number of ways to make `amount` from `coins`.
+#[wasm_bindgen(js_name = coinChangeWays)]
+pub fn coin_change_ways(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] coins: &[u32],
+    amount: u32,
+) -> f64 {
+    dsa::coin_change_ways(&to_sizes(coins), amount as usize) as f64
+}
+
+/// Returns the fewest `coins` that make `amount`, or `undefined` if it can't be made.
+#[wasm_bindgen(js_name = coinChangeMinCoins)]
+pub fn coin_change_min_coins(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] coins: &[u32],
+    amount: u32,
+) -> Option<f64> {
+    dsa::coin_change_min_coins(&to_sizes(coins), amount as usize).map(|count| count as f64)
+}
+
+/// Returns the first longest strictly increasing subsequence of `nums`.
+#[wasm_bindgen(js_name = longestIncreasingSubsequence, unchecked_return_type = "number[]")]
+pub fn longest_increasing_subsequence(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] nums: &[f64],
+) -> Result<JsValue, JsError> {
+    to_js(&dsa::longest_increasing_subsequence(&to_integers(nums)?))
+}
+
+/// Returns the optimal parenthesization of a chain of matrices, such as `((A1A2)A3)`, where
+/// matrix `i` is `dims[i]` by `dims[i + 1]`.
+#[wasm_bindgen(js_name = matrixChainOrder)]
+pub fn matrix_chain_order(
+    #[wasm_bindgen(unchecked_param_type = "number[]")] dims: &[u32],
+) -> Result<String, JsError> {
+    if dims.len() < 2 {
+        return Err(JsError::new(
+            "a chain of matrices needs at least 2 dimensions",
+        ));
+    }
+    let dims: Vec<u64> = dims.iter().map(|&dim| dim as u64).collect();
+    Ok(dsa::matrix_chain_order(&dims))
+}
+
+/// Returns the table for a dynamic programming problem as text, for showing how it was solved.
+///
+/// `problem` is one of `knapsack`, `coin-change`, `lis` or `edit-distance`. `a` and `b` are
+/// comma-separated numbers (or strings for `edit-distance`), and `n` is the capacity or amount.
+#[wasm_bindgen(js_name = dpTable)]
+pub fn dp_table(problem: &str, a: &str, b: &str, n: u32) -> Result<String, JsError> {
+    let numbers = |s: &str| -> Result<Vec<u64>, JsError> {
+        s.split(',')
+            .filter(|part| !part.trim().is_empty())
+            .map(|part| {
+                part.trim()
+                    .parse::<u64>()
+                    .map_err(|_| JsError::new(&format!("{part:?} is not a nonnegative integer")))
+            })
+            .collect()
+    };
+    let sizes = |s: &str| -> Result<Vec<usize>, JsError> {
+        Ok(numbers(s)?.into_iter().map(|n| n as usize).collect())
+    };
+    let table = match problem {
+        "knapsack" => {
+            let (weights, values) = (sizes(a)?, numbers(b)?);
+            check_items(weights.len(), values.len())?;
+            dsa::knapsack_01_table(&weights, &values, n as usize)
+        }
+        "coin-change" => dsa::coin_change_ways_table(&sizes(a)?, n as usize),
+        "lis" => dsa::longest_increasing_subsequence_table(&numbers(a)?),
+        "edit-distance" => dsa::edit_distance_table(a, b),
+        _ => {
+            return Err(JsError::new(&format!(
+                "unknown problem {problem:?}, expected knapsack, coin-change, lis or edit-distance"
+            )))
+        }
+    };
+    Ok(table.to_string())
+}
@@ -0,0 +1,69 @@
+//! # ladderz-wasm
+//!
+//! WebAssembly bindings for the [ladderz](https://github.com/rzmk/ladderz) library, built with
+//! [wasm-bindgen](https://rustwasm.github.io/docs/wasm-bindgen/).
+//!
+//! Functions take and return JavaScript-friendly types: numbers instead of 64-bit integers, and
+//! plain arrays and objects instead of `HashSet`s and `HashMap`s. Sets are returned as sorted
+//! arrays, and maps as objects with sorted keys, so results are the same on every run.
+//!
+//! # Example
+//!
+//! ```js
+//! import init, { getFactors, getPrimeFactorization } from "./pkg/ladderz_wasm.js";
+//!
+//! await init();
+//! console.log(getFactors(12));
+//! console.log(getPrimeFactorization(360));
+//! ```
+//!
+//! ```console
+//! [1, 2, 3, 4, 6, 12]
+//! { "2": 3, "3": 2, "5": 1 }
+//! ```
+//!
+//! See `ladderz-wasm/README.md` for how to build the package, open the demo page and run the
+//! tests.
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// WebAssembly bindings for [`ladderz::prealgebra`].
+pub mod prealgebra;
+
+/// WebAssembly bindings for [`ladderz::dsa`].
+pub mod dsa;
+
+/// The largest integer a JavaScript number holds exactly, `Number.MAX_SAFE_INTEGER`.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// Converts a value to JavaScript, with sequences as arrays and maps as plain objects.
+fn to_js<T: Serialize + ?Sized>(value: &T) -> Result<JsValue, JsError> {
+    let serializer = serde_wasm_bindgen::Serializer::new().serialize_maps_as_objects(true);
+    Ok(value.serialize(&serializer)?)
+}
+
+/// Converts a JavaScript number to an integer, or returns an error if it isn't a safe integer.
+fn to_integer(x: f64) -> Result<i64, JsError> {
+    match x.fract() == 0.0 && x.abs() <= MAX_SAFE_INTEGER {
+        true => Ok(x as i64),
+        false => Err(JsError::new(&format!("{x} is not a safe integer"))),
+    }
+}
+
+/// Converts an array of JavaScript numbers to integers, or returns an error for the first one that
+/// isn't a safe integer.
+fn to_integers(nums: &[f64]) -> Result<Vec<i64>, JsError> {
+    nums.iter().map(|&x| to_integer(x)).collect()
+}
+
+/// Converts an integer to a JavaScript number, or returns an error if it can't be held exactly.
+fn to_number(n: impl Into<i128>) -> Result<f64, JsError> {
+    let n: i128 = n.into();
+    match (n.unsigned_abs() as f64) <= MAX_SAFE_INTEGER {
+        true => Ok(n as f64),
+        false => Err(JsError::new(&format!(
+            "{n} is too large to be a safe JavaScript integer"
+        ))),
+    }
+}
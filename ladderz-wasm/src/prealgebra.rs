@@ -0,0 +1,102 @@
+use crate::{to_integer, to_js, to_number};
+use ladderz::prealgebra;
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+
+/// Returns the factor pairs of `n`, sorted by their first factor.
+#[wasm_bindgen(js_name = getFactorPairs, unchecked_return_type = "[number, number][]")]
+pub fn get_factor_pairs(n: u32) -> Result<JsValue, JsError> {
+    let mut pairs: Vec<_> = prealgebra::get_factor_pairs(n).into_iter().collect();
+    pairs.sort_unstable();
+    to_js(&pairs)
+}
+
+/// Returns the factors of `n` in ascending order.
+#[wasm_bindgen(js_name = getFactors, unchecked_return_type = "number[]")]
+pub fn get_factors(n: u32) -> Result<JsValue, JsError> {
+    let mut factors: Vec<_> = prealgebra::get_factors(n).into_iter().collect();
+    factors.sort_unstable();
+    to_js(&factors)
+}
+
+/// Returns whether `x` is a factor of `y`.
+#[wasm_bindgen(js_name = isFactor)]
+pub fn is_factor(x: u32, y: u32) -> bool {
+    prealgebra::is_factor(x, y)
+}
+
+/// Returns whether `x` is a multiple of `y`.
+#[wasm_bindgen(js_name = isMultiple)]
+pub fn is_multiple(x: u32, y: u32) -> bool {
+    prealgebra::is_multiple(x, y)
+}
+
+/// Returns the multiples of `n` in the range `[start, end]` in ascending order.
+#[wasm_bindgen(js_name = getMultiplesInRange, unchecked_return_type = "number[]")]
+pub fn get_multiples_in_range(n: u32, start: u32, end: u32) -> Result<JsValue, JsError> {
+    let mut multiples: Vec<_> = prealgebra::get_multiples_in_range(n, start, end)
+        .into_iter()
+        .collect();
+    multiples.sort_unstable();
+    to_js(&multiples)
+}
+
+/// Returns whether `n` is prime.
+#[wasm_bindgen(js_name = isPrime)]
+pub fn is_prime(n: u32) -> bool {
+    prealgebra::is_prime(n)
+}
+
+/// Returns whether `n` is composite.
+#[wasm_bindgen(js_name = isComposite)]
+pub fn is_composite(n: u32) -> bool {
+    prealgebra::is_composite(n)
+}
+
+/// Returns the primes in the range `[start, end]` in ascending order.
+#[wasm_bindgen(js_name = getPrimesInRange, unchecked_return_type = "number[]")]
+pub fn get_primes_in_range(start: u32, end: u32) -> Result<JsValue, JsError> {
+    let mut primes: Vec<_> = prealgebra::get_primes_in_range(start, end)
+        .into_iter()
+        .collect();
+    primes.sort_unstable();
+    to_js(&primes)
+}
+
+/// Returns the prime factorization of `n` as an object from each prime to its exponent.
+#[wasm_bindgen(
+    js_name = getPrimeFactorization,
+    unchecked_return_type = "Record<number, number>"
+)]
+pub fn get_prime_factorization(n: u32) -> Result<JsValue, JsError> {
+    // Object keys are strings in JavaScript, which still lists integer keys in ascending order.
+    let factorization: BTreeMap<_, _> = prealgebra::get_prime_factorization(n)
+        .into_iter()
+        .map(|(prime, exponent)| (prime.to_string(), exponent))
+        .collect();
+    to_js(&factorization)
+}
+
+/// Writes the integer `n` in a base from 2 to 36.
+#[wasm_bindgen(js_name = toBase)]
+pub fn to_base(n: f64, base: u32) -> Result<String, JsError> {
+    Ok(prealgebra::to_base(to_integer(n)?, base)?)
+}
+
+/// Reads an integer written in a base from 2 to 36.
+#[wasm_bindgen(js_name = fromBase)]
+pub fn from_base(s: &str, base: u32) -> Result<f64, JsError> {
+    to_number(prealgebra::from_base(s, base)?)
+}
+
+/// Writes a number from 1 to 3999 in Roman numerals.
+#[wasm_bindgen(js_name = toRoman)]
+pub fn to_roman(n: u32) -> Result<String, JsError> {
+    Ok(prealgebra::to_roman(n)?)
+}
+
+/// Reads a Roman numeral in standard form.
+#[wasm_bindgen(js_name = fromRoman)]
+pub fn from_roman(s: &str) -> Result<u32, JsError> {
+    Ok(prealgebra::from_roman(s)?)
+}
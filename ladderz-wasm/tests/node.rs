@@ -0,0 +1,126 @@
+//! Tests for the JavaScript bindings, run under Node with
+//! `cargo test -p ladderz-wasm --target wasm32-unknown-unknown`.
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::JSON;
+use ladderz_wasm::{dsa, prealgebra};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+/// Returns the JSON for a value returned to JavaScript, to check it's a plain array or object.
+fn json(value: Result<JsValue, wasm_bindgen::JsError>) -> String {
+    let value = value.unwrap_or_else(|_| panic!("expected a value"));
+    JSON::stringify(&value).unwrap().into()
+}
+
+#[wasm_bindgen_test]
+fn test_sets_are_sorted_arrays() {
+    assert_eq!(json(prealgebra::get_factors(12)), "[1,2,3,4,6,12]");
+    assert_eq!(
+        json(prealgebra::get_factor_pairs(12)),
+        "[[1,12],[2,6],[3,4]]"
+    );
+    assert_eq!(
+        json(prealgebra::get_multiples_in_range(3, 1, 10)),
+        "[3,6,9]"
+    );
+    assert_eq!(
+        json(prealgebra::get_primes_in_range(1, 20)),
+        "[2,3,5,7,11,13,17,19]"
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_maps_are_plain_objects() {
+    assert_eq!(
+        json(prealgebra::get_prime_factorization(360)),
+        r#"{"2":3,"3":2,"5":1}"#
+    );
+    assert_eq!(json(prealgebra::get_prime_factorization(1)), "{}");
+}
+
+#[wasm_bindgen_test]
+fn test_predicates() {
+    assert!(prealgebra::is_factor(3, 12));
+    assert!(prealgebra::is_multiple(12, 3));
+    assert!(prealgebra::is_prime(97));
+    assert!(prealgebra::is_composite(91));
+}
+
+#[wasm_bindgen_test]
+fn test_bases_and_errors() {
+    assert_eq!(prealgebra::to_base(-255.0, 16).ok().unwrap(), "-FF");
+    assert_eq!(prealgebra::from_base("zz", 36).ok().unwrap(), 1295.0);
+    assert_eq!(prealgebra::to_roman(1994).ok().unwrap(), "MCMXCIV");
+    assert!(prealgebra::to_base(1.5, 10).is_err());
+    assert!(prealgebra::from_base("12", 2).is_err());
+    assert!(prealgebra::from_roman("IIII").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_k_sum_and_subarrays() {
+    assert!(dsa::contains_duplicate(&[2.0, 3.0, 4.0, 2.0]).ok().unwrap());
+    assert_eq!(
+        json(dsa::all_two_sum_pairs(&[3.0, 1.0, 2.0, 2.0], 4.0)),
+        "[[0,1],[2,3]]"
+    );
+    assert_eq!(
+        json(dsa::k_sum(&[-1.0, 0.0, 1.0, 2.0, -1.0, -4.0], 3, 0.0)),
+        "[[-1,-1,2],[-1,0,1]]"
+    );
+    assert_eq!(
+        json(dsa::max_subarray(&[
+            -2.0, 1.0, -3.0, 4.0, -1.0, 2.0, 1.0, -5.0, 4.0
+        ])),
+        r#"{"sum":6,"start":3,"end":7}"#
+    );
+    assert!(dsa::max_subarray(&[]).ok().unwrap().is_undefined());
+    assert_eq!(json(dsa::two_sum(&[2.0, 7.0, 11.0], 9.0)), "[1,0]");
+    assert_eq!(json(dsa::two_sum(&[1.0, 2.0], 10.0)), "[]");
+    assert!(dsa::two_sum(&[0.5], 1.0).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_strings() {
+    assert_eq!(json(dsa::kmp_search("abababa", "aba")), "[0,2,4]");
+    assert_eq!(dsa::levenshtein("kitten", "sitting"), 3);
+    assert_eq!(
+        dsa::levenshtein_alignment("kitten", "sitting"),
+        "kitten-\n.|||.| \nsitting"
+    );
+    assert_eq!(json(dsa::suffix_array("banana")), "[5,3,1,0,4,2]");
+    assert_eq!(json(dsa::lcp_array("banana")), "[0,1,3,0,0,2]");
+    let words = ["eat", "tea", "tan", "ate", "nat", "bat"].map(String::from);
+    assert_eq!(
+        json(dsa::group_anagrams(words.to_vec())),
+        r#"[["ate","eat","tea"],["bat"],["nat","tan"]]"#
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_dynamic_programming() {
+    assert_eq!(
+        dsa::knapsack_01(&[1, 3, 4, 5], &[1, 4, 5, 7], 7).ok(),
+        Some(9.0)
+    );
+    assert!(dsa::knapsack_01(&[1, 2], &[3], 5).is_err());
+    assert_eq!(dsa::coin_change_ways(&[1, 2, 5], 5), 4.0);
+    assert_eq!(dsa::coin_change_min_coins(&[2], 3), None);
+    assert_eq!(
+        json(dsa::longest_increasing_subsequence(&[
+            10.0, 9.0, 2.0, 5.0, 3.0, 7.0, 101.0, 18.0
+        ])),
+        "[2,5,7,101]"
+    );
+    assert_eq!(
+        dsa::matrix_chain_order(&[10, 30, 5, 60]).ok().unwrap(),
+        "((A1A2)A3)"
+    );
+    assert!(dsa::matrix_chain_order(&[10]).is_err());
+    assert!(dsa::dp_table("edit-distance", "ab", "b", 0)
+        .ok()
+        .unwrap()
+        .contains('ε'));
+    assert!(dsa::dp_table("unknown", "", "", 0).is_err());
+    assert!(dsa::dp_table("knapsack", "1,2", "3", 5).is_err());
+}
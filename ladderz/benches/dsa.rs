@@ -0,0 +1,707 @@
+//! Benchmarks every public function in `ladderz::dsa`, and the main operations of each data
+//! structure, across input sizes.
+//!
+//! Run with `cargo bench -p ladderz --bench dsa`. To check a change for regressions, save a
+//! baseline before it with `-- --save-baseline main` and compare against it after with
+//! `-- --baseline main`.
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use ladderz::dsa::*;
+use std::hint::black_box;
+
+/// The sizes of inputs to algorithms that take linear or log-linear time.
+const LINEAR: [usize; 3] = [100, 1_000, 10_000];
+/// The sizes of inputs to algorithms that take quadratic time or more.
+const QUADRATIC: [usize; 3] = [10, 100, 1_000];
+
+/// Returns `n` pseudo-random numbers in `0..bound`, the same on every run so a baseline is
+/// compared against the same inputs.
+fn numbers(n: usize, bound: u64) -> Vec<i64> {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    (0..n)
+        .map(|_| {
+            // Knuth's MMIX linear congruential generator.
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 33) % bound) as i64
+        })
+        .collect()
+}
+
+/// Returns `n` pseudo-random lowercase letters from the first `letters` of the alphabet.
+fn text(n: usize, letters: u8) -> String {
+    numbers(n, letters as u64)
+        .into_iter()
+        .map(|i| (b'a' + i as u8) as char)
+        .collect()
+}
+
+/// Benchmarks `f` on the input `setup` makes for each of `sizes`, in a group called `name`.
+fn bench_sizes<I, O>(
+    c: &mut Criterion,
+    name: &str,
+    sizes: impl IntoIterator<Item = usize>,
+    setup: impl Fn(usize) -> I,
+    f: impl Fn(&I) -> O,
+) {
+    let mut group = c.benchmark_group(name);
+    for size in sizes {
+        let input = setup(size);
+        group.bench_with_input(BenchmarkId::from_parameter(size), &input, |b, input| {
+            b.iter(|| f(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+/// Benchmarks `f` on a new input from `setup` for each run and each of `sizes`, for operations
+/// that change their input such as removing from a tree.
+fn bench_sizes_mut<I, O>(
+    c: &mut Criterion,
+    name: &str,
+    sizes: impl IntoIterator<Item = usize>,
+    setup: impl Fn(usize) -> I,
+    f: impl Fn(I) -> O,
+) {
+    let mut group = c.benchmark_group(name);
+    for size in sizes {
+        group.bench_function(BenchmarkId::from_parameter(size), |b| {
+            b.iter_batched(|| setup(size), &f, BatchSize::SmallInput)
+        });
+    }
+    group.finish();
+}
+
+fn arrays(c: &mut Criterion) {
+    // Distinct numbers, so every one is checked before answering.
+    bench_sizes(
+        c,
+        "contains_duplicate",
+        LINEAR,
+        |n| (0..n as i64).collect::<Vec<i64>>(),
+        |nums| contains_duplicate(nums),
+    );
+    // The only pair is the last two numbers.
+    let no_early_pair = |n: usize| {
+        let mut nums: Vec<i64> = (0..n as i64).map(|i| 4 * i).collect();
+        nums.extend([1, 2]);
+        nums
+    };
+    bench_sizes(c, "two_sum", LINEAR, no_early_pair, |nums| two_sum(nums, 3));
+    bench_sizes(
+        c,
+        "all_two_sum_pairs",
+        LINEAR,
+        |n| numbers(n, 100),
+        |nums| all_two_sum_pairs(nums, 100),
+    );
+    bench_sizes(
+        c,
+        "two_sum_sorted",
+        LINEAR,
+        |n| (0..n as i64).collect::<Vec<i64>>(),
+        |nums| two_sum_sorted(nums, -1),
+    );
+    bench_sizes(
+        c,
+        "three_sum",
+        QUADRATIC,
+        |n| {
+            numbers(n, 200)
+                .iter()
+                .map(|x| x - 100)
+                .collect::<Vec<i64>>()
+        },
+        |nums| three_sum(nums, 0),
+    );
+    bench_sizes(
+        c,
+        "four_sum",
+        [10, 50, 200],
+        |n| {
+            numbers(n, 200)
+                .iter()
+                .map(|x| x - 100)
+                .collect::<Vec<i64>>()
+        },
+        |nums| four_sum(nums, 0),
+    );
+    bench_sizes(
+        c,
+        "k_sum",
+        [10, 30, 60],
+        |n| {
+            numbers(n, 200)
+                .iter()
+                .map(|x| x - 100)
+                .collect::<Vec<i64>>()
+        },
+        |nums| k_sum(nums, 5, 0),
+    );
+    bench_sizes(
+        c,
+        "subarray_sum_equals_k",
+        LINEAR,
+        |n| numbers(n, 21).iter().map(|x| x - 10).collect::<Vec<i64>>(),
+        |nums| subarray_sum_equals_k(nums, 7),
+    );
+    bench_sizes(
+        c,
+        "max_subarray",
+        LINEAR,
+        |n| numbers(n, 21).iter().map(|x| x - 10).collect::<Vec<i64>>(),
+        |nums| max_subarray(nums),
+    );
+}
+
+/// Compares the anagram checks on the same words, where `is_anagram2` can stop early on words
+/// of different lengths.
+fn anagrams(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_anagram");
+    for n in LINEAR {
+        let a = text(n, 26);
+        let anagram: String = a.chars().rev().collect();
+        let longer = format!("{a}a");
+        for (case, b) in [("anagram", &anagram), ("different length", &longer)] {
+            let words = (a.as_str(), b.as_str());
+            let id = |function| BenchmarkId::new(format!("{function}/{case}"), n);
+            group.bench_with_input(id("is_anagram"), &words, |bench, &(a, b)| {
+                bench.iter(|| is_anagram(black_box(a), black_box(b)))
+            });
+            group.bench_with_input(id("is_anagram2"), &words, |bench, &(a, b)| {
+                bench.iter(|| is_anagram2(black_box(a), black_box(b)))
+            });
+            group.bench_with_input(id("is_anagram_unicode"), &words, |bench, &(a, b)| {
+                bench.iter(|| is_anagram_unicode(black_box(a), black_box(b)))
+            });
+        }
+    }
+    group.finish();
+    bench_sizes(
+        c,
+        "group_anagrams",
+        QUADRATIC,
+        |n| {
+            (0..n)
+                .map(|i| text(6, 4 + (i % 3) as u8))
+                .collect::<Vec<String>>()
+        },
+        |words| group_anagrams(words),
+    );
+}
+
+fn strings(c: &mut Criterion) {
+    // A text of `a`s with a `b` at the end is the worst case for naive searches.
+    let haystack = |n: usize| (format!("{}b", "a".repeat(n)), format!("{}b", "a".repeat(9)));
+    bench_sizes(
+        c,
+        "prefix_function",
+        LINEAR,
+        |n| text(n, 2),
+        |s| prefix_function(s),
+    );
+    bench_sizes(c, "kmp_search", LINEAR, haystack, |(text, pattern)| {
+        kmp_search(text, pattern)
+    });
+    bench_sizes(c, "z_function", LINEAR, |n| text(n, 2), |s| z_function(s));
+    bench_sizes(c, "z_search", LINEAR, haystack, |(text, pattern)| {
+        z_search(text, pattern)
+    });
+    bench_sizes(c, "rabin_karp", LINEAR, haystack, |(text, pattern)| {
+        rabin_karp(text, pattern)
+    });
+    let pair = |n: usize| {
+        let both = text(2 * n, 4);
+        (both[..n].to_owned(), both[n..].to_owned())
+    };
+    bench_sizes(
+        c,
+        "longest_common_subsequence",
+        QUADRATIC,
+        pair,
+        |(a, b)| longest_common_subsequence(a, b),
+    );
+    bench_sizes(c, "longest_common_substring", QUADRATIC, pair, |(a, b)| {
+        longest_common_substring(a, b)
+    });
+    bench_sizes(c, "levenshtein", QUADRATIC, pair, |(a, b)| {
+        levenshtein(a, b)
+    });
+    bench_sizes(c, "levenshtein_alignment", QUADRATIC, pair, |(a, b)| {
+        levenshtein_alignment(a, b)
+    });
+    bench_sizes(c, "damerau_levenshtein", QUADRATIC, pair, |(a, b)| {
+        damerau_levenshtein(a, b)
+    });
+    bench_sizes(
+        c,
+        "damerau_levenshtein_alignment",
+        QUADRATIC,
+        pair,
+        |(a, b)| damerau_levenshtein_alignment(a, b),
+    );
+    bench_sizes(
+        c,
+        "longest_palindromic_substring",
+        LINEAR,
+        |n| text(n, 2),
+        |s| longest_palindromic_substring(s),
+    );
+    bench_sizes(
+        c,
+        "suffix_array",
+        LINEAR,
+        |n| text(n, 4),
+        |s| suffix_array(s),
+    );
+    bench_sizes(
+        c,
+        "lcp_array",
+        LINEAR,
+        |n| {
+            let s = text(n, 4);
+            let sa = suffix_array(&s);
+            (s, sa)
+        },
+        |(s, sa)| lcp_array(s, sa),
+    );
+}
+
+fn dynamic_programming(c: &mut Criterion) {
+    // `n` items, with a capacity that grows with them.
+    let items = |n: usize| {
+        let weights: Vec<usize> = numbers(n, 20).iter().map(|&w| w as usize + 1).collect();
+        let values: Vec<u64> = numbers(n, 100).iter().map(|&v| v as u64).collect();
+        (weights, values, 5 * n)
+    };
+    bench_sizes(c, "knapsack_01", QUADRATIC, items, |(w, v, capacity)| {
+        knapsack_01(w, v, *capacity)
+    });
+    bench_sizes(
+        c,
+        "knapsack_01_table",
+        QUADRATIC,
+        items,
+        |(w, v, capacity)| knapsack_01_table(w, v, *capacity),
+    );
+    bench_sizes(
+        c,
+        "unbounded_knapsack",
+        QUADRATIC,
+        items,
+        |(w, v, capacity)| unbounded_knapsack(w, v, *capacity),
+    );
+    bench_sizes(
+        c,
+        "unbounded_knapsack_table",
+        QUADRATIC,
+        items,
+        |(w, v, capacity)| unbounded_knapsack_table(w, v, *capacity),
+    );
+    const COINS: [usize; 6] = [1, 2, 5, 10, 20, 50];
+    bench_sizes(
+        c,
+        "coin_change_ways",
+        LINEAR,
+        |n| n,
+        |&amount| coin_change_ways(&COINS, amount),
+    );
+    bench_sizes(
+        c,
+        "coin_change_ways_table",
+        LINEAR,
+        |n| n,
+        |&amount| coin_change_ways_table(&COINS, amount),
+    );
+    bench_sizes(
+        c,
+        "coin_change_min_coins",
+        LINEAR,
+        |n| n,
+        |&amount| coin_change_min_coins(&COINS, amount),
+    );
+    bench_sizes(
+        c,
+        "coin_change_min_coins_table",
+        LINEAR,
+        |n| n,
+        |&amount| coin_change_min_coins_table(&COINS, amount),
+    );
+    bench_sizes(
+        c,
+        "longest_increasing_subsequence",
+        QUADRATIC,
+        |n| numbers(n, 1_000),
+        |nums| longest_increasing_subsequence(nums),
+    );
+    bench_sizes(
+        c,
+        "longest_increasing_subsequence_table",
+        QUADRATIC,
+        |n| numbers(n, 1_000),
+        |nums| longest_increasing_subsequence_table(nums),
+    );
+    let dims =
+        |n: usize| -> Vec<u64> { numbers(n + 1, 50).iter().map(|&d| d as u64 + 1).collect() };
+    bench_sizes(
+        c,
+        "matrix_chain_multiplication",
+        [10, 50, 200],
+        dims,
+        |dims| matrix_chain_multiplication(dims),
+    );
+    bench_sizes(c, "matrix_chain_order", [10, 50, 200], dims, |dims| {
+        matrix_chain_order(dims)
+    });
+    bench_sizes(c, "matrix_chain_table", [10, 50, 200], dims, |dims| {
+        matrix_chain_table(dims)
+    });
+    bench_sizes(
+        c,
+        "edit_distance_table",
+        QUADRATIC,
+        |n| (text(n, 4), text(n + 1, 4)),
+        |(a, b)| edit_distance_table(a, b),
+    );
+    let prices = |n: usize| -> Vec<u64> { (1..=n as u64).map(|i| 3 * i - i / 2).collect() };
+    bench_sizes(c, "rod_cutting", QUADRATIC, prices, |prices| {
+        rod_cutting(prices, prices.len())
+    });
+    bench_sizes(c, "rod_cutting_table", QUADRATIC, prices, |prices| {
+        rod_cutting_table(prices, prices.len())
+    });
+}
+
+fn lists(c: &mut Criterion) {
+    bench_sizes(
+        c,
+        "Stack::push",
+        LINEAR,
+        |n| n,
+        |&n| {
+            let mut stack = Stack::new();
+            (0..n).for_each(|i| stack.push(i));
+            stack
+        },
+    );
+    bench_sizes_mut(
+        c,
+        "Stack::pop",
+        LINEAR,
+        |n| {
+            let mut stack = Stack::new();
+            (0..n).for_each(|i| stack.push(i));
+            stack
+        },
+        |mut stack| while stack.pop().is_some() {},
+    );
+    bench_sizes(
+        c,
+        "Queue::push",
+        LINEAR,
+        |n| n,
+        |&n| {
+            let mut queue = Queue::new();
+            (0..n).for_each(|i| queue.push(i));
+            queue
+        },
+    );
+    bench_sizes_mut(
+        c,
+        "Queue::pop",
+        LINEAR,
+        |n| {
+            let mut queue = Queue::new();
+            (0..n).for_each(|i| queue.push(i));
+            queue
+        },
+        |mut queue| while queue.pop().is_some() {},
+    );
+    // Pushing past the capacity makes the buffer grow.
+    bench_sizes(
+        c,
+        "RingBuffer::push_back",
+        LINEAR,
+        |n| n,
+        |&n| {
+            let mut buffer = RingBuffer::with_capacity(4);
+            (0..n).for_each(|i| buffer.push_back(i));
+            buffer
+        },
+    );
+    bench_sizes_mut(
+        c,
+        "RingBuffer::pop_front",
+        LINEAR,
+        |n| {
+            let mut buffer = RingBuffer::with_capacity(n);
+            (0..n).for_each(|i| buffer.push_front(i));
+            buffer
+        },
+        |mut buffer| while buffer.pop_front().is_some() {},
+    );
+    bench_sizes(
+        c,
+        "SinglyLinkedList::push_front",
+        LINEAR,
+        |n| n,
+        |&n| {
+            let mut list = SinglyLinkedList::new();
+            (0..n).for_each(|i| list.push_front(i));
+            list
+        },
+    );
+    let singly = |n: usize| {
+        let mut list = SinglyLinkedList::new();
+        (0..n).for_each(|i| list.push_front(i));
+        list
+    };
+    bench_sizes(c, "SinglyLinkedList::contains", LINEAR, singly, |list| {
+        list.contains(&0)
+    });
+    bench_sizes_mut(
+        c,
+        "SinglyLinkedList::reverse",
+        LINEAR,
+        singly,
+        |mut list| {
+            list.reverse();
+            list
+        },
+    );
+    bench_sizes(
+        c,
+        "DoublyLinkedList::push_back",
+        LINEAR,
+        |n| n,
+        |&n| {
+            let mut list = DoublyLinkedList::new();
+            (0..n).for_each(|i| list.push_back(i));
+            list
+        },
+    );
+    let doubly = |n: usize| {
+        let mut list = DoublyLinkedList::new();
+        (0..n).for_each(|i| list.push_back(i));
+        list
+    };
+    bench_sizes(c, "DoublyLinkedList::contains", LINEAR, doubly, |list| {
+        list.contains(&(list.len() - 1))
+    });
+    bench_sizes_mut(
+        c,
+        "DoublyLinkedList::pop_back",
+        LINEAR,
+        doubly,
+        |mut list| {
+            while list.pop_back().is_some() {}
+        },
+    );
+}
+
+fn trees(c: &mut Criterion) {
+    let values = |n: usize| numbers(n, 1_000_000);
+    bench_sizes(c, "BinarySearchTree::insert", LINEAR, values, |values| {
+        let mut tree = BinarySearchTree::new();
+        values.iter().for_each(|&v| {
+            tree.insert(v);
+        });
+        tree
+    });
+    bench_sizes(c, "AvlTree::insert", LINEAR, values, |values| {
+        let mut tree = AvlTree::new();
+        values.iter().for_each(|&v| {
+            tree.insert(v);
+        });
+        tree
+    });
+    // Sorted values make an unbalanced search tree as deep as it is long.
+    let sorted = |n: usize| (0..n as i64).collect::<Vec<i64>>();
+    bench_sizes(
+        c,
+        "BinarySearchTree::insert_sorted",
+        QUADRATIC,
+        sorted,
+        |values| {
+            let mut tree = BinarySearchTree::new();
+            values.iter().for_each(|&v| {
+                tree.insert(v);
+            });
+            tree
+        },
+    );
+    bench_sizes(c, "AvlTree::insert_sorted", QUADRATIC, sorted, |values| {
+        let mut tree = AvlTree::new();
+        values.iter().for_each(|&v| {
+            tree.insert(v);
+        });
+        tree
+    });
+    let bst = |n: usize| {
+        let values = values(n);
+        let tree: BinarySearchTree<i64> = values.iter().copied().collect();
+        (tree, values)
+    };
+    let avl = |n: usize| {
+        let values = values(n);
+        let tree: AvlTree<i64> = values.iter().copied().collect();
+        (tree, values)
+    };
+    bench_sizes(
+        c,
+        "BinarySearchTree::contains",
+        LINEAR,
+        bst,
+        |(tree, values)| values.iter().filter(|v| tree.contains(v)).count(),
+    );
+    bench_sizes(c, "AvlTree::contains", LINEAR, avl, |(tree, values)| {
+        values.iter().filter(|v| tree.contains(v)).count()
+    });
+    bench_sizes_mut(
+        c,
+        "BinarySearchTree::remove",
+        LINEAR,
+        bst,
+        |(mut tree, values)| {
+            values.iter().for_each(|v| {
+                tree.remove(v);
+            });
+            tree
+        },
+    );
+    bench_sizes_mut(c, "AvlTree::remove", LINEAR, avl, |(mut tree, values)| {
+        values.iter().for_each(|v| {
+            tree.remove(v);
+        });
+        tree
+    });
+    bench_sizes(c, "AvlTree::iter", LINEAR, avl, |(tree, _)| {
+        tree.iter().count()
+    });
+}
+
+fn maps(c: &mut Criterion) {
+    let keyed = |n: usize| numbers(n, 1_000_000);
+    bench_sizes(c, "BinaryHeap::push", LINEAR, keyed, |keys| {
+        let mut heap = BinaryHeap::new();
+        keys.iter().enumerate().for_each(|(i, &key)| {
+            heap.push(i, key);
+        });
+        heap
+    });
+    let heap = |n: usize| {
+        let mut heap = BinaryHeap::new();
+        keyed(n).into_iter().enumerate().for_each(|(i, key)| {
+            heap.push(i, key);
+        });
+        heap
+    };
+    bench_sizes_mut(c, "BinaryHeap::pop", LINEAR, heap, |mut heap| {
+        while heap.pop().is_some() {}
+    });
+    bench_sizes_mut(c, "BinaryHeap::decrease_key", LINEAR, heap, |mut heap| {
+        (0..heap.len()).for_each(|i| {
+            heap.decrease_key(&i, -1 - i as i64);
+        });
+        heap
+    });
+    // A cache of half the keys, so about half the lookups miss and evict.
+    bench_sizes_mut(
+        c,
+        "LruCache::put_get",
+        LINEAR,
+        |n| (LruCache::new(n / 2), keyed(n)),
+        |(mut cache, keys)| {
+            for &key in keys.iter() {
+                if cache.get(&(key % 1_000)).is_none() {
+                    cache.put(key % 1_000, key);
+                }
+            }
+            cache
+        },
+    );
+    bench_sizes(c, "Trie::insert", LINEAR, words, |words| {
+        let mut trie = Trie::new();
+        words.iter().for_each(|word| {
+            trie.insert(word);
+        });
+        trie
+    });
+    let trie = |n: usize| {
+        let words = words(n);
+        let mut trie = Trie::new();
+        words.iter().for_each(|word| {
+            trie.insert(word);
+        });
+        (trie, words)
+    };
+    bench_sizes(c, "Trie::contains", LINEAR, trie, |(trie, words)| {
+        words.iter().filter(|word| trie.contains(word)).count()
+    });
+    bench_sizes(c, "Trie::words_with_prefix", LINEAR, trie, |(trie, _)| {
+        trie.words_with_prefix("ab")
+    });
+    bench_sizes_mut(c, "Trie::remove", LINEAR, trie, |(mut trie, words)| {
+        words.iter().for_each(|word| {
+            trie.remove(word);
+        });
+        trie
+    });
+    // Joins random pairs until about one set is left.
+    bench_sizes(
+        c,
+        "UnionFind::union",
+        LINEAR,
+        |n| (n, numbers(2 * n, n as u64)),
+        |(n, ends)| {
+            let mut sets = UnionFind::new();
+            (0..*n as i64).for_each(|i| {
+                sets.insert(i);
+            });
+            ends.chunks(2).for_each(|pair| {
+                sets.union(pair[0], pair[1]);
+            });
+            sets
+        },
+    );
+    let joined = |n: usize| {
+        let mut sets = UnionFind::new();
+        (0..n as i64).for_each(|i| {
+            sets.insert(i);
+        });
+        numbers(2 * n, n as u64).chunks(2).for_each(|pair| {
+            sets.union(pair[0], pair[1]);
+        });
+        sets
+    };
+    bench_sizes_mut(c, "UnionFind::connected", LINEAR, joined, |mut sets| {
+        let n = sets.len() as i64;
+        (0..n).filter(|&i| sets.connected(&0, &i)).count()
+    });
+    bench_sizes(c, "UnionFind::sets", LINEAR, joined, |sets| {
+        sets.sets().len()
+    });
+}
+
+/// Returns `n` pseudo-random words of 3 to 10 letters from the first half of the alphabet.
+fn words(n: usize) -> Vec<String> {
+    let letters = text(10 * n, 13);
+    numbers(n, 8)
+        .into_iter()
+        .enumerate()
+        .map(|(i, extra)| letters[10 * i..10 * i + 3 + extra as usize].to_owned())
+        .collect()
+}
+
+criterion_group!(
+    benches,
+    arrays,
+    anagrams,
+    strings,
+    dynamic_programming,
+    lists,
+    trees,
+    maps
+);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+//! Compares the range functions in `ladderz::prealgebra` with their parallel versions on pools
+//! of different sizes.
+//!
+//! Run with `cargo bench -p ladderz --features parallel --bench parallel`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ladderz::prealgebra::{self, parallel};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::collections::HashSet;
+use std::hint::black_box;
+
+/// The thread counts each parallel function is measured with.
+const THREADS: [usize; 4] = [1, 2, 4, 8];
+
+fn pool(threads: usize) -> ThreadPool {
+    ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap()
+}
+
+/// Benchmarks `sequential` and `parallel` over the same range, with `parallel` on a pool of
+/// each of [`THREADS`].
+fn compare(
+    c: &mut Criterion,
+    name: &str,
+    (start, end): (u32, u32),
+    sequential: fn(u32, u32) -> HashSet<u32>,
+    parallel: fn(u32, u32) -> HashSet<u32>,
+) {
+    let mut group = c.benchmark_group(name);
+    group.bench_function("sequential", |b| {
+        b.iter(|| sequential(black_box(start), black_box(end)))
+    });
+    for threads in THREADS {
+        let pool = pool(threads);
+        group.bench_with_input(BenchmarkId::new("parallel", threads), &threads, |b, _| {
+            b.iter(|| pool.install(|| parallel(black_box(start), black_box(end))))
+        });
+    }
+    group.finish();
+}
+
+fn primes_in_range(c: &mut Criterion) {
+    compare(
+        c,
+        "get_primes_in_range",
+        (1, 20_000),
+        prealgebra::get_primes_in_range,
+        parallel::get_primes_in_range,
+    );
+}
+
+fn multiples_in_range(c: &mut Criterion) {
+    compare(
+        c,
+        "get_multiples_in_range",
+        (1, 2_000_000),
+        |start, end| prealgebra::get_multiples_in_range(3, start, end),
+        |start, end| parallel::get_multiples_in_range(3, start, end),
+    );
+}
+
+fn sieve_primes_in_range(c: &mut Criterion) {
+    compare(
+        c,
+        "sieve_primes_in_range",
+        (1, 10_000_000),
+        prealgebra::sieve_primes_in_range,
+        parallel::sieve_primes_in_range,
+    );
+}
+
+criterion_group!(
+    benches,
+    primes_in_range,
+    multiples_in_range,
+    sieve_primes_in_range
+);
+criterion_main!(benches);
@@ -0,0 +1,139 @@
+//! Benchmarks every public function in `ladderz::prealgebra` across input sizes.
+//!
+//! Run with `cargo bench -p ladderz --bench prealgebra`. To check a change for regressions,
+//! save a baseline before it with `-- --save-baseline main` and compare against it after with
+//! `-- --baseline main`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ladderz::prealgebra::*;
+use std::hint::black_box;
+
+/// Benchmarks `f` on each of `inputs` in a group called `name`.
+fn bench_inputs<I: std::fmt::Display, O>(
+    c: &mut Criterion,
+    name: &str,
+    inputs: impl IntoIterator<Item = I>,
+    f: impl Fn(&I) -> O,
+) {
+    let inputs = inputs.into_iter().map(|input| (input.to_string(), input));
+    bench_labeled(c, name, inputs, f);
+}
+
+/// Benchmarks `f` on each of `inputs`, named by its label, in a group called `name`.
+fn bench_labeled<I, O>(
+    c: &mut Criterion,
+    name: &str,
+    inputs: impl IntoIterator<Item = (String, I)>,
+    f: impl Fn(&I) -> O,
+) {
+    let mut group = c.benchmark_group(name);
+    for (label, input) in inputs {
+        group.bench_with_input(BenchmarkId::from_parameter(label), &input, |b, input| {
+            b.iter(|| f(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+/// Numbers with many factors, to be factored.
+const HIGHLY_COMPOSITE: [u32; 4] = [60, 720, 5_040, 55_440];
+/// Primes, which are the slowest numbers to test for primality.
+const PRIMES: [u32; 4] = [101, 1_009, 10_007, 100_003];
+/// The upper ends of the ranges that are scanned.
+const RANGE_ENDS: [u32; 3] = [100, 1_000, 10_000];
+
+fn factors(c: &mut Criterion) {
+    bench_inputs(c, "get_factor_pairs", HIGHLY_COMPOSITE, |&n| {
+        get_factor_pairs(n)
+    });
+    bench_inputs(c, "get_factors", HIGHLY_COMPOSITE, |&n| get_factors(n));
+    bench_inputs(c, "is_factor", HIGHLY_COMPOSITE, |&n| is_factor(7, n));
+    bench_inputs(c, "is_multiple", HIGHLY_COMPOSITE, |&n| is_multiple(n, 7));
+}
+
+fn multiples(c: &mut Criterion) {
+    bench_inputs(
+        c,
+        "get_multiples_in_range",
+        [1_000, 100_000, 10_000_000],
+        |&end| get_multiples_in_range(3, 1, end),
+    );
+}
+
+fn primes(c: &mut Criterion) {
+    bench_inputs(c, "is_prime", PRIMES, |&n| is_prime(n));
+    bench_inputs(c, "is_composite", PRIMES, |&n| is_composite(n));
+    bench_inputs(c, "get_primes_in_range", RANGE_ENDS, |&end| {
+        get_primes_in_range(1, end)
+    });
+    bench_inputs(
+        c,
+        "sieve_primes_in_range",
+        [10_000, 1_000_000, 10_000_000],
+        |&end| sieve_primes_in_range(1, end),
+    );
+    // Dominated by the `get_primes_in_range(2, n)` scan inside, which grows with `n`.
+    bench_inputs(
+        c,
+        "get_prime_factorization",
+        RANGE_ENDS.map(|n| n + 8),
+        |&n| get_prime_factorization(n),
+    );
+}
+
+fn bases(c: &mut Criterion) {
+    let numbers = [255i64, 1_000_000, i64::MAX];
+    bench_inputs(c, "to_base", numbers, |&n| to_base(n, 2));
+    bench_inputs(c, "from_base", numbers.map(|n| format!("{n:x}")), |s| {
+        from_base(s, 16)
+    });
+    bench_inputs(c, "convert_base", numbers.map(|n| format!("{n:x}")), |s| {
+        convert_base(s, 16, 3)
+    });
+    bench_inputs(
+        c,
+        "parse_int_literal",
+        ["0xff", "0b11110100001001000000", "0o777777777777777777777"],
+        |s| parse_int_literal(s),
+    );
+    bench_inputs(c, "to_balanced_ternary", numbers, |&n| {
+        to_balanced_ternary(n)
+    });
+    bench_inputs(
+        c,
+        "from_balanced_ternary",
+        numbers.map(to_balanced_ternary),
+        |s| from_balanced_ternary(s),
+    );
+    let romans = [9u32, 1_994, 3_888];
+    bench_inputs(c, "to_roman", romans, |&n| to_roman(n));
+    bench_inputs(c, "from_roman", romans.map(|n| to_roman(n).unwrap()), |s| {
+        from_roman(s)
+    });
+    bench_inputs(
+        c,
+        "to_scientific_notation",
+        [3, 9, 17],
+        |&significant_digits| to_scientific_notation(6.02214076e23, significant_digits),
+    );
+    let widths = [8u32, 32, 64];
+    bench_inputs(c, "to_twos_complement", widths, |&bits| {
+        to_twos_complement(-5, bits)
+    });
+    bench_labeled(
+        c,
+        "from_twos_complement",
+        widths.map(|bits| (bits.to_string(), to_twos_complement(-5, bits).unwrap())),
+        |s| from_twos_complement(s),
+    );
+    bench_inputs(c, "twos_complement_range", widths, |&bits| {
+        twos_complement_range(bits)
+    });
+    // The length of the repeating part grows with the denominator.
+    bench_inputs(c, "fraction_to_base", [7u64, 97, 9_973], |&denominator| {
+        fraction_to_base(1, denominator, 10)
+    });
+}
+
+criterion_group!(benches, factors, multiples, primes, bases);
+criterion_main!(benches);
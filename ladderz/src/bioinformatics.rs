@@ -0,0 +1,533 @@
+/// Global and local pairwise alignment with scoring matrices and affine gap penalties.
+pub mod alignment;
+/// Streaming FASTA reading and writing.
+pub mod fasta;
+/// Streaming FASTQ reading and writing with quality score decoding.
+pub mod fastq;
+
+pub use alignment::{
+    align, align_with_matrix, needleman_wunsch, smith_waterman, AlignmentError, AlignmentMatrix,
+    AlignmentMode, GapPenalty, PairwiseAlignment, Scoring, ScoringMatrix,
+};
+pub use fasta::{FastaReader, FastaRecord, FastaWriter};
+pub use fastq::{FastqReader, FastqRecord, FastqWriter, QualityEncoding};
+
+use crate::dsa::kmp_search;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The kinds of biological sequence that a [`Sequence`] can hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Alphabet {
+    /// Nucleotides `A`, `C`, `G`, `T`, and `N` for an unknown base.
+    Dna,
+    /// Nucleotides `A`, `C`, `G`, `U`, and `N` for an unknown base.
+    Rna,
+    /// The 20 standard amino acids, `X` for an unknown amino acid, and `*` for a stop codon.
+    Protein,
+}
+
+impl Alphabet {
+    /// Returns the uppercase residues that are valid in this alphabet.
+    pub fn residues(&self) -> &'static str {
+        match self {
+            Alphabet::Dna => "ACGTN",
+            Alphabet::Rna => "ACGUN",
+            Alphabet::Protein => "ACDEFGHIKLMNPQRSTVWYX*",
+        }
+    }
+}
+
+impl fmt::Display for Alphabet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Alphabet::Dna => write!(f, "DNA"),
+            Alphabet::Rna => write!(f, "RNA"),
+            Alphabet::Protein => write!(f, "protein"),
+        }
+    }
+}
+
+/// An error from creating or operating on a [`Sequence`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceError {
+    /// A character that isn't in the sequence's alphabet (`position` is 0-based).
+    InvalidResidue {
+        residue: char,
+        position: usize,
+        alphabet: Alphabet,
+    },
+    /// An operation that isn't defined for the sequence's alphabet, e.g. the GC content of a protein.
+    UnsupportedAlphabet {
+        operation: &'static str,
+        alphabet: Alphabet,
+    },
+    /// Two sequences that must have the same length don't.
+    LengthMismatch { left: usize, right: usize },
+}
+
+impl fmt::Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SequenceError::InvalidResidue {
+                residue,
+                position,
+                alphabet,
+            } => write!(
+                f,
+                "invalid {alphabet} residue {residue:?} at position {position}"
+            ),
+            SequenceError::UnsupportedAlphabet {
+                operation,
+                alphabet,
+            } => write!(f, "{operation} is not defined for {alphabet} sequences"),
+            SequenceError::LengthMismatch { left, right } => write!(
+                f,
+                "sequences must have the same length, but have lengths {left} and {right}"
+            ),
+        }
+    }
+}
+
+impl Error for SequenceError {}
+
+/// An error from reading a FASTA or FASTQ record.
+#[derive(Debug)]
+pub enum RecordError {
+    /// The underlying reader failed.
+    Io(io::Error),
+    /// The input doesn't follow the file format (`line` is 1-based).
+    Malformed { line: usize, message: String },
+    /// A quality character outside the encoding's range (`position` is 0-based).
+    InvalidQuality { character: char, position: usize },
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::Io(error) => write!(f, "{error}"),
+            RecordError::Malformed { line, message } => write!(f, "line {line}: {message}"),
+            RecordError::InvalidQuality {
+                character,
+                position,
+            } => write!(
+                f,
+                "invalid quality character {character:?} at position {position}"
+            ),
+        }
+    }
+}
+
+impl Error for RecordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RecordError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RecordError {
+    fn from(error: io::Error) -> Self {
+        RecordError::Io(error)
+    }
+}
+
+/// The standard genetic code, indexed by codon with the bases ordered `T`/`U`, `C`, `A`, `G`.
+const STANDARD_CODE: &[u8; 64] =
+    b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+
+/// Translates a single DNA or RNA codon into its amino acid with the standard genetic code.
+///
+/// Stop codons translate to `*`. Returns `None` if `codon` is not three valid nucleotides, and
+/// `X` if it contains an unknown base `N`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::translate_codon;
+///
+/// assert_eq!(translate_codon("AUG"), Some('M'));
+/// assert_eq!(translate_codon("tga"), Some('*'));
+/// assert_eq!(translate_codon("GCN"), Some('X'));
+/// assert_eq!(translate_codon("GC"), None);
+/// ```
+pub fn translate_codon(codon: &str) -> Option<char> {
+    let bases: Vec<char> = codon.chars().map(|c| c.to_ascii_uppercase()).collect();
+    if bases.len() != 3 {
+        return None;
+    }
+    let mut index: usize = 0;
+    let mut unknown = false;
+    for base in bases {
+        let value = match base {
+            'T' | 'U' => 0,
+            'C' => 1,
+            'A' => 2,
+            'G' => 3,
+            'N' => {
+                unknown = true;
+                0
+            }
+            _ => return None,
+        };
+        index = index * 4 + value;
+    }
+    Some(if unknown {
+        'X'
+    } else {
+        STANDARD_CODE[index] as char
+    })
+}
+
+/// A validated DNA, RNA or protein sequence.
+///
+/// Residues are stored in uppercase. Every constructor checks that each residue belongs to the
+/// sequence's [`Alphabet`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::Sequence;
+///
+/// let dna = Sequence::dna("atgGCC").unwrap();
+/// assert_eq!(dna.as_str(), "ATGGCC");
+/// assert_eq!(dna.gc_content().unwrap(), 4.0 / 6.0);
+/// assert_eq!(dna.reverse_complement().unwrap().as_str(), "GGCCAT");
+/// assert_eq!(dna.transcribe().unwrap().as_str(), "AUGGCC");
+/// assert_eq!(dna.translate().unwrap().as_str(), "MA");
+///
+/// assert!(Sequence::dna("ACGU").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sequence {
+    alphabet: Alphabet,
+    residues: String,
+}
+
+impl Sequence {
+    /// Creates a sequence, returning an error for the first residue not in `alphabet`.
+    pub fn new(alphabet: Alphabet, residues: &str) -> Result<Self, SequenceError> {
+        let residues = residues.to_ascii_uppercase();
+        let valid = alphabet.residues();
+        if let Some((position, residue)) = residues
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !valid.contains(*c))
+        {
+            return Err(SequenceError::InvalidResidue {
+                residue,
+                position,
+                alphabet,
+            });
+        }
+        Ok(Sequence { alphabet, residues })
+    }
+
+    /// Creates a DNA sequence.
+    pub fn dna(residues: &str) -> Result<Self, SequenceError> {
+        Self::new(Alphabet::Dna, residues)
+    }
+
+    /// Creates an RNA sequence.
+    pub fn rna(residues: &str) -> Result<Self, SequenceError> {
+        Self::new(Alphabet::Rna, residues)
+    }
+
+    /// Creates a protein sequence.
+    pub fn protein(residues: &str) -> Result<Self, SequenceError> {
+        Self::new(Alphabet::Protein, residues)
+    }
+
+    /// Creates a sequence in the first alphabet that accepts every residue: DNA, then RNA, then protein.
+    ///
+    /// If no alphabet fits, the error comes from the alphabet that accepted the longest prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ladderz::bioinformatics::{Alphabet, Sequence};
+    ///
+    /// assert_eq!(Sequence::infer("ACGT").unwrap().alphabet(), Alphabet::Dna);
+    /// assert_eq!(Sequence::infer("ACGU").unwrap().alphabet(), Alphabet::Rna);
+    /// assert_eq!(Sequence::infer("MKV*").unwrap().alphabet(), Alphabet::Protein);
+    /// ```
+    pub fn infer(residues: &str) -> Result<Self, SequenceError> {
+        let mut closest: Option<SequenceError> = None;
+        for alphabet in [Alphabet::Dna, Alphabet::Rna, Alphabet::Protein] {
+            match Self::new(alphabet, residues) {
+                Ok(sequence) => return Ok(sequence),
+                // Report the alphabet that matched the most residues before failing.
+                Err(error) => match (&closest, &error) {
+                    (
+                        Some(SequenceError::InvalidResidue { position: best, .. }),
+                        SequenceError::InvalidResidue { position, .. },
+                    ) if position <= best => {}
+                    _ => closest = Some(error),
+                },
+            }
+        }
+        Err(closest.expect("every alphabet was tried"))
+    }
+
+    /// Returns the sequence's alphabet.
+    pub fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// Returns the residues as an uppercase string.
+    pub fn as_str(&self) -> &str {
+        &self.residues
+    }
+
+    /// Returns the number of residues.
+    pub fn len(&self) -> usize {
+        self.residues.len()
+    }
+
+    /// Returns `true` if the sequence has no residues.
+    pub fn is_empty(&self) -> bool {
+        self.residues.is_empty()
+    }
+
+    fn require_nucleic(&self, operation: &'static str) -> Result<(), SequenceError> {
+        match self.alphabet {
+            Alphabet::Protein => Err(SequenceError::UnsupportedAlphabet {
+                operation,
+                alphabet: self.alphabet,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the fraction of nucleotides that are `G` or `C`, or `0.0` for an empty sequence.
+    pub fn gc_content(&self) -> Result<f64, SequenceError> {
+        self.require_nucleic("GC content")?;
+        if self.is_empty() {
+            return Ok(0.0);
+        }
+        let gc = self
+            .residues
+            .chars()
+            .filter(|c| matches!(c, 'G' | 'C'))
+            .count();
+        Ok(gc as f64 / self.len() as f64)
+    }
+
+    /// Returns the reverse complement: the sequence of the opposite strand, read in the same direction.
+    pub fn reverse_complement(&self) -> Result<Sequence, SequenceError> {
+        self.require_nucleic("reverse complement")?;
+        let complement = |c: char| match (c, self.alphabet) {
+            ('A', Alphabet::Rna) => 'U',
+            ('A', _) => 'T',
+            ('T' | 'U', _) => 'A',
+            ('C', _) => 'G',
+            ('G', _) => 'C',
+            (other, _) => other,
+        };
+        Ok(Sequence {
+            alphabet: self.alphabet,
+            residues: self.residues.chars().rev().map(complement).collect(),
+        })
+    }
+
+    /// Transcribes a DNA sequence into RNA by replacing every `T` with `U`.
+    pub fn transcribe(&self) -> Result<Sequence, SequenceError> {
+        if self.alphabet != Alphabet::Dna {
+            return Err(SequenceError::UnsupportedAlphabet {
+                operation: "transcription",
+                alphabet: self.alphabet,
+            });
+        }
+        Ok(Sequence {
+            alphabet: Alphabet::Rna,
+            residues: self.residues.replace('T', "U"),
+        })
+    }
+
+    /// Translates a DNA or RNA sequence into protein with the standard genetic code.
+    ///
+    /// Codons are read from the first nucleotide, stop codons become `*`, and a trailing partial
+    /// codon is ignored.
+    pub fn translate(&self) -> Result<Sequence, SequenceError> {
+        self.require_nucleic("translation")?;
+        let residues = self
+            .residues
+            .as_bytes()
+            .chunks_exact(3)
+            .filter_map(|codon| std::str::from_utf8(codon).ok().and_then(translate_codon))
+            .collect();
+        Ok(Sequence {
+            alphabet: Alphabet::Protein,
+            residues,
+        })
+    }
+
+    /// Counts every substring of length `k` (k-mer), including overlapping ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ladderz::bioinformatics::Sequence;
+    /// use std::collections::HashMap;
+    ///
+    /// let counts = Sequence::dna("ACGACG").unwrap().kmer_counts(3);
+    /// let expected: HashMap<String, u32> =
+    ///     [("ACG".to_owned(), 2), ("CGA".to_owned(), 1), ("GAC".to_owned(), 1)].into();
+    /// assert_eq!(counts, expected);
+    /// ```
+    pub fn kmer_counts(&self, k: usize) -> HashMap<String, u32> {
+        let mut counts: HashMap<String, u32> = HashMap::new();
+        if k == 0 || k > self.len() {
+            return counts;
+        }
+        for i in 0..=self.len() - k {
+            *counts
+                .entry(self.residues[i..i + k].to_owned())
+                .or_default() += 1;
+        }
+        counts
+    }
+
+    /// Counts the positions at which this sequence and `other` have different residues.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ladderz::bioinformatics::Sequence;
+    ///
+    /// let a = Sequence::dna("GAGCCTACTAACGGGAT").unwrap();
+    /// let b = Sequence::dna("CATCGTAATGACGGCCT").unwrap();
+    /// assert_eq!(a.hamming_distance(&b).unwrap(), 7);
+    /// ```
+    pub fn hamming_distance(&self, other: &Sequence) -> Result<usize, SequenceError> {
+        if self.len() != other.len() {
+            return Err(SequenceError::LengthMismatch {
+                left: self.len(),
+                right: other.len(),
+            });
+        }
+        Ok(self
+            .residues
+            .chars()
+            .zip(other.residues.chars())
+            .filter(|(a, b)| a != b)
+            .count())
+    }
+
+    /// Returns the 0-based positions of every (possibly overlapping) occurrence of `motif`.
+    ///
+    /// The motif is matched case-insensitively with [`kmp_search`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ladderz::bioinformatics::Sequence;
+    ///
+    /// let dna = Sequence::dna("GATATATGCATATACTT").unwrap();
+    /// assert_eq!(dna.find_motif("atat"), vec![1, 3, 9]);
+    /// ```
+    pub fn find_motif(&self, motif: &str) -> Vec<usize> {
+        kmp_search(&self.residues, &motif.to_ascii_uppercase())
+    }
+}
+
+impl fmt::Display for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.residues)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            Sequence::dna("ACGX"),
+            Err(SequenceError::InvalidResidue {
+                residue: 'X',
+                position: 3,
+                alphabet: Alphabet::Dna
+            })
+        );
+        assert!(Sequence::rna("ACGT").is_err());
+        assert!(Sequence::protein("MKB").is_err());
+        assert_eq!(Sequence::dna("acgn").unwrap().as_str(), "ACGN");
+        assert!(Sequence::dna("").unwrap().is_empty());
+        assert!(matches!(
+            Sequence::infer("ACGZ"),
+            Err(SequenceError::InvalidResidue {
+                alphabet: Alphabet::Dna,
+                ..
+            })
+        ));
+        assert!(matches!(
+            Sequence::infer("hello"),
+            Err(SequenceError::InvalidResidue {
+                residue: 'O',
+                alphabet: Alphabet::Protein,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_gc_content() {
+        let dna = Sequence::dna("CCACCCTCGTGGTATGGCTAGGCATTCAGGAACCGGAGAACGCTTCAGACCAGCCCGGACTGGGAACCTGCGGGCAGTAGGTGGAAT").unwrap();
+        assert!((dna.gc_content().unwrap() - 0.60919540).abs() < 1e-8);
+        assert_eq!(Sequence::rna("").unwrap().gc_content(), Ok(0.0));
+        assert!(Sequence::protein("MK").unwrap().gc_content().is_err());
+    }
+
+    #[test]
+    fn test_reverse_complement_and_transcription() {
+        let dna = Sequence::dna("AAAACCCGGT").unwrap();
+        assert_eq!(dna.reverse_complement().unwrap().as_str(), "ACCGGGTTTT");
+        let rna = Sequence::rna("AAUCG").unwrap();
+        assert_eq!(rna.reverse_complement().unwrap().as_str(), "CGAUU");
+        assert_eq!(
+            Sequence::dna("GATGGAACTTGACTACGTAAATT")
+                .unwrap()
+                .transcribe()
+                .unwrap()
+                .as_str(),
+            "GAUGGAACUUGACUACGUAAAUU"
+        );
+        assert!(rna.transcribe().is_err());
+    }
+
+    #[test]
+    fn test_translate() {
+        let rna = Sequence::rna("AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA").unwrap();
+        let protein = rna.translate().unwrap();
+        assert_eq!(protein.as_str(), "MAMAPRTEINSTRING*");
+        assert_eq!(protein.alphabet(), Alphabet::Protein);
+        assert_eq!(
+            Sequence::dna("ATGCC")
+                .unwrap()
+                .translate()
+                .unwrap()
+                .as_str(),
+            "M"
+        );
+        assert!(protein.translate().is_err());
+    }
+
+    #[test]
+    fn test_kmers_hamming_and_motifs() {
+        let dna = Sequence::dna("AAAA").unwrap();
+        assert_eq!(dna.kmer_counts(2), [("AA".to_owned(), 3)].into());
+        assert!(dna.kmer_counts(5).is_empty());
+        assert!(dna.kmer_counts(0).is_empty());
+        assert_eq!(
+            dna.hamming_distance(&Sequence::dna("AA").unwrap()),
+            Err(SequenceError::LengthMismatch { left: 4, right: 2 })
+        );
+        assert_eq!(dna.find_motif("AA"), vec![0, 1, 2]);
+        assert_eq!(dna.find_motif("C"), Vec::<usize>::new());
+    }
+}
@@ -0,0 +1,746 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// An error from parsing a scoring matrix or aligning two sequences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlignmentError {
+    /// A residue that the scoring matrix has no scores for. `sequence` is 1 or 2 and `position`
+    /// is 0-based.
+    UnscoredResidue {
+        residue: char,
+        sequence: usize,
+        position: usize,
+    },
+    /// A scoring matrix that couldn't be parsed (`line` is 1-based).
+    InvalidMatrix { line: usize, message: String },
+}
+
+impl fmt::Display for AlignmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlignmentError::UnscoredResidue {
+                residue,
+                sequence,
+                position,
+            } => write!(
+                f,
+                "residue {residue:?} at position {position} of sequence {sequence} is not in the scoring matrix"
+            ),
+            AlignmentError::InvalidMatrix { line, message } => {
+                write!(f, "invalid scoring matrix on line {line}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for AlignmentError {}
+
+/// A table of scores for aligning one residue against another.
+///
+/// Lookups are case-insensitive. [`ScoringMatrix::blosum62`] and [`ScoringMatrix::pam250`] are
+/// parsed from the NCBI matrix files shipped with this crate, and [`ScoringMatrix::parse`] reads
+/// any matrix in the same format.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::ScoringMatrix;
+///
+/// let blosum62 = ScoringMatrix::blosum62();
+/// assert_eq!(blosum62.score('W', 'W'), Some(11));
+/// assert_eq!(blosum62.score('h', 'a'), Some(-2));
+/// assert_eq!(blosum62.score('J', 'A'), None);
+///
+/// let dna = ScoringMatrix::match_mismatch("ACGT", 2, -1);
+/// assert_eq!(dna.score('A', 'A'), Some(2));
+/// assert_eq!(dna.score('A', 'C'), Some(-1));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoringMatrix {
+    residues: Vec<char>,
+    scores: Vec<Vec<i32>>,
+}
+
+impl ScoringMatrix {
+    /// Parses a matrix in the NCBI format: a header line of residues, then one line per residue
+    /// with the residue followed by its scores. Blank lines and lines starting with `#` are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ladderz::bioinformatics::ScoringMatrix;
+    ///
+    /// let matrix = ScoringMatrix::parse("# toy\n   A  B\nA  1 -1\nB -1  3\n").unwrap();
+    /// assert_eq!(matrix.score('B', 'B'), Some(3));
+    /// assert!(ScoringMatrix::parse("   A  B\nA  1\n").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, AlignmentError> {
+        let invalid =
+            |line: usize, message: String| AlignmentError::InvalidMatrix { line, message };
+        let residue = |token: &str, line: usize| {
+            let mut chars = token.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(c.to_ascii_uppercase()),
+                _ => Err(invalid(
+                    line,
+                    format!("expected a single residue but found {token:?}"),
+                )),
+            }
+        };
+
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+        let (header_line, header) = lines
+            .next()
+            .ok_or_else(|| invalid(1, "the matrix is empty".to_owned()))?;
+        let columns = header
+            .split_whitespace()
+            .map(|token| residue(token, header_line))
+            .collect::<Result<Vec<char>, _>>()?;
+
+        let mut scores: Vec<Option<Vec<i32>>> = vec![None; columns.len()];
+        let mut last_line = header_line;
+        for (line_number, line) in lines {
+            last_line = line_number;
+            let mut tokens = line.split_whitespace();
+            let label = residue(tokens.next().unwrap_or_default(), line_number)?;
+            let row = columns
+                .iter()
+                .position(|&c| c == label)
+                .ok_or_else(|| invalid(line_number, format!("{label:?} is not in the header")))?;
+            if scores[row].is_some() {
+                return Err(invalid(
+                    line_number,
+                    format!("{label:?} has more than one row"),
+                ));
+            }
+            let values = tokens
+                .map(|token| {
+                    token
+                        .parse::<i32>()
+                        .map_err(|_| invalid(line_number, format!("{token:?} is not an integer")))
+                })
+                .collect::<Result<Vec<i32>, _>>()?;
+            if values.len() != columns.len() {
+                return Err(invalid(
+                    line_number,
+                    format!(
+                        "expected {} scores but found {}",
+                        columns.len(),
+                        values.len()
+                    ),
+                ));
+            }
+            scores[row] = Some(values);
+        }
+
+        let scores = scores
+            .into_iter()
+            .zip(columns.iter())
+            .map(|(row, label)| {
+                row.ok_or_else(|| invalid(last_line, format!("{label:?} has no row")))
+            })
+            .collect::<Result<Vec<Vec<i32>>, _>>()?;
+        Ok(ScoringMatrix {
+            residues: columns,
+            scores,
+        })
+    }
+
+    /// Returns the BLOSUM62 amino acid substitution matrix.
+    pub fn blosum62() -> Self {
+        Self::parse(include_str!("data/blosum62.txt"))
+            .expect("the bundled BLOSUM62 matrix is valid")
+    }
+
+    /// Returns the PAM250 amino acid substitution matrix.
+    pub fn pam250() -> Self {
+        Self::parse(include_str!("data/pam250.txt")).expect("the bundled PAM250 matrix is valid")
+    }
+
+    /// Returns a matrix over `residues` that scores `match_score` for identical residues and
+    /// `mismatch_score` otherwise.
+    pub fn match_mismatch(residues: &str, match_score: i32, mismatch_score: i32) -> Self {
+        let residues: Vec<char> = residues.chars().map(|c| c.to_ascii_uppercase()).collect();
+        let scores = (0..residues.len())
+            .map(|i| {
+                (0..residues.len())
+                    .map(|j| if i == j { match_score } else { mismatch_score })
+                    .collect()
+            })
+            .collect();
+        ScoringMatrix { residues, scores }
+    }
+
+    /// Returns the residues the matrix has scores for, in order.
+    pub fn residues(&self) -> &[char] {
+        &self.residues
+    }
+
+    /// Returns the score for aligning `a` against `b`, or `None` if either isn't in the matrix.
+    pub fn score(&self, a: char, b: char) -> Option<i32> {
+        let index = |c: char| {
+            let c = c.to_ascii_uppercase();
+            self.residues.iter().position(|&r| r == c)
+        };
+        Some(self.scores[index(a)?][index(b)?])
+    }
+}
+
+/// The penalty for a run of gaps, given as positive numbers that are subtracted from the score.
+///
+/// A gap of length `n` costs `open + extend * (n - 1)`, so a linear penalty has `open == extend`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GapPenalty {
+    /// The cost of the first position in a gap.
+    pub open: i32,
+    /// The cost of each further position in the same gap.
+    pub extend: i32,
+}
+
+impl GapPenalty {
+    /// A penalty of `gap` for every position in a gap.
+    pub fn linear(gap: i32) -> Self {
+        GapPenalty {
+            open: gap,
+            extend: gap,
+        }
+    }
+
+    /// An affine penalty, which usually makes opening a gap more expensive than extending one.
+    pub fn affine(open: i32, extend: i32) -> Self {
+        GapPenalty { open, extend }
+    }
+}
+
+/// A scoring matrix together with a gap penalty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoring {
+    /// The scores for aligning residues against each other.
+    pub matrix: ScoringMatrix,
+    /// The penalty for gaps.
+    pub gap: GapPenalty,
+}
+
+impl Scoring {
+    /// Nucleotide scoring: `+2` for a match, `-1` for a mismatch, and a linear gap penalty of 1.
+    ///
+    /// Both DNA and RNA nucleotides, plus `N`, are scored.
+    pub fn dna() -> Self {
+        Scoring {
+            matrix: ScoringMatrix::match_mismatch("ACGTUN", 2, -1),
+            gap: GapPenalty::linear(1),
+        }
+    }
+
+    /// BLOSUM62 with a gap open penalty of 11 and extend penalty of 1.
+    pub fn blosum62() -> Self {
+        Scoring {
+            matrix: ScoringMatrix::blosum62(),
+            gap: GapPenalty::affine(11, 1),
+        }
+    }
+
+    /// PAM250 with a gap open penalty of 11 and extend penalty of 1.
+    pub fn pam250() -> Self {
+        Scoring {
+            matrix: ScoringMatrix::pam250(),
+            gap: GapPenalty::affine(11, 1),
+        }
+    }
+}
+
+/// Whether to align whole sequences or their best-matching regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlignmentMode {
+    /// Needleman–Wunsch: align both sequences end to end.
+    Global,
+    /// Smith–Waterman: align the highest-scoring pair of substrings.
+    Local,
+}
+
+impl fmt::Display for AlignmentMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlignmentMode::Global => write!(f, "global"),
+            AlignmentMode::Local => write!(f, "local"),
+        }
+    }
+}
+
+/// The DP matrix of an alignment and the path the traceback took through it.
+///
+/// Cell `(i, j)` holds the best score of aligning the first `i` residues of the first sequence
+/// with the first `j` residues of the second. Displaying the matrix prints an aligned grid with
+/// the traceback cells marked with `*`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::{align_with_matrix, AlignmentMode, Scoring};
+///
+/// let alignment = align_with_matrix("AC", "A", AlignmentMode::Global, &Scoring::dna()).unwrap();
+/// let matrix = alignment.matrix.unwrap();
+/// assert_eq!(matrix.traceback, vec![(0, 0), (1, 1), (2, 1)]);
+/// assert_eq!(matrix.to_string(), "    ε   A\nε  0*  -1\nA  -1  2*\nC  -2  1*");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlignmentMatrix {
+    /// The first sequence, which labels the rows.
+    pub a: Vec<char>,
+    /// The second sequence, which labels the columns.
+    pub b: Vec<char>,
+    /// The scores, indexed by row and then by column.
+    pub scores: Vec<Vec<i32>>,
+    /// The cells on the traceback path, from the start of the alignment to its end.
+    pub traceback: Vec<(usize, usize)>,
+}
+
+impl fmt::Display for AlignmentMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cell = |i: usize, j: usize| {
+            let marker = if self.traceback.contains(&(i, j)) {
+                "*"
+            } else {
+                ""
+            };
+            format!("{}{marker}", self.scores[i][j])
+        };
+        let labels = |chars: &[char]| {
+            std::iter::once("ε".to_owned())
+                .chain(chars.iter().map(|c| c.to_string()))
+                .collect::<Vec<String>>()
+        };
+        let (row_labels, column_labels) = (labels(&self.a), labels(&self.b));
+        let widths: Vec<usize> = (0..column_labels.len())
+            .map(|j| {
+                (0..row_labels.len())
+                    .map(|i| cell(i, j).chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        write!(f, " ")?;
+        for (label, width) in column_labels.iter().zip(widths.iter()) {
+            write!(f, "  {label:>width$}")?;
+        }
+        for (i, label) in row_labels.iter().enumerate() {
+            write!(f, "\n{label}")?;
+            for (j, width) in widths.iter().enumerate() {
+                write!(f, "  {:>width$}", cell(i, j))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A pairwise alignment of two sequences.
+///
+/// Displaying an alignment prints three lines: the first sequence, a marker line, and the
+/// second sequence, each sequence prefixed and suffixed with its 1-based start and end
+/// positions. In the marker line `|` marks identical residues, `:` a positive score, `.` a
+/// score of zero or less, and a space a gap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairwiseAlignment {
+    /// The alignment's score.
+    pub score: i32,
+    /// The aligned part of the first sequence, with `-` for gaps.
+    pub aligned_a: String,
+    /// The aligned part of the second sequence, with `-` for gaps.
+    pub aligned_b: String,
+    /// The marker line shown between the aligned sequences.
+    pub markers: String,
+    /// The 0-based range of the first sequence covered by the alignment.
+    pub a_range: Range<usize>,
+    /// The 0-based range of the second sequence covered by the alignment.
+    pub b_range: Range<usize>,
+    /// The DP matrix and traceback, if requested with [`align_with_matrix`].
+    pub matrix: Option<AlignmentMatrix>,
+}
+
+impl fmt::Display for PairwiseAlignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.aligned_a.is_empty() {
+            return write!(f, "(empty alignment)");
+        }
+        let starts = [self.a_range.start + 1, self.b_range.start + 1];
+        let width = starts
+            .iter()
+            .map(|s| s.to_string().len())
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            f,
+            "{:>width$} {} {}",
+            starts[0], self.aligned_a, self.a_range.end
+        )?;
+        writeln!(f, "{:width$} {}", "", self.markers)?;
+        write!(
+            f,
+            "{:>width$} {} {}",
+            starts[1], self.aligned_b, self.b_range.end
+        )
+    }
+}
+
+/// The traceback state: which kind of column ends the alignment at a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Two residues aligned against each other.
+    Pair,
+    /// A residue of the first sequence against a gap.
+    GapInB,
+    /// A residue of the second sequence against a gap.
+    GapInA,
+}
+
+/// A score low enough to mark impossible cells without overflowing when penalties are subtracted.
+const IMPOSSIBLE: i32 = i32::MIN / 4;
+
+fn residues(
+    sequence: &str,
+    number: usize,
+    matrix: &ScoringMatrix,
+) -> Result<Vec<char>, AlignmentError> {
+    sequence
+        .chars()
+        .enumerate()
+        .map(|(position, residue)| match matrix.score(residue, residue) {
+            Some(_) => Ok(residue.to_ascii_uppercase()),
+            None => Err(AlignmentError::UnscoredResidue {
+                residue,
+                sequence: number,
+                position,
+            }),
+        })
+        .collect()
+}
+
+/// Aligns `a` and `b` with affine gap penalties using Gotoh's three-matrix algorithm.
+///
+/// [`AlignmentMode::Global`] is Needleman–Wunsch and [`AlignmentMode::Local`] is
+/// Smith–Waterman. Ties are broken in favour of aligning residues, then gaps in `b`, then gaps
+/// in `a`. A local alignment with no positive score is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::{align, AlignmentMode, Scoring};
+///
+/// let alignment = align("ACGT", "AGT", AlignmentMode::Global, &Scoring::dna()).unwrap();
+/// assert_eq!(alignment.score, 5);
+/// assert_eq!(alignment.to_string(), "1 ACGT 4\n  | ||\n1 A-GT 3");
+///
+/// let local = align("TTACGTT", "CCACGCC", AlignmentMode::Local, &Scoring::dna()).unwrap();
+/// assert_eq!((local.aligned_a.as_str(), local.score), ("ACG", 6));
+/// assert_eq!(local.a_range, 2..5);
+/// ```
+pub fn align(
+    a: &str,
+    b: &str,
+    mode: AlignmentMode,
+    scoring: &Scoring,
+) -> Result<PairwiseAlignment, AlignmentError> {
+    let mut alignment = align_with_matrix(a, b, mode, scoring)?;
+    alignment.matrix = None;
+    Ok(alignment)
+}
+
+/// Aligns `a` and `b` like [`align`], also returning the DP matrix and traceback.
+pub fn align_with_matrix(
+    a: &str,
+    b: &str,
+    mode: AlignmentMode,
+    scoring: &Scoring,
+) -> Result<PairwiseAlignment, AlignmentError> {
+    let a = residues(a, 1, &scoring.matrix)?;
+    let b = residues(b, 2, &scoring.matrix)?;
+    let (n, m) = (a.len(), b.len());
+    let (open, extend) = (scoring.gap.open, scoring.gap.extend);
+    let local = mode == AlignmentMode::Local;
+    let score = |i: usize, j: usize| {
+        scoring
+            .matrix
+            .score(a[i - 1], b[j - 1])
+            .expect("residues were checked against the matrix")
+    };
+
+    // pair[i][j], gap_b[i][j] and gap_a[i][j] hold the best score of aligning a[..i] with b[..j]
+    // when the last column is a pair of residues, a[i - 1] against a gap, or b[j - 1] against a
+    // gap, respectively.
+    let mut pair = vec![vec![IMPOSSIBLE; m + 1]; n + 1];
+    let mut gap_b = vec![vec![IMPOSSIBLE; m + 1]; n + 1];
+    let mut gap_a = vec![vec![IMPOSSIBLE; m + 1]; n + 1];
+    pair[0][0] = 0;
+    for i in 1..=n {
+        match local {
+            true => pair[i][0] = 0,
+            false => gap_b[i][0] = -(open + extend * (i as i32 - 1)),
+        }
+    }
+    for j in 1..=m {
+        match local {
+            true => pair[0][j] = 0,
+            false => gap_a[0][j] = -(open + extend * (j as i32 - 1)),
+        }
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let mut previous = pair[i - 1][j - 1]
+                .max(gap_b[i - 1][j - 1])
+                .max(gap_a[i - 1][j - 1]);
+            if local {
+                previous = previous.max(0);
+            }
+            pair[i][j] = score(i, j) + previous;
+            gap_b[i][j] = (pair[i - 1][j] - open)
+                .max(gap_b[i - 1][j] - extend)
+                .max(gap_a[i - 1][j] - open)
+                .max(IMPOSSIBLE);
+            gap_a[i][j] = (pair[i][j - 1] - open)
+                .max(gap_a[i][j - 1] - extend)
+                .max(gap_b[i][j - 1] - open)
+                .max(IMPOSSIBLE);
+        }
+    }
+    let best = |i: usize, j: usize| pair[i][j].max(gap_b[i][j]).max(gap_a[i][j]);
+
+    let (mut i, mut j, mut state, total) = if local {
+        let mut end = (0, 0, State::Pair, 0);
+        for (i, row) in pair.iter().enumerate() {
+            for (j, &score) in row.iter().enumerate() {
+                if score > end.3 {
+                    end = (i, j, State::Pair, score);
+                }
+            }
+        }
+        end
+    } else {
+        let state = if best(n, m) == pair[n][m] {
+            State::Pair
+        } else if best(n, m) == gap_b[n][m] {
+            State::GapInB
+        } else {
+            State::GapInA
+        };
+        (n, m, state, best(n, m))
+    };
+    let end = (i, j);
+
+    let (mut aligned_a, mut aligned_b, mut markers) = (Vec::new(), Vec::new(), Vec::new());
+    let mut traceback = vec![(i, j)];
+    while (i, j) != (0, 0) && !(local && total == 0) {
+        let next = match state {
+            State::Pair => {
+                let s = score(i, j);
+                aligned_a.push(a[i - 1]);
+                aligned_b.push(b[j - 1]);
+                markers.push(match (a[i - 1] == b[j - 1], s > 0) {
+                    (true, _) => '|',
+                    (false, true) => ':',
+                    (false, false) => '.',
+                });
+                let previous = pair[i][j] - s;
+                let next = if local && pair[i][j] == s {
+                    None
+                } else if previous == pair[i - 1][j - 1] {
+                    Some(State::Pair)
+                } else if previous == gap_b[i - 1][j - 1] {
+                    Some(State::GapInB)
+                } else {
+                    Some(State::GapInA)
+                };
+                (i, j) = (i - 1, j - 1);
+                next
+            }
+            State::GapInB => {
+                aligned_a.push(a[i - 1]);
+                aligned_b.push('-');
+                markers.push(' ');
+                let next = if gap_b[i][j] == pair[i - 1][j] - open {
+                    State::Pair
+                } else if gap_b[i][j] == gap_b[i - 1][j] - extend {
+                    State::GapInB
+                } else {
+                    State::GapInA
+                };
+                i -= 1;
+                Some(next)
+            }
+            State::GapInA => {
+                aligned_a.push('-');
+                aligned_b.push(b[j - 1]);
+                markers.push(' ');
+                let next = if gap_a[i][j] == pair[i][j - 1] - open {
+                    State::Pair
+                } else if gap_a[i][j] == gap_a[i][j - 1] - extend {
+                    State::GapInA
+                } else {
+                    State::GapInB
+                };
+                j -= 1;
+                Some(next)
+            }
+        };
+        traceback.push((i, j));
+        match next {
+            Some(next) => state = next,
+            None => break,
+        }
+    }
+    traceback.reverse();
+    if local && total == 0 {
+        traceback.clear();
+    }
+
+    let reversed = |chars: Vec<char>| chars.into_iter().rev().collect::<String>();
+    Ok(PairwiseAlignment {
+        score: total,
+        aligned_a: reversed(aligned_a),
+        aligned_b: reversed(aligned_b),
+        markers: reversed(markers),
+        a_range: i..end.0,
+        b_range: j..end.1,
+        matrix: Some(AlignmentMatrix {
+            scores: (0..=n)
+                .map(|i| (0..=m).map(|j| best(i, j)).collect())
+                .collect(),
+            a,
+            b,
+            traceback,
+        }),
+    })
+}
+
+/// Globally aligns `a` and `b` end to end (Needleman–Wunsch).
+pub fn needleman_wunsch(
+    a: &str,
+    b: &str,
+    scoring: &Scoring,
+) -> Result<PairwiseAlignment, AlignmentError> {
+    align(a, b, AlignmentMode::Global, scoring)
+}
+
+/// Finds the best local alignment between substrings of `a` and `b` (Smith–Waterman).
+pub fn smith_waterman(
+    a: &str,
+    b: &str,
+    scoring: &Scoring,
+) -> Result<PairwiseAlignment, AlignmentError> {
+    align(a, b, AlignmentMode::Local, scoring)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_matrices() {
+        for matrix in [ScoringMatrix::blosum62(), ScoringMatrix::pam250()] {
+            assert_eq!(matrix.residues().len(), 24);
+            for &x in matrix.residues() {
+                for &y in matrix.residues() {
+                    assert_eq!(matrix.score(x, y), matrix.score(y, x));
+                }
+            }
+        }
+        assert_eq!(ScoringMatrix::pam250().score('W', 'W'), Some(17));
+        assert_eq!(ScoringMatrix::pam250().score('C', '*'), Some(-8));
+    }
+
+    #[test]
+    fn test_invalid_matrices() {
+        let error = |text: &str| ScoringMatrix::parse(text).unwrap_err().to_string();
+        assert_eq!(
+            error(""),
+            "invalid scoring matrix on line 1: the matrix is empty"
+        );
+        assert_eq!(
+            error(" A B\nA 1 x\n"),
+            "invalid scoring matrix on line 2: \"x\" is not an integer"
+        );
+        assert_eq!(
+            error(" A B\nA 1 0\n"),
+            "invalid scoring matrix on line 2: 'B' has no row"
+        );
+        assert_eq!(
+            error(" A AB\n"),
+            "invalid scoring matrix on line 1: expected a single residue but found \"AB\""
+        );
+    }
+
+    #[test]
+    fn test_global_alignment() {
+        // The example from Durbin et al., Biological Sequence Analysis, scored with BLOSUM62.
+        let scoring = Scoring {
+            matrix: ScoringMatrix::blosum62(),
+            gap: GapPenalty::linear(8),
+        };
+        let alignment = needleman_wunsch("HEAGAWGHEE", "PAWHEAE", &scoring).unwrap();
+        assert_eq!(alignment.score, -8);
+        assert_eq!(alignment.aligned_a, "HEAGAWGHEE");
+        assert_eq!(alignment.aligned_b, "--P-AWHEAE");
+        assert_eq!(alignment.markers, "  . ||...|");
+        assert_eq!((alignment.a_range, alignment.b_range), (0..10, 0..7));
+
+        let empty = needleman_wunsch("", "AC", &Scoring::dna()).unwrap();
+        assert_eq!((empty.aligned_a.as_str(), empty.score), ("--", -2));
+    }
+
+    #[test]
+    fn test_affine_gaps() {
+        // One long gap is cheaper than two short ones under an affine penalty.
+        let scoring = Scoring {
+            matrix: ScoringMatrix::match_mismatch("ACGT", 5, -4),
+            gap: GapPenalty::affine(10, 1),
+        };
+        let alignment = needleman_wunsch("ACGTTTTACG", "ACGACG", &scoring).unwrap();
+        assert_eq!(alignment.aligned_b, "ACG----ACG");
+        assert_eq!(alignment.score, 30 - 10 - 3);
+        assert_eq!(alignment.markers, "|||    |||");
+
+        let scoring = Scoring::blosum62();
+        let (a, b) = ("MEEPLQWHHAVKLLEG", "MEPLVWHHAVRLEG");
+        let global = needleman_wunsch(a, b, &scoring).unwrap();
+        assert_eq!(global.score, 49);
+        assert_eq!(global.aligned_b, "M-EPLVWHHAVR-LEG");
+        assert_eq!(global.markers, "| |||.|||||: |||");
+        let local = smith_waterman(a, b, &scoring).unwrap();
+        assert_eq!(local.score, 55);
+        assert_eq!(
+            local.to_string(),
+            "3 EPLQWHHAVKL 13\n  |||.|||||:|\n2 EPLVWHHAVRL 12"
+        );
+    }
+
+    #[test]
+    fn test_local_alignment() {
+        let scoring = Scoring {
+            matrix: ScoringMatrix::blosum62(),
+            gap: GapPenalty::linear(8),
+        };
+        let alignment = smith_waterman("HEAGAWGHEE", "PAWHEAE", &scoring).unwrap();
+        assert_eq!(alignment.score, 20);
+        assert_eq!(alignment.aligned_a, "AWGHE");
+        assert_eq!(alignment.aligned_b, "AW-HE");
+        assert_eq!(alignment.to_string(), "5 AWGHE 9\n  || ||\n2 AW-HE 5");
+
+        let none = smith_waterman("AAA", "CCC", &Scoring::dna()).unwrap();
+        assert_eq!(none.score, 0);
+        assert_eq!(none.to_string(), "(empty alignment)");
+        assert_eq!(
+            smith_waterman("ACGZ", "A", &Scoring::dna()),
+            Err(AlignmentError::UnscoredResidue {
+                residue: 'Z',
+                sequence: 1,
+                position: 3
+            })
+        );
+    }
+}
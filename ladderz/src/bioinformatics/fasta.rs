@@ -0,0 +1,293 @@
+use super::{RecordError, Sequence, SequenceError};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// A single FASTA record: a `>` header line followed by sequence lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastaRecord {
+    /// The identifier, i.e. the header text up to the first whitespace.
+    pub id: String,
+    /// The rest of the header after the identifier, if any.
+    pub description: Option<String>,
+    /// The sequence with line breaks and whitespace removed.
+    pub sequence: String,
+}
+
+impl FastaRecord {
+    /// Creates a record without a description.
+    pub fn new(id: impl Into<String>, sequence: impl Into<String>) -> Self {
+        FastaRecord {
+            id: id.into(),
+            description: None,
+            sequence: sequence.into(),
+        }
+    }
+
+    /// Validates the record's sequence with [`Sequence::infer`].
+    pub fn to_sequence(&self) -> Result<Sequence, SequenceError> {
+        Sequence::infer(&self.sequence)
+    }
+}
+
+impl fmt::Display for FastaRecord {
+    /// Writes the record with the whole sequence on one line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, ">{}", self.id)?;
+        if let Some(description) = &self.description {
+            write!(f, " {description}")?;
+        }
+        write!(f, "\n{}", self.sequence)
+    }
+}
+
+/// Splits a header line (without its marker) into an identifier and an optional description.
+pub(super) fn split_header(
+    header: &str,
+    line: usize,
+) -> Result<(String, Option<String>), RecordError> {
+    let header = header.trim();
+    let (id, description) = match header.split_once(char::is_whitespace) {
+        Some((id, description)) => (id, Some(description.trim().to_owned())),
+        None => (header, None),
+    };
+    if id.is_empty() {
+        return Err(RecordError::Malformed {
+            line,
+            message: "the header is missing a record identifier".to_owned(),
+        });
+    }
+    Ok((id.to_owned(), description.filter(|d| !d.is_empty())))
+}
+
+/// Reads lines one at a time, tracking the current line number and stripping line endings.
+pub(super) struct LineReader<R> {
+    reader: R,
+    pub(super) line_number: usize,
+}
+
+impl<R: BufRead> LineReader<R> {
+    pub(super) fn new(reader: R) -> Self {
+        LineReader {
+            reader,
+            line_number: 0,
+        }
+    }
+
+    pub(super) fn next_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        self.line_number += 1;
+        let trimmed = line.trim_end_matches(['\n', '\r']).len();
+        line.truncate(trimmed);
+        Ok(Some(line))
+    }
+}
+
+/// A streaming reader that yields one [`FastaRecord`] at a time.
+///
+/// Blank lines are skipped, and sequences may span any number of lines. Iteration stops after
+/// the first error.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::fasta::FastaReader;
+///
+/// let data = ">seq1 first read\nACGT\nAC\n>seq2\nGGCC\n";
+/// let records: Vec<_> = FastaReader::new(data.as_bytes())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].id, "seq1");
+/// assert_eq!(records[0].description.as_deref(), Some("first read"));
+/// assert_eq!(records[0].sequence, "ACGTAC");
+/// assert_eq!(records[1].sequence, "GGCC");
+/// ```
+pub struct FastaReader<R> {
+    lines: LineReader<R>,
+    header: Option<(String, usize)>,
+    done: bool,
+}
+
+impl<R: BufRead> FastaReader<R> {
+    /// Creates a reader over any buffered input, such as a `BufReader<File>` or a byte slice.
+    pub fn new(reader: R) -> Self {
+        FastaReader {
+            lines: LineReader::new(reader),
+            header: None,
+            done: false,
+        }
+    }
+
+    fn read_record(&mut self) -> Result<Option<FastaRecord>, RecordError> {
+        let (header, header_line) = match self.header.take() {
+            Some(header) => header,
+            None => loop {
+                match self.lines.next_line()? {
+                    None => return Ok(None),
+                    Some(line) if line.trim().is_empty() => continue,
+                    Some(line) => match line.strip_prefix('>') {
+                        Some(header) => break (header.to_owned(), self.lines.line_number),
+                        None => {
+                            return Err(RecordError::Malformed {
+                                line: self.lines.line_number,
+                                message: "expected a header line starting with '>'".to_owned(),
+                            })
+                        }
+                    },
+                }
+            },
+        };
+        let (id, description) = split_header(&header, header_line)?;
+
+        let mut sequence = String::new();
+        while let Some(line) = self.lines.next_line()? {
+            if let Some(next_header) = line.strip_prefix('>') {
+                self.header = Some((next_header.to_owned(), self.lines.line_number));
+                break;
+            }
+            sequence.extend(line.chars().filter(|c| !c.is_whitespace()));
+        }
+        Ok(Some(FastaRecord {
+            id,
+            description,
+            sequence,
+        }))
+    }
+}
+
+impl<R: BufRead> Iterator for FastaReader<R> {
+    type Item = Result<FastaRecord, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.read_record().transpose();
+        if !matches!(result, Some(Ok(_))) {
+            self.done = true;
+        }
+        result
+    }
+}
+
+/// Writes [`FastaRecord`]s, wrapping sequences at a fixed line width.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::fasta::{FastaRecord, FastaWriter};
+///
+/// let mut writer = FastaWriter::with_line_width(Vec::new(), 4);
+/// writer.write_record(&FastaRecord::new("seq1", "ACGTACG")).unwrap();
+///
+/// assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), ">seq1\nACGT\nACG\n");
+/// ```
+pub struct FastaWriter<W> {
+    writer: W,
+    line_width: usize,
+}
+
+impl<W: Write> FastaWriter<W> {
+    /// Creates a writer that wraps sequences at 60 characters per line.
+    pub fn new(writer: W) -> Self {
+        Self::with_line_width(writer, 60)
+    }
+
+    /// Creates a writer that wraps sequences at `line_width` characters per line, or never if
+    /// `line_width` is 0.
+    pub fn with_line_width(writer: W, line_width: usize) -> Self {
+        FastaWriter { writer, line_width }
+    }
+
+    /// Writes one record.
+    pub fn write_record(&mut self, record: &FastaRecord) -> io::Result<()> {
+        write!(self.writer, ">{}", record.id)?;
+        if let Some(description) = &record.description {
+            write!(self.writer, " {description}")?;
+        }
+        writeln!(self.writer)?;
+        if self.line_width == 0 {
+            return writeln!(self.writer, "{}", record.sequence);
+        }
+        for chunk in record.sequence.as_bytes().chunks(self.line_width) {
+            self.writer.write_all(chunk)?;
+            writeln!(self.writer)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_multiple_records() {
+        let data = "\n>a\r\nAC GT\r\n\r\n>b desc  \n>c\nTT\n";
+        let records: Vec<FastaRecord> = FastaReader::new(data.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            records,
+            vec![
+                FastaRecord::new("a", "ACGT"),
+                FastaRecord {
+                    id: "b".to_owned(),
+                    description: Some("desc".to_owned()),
+                    sequence: String::new()
+                },
+                FastaRecord::new("c", "TT"),
+            ]
+        );
+        assert_eq!(FastaReader::new("".as_bytes()).count(), 0);
+    }
+
+    #[test]
+    fn test_malformed_records() {
+        let mut reader = FastaReader::new("ACGT\n>a\nAC\n".as_bytes());
+        let error = reader.next().unwrap().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: expected a header line starting with '>'"
+        );
+        assert!(reader.next().is_none());
+
+        let mut reader = FastaReader::new(">a\nAC\n> \nGG\n".as_bytes());
+        assert!(reader.next().unwrap().is_ok());
+        assert!(matches!(
+            reader.next(),
+            Some(Err(RecordError::Malformed { line: 3, .. }))
+        ));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let records = vec![
+            FastaRecord {
+                id: "x".to_owned(),
+                description: Some("two words".to_owned()),
+                sequence: "ACGTACGTAC".to_owned(),
+            },
+            FastaRecord::new("y", "G"),
+        ];
+        let mut writer = FastaWriter::with_line_width(Vec::new(), 3);
+        for record in &records {
+            writer.write_record(record).unwrap();
+        }
+        let bytes = writer.into_inner();
+        let read: Vec<FastaRecord> = FastaReader::new(bytes.as_slice())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(read, records);
+        assert_eq!(records[1].to_string(), ">y\nG");
+    }
+}
@@ -0,0 +1,343 @@
+use super::fasta::{split_header, FastaRecord, LineReader};
+use super::{RecordError, Sequence, SequenceError};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// How quality scores are encoded as printable ASCII characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QualityEncoding {
+    /// Phred scores offset by 33 (`!` is 0), used by Sanger and Illumina 1.8+.
+    #[default]
+    Phred33,
+    /// Phred scores offset by 64 (`@` is 0), used by Illumina 1.3 to 1.7.
+    Phred64,
+}
+
+impl QualityEncoding {
+    /// Returns the ASCII code that represents a quality score of 0.
+    pub fn offset(&self) -> u8 {
+        match self {
+            QualityEncoding::Phred33 => 33,
+            QualityEncoding::Phred64 => 64,
+        }
+    }
+}
+
+/// Decodes a quality string into Phred scores.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::fastq::{decode_quality, QualityEncoding};
+///
+/// assert_eq!(decode_quality("!+5I", QualityEncoding::Phred33).unwrap(), vec![0, 10, 20, 40]);
+/// assert!(decode_quality("!", QualityEncoding::Phred64).is_err());
+/// ```
+pub fn decode_quality(quality: &str, encoding: QualityEncoding) -> Result<Vec<u8>, RecordError> {
+    let offset = encoding.offset();
+    quality
+        .chars()
+        .enumerate()
+        .map(|(position, character)| match u8::try_from(character) {
+            Ok(code) if (offset..=b'~').contains(&code) => Ok(code - offset),
+            _ => Err(RecordError::InvalidQuality {
+                character,
+                position,
+            }),
+        })
+        .collect()
+}
+
+/// Encodes Phred scores as a quality string, capping each score at the highest printable value.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::fastq::{encode_quality, QualityEncoding};
+///
+/// assert_eq!(encode_quality(&[0, 10, 20, 40], QualityEncoding::Phred33), "!+5I");
+/// ```
+pub fn encode_quality(scores: &[u8], encoding: QualityEncoding) -> String {
+    let offset = encoding.offset();
+    scores
+        .iter()
+        .map(|&score| score.min(b'~' - offset) + offset)
+        .map(char::from)
+        .collect()
+}
+
+/// Converts a Phred quality score into the probability that the base call is wrong.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::fastq::error_probability;
+///
+/// assert_eq!(error_probability(20), 0.01);
+/// ```
+pub fn error_probability(score: u8) -> f64 {
+    10f64.powf(-(score as f64) / 10.0)
+}
+
+/// A single FASTQ record: a sequence with one quality character per base.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastqRecord {
+    /// The identifier, i.e. the header text up to the first whitespace.
+    pub id: String,
+    /// The rest of the header after the identifier, if any.
+    pub description: Option<String>,
+    /// The sequence.
+    pub sequence: String,
+    /// The encoded quality string, which is as long as the sequence.
+    pub quality: String,
+}
+
+impl FastqRecord {
+    /// Decodes the record's quality string into Phred scores.
+    pub fn quality_scores(&self, encoding: QualityEncoding) -> Result<Vec<u8>, RecordError> {
+        decode_quality(&self.quality, encoding)
+    }
+
+    /// Validates the record's sequence with [`Sequence::infer`].
+    pub fn to_sequence(&self) -> Result<Sequence, SequenceError> {
+        Sequence::infer(&self.sequence)
+    }
+}
+
+impl From<FastqRecord> for FastaRecord {
+    /// Drops the quality string.
+    fn from(record: FastqRecord) -> Self {
+        FastaRecord {
+            id: record.id,
+            description: record.description,
+            sequence: record.sequence,
+        }
+    }
+}
+
+impl fmt::Display for FastqRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "@{}", self.id)?;
+        if let Some(description) = &self.description {
+            write!(f, " {description}")?;
+        }
+        write!(f, "\n{}\n+\n{}", self.sequence, self.quality)
+    }
+}
+
+/// A streaming reader that yields one [`FastqRecord`] at a time.
+///
+/// Each record must be four lines: an `@` header, the sequence, a `+` separator (optionally
+/// repeating the header), and the quality string. Blank lines between records are skipped.
+/// Iteration stops after the first error.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::fastq::{FastqReader, QualityEncoding};
+///
+/// let data = "@read1\nACGT\n+\nII#!\n@read2 lane 2\nGG\n+read2 lane 2\n55\n";
+/// let records: Vec<_> = FastqReader::new(data.as_bytes())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].quality_scores(QualityEncoding::Phred33).unwrap(), vec![40, 40, 2, 0]);
+/// assert_eq!(records[1].description.as_deref(), Some("lane 2"));
+/// ```
+pub struct FastqReader<R> {
+    lines: LineReader<R>,
+    done: bool,
+}
+
+impl<R: BufRead> FastqReader<R> {
+    /// Creates a reader over any buffered input, such as a `BufReader<File>` or a byte slice.
+    pub fn new(reader: R) -> Self {
+        FastqReader {
+            lines: LineReader::new(reader),
+            done: false,
+        }
+    }
+
+    fn malformed(&self, message: impl Into<String>) -> RecordError {
+        RecordError::Malformed {
+            line: self.lines.line_number,
+            message: message.into(),
+        }
+    }
+
+    fn required_line(&mut self, name: &str) -> Result<String, RecordError> {
+        match self.lines.next_line()? {
+            Some(line) => Ok(line),
+            None => {
+                Err(self.malformed(format!("unexpected end of input, expected the {name} line")))
+            }
+        }
+    }
+
+    fn read_record(&mut self) -> Result<Option<FastqRecord>, RecordError> {
+        let header = loop {
+            match self.lines.next_line()? {
+                None => return Ok(None),
+                Some(line) if line.trim().is_empty() => continue,
+                Some(line) => match line.strip_prefix('@') {
+                    Some(header) => break header.to_owned(),
+                    None => return Err(self.malformed("expected a header line starting with '@'")),
+                },
+            }
+        };
+        let (id, description) = split_header(&header, self.lines.line_number)?;
+
+        let sequence = self.required_line("sequence")?.trim().to_owned();
+        let separator = self.required_line("'+' separator")?;
+        match separator.strip_prefix('+') {
+            Some(repeated) if repeated.trim().is_empty() || repeated.trim() == header.trim() => {}
+            Some(_) => return Err(self.malformed("the '+' line doesn't match the header")),
+            None => return Err(self.malformed("expected a separator line starting with '+'")),
+        }
+        let quality = self.required_line("quality")?.trim().to_owned();
+        if quality.chars().count() != sequence.chars().count() {
+            return Err(self.malformed(format!(
+                "the quality string has {} characters but the sequence has {}",
+                quality.chars().count(),
+                sequence.chars().count()
+            )));
+        }
+        Ok(Some(FastqRecord {
+            id,
+            description,
+            sequence,
+            quality,
+        }))
+    }
+}
+
+impl<R: BufRead> Iterator for FastqReader<R> {
+    type Item = Result<FastqRecord, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = self.read_record().transpose();
+        if !matches!(result, Some(Ok(_))) {
+            self.done = true;
+        }
+        result
+    }
+}
+
+/// Writes [`FastqRecord`]s in the four-line format.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::bioinformatics::fastq::{FastqRecord, FastqWriter};
+///
+/// let record = FastqRecord {
+///     id: "read1".to_owned(),
+///     description: None,
+///     sequence: "ACGT".to_owned(),
+///     quality: "IIII".to_owned(),
+/// };
+/// let mut writer = FastqWriter::new(Vec::new());
+/// writer.write_record(&record).unwrap();
+///
+/// assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), "@read1\nACGT\n+\nIIII\n");
+/// ```
+pub struct FastqWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> FastqWriter<W> {
+    /// Creates a writer.
+    pub fn new(writer: W) -> Self {
+        FastqWriter { writer }
+    }
+
+    /// Writes one record.
+    pub fn write_record(&mut self, record: &FastqRecord) -> io::Result<()> {
+        writeln!(self.writer, "{record}")
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_all(data: &str) -> Vec<Result<FastqRecord, RecordError>> {
+        FastqReader::new(data.as_bytes()).collect()
+    }
+
+    #[test]
+    fn test_quality_decoding() {
+        assert_eq!(
+            decode_quality("@Jh", QualityEncoding::Phred64).unwrap(),
+            vec![0, 10, 40]
+        );
+        assert!(matches!(
+            decode_quality("II é", QualityEncoding::Phred33),
+            Err(RecordError::InvalidQuality {
+                character: ' ',
+                position: 2
+            })
+        ));
+        assert_eq!(encode_quality(&[255], QualityEncoding::Phred64), "~");
+        assert!((error_probability(30) - 0.001).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_malformed_records() {
+        let cases = [
+            (
+                ">read\nAC\n+\nII\n",
+                "line 1: expected a header line starting with '@'",
+            ),
+            (
+                "@read\nAC\n",
+                "line 2: unexpected end of input, expected the '+' separator line",
+            ),
+            (
+                "@read\nAC\n-\nII\n",
+                "line 3: expected a separator line starting with '+'",
+            ),
+            (
+                "@read\nAC\n+other\nII\n",
+                "line 3: the '+' line doesn't match the header",
+            ),
+            (
+                "@read\nACG\n+\nII\n",
+                "line 4: the quality string has 2 characters but the sequence has 3",
+            ),
+        ];
+        for (data, message) in cases {
+            let results = read_all(data);
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].as_ref().unwrap_err().to_string(), message);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data = "@r1 first\nACGT\n+\nI5+!\n\n@r2\nTT\n+\n##\n";
+        let records: Vec<FastqRecord> = read_all(data)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let mut writer = FastqWriter::new(Vec::new());
+        for record in &records {
+            writer.write_record(record).unwrap();
+        }
+        let written = String::from_utf8(writer.into_inner()).unwrap();
+        assert_eq!(written, data.replace("\n\n", "\n"));
+        assert_eq!(
+            FastaRecord::from(records[1].clone()),
+            FastaRecord::new("r2", "TT")
+        );
+    }
+}
@@ -1,9 +1,77 @@
+/// A self-balancing AVL tree.
+pub mod avl;
+/// An unbalanced binary search tree.
+pub mod bst;
+/// Classic dynamic programming problems that can return their DP tables.
+pub mod dp;
+/// A binary min-heap with decrease-key.
+pub mod heap;
+/// The k-Sum family of problems and subarray sums.
+pub mod k_sum;
+/// A singly linked list and an arena-backed doubly linked list.
+pub mod linked_list;
+/// A least recently used (LRU) cache.
+pub mod lru_cache;
+/// A first-in, first-out queue on a ring buffer.
+pub mod queue;
+/// A growable circular buffer used by [`Stack`] and [`Queue`].
+pub mod ring_buffer;
+/// A last-in, first-out stack on a ring buffer.
+pub mod stack;
+/// String searching, comparison, palindrome and suffix array algorithms.
+pub mod strings;
+/// A prefix tree of words.
+pub mod trie;
+/// A disjoint-set forest with path compression and union by rank.
+pub mod union_find;
+
+pub use avl::AvlTree;
+pub use bst::BinarySearchTree;
+pub use dp::{
+    coin_change_min_coins, coin_change_min_coins_table, coin_change_ways, coin_change_ways_table,
+    edit_distance_table, knapsack_01, knapsack_01_table, longest_increasing_subsequence,
+    longest_increasing_subsequence_table, matrix_chain_multiplication, matrix_chain_order,
+    matrix_chain_table, rod_cutting, rod_cutting_table, unbounded_knapsack,
+    unbounded_knapsack_table, DpTable,
+};
+pub use heap::BinaryHeap;
+pub use k_sum::{
+    all_two_sum_pairs, four_sum, k_sum, max_subarray, subarray_sum_equals_k, three_sum,
+    two_sum_sorted,
+};
+pub use linked_list::{DoublyLinkedList, SinglyLinkedList};
+pub use lru_cache::LruCache;
+pub use queue::Queue;
+pub use ring_buffer::RingBuffer;
+pub use stack::Stack;
+pub use strings::{
+    damerau_levenshtein, damerau_levenshtein_alignment, group_anagrams, kmp_search, lcp_array,
+    levenshtein, levenshtein_alignment, longest_common_subsequence, longest_common_substring,
+    longest_palindromic_substring, prefix_function, rabin_karp, suffix_array, z_function, z_search,
+    Alignment, EditOp,
+};
+pub use trie::Trie;
+pub use union_find::UnionFind;
+
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
 
-pub fn contains_duplicate(nums: Vec<i32>) -> bool {
-    let mut seen = HashSet::<i32>::new();
-    for &num in nums.iter() {
-        if seen.contains(&num) {
+/// Checks whether any value appears more than once in `nums`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::contains_duplicate;
+///
+/// assert!(contains_duplicate(&[1, 2, 3, 2]));
+/// assert!(!contains_duplicate(&["a", "b", "c"]));
+/// ```
+pub fn contains_duplicate<T: Hash + Eq>(nums: &[T]) -> bool {
+    let mut seen = HashSet::<&T>::new();
+    for num in nums.iter() {
+        if seen.contains(num) {
             return true;
         }
         seen.insert(num);
@@ -11,16 +79,26 @@ pub fn contains_duplicate(nums: Vec<i32>) -> bool {
     false
 }
 
-pub fn is_anagram(a: String, b: String) -> bool {
+/// Checks whether `a` is an anagram of `b`, comparing `char`s exactly.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::is_anagram;
+///
+/// assert!(is_anagram("marc", "cram"));
+/// assert!(!is_anagram("Marc", "cram"));
+/// ```
+pub fn is_anagram(a: impl AsRef<str>, b: impl AsRef<str>) -> bool {
     let mut letters = HashMap::new();
-    for c in a.chars() {
+    for c in a.as_ref().chars() {
         if let Some(&value) = letters.get(&c) {
             letters.insert(c, value + 1);
         } else {
             letters.insert(c, 1);
         }
     }
-    for c in b.chars() {
+    for c in b.as_ref().chars() {
         if let Some(&value) = letters.get(&c) {
             if value - 1 < 0 {
                 return false;
@@ -38,7 +116,17 @@ pub fn is_anagram(a: String, b: String) -> bool {
     true
 }
 
-pub fn is_anagram2(a: String, b: String) -> bool {
+/// Checks whether `a` is an anagram of `b` by counting `char`s in a single `HashMap`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::is_anagram2;
+///
+/// assert!(is_anagram2("listen", "silent"));
+/// ```
+pub fn is_anagram2(a: impl AsRef<str>, b: impl AsRef<str>) -> bool {
+    let (a, b) = (a.as_ref(), b.as_ref());
     if a.len() != b.len() {
         return false;
     }
@@ -54,40 +142,191 @@ pub fn is_anagram2(a: String, b: String) -> bool {
     letters.into_values().all(|c: i32| c == 0)
 }
 
-pub fn two_sum(nums: Vec<i32>, target: i32) -> Vec<i32> {
-    let mut seen = HashMap::new();
+/// Checks whether `a` is an anagram of `b`, ignoring case and treating each user-perceived
+/// character (grapheme cluster) as a single letter.
+///
+/// Both strings are lowercased and normalized to Unicode Normalization Form C, so a precomposed
+/// `é` matches `e` followed by a combining acute accent, and a letter with combining marks is
+/// never split from its marks.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::{is_anagram, is_anagram_unicode};
+///
+/// assert!(is_anagram_unicode("Marc", "cRAM"));
+/// // "é" written as one code point vs. "e" + U+0301 COMBINING ACUTE ACCENT.
+/// assert!(is_anagram_unicode("caf\u{e9}", "fe\u{301}ac"));
+/// assert!(!is_anagram("caf\u{e9}", "fe\u{301}ac"));
+/// // The accent stays attached to its "e" instead of counting as a separate letter.
+/// assert!(!is_anagram_unicode("e\u{301}a", "ea\u{301}"));
+/// ```
+pub fn is_anagram_unicode(a: impl AsRef<str>, b: impl AsRef<str>) -> bool {
+    fn normalize(s: &str) -> String {
+        s.to_lowercase().nfc().collect()
+    }
+    let (a, b) = (normalize(a.as_ref()), normalize(b.as_ref()));
+
+    let mut letters: HashMap<&str, i32> = HashMap::new();
+    for g in a.graphemes(true) {
+        *letters.entry(g).or_default() += 1;
+    }
+    for g in b.graphemes(true) {
+        *letters.entry(g).or_default() -= 1;
+    }
+
+    letters.into_values().all(|c| c == 0)
+}
+
+/// An integer type whose sums and differences can be computed without overflowing.
+///
+/// The sum functions are generic over it so that numbers near the limits of their type, such as
+/// `i32::MAX`, give the right answer instead of panicking. Differences are checked, and running
+/// sums are kept as `i128`, which holds the sum of any slice of these types.
+pub trait CheckedInt: Copy + Ord {
+    /// Returns `self - rhs`, or `None` if it overflows.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Returns `self` as an `i128`.
+    fn widen(self) -> i128;
+}
+
+macro_rules! impl_checked_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedInt for $t {
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn widen(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Returns the indices of two numbers in `nums` that sum to `target`.
+///
+/// The later index comes first. If no such pair exists, an empty `Vec` is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::two_sum;
+///
+/// assert_eq!(two_sum(&[2, 3, 8, 5], 8), vec![3, 1]);
+/// assert_eq!(two_sum(&[-3_i64, 7, 13], 10), vec![2, 0]);
+/// assert_eq!(two_sum(&[1, 2], 10), Vec::<usize>::new());
+/// ```
+pub fn two_sum<T>(nums: &[T], target: T) -> Vec<usize>
+where
+    T: CheckedInt + Hash + Eq,
+{
+    let mut seen: HashMap<T, usize> = HashMap::new();
     for (i, num) in nums.iter().enumerate() {
-        if seen.contains_key(num) {
-            return vec![i as i32, seen[num]];
+        if let Some(&j) = seen.get(num) {
+            return vec![i, j];
+        }
+        // If the difference doesn't fit in `T`, no number in `nums` can complete the pair.
+        if let Some(diff) = target.checked_sub(*num) {
+            seen.insert(diff, i);
         }
-        let diff = target - num;
-        seen.insert(diff, i as i32);
     }
-    vec![0, 1]
+    Vec::new()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_contains_duplicate() {
-        let result = contains_duplicate(vec![1, 2, 3, 2]);
+        let result = contains_duplicate(&[1, 2, 3, 2]);
         let expected = true;
         assert_eq!(result, expected);
+
+        assert!(!contains_duplicate::<i32>(&[]));
+        assert!(contains_duplicate(&[
+            "a".to_owned(),
+            "b".to_owned(),
+            "a".to_owned()
+        ]));
     }
 
     #[test]
     fn test_is_anagram() {
-        let result = is_anagram("marc".to_owned(), "cram".to_owned());
+        let result = is_anagram("marc", "cram");
         let expected = true;
         assert_eq!(result, expected);
+
+        assert!(is_anagram2("marc", "cram"));
+        assert!(!is_anagram("marc", "crams"));
+    }
+
+    #[test]
+    fn test_is_anagram_unicode() {
+        assert!(is_anagram_unicode(
+            "Dormitory",
+            "dirty room".replace(' ', "")
+        ));
+        assert!(is_anagram_unicode("ΑΒΓ", "γβα"));
+        assert!(is_anagram_unicode("n\u{303}a", "a\u{f1}"));
+        // A flag emoji is one grapheme made of two regional indicator code points.
+        assert!(is_anagram_unicode("🇺🇸x", "x🇺🇸"));
+        assert!(!is_anagram_unicode("🇺🇸", "🇸🇺"));
+        assert!(!is_anagram_unicode("abc", "abd"));
     }
 
     #[test]
     fn test_two_sum() {
-        let result: HashSet<i32> = HashSet::from_iter(two_sum(vec![2, 3, 8, 5], 8));
-        let expected: HashSet<i32> = HashSet::from_iter(vec![1, 3]);
+        let result: HashSet<usize> = HashSet::from_iter(two_sum(&[2, 3, 8, 5], 8));
+        let expected: HashSet<usize> = HashSet::from_iter(vec![1, 3]);
         assert_eq!(result, expected);
+
+        assert_eq!(two_sum(&[1, 2, 4], 10), Vec::<usize>::new());
+        assert_eq!(two_sum::<i32>(&[], 0), Vec::<usize>::new());
+        // Differences past the limits of `i32` are skipped instead of overflowing.
+        assert_eq!(two_sum(&[i32::MAX, 1], i32::MIN), Vec::<usize>::new());
+        assert_eq!(two_sum(&[i32::MIN, -1, i32::MAX], -1), vec![2, 0]);
+        assert_eq!(two_sum(&[u8::MAX, 0], u8::MAX), vec![1, 0]);
+    }
+
+    // Properties that hold for every input, checked on random inputs by proptest.
+    proptest! {
+        #[test]
+        fn prop_is_anagram_agrees_with_is_anagram2(a in ".{0,20}", b in ".{0,20}") {
+            prop_assert_eq!(is_anagram(&a, &b), is_anagram2(&a, &b));
+        }
+
+        #[test]
+        fn prop_shuffled_words_are_anagrams(
+            (word, shuffled) in prop::collection::vec(prop::char::any(), 0..20)
+                .prop_flat_map(|chars| (Just(chars.clone()), Just(chars).prop_shuffle()))
+        ) {
+            let (word, shuffled): (String, String) =
+                (word.into_iter().collect(), shuffled.into_iter().collect());
+            prop_assert!(is_anagram(&word, &shuffled));
+            prop_assert!(is_anagram2(&word, &shuffled));
+        }
+
+        #[test]
+        fn prop_two_sum_indices_sum_to_target(
+            (nums, i, j) in prop::collection::vec(-1_000i64..1_000, 2..50)
+                .prop_flat_map(|nums| {
+                    let len = nums.len();
+                    (Just(nums), 0..len, 0..len)
+                })
+                .prop_filter("the indices must differ", |(_, i, j)| i != j)
+        ) {
+            let target = nums[i] + nums[j];
+            let indices = two_sum(&nums, target);
+            prop_assert_eq!(indices.len(), 2);
+            prop_assert!(indices[0] > indices[1]);
+            prop_assert_eq!(nums[indices[0]] + nums[indices[1]], target);
+        }
     }
 }
@@ -0,0 +1,333 @@
+use super::bst::write_tree;
+use std::cmp::Ordering;
+use std::fmt;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    height: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T> {
+    fn leaf(value: T) -> Box<Self> {
+        Box::new(Node {
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        })
+    }
+}
+
+/// A self-balancing AVL tree that stores unique, ordered values.
+///
+/// After every insertion or removal the heights of each node's two subtrees are kept within one
+/// of each other by rotating nodes, so lookups, insertions and removals all take O(log n) time.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::AvlTree;
+///
+/// // Inserting in sorted order would make an unbalanced tree a linked list of height 7.
+/// let tree: AvlTree<i32> = (1..=7).collect();
+/// assert_eq!(tree.height(), 3);
+/// assert_eq!(tree.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3, 4, 5, 6, 7]);
+/// ```
+pub struct AvlTree<T> {
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T: Ord> AvlTree<T> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        AvlTree { root: None, len: 0 }
+    }
+
+    /// Inserts `value` into the tree.
+    ///
+    /// Returns `false` and leaves the tree unchanged if an equal value is already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut inserted = false;
+        self.root = Some(insert(self.root.take(), value, &mut inserted));
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+
+    /// Returns `true` if the tree contains a value equal to `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Removes `value` from the tree, returning `true` if it was present.
+    pub fn remove(&mut self, value: &T) -> bool {
+        let mut removed = false;
+        self.root = remove(self.root.take(), value, &mut removed);
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Returns the smallest value in the tree.
+    pub fn min(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    /// Returns the largest value in the tree.
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+        Some(&node.value)
+    }
+}
+
+impl<T> AvlTree<T> {
+    /// Returns the number of nodes on the longest path from the root to a leaf.
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Returns the number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the values in ascending (in-order) order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+}
+
+fn height<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.height)
+}
+
+fn balance_factor<T>(node: &Node<T>) -> isize {
+    height(&node.left) as isize - height(&node.right) as isize
+}
+
+fn update_height<T>(node: &mut Node<T>) {
+    node.height = 1 + height(&node.left).max(height(&node.right));
+}
+
+fn rotate_right<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    let mut pivot = node.left.take().expect("left child to rotate");
+    node.left = pivot.right.take();
+    update_height(&mut node);
+    pivot.right = Some(node);
+    update_height(&mut pivot);
+    pivot
+}
+
+fn rotate_left<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    let mut pivot = node.right.take().expect("right child to rotate");
+    node.right = pivot.left.take();
+    update_height(&mut node);
+    pivot.left = Some(node);
+    update_height(&mut pivot);
+    pivot
+}
+
+fn rebalance<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    update_height(&mut node);
+    let balance = balance_factor(&node);
+    if balance > 1 {
+        if node.left.as_deref().map_or(0, balance_factor) < 0 {
+            node.left = node.left.take().map(rotate_left);
+        }
+        return rotate_right(node);
+    }
+    if balance < -1 {
+        if node.right.as_deref().map_or(0, balance_factor) > 0 {
+            node.right = node.right.take().map(rotate_right);
+        }
+        return rotate_left(node);
+    }
+    node
+}
+
+fn insert<T: Ord>(link: Link<T>, value: T, inserted: &mut bool) -> Box<Node<T>> {
+    let Some(mut node) = link else {
+        *inserted = true;
+        return Node::leaf(value);
+    };
+    match value.cmp(&node.value) {
+        Ordering::Less => node.left = Some(insert(node.left.take(), value, inserted)),
+        Ordering::Greater => node.right = Some(insert(node.right.take(), value, inserted)),
+        Ordering::Equal => return node,
+    }
+    rebalance(node)
+}
+
+fn remove<T: Ord>(link: Link<T>, value: &T, removed: &mut bool) -> Link<T> {
+    let mut node = link?;
+    match value.cmp(&node.value) {
+        Ordering::Less => node.left = remove(node.left.take(), value, removed),
+        Ordering::Greater => node.right = remove(node.right.take(), value, removed),
+        Ordering::Equal => {
+            *removed = true;
+            match (node.left.take(), node.right.take()) {
+                (None, None) => return None,
+                (Some(child), None) | (None, Some(child)) => return Some(child),
+                (Some(left), Some(right)) => {
+                    let (successor, rest) = remove_min(right);
+                    node.value = successor;
+                    node.left = Some(left);
+                    node.right = rest;
+                }
+            }
+        }
+    }
+    Some(rebalance(node))
+}
+
+fn remove_min<T>(mut node: Box<Node<T>>) -> (T, Link<T>) {
+    match node.left.take() {
+        None => {
+            let Node { value, right, .. } = *node;
+            (value, right)
+        }
+        Some(left) => {
+            let (min, rest) = remove_min(left);
+            node.left = rest;
+            (min, Some(rebalance(node)))
+        }
+    }
+}
+
+impl<T: Ord> Default for AvlTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for AvlTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = AvlTree::new();
+        for value in iter {
+            tree.insert(value);
+        }
+        tree
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for AvlTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Draws the tree with the root on the first line and each child labelled `L` or `R`.
+impl<T: fmt::Display> fmt::Display for AvlTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self.root.as_deref(), &|node: &Node<T>| {
+            (&node.value, node.left.as_deref(), node.right.as_deref())
+        })
+    }
+}
+
+/// An in-order iterator over the values of an [`AvlTree`].
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some(&node.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_balanced<T>(link: &Link<T>) -> usize {
+        match link {
+            None => 0,
+            Some(node) => {
+                let left = assert_balanced(&node.left);
+                let right = assert_balanced(&node.right);
+                assert!(left.abs_diff(right) <= 1);
+                assert_eq!(node.height, 1 + left.max(right));
+                node.height
+            }
+        }
+    }
+
+    #[test]
+    fn test_stays_balanced_on_sorted_input() {
+        let mut tree: AvlTree<u32> = AvlTree::new();
+        for i in 0..1000 {
+            assert!(tree.insert(i));
+            assert_balanced(&tree.root);
+        }
+        assert!(!tree.insert(500));
+        assert_eq!(tree.len(), 1000);
+        assert_eq!(tree.height(), 10);
+        assert_eq!(tree.min(), Some(&0));
+        assert_eq!(tree.max(), Some(&999));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tree: AvlTree<i32> = (1..=15).collect();
+        for value in [8, 1, 15, 4, 12] {
+            assert!(tree.remove(&value));
+            assert!(!tree.contains(&value));
+            assert_balanced(&tree.root);
+        }
+        assert!(!tree.remove(&100));
+        assert_eq!(
+            tree.iter().copied().collect::<Vec<i32>>(),
+            vec![2, 3, 5, 6, 7, 9, 10, 11, 13, 14]
+        );
+        assert_eq!(tree.len(), 10);
+    }
+
+    #[test]
+    fn test_rotations_display() {
+        // Left-right case: 3, 1, 2 rotates into 2 with children 1 and 3.
+        let tree: AvlTree<i32> = [3, 1, 2].into_iter().collect();
+        assert_eq!(tree.to_string(), "2\n├── L: 1\n└── R: 3");
+        // Right-left case.
+        let tree: AvlTree<i32> = [1, 3, 2].into_iter().collect();
+        assert_eq!(tree.to_string(), "2\n├── L: 1\n└── R: 3");
+    }
+}
@@ -0,0 +1,341 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+type Link<T> = Option<Box<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// An unbalanced binary search tree that stores unique, ordered values.
+///
+/// Every value in a node's left subtree is smaller than the node's value and every value in its
+/// right subtree is larger. Operations take O(h) time where h is the height of the tree, which
+/// degrades to O(n) when values are inserted in sorted order (see [`super::AvlTree`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::BinarySearchTree;
+///
+/// let mut tree: BinarySearchTree<i32> = [5, 3, 8, 1].into_iter().collect();
+/// assert!(tree.contains(&3));
+/// assert!(tree.remove(&3));
+/// assert_eq!(tree.iter().copied().collect::<Vec<i32>>(), vec![1, 5, 8]);
+/// assert_eq!(tree.to_string(), "5\n├── L: 1\n└── R: 8");
+/// ```
+pub struct BinarySearchTree<T> {
+    root: Link<T>,
+    len: usize,
+}
+
+impl<T: Ord> BinarySearchTree<T> {
+    /// Creates an empty tree.
+    pub fn new() -> Self {
+        BinarySearchTree { root: None, len: 0 }
+    }
+
+    /// Inserts `value` into the tree.
+    ///
+    /// Returns `false` and leaves the tree unchanged if an equal value is already present.
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut link = &mut self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                Ordering::Less => &mut node.left,
+                Ordering::Greater => &mut node.right,
+                Ordering::Equal => return false,
+            };
+        }
+        *link = Some(Box::new(Node {
+            value,
+            left: None,
+            right: None,
+        }));
+        self.len += 1;
+        true
+    }
+
+    /// Returns `true` if the tree contains a value equal to `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link {
+            link = match value.cmp(&node.value) {
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+                Ordering::Equal => return true,
+            };
+        }
+        false
+    }
+
+    /// Removes `value` from the tree, returning `true` if it was present.
+    ///
+    /// A node with two children is replaced by its in-order successor.
+    pub fn remove(&mut self, value: &T) -> bool {
+        // Move a cursor down to the link that holds `value`, then unlink its node there.
+        let mut link = &mut self.root;
+        loop {
+            match link.as_ref().map(|node| value.cmp(&node.value)) {
+                None => return false,
+                Some(Ordering::Equal) => break,
+                Some(Ordering::Less) => link = &mut link.as_mut().expect("a node").left,
+                Some(Ordering::Greater) => link = &mut link.as_mut().expect("a node").right,
+            }
+        }
+        let node = link.as_mut().expect("the loop stops at a node");
+        *link = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                node.left = Some(left);
+                node.right = Some(right);
+                node.value = take_min(&mut node.right);
+                link.take()
+            }
+        };
+        self.len -= 1;
+        true
+    }
+
+    /// Returns the smallest value in the tree.
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+        Some(&node.value)
+    }
+
+    /// Returns the largest value in the tree.
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+        Some(&node.value)
+    }
+}
+
+impl<T> BinarySearchTree<T> {
+    /// Returns the number of nodes on the longest path from the root to a leaf.
+    ///
+    /// The levels are counted breadth-first, so a degenerate tree doesn't overflow the stack.
+    pub fn height(&self) -> usize {
+        let mut level: Vec<&Node<T>> = self.root.as_deref().into_iter().collect();
+        let mut height = 0;
+        while !level.is_empty() {
+            height += 1;
+            level = level
+                .iter()
+                .flat_map(|node| [node.left.as_deref(), node.right.as_deref()])
+                .flatten()
+                .collect();
+        }
+        height
+    }
+
+    /// Returns the number of values in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the values in ascending (in-order) order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+}
+
+/// Removes the smallest value from a non-empty subtree, moving its right child up in its place.
+fn take_min<T>(mut link: &mut Link<T>) -> T {
+    while link.as_ref().is_some_and(|node| node.left.is_some()) {
+        link = &mut link.as_mut().expect("non-empty subtree").left;
+    }
+    let node = link.take().expect("non-empty subtree");
+    *link = node.right;
+    node.value
+}
+
+impl<T> Drop for BinarySearchTree<T> {
+    // Drop the nodes iteratively so that degenerate trees don't overflow the stack.
+    fn drop(&mut self) {
+        let mut stack: Vec<Box<Node<T>>> = self.root.take().into_iter().collect();
+        while let Some(mut node) = stack.pop() {
+            stack.extend(node.left.take());
+            stack.extend(node.right.take());
+        }
+    }
+}
+
+impl<T: Ord> Default for BinarySearchTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for BinarySearchTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = BinarySearchTree::new();
+        for value in iter {
+            tree.insert(value);
+        }
+        tree
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for BinarySearchTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Draws the tree with the root on the first line and each child labelled `L` or `R`.
+impl<T: fmt::Display> fmt::Display for BinarySearchTree<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self.root.as_deref(), &|node: &Node<T>| {
+            (&node.value, node.left.as_deref(), node.right.as_deref())
+        })
+    }
+}
+
+/// An in-order iterator over the values of a [`BinarySearchTree`].
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(n) = node {
+            self.stack.push(n);
+            node = n.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some(&node.value)
+    }
+}
+
+/// Splits a tree node into its value and its left and right children.
+pub(super) type NodeParts<N, T> = dyn Fn(&N) -> (&T, Option<&N>, Option<&N>);
+
+/// Writes a binary tree sideways, e.g. `5\n├── L: 3\n└── R: 8`.
+///
+/// Taking the node accessors as `parts` lets the different tree types share the drawing code.
+pub(super) fn write_tree<N, T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    root: Option<&N>,
+    parts: &NodeParts<N, T>,
+) -> fmt::Result {
+    fn write_children<N, T: fmt::Display>(
+        f: &mut fmt::Formatter<'_>,
+        node: &N,
+        parts: &NodeParts<N, T>,
+        prefix: &str,
+    ) -> fmt::Result {
+        let (_, left, right) = parts(node);
+        let children: Vec<(&str, &N)> = [("L", left), ("R", right)]
+            .into_iter()
+            .filter_map(|(label, child)| child.map(|c| (label, c)))
+            .collect();
+        for (i, (label, child)) in children.iter().enumerate() {
+            let last = i == children.len() - 1;
+            let (value, _, _) = parts(child);
+            let branch = if last { "└── " } else { "├── " };
+            write!(f, "\n{prefix}{branch}{label}: {value}")?;
+            let indent = if last { "    " } else { "│   " };
+            write_children(f, *child, parts, &format!("{prefix}{indent}"))?;
+        }
+        Ok(())
+    }
+
+    match root {
+        None => write!(f, "(empty)"),
+        Some(node) => {
+            write!(f, "{}", parts(node).0)?;
+            write_children(f, node, parts, "")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_contains() {
+        let mut tree: BinarySearchTree<i32> = BinarySearchTree::new();
+        assert!(tree.insert(5));
+        assert!(tree.insert(3));
+        assert!(tree.insert(8));
+        assert!(!tree.insert(3));
+        assert_eq!(tree.len(), 3);
+        assert!(tree.contains(&8));
+        assert!(!tree.contains(&4));
+        assert_eq!(tree.min(), Some(&3));
+        assert_eq!(tree.max(), Some(&8));
+        assert_eq!(tree.height(), 2);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut tree: BinarySearchTree<i32> =
+            [50, 30, 70, 20, 40, 60, 80, 65].into_iter().collect();
+        // Leaf, node with one child, node with two children, and the root.
+        assert!(tree.remove(&20));
+        assert!(tree.remove(&60));
+        assert!(tree.remove(&30));
+        assert!(tree.remove(&50));
+        assert!(!tree.remove(&50));
+        assert_eq!(
+            tree.iter().copied().collect::<Vec<i32>>(),
+            vec![40, 65, 70, 80]
+        );
+        assert_eq!(tree.len(), 4);
+    }
+
+    #[test]
+    fn test_degenerate_tree() {
+        // Sorted inserts make a chain as deep as the tree is long. A small stack makes recursing
+        // once per level overflow with few enough values for the O(n^2) inserts to stay fast.
+        let n = 10_000;
+        std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(move || {
+                let mut tree: BinarySearchTree<i32> = (0..n).collect();
+                assert_eq!(tree.height(), n as usize);
+                assert!(tree.remove(&(n - 1)));
+                assert!(tree.remove(&0));
+                assert!(!tree.remove(&n));
+                assert_eq!(tree.len(), n as usize - 2);
+                assert_eq!(tree.iter().count(), n as usize - 2);
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_display() {
+        let tree: BinarySearchTree<i32> = [5, 3, 8, 1, 4, 9].into_iter().collect();
+        let expected = "5\n├── L: 3\n│   ├── L: 1\n│   └── R: 4\n└── R: 8\n    └── R: 9";
+        assert_eq!(tree.to_string(), expected);
+        assert_eq!(format!("{:?}", tree), "{1, 3, 4, 5, 8, 9}");
+        assert_eq!(BinarySearchTree::<i32>::new().to_string(), "(empty)");
+    }
+}
@@ -0,0 +1,548 @@
+use super::strings::edit_distance_grid;
+use std::fmt;
+
+/// A dynamic programming table with labelled rows and columns.
+///
+/// Every solver in this module has a `*_table` function that returns the table it fills in, so
+/// the way the solution builds up from smaller subproblems can be inspected. A cell is `None`
+/// when it has no value, such as an unreachable amount in [`coin_change_min_coins_table`] or the
+/// unused lower half of [`matrix_chain_table`].
+///
+/// Displaying a table prints an aligned grid with `-` for empty cells.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::rod_cutting_table;
+///
+/// let table = rod_cutting_table(&[1, 5, 8, 9], 4);
+/// assert_eq!(table.get(0, 4), Some(10));
+/// assert_eq!(
+///     table.to_string(),
+///     "            0  1  2  3   4\nbest price  0  1  5  8  10\nfirst cut   -  1  2  3   2"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DpTable {
+    /// A label for each row, e.g. the items considered so far.
+    pub row_labels: Vec<String>,
+    /// A label for each column, e.g. the remaining capacity.
+    pub column_labels: Vec<String>,
+    /// The cells, indexed by row and then by column.
+    pub cells: Vec<Vec<Option<u64>>>,
+}
+
+impl DpTable {
+    /// Returns the value in the cell at `row` and `column`, if it has one.
+    pub fn get(&self, row: usize, column: usize) -> Option<u64> {
+        self.cells.get(row)?.get(column).copied().flatten()
+    }
+
+    /// Returns the value in the bottom-right cell, which is where most solvers store their answer.
+    pub fn last(&self) -> Option<u64> {
+        self.cells.last()?.last().copied().flatten()
+    }
+}
+
+impl fmt::Display for DpTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cell = |value: Option<u64>| value.map_or("-".to_owned(), |v| v.to_string());
+        let label_width = self
+            .row_labels
+            .iter()
+            .map(|l| l.chars().count())
+            .max()
+            .unwrap_or(0);
+        let widths: Vec<usize> = self
+            .column_labels
+            .iter()
+            .enumerate()
+            .map(|(j, label)| {
+                self.cells
+                    .iter()
+                    .filter_map(|row| row.get(j))
+                    .map(|&v| cell(v).chars().count())
+                    .chain(std::iter::once(label.chars().count()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        write!(f, "{:label_width$}", "")?;
+        for (label, width) in self.column_labels.iter().zip(widths.iter()) {
+            write!(f, "  {label:>width$}")?;
+        }
+        for (label, row) in self.row_labels.iter().zip(self.cells.iter()) {
+            write!(f, "\n{label:<label_width$}")?;
+            for (&value, width) in row.iter().zip(widths.iter()) {
+                write!(f, "  {:>width$}", cell(value))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn numbered_columns(last: usize) -> Vec<String> {
+    (0..=last).map(|c| c.to_string()).collect()
+}
+
+fn knapsack_table(weights: &[usize], values: &[u64], capacity: usize, unbounded: bool) -> DpTable {
+    assert_eq!(
+        weights.len(),
+        values.len(),
+        "every item needs both a weight and a value"
+    );
+    let mut cells: Vec<Vec<Option<u64>>> = vec![vec![Some(0); capacity + 1]];
+    let mut row_labels: Vec<String> = vec!["no items".to_owned()];
+    for (i, (&weight, &value)) in weights.iter().zip(values.iter()).enumerate() {
+        let previous = &cells[i];
+        let mut row: Vec<Option<u64>> = previous.clone();
+        for c in weight..=capacity {
+            // 0/1 items can only be added to a solution without them (the previous row),
+            // while unbounded items can be added to a solution that already uses them.
+            let without = if unbounded {
+                row[c - weight]
+            } else {
+                previous[c - weight]
+            };
+            row[c] = row[c].max(without.map(|v| v + value));
+        }
+        row_labels.push(format!("+ item {} (w={weight}, v={value})", i + 1));
+        cells.push(row);
+    }
+    DpTable {
+        row_labels,
+        column_labels: numbered_columns(capacity),
+        cells,
+    }
+}
+
+/// Returns the table for the 0/1 knapsack problem.
+///
+/// Cell `[i][c]` is the largest total value of a subset of the first `i` items whose weights sum
+/// to at most `c`. Each item can be taken at most once, so it either leaves the value from the
+/// row above unchanged or adds its value to the best value of the row above at `c - weight`.
+///
+/// # Panics
+///
+/// Panics if `weights` and `values` have different lengths.
+pub fn knapsack_01_table(weights: &[usize], values: &[u64], capacity: usize) -> DpTable {
+    knapsack_table(weights, values, capacity, false)
+}
+
+/// Returns the largest total value of items that fit in a knapsack, taking each item at most once.
+///
+/// See [`knapsack_01_table`] for the table this is read from.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::knapsack_01;
+///
+/// // Take the items with weights 1 and 3.
+/// assert_eq!(knapsack_01(&[1, 3, 4], &[15, 20, 30], 4), 35);
+/// ```
+pub fn knapsack_01(weights: &[usize], values: &[u64], capacity: usize) -> u64 {
+    knapsack_01_table(weights, values, capacity)
+        .last()
+        .unwrap_or(0)
+}
+
+/// Returns the table for the unbounded knapsack problem.
+///
+/// Like [`knapsack_01_table`], but each item may be taken any number of times, so an item's value
+/// is added to the best value of its own row at `c - weight`.
+///
+/// # Panics
+///
+/// Panics if `weights` and `values` have different lengths.
+pub fn unbounded_knapsack_table(weights: &[usize], values: &[u64], capacity: usize) -> DpTable {
+    knapsack_table(weights, values, capacity, true)
+}
+
+/// Returns the largest total value of items that fit in a knapsack, taking each item any number of times.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::unbounded_knapsack;
+///
+/// // Take the item with weight 1 four times.
+/// assert_eq!(unbounded_knapsack(&[1, 3, 4], &[15, 20, 30], 4), 60);
+/// ```
+pub fn unbounded_knapsack(weights: &[usize], values: &[u64], capacity: usize) -> u64 {
+    unbounded_knapsack_table(weights, values, capacity)
+        .last()
+        .unwrap_or(0)
+}
+
+fn coin_rows(coins: &[usize]) -> Vec<String> {
+    std::iter::once("no coins".to_owned())
+        .chain(coins.iter().map(|coin| format!("+ coin {coin}")))
+        .collect()
+}
+
+/// Returns the table for counting the ways to make change.
+///
+/// Cell `[i][a]` is the number of ways to make the amount `a` from the first `i` coin
+/// denominations, using each as many times as needed. Either the `i`-th coin is not used (the row
+/// above) or it is used at least once (the same row at `a - coin`).
+pub fn coin_change_ways_table(coins: &[usize], amount: usize) -> DpTable {
+    let mut first: Vec<Option<u64>> = vec![Some(0); amount + 1];
+    first[0] = Some(1);
+    let mut cells: Vec<Vec<Option<u64>>> = vec![first];
+    for &coin in coins.iter() {
+        let mut row: Vec<Option<u64>> = cells[cells.len() - 1].clone();
+        for a in coin.max(1)..=amount {
+            row[a] = Some(row[a].unwrap_or(0) + row[a - coin].unwrap_or(0));
+        }
+        cells.push(row);
+    }
+    DpTable {
+        row_labels: coin_rows(coins),
+        column_labels: numbered_columns(amount),
+        cells,
+    }
+}
+
+/// Returns the number of ways to make `amount` from unlimited coins of the given denominations.
+///
+/// The order of the coins does not matter, so `1 + 2` and `2 + 1` are the same way.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::coin_change_ways;
+///
+/// // 5, 2+2+1, 2+1+1+1 and 1+1+1+1+1
+/// assert_eq!(coin_change_ways(&[1, 2, 5], 5), 4);
+/// ```
+pub fn coin_change_ways(coins: &[usize], amount: usize) -> u64 {
+    coin_change_ways_table(coins, amount).last().unwrap_or(0)
+}
+
+/// Returns the table for finding the fewest coins that make change.
+///
+/// Cell `[i][a]` is the fewest coins from the first `i` denominations that sum to `a`, or `None`
+/// if `a` cannot be made from them.
+pub fn coin_change_min_coins_table(coins: &[usize], amount: usize) -> DpTable {
+    let mut first: Vec<Option<u64>> = vec![None; amount + 1];
+    first[0] = Some(0);
+    let mut cells: Vec<Vec<Option<u64>>> = vec![first];
+    for &coin in coins.iter() {
+        let mut row: Vec<Option<u64>> = cells[cells.len() - 1].clone();
+        for a in coin.max(1)..=amount {
+            row[a] = match (row[a], row[a - coin].map(|n| n + 1)) {
+                (Some(x), Some(y)) => Some(x.min(y)),
+                (x, y) => x.or(y),
+            };
+        }
+        cells.push(row);
+    }
+    DpTable {
+        row_labels: coin_rows(coins),
+        column_labels: numbered_columns(amount),
+        cells,
+    }
+}
+
+/// Returns the fewest coins of the given denominations that sum to `amount`, or `None` if it can't be made.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::coin_change_min_coins;
+///
+/// // 5 + 5 + 1
+/// assert_eq!(coin_change_min_coins(&[1, 2, 5], 11), Some(3));
+/// assert_eq!(coin_change_min_coins(&[2], 3), None);
+/// ```
+pub fn coin_change_min_coins(coins: &[usize], amount: usize) -> Option<u64> {
+    coin_change_min_coins_table(coins, amount).last()
+}
+
+/// Returns the table for the longest strictly increasing subsequence of `nums`.
+///
+/// There is a column for each number. The `length` row holds the length of the longest
+/// increasing subsequence ending at that number, which is one more than the longest one ending at
+/// any smaller number before it. The `previous` row holds the index of that earlier number.
+pub fn longest_increasing_subsequence_table<T: Ord + fmt::Display>(nums: &[T]) -> DpTable {
+    let mut lengths: Vec<Option<u64>> = Vec::with_capacity(nums.len());
+    let mut previous: Vec<Option<u64>> = Vec::with_capacity(nums.len());
+    for (i, num) in nums.iter().enumerate() {
+        let best = (0..i)
+            .filter(|&j| nums[j] < *num)
+            .max_by_key(|&j| (lengths[j], std::cmp::Reverse(j)));
+        lengths.push(Some(best.and_then(|j| lengths[j]).unwrap_or(0) + 1));
+        previous.push(best.map(|j| j as u64));
+    }
+    DpTable {
+        row_labels: vec!["length".to_owned(), "previous".to_owned()],
+        column_labels: nums.iter().map(|n| n.to_string()).collect(),
+        cells: vec![lengths, previous],
+    }
+}
+
+/// Returns the first longest strictly increasing subsequence of `nums`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::longest_increasing_subsequence;
+///
+/// let result = longest_increasing_subsequence(&[10, 9, 2, 5, 3, 7, 101, 18]);
+/// assert_eq!(result, vec![2, 5, 7, 101]);
+/// ```
+pub fn longest_increasing_subsequence<T: Ord + Clone + fmt::Display>(nums: &[T]) -> Vec<T> {
+    let table = longest_increasing_subsequence_table(nums);
+    let end = (0..nums.len())
+        .rev()
+        .max_by_key(|&i| table.get(0, i))
+        .map(|i| i as u64);
+    let mut subsequence: Vec<T> = Vec::new();
+    let mut current = end;
+    while let Some(i) = current {
+        subsequence.push(nums[i as usize].clone());
+        current = table.get(1, i as usize);
+    }
+    subsequence.reverse();
+    subsequence
+}
+
+/// Fills the cost and split tables for multiplying the chain of matrices described by `dims`.
+fn matrix_chain(dims: &[u64]) -> (Vec<Vec<Option<u64>>>, Vec<Vec<usize>>) {
+    let n = dims.len().saturating_sub(1);
+    let mut cost: Vec<Vec<Option<u64>>> = vec![vec![None; n]; n];
+    let mut split: Vec<Vec<usize>> = vec![vec![0; n]; n];
+    for (i, row) in cost.iter_mut().enumerate() {
+        row[i] = Some(0);
+    }
+    for length in 2..=n {
+        for i in 0..=n - length {
+            let j = i + length - 1;
+            for k in i..j {
+                let candidate = cost[i][k].unwrap_or(0)
+                    + cost[k + 1][j].unwrap_or(0)
+                    + dims[i] * dims[k + 1] * dims[j + 1];
+                if cost[i][j].is_none_or(|c| candidate < c) {
+                    cost[i][j] = Some(candidate);
+                    split[i][j] = k;
+                }
+            }
+        }
+    }
+    (cost, split)
+}
+
+/// Returns the table for the matrix-chain multiplication problem.
+///
+/// Matrix `A{i}` has `dims[i - 1]` rows and `dims[i]` columns. Cell `[i][j]` is the fewest scalar
+/// multiplications needed to compute the product of matrices `i + 1` through `j + 1`; it tries
+/// every split point `k` between them and adds the cost of multiplying the two halves together.
+pub fn matrix_chain_table(dims: &[u64]) -> DpTable {
+    let (cells, _) = matrix_chain(dims);
+    let labels: Vec<String> = (1..dims.len()).map(|i| format!("A{i}")).collect();
+    DpTable {
+        row_labels: labels.clone(),
+        column_labels: labels,
+        cells,
+    }
+}
+
+/// Returns the fewest scalar multiplications needed to multiply a chain of matrices.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::{matrix_chain_multiplication, matrix_chain_order};
+///
+/// // A1 is 10x30, A2 is 30x5 and A3 is 5x60.
+/// assert_eq!(matrix_chain_multiplication(&[10, 30, 5, 60]), 4500);
+/// assert_eq!(matrix_chain_order(&[10, 30, 5, 60]), "((A1A2)A3)");
+/// ```
+pub fn matrix_chain_multiplication(dims: &[u64]) -> u64 {
+    let (cost, _) = matrix_chain(dims);
+    cost.first()
+        .and_then(|row| row.last().copied().flatten())
+        .unwrap_or(0)
+}
+
+/// Returns an optimal parenthesization of a chain of matrices, e.g. `((A1A2)A3)`.
+pub fn matrix_chain_order(dims: &[u64]) -> String {
+    fn parenthesize(split: &[Vec<usize>], i: usize, j: usize) -> String {
+        if i == j {
+            return format!("A{}", i + 1);
+        }
+        let k = split[i][j];
+        format!(
+            "({}{})",
+            parenthesize(split, i, k),
+            parenthesize(split, k + 1, j)
+        )
+    }
+    let (_, split) = matrix_chain(dims);
+    match split.len() {
+        0 => String::new(),
+        n => parenthesize(&split, 0, n - 1),
+    }
+}
+
+/// Returns the table for the edit (Levenshtein) distance between `a` and `b`.
+///
+/// Cell `[i][j]` is the edit distance between the first `i` characters of `a` and the first `j`
+/// characters of `b`. Rows and columns are labelled with the character they add, with `ε` for the
+/// empty prefix. The distance itself is returned by [`super::levenshtein`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::edit_distance_table;
+///
+/// let table = edit_distance_table("ab", "b");
+/// assert_eq!(table.to_string(), "   ε  b\nε  0  1\na  1  1\nb  2  1");
+/// ```
+pub fn edit_distance_table(a: &str, b: &str) -> DpTable {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let labels = |chars: &[char]| {
+        std::iter::once("ε".to_owned())
+            .chain(chars.iter().map(|c| c.to_string()))
+            .collect::<Vec<String>>()
+    };
+    DpTable {
+        row_labels: labels(&a),
+        column_labels: labels(&b),
+        cells: edit_distance_grid(&a, &b, false)
+            .into_iter()
+            .map(|row| row.into_iter().map(|d| Some(d as u64)).collect())
+            .collect(),
+    }
+}
+
+/// Returns the table for the rod cutting problem.
+///
+/// There is a column for each rod length from 0 to `length`. The `best price` row holds the best
+/// revenue from cutting a rod of that length, where a piece of length `i` sells for
+/// `prices[i - 1]`, and the `first cut` row holds the length of the first piece in that solution.
+pub fn rod_cutting_table(prices: &[u64], length: usize) -> DpTable {
+    let mut best: Vec<Option<u64>> = vec![Some(0); length + 1];
+    let mut first_cut: Vec<Option<u64>> = vec![None; length + 1];
+    for n in 1..=length {
+        for (piece, &price) in prices.iter().enumerate().map(|(i, p)| (i + 1, p)) {
+            if piece > n {
+                break;
+            }
+            let candidate = best[n - piece].map(|rest| rest + price);
+            if candidate > best[n] || first_cut[n].is_none() {
+                best[n] = candidate;
+                first_cut[n] = Some(piece as u64);
+            }
+        }
+    }
+    DpTable {
+        row_labels: vec!["best price".to_owned(), "first cut".to_owned()],
+        column_labels: numbered_columns(length),
+        cells: vec![best, first_cut],
+    }
+}
+
+/// Returns the best revenue from cutting a rod of `length` into pieces sold at `prices`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::rod_cutting;
+///
+/// // Two pieces of length 2 sell for 5 + 5.
+/// assert_eq!(rod_cutting(&[1, 5, 8, 9], 4), 10);
+/// ```
+pub fn rod_cutting(prices: &[u64], length: usize) -> u64 {
+    rod_cutting_table(prices, length)
+        .get(0, length)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knapsack() {
+        assert_eq!(knapsack_01(&[10, 20, 30], &[60, 100, 120], 50), 220);
+        assert_eq!(knapsack_01(&[5], &[10], 4), 0);
+        assert_eq!(knapsack_01(&[], &[], 10), 0);
+        assert_eq!(unbounded_knapsack(&[5, 10, 15], &[10, 30, 20], 100), 300);
+
+        let table = knapsack_01_table(&[1, 2], &[3, 4], 3);
+        assert_eq!(table.cells.len(), 3);
+        assert_eq!(table.cells[2], vec![Some(0), Some(3), Some(4), Some(7)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_knapsack_mismatched_items() {
+        knapsack_01(&[1, 2], &[3], 3);
+    }
+
+    #[test]
+    fn test_coin_change() {
+        assert_eq!(coin_change_ways(&[2, 5, 3, 6], 10), 5);
+        assert_eq!(coin_change_ways(&[], 0), 1);
+        assert_eq!(coin_change_ways(&[2], 3), 0);
+        assert_eq!(coin_change_min_coins(&[1, 3, 4], 6), Some(2));
+        assert_eq!(coin_change_min_coins(&[], 0), Some(0));
+        assert_eq!(coin_change_min_coins(&[], 1), None);
+
+        let table = coin_change_min_coins_table(&[2], 3);
+        assert_eq!(
+            table.to_string(),
+            "          0  1  2  3\nno coins  0  -  -  -\n+ coin 2  0  -  1  -"
+        );
+    }
+
+    #[test]
+    fn test_longest_increasing_subsequence() {
+        assert_eq!(
+            longest_increasing_subsequence(&[0, 1, 0, 3, 2, 3]),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(longest_increasing_subsequence(&[7, 7, 7]), vec![7]);
+        assert_eq!(
+            longest_increasing_subsequence::<i32>(&[]),
+            Vec::<i32>::new()
+        );
+        let table = longest_increasing_subsequence_table(&[3, 1, 2]);
+        assert_eq!(table.cells[0], vec![Some(1), Some(1), Some(2)]);
+        assert_eq!(table.cells[1], vec![None, None, Some(1)]);
+    }
+
+    #[test]
+    fn test_matrix_chain() {
+        assert_eq!(matrix_chain_multiplication(&[40, 20, 30, 10, 30]), 26000);
+        assert_eq!(matrix_chain_order(&[40, 20, 30, 10, 30]), "((A1(A2A3))A4)");
+        assert_eq!(matrix_chain_multiplication(&[10, 20]), 0);
+        assert_eq!(matrix_chain_order(&[10, 20]), "A1");
+        assert_eq!(matrix_chain_multiplication(&[]), 0);
+        assert_eq!(matrix_chain_order(&[]), "");
+
+        let table = matrix_chain_table(&[10, 30, 5, 60]);
+        assert_eq!(table.get(0, 1), Some(1500));
+        assert_eq!(table.get(1, 0), None);
+    }
+
+    #[test]
+    fn test_edit_distance_table() {
+        let table = edit_distance_table("kitten", "sitting");
+        assert_eq!(table.last(), Some(3));
+        assert_eq!(table.row_labels.len(), 7);
+        assert_eq!(table.column_labels.len(), 8);
+    }
+
+    #[test]
+    fn test_rod_cutting() {
+        assert_eq!(rod_cutting(&[1, 5, 8, 9, 10, 17, 17, 20], 8), 22);
+        assert_eq!(rod_cutting(&[3, 5, 8, 9, 10, 17, 17, 20], 8), 24);
+        assert_eq!(rod_cutting(&[2], 3), 6);
+        assert_eq!(rod_cutting(&[], 3), 0);
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// A binary min-heap of keys ordered by priority that supports decreasing a key's priority.
+///
+/// Each key may appear in the heap at most once. A `HashMap` tracks the position of every key
+/// in the underlying array so that [`BinaryHeap::decrease_key`] can find and sift the entry up
+/// in O(log n), which is what algorithms such as Dijkstra's shortest paths rely on.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::BinaryHeap;
+///
+/// let mut heap: BinaryHeap<char, u32> = BinaryHeap::new();
+/// heap.push('a', 5);
+/// heap.push('b', 3);
+/// heap.push('c', 8);
+/// assert_eq!(heap.peek(), Some((&'b', &3)));
+///
+/// heap.decrease_key(&'c', 1);
+/// assert_eq!(heap.pop(), Some(('c', 1)));
+/// assert_eq!(heap.pop(), Some(('b', 3)));
+/// ```
+pub struct BinaryHeap<K, P> {
+    entries: Vec<(K, P)>,
+    positions: HashMap<K, usize>,
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> BinaryHeap<K, P> {
+    /// Creates an empty heap.
+    pub fn new() -> Self {
+        BinaryHeap {
+            entries: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Inserts `key` with the given `priority`.
+    ///
+    /// Returns `false` and leaves the heap unchanged if `key` is already present.
+    pub fn push(&mut self, key: K, priority: P) -> bool {
+        if self.positions.contains_key(&key) {
+            return false;
+        }
+        self.positions.insert(key.clone(), self.entries.len());
+        self.entries.push((key, priority));
+        self.sift_up(self.entries.len() - 1);
+        true
+    }
+
+    /// Removes and returns the key with the smallest priority, or `None` if the heap is empty.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let last = self.entries.len() - 1;
+        self.swap(0, last);
+        let (key, priority) = self.entries.pop()?;
+        self.positions.remove(&key);
+        if !self.entries.is_empty() {
+            self.sift_down(0);
+        }
+        Some((key, priority))
+    }
+
+    /// Returns the key with the smallest priority without removing it.
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.entries.first().map(|(key, priority)| (key, priority))
+    }
+
+    /// Returns the current priority of `key`, if it is in the heap.
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.positions.get(key).map(|&i| &self.entries[i].1)
+    }
+
+    /// Lowers the priority of `key` to `priority`.
+    ///
+    /// Returns `false` and leaves the heap unchanged if `key` is not present or if `priority`
+    /// is not smaller than its current priority.
+    pub fn decrease_key(&mut self, key: &K, priority: P) -> bool {
+        match self.positions.get(key) {
+            Some(&index) if priority < self.entries[index].1 => {
+                self.entries[index].1 = priority;
+                self.sift_up(index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `key` is in the heap.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    /// Returns the number of keys in the heap.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the heap contains no keys.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the `(key, priority)` entries in array (level) order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &P)> {
+        self.entries.iter().map(|(key, priority)| (key, priority))
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.entries[index].1 >= self.entries[parent].1 {
+                break;
+            }
+            self.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = left + 1;
+            let mut smallest = index;
+            if left < self.entries.len() && self.entries[left].1 < self.entries[smallest].1 {
+                smallest = left;
+            }
+            if right < self.entries.len() && self.entries[right].1 < self.entries[smallest].1 {
+                smallest = right;
+            }
+            if smallest == index {
+                break;
+            }
+            self.swap(index, smallest);
+            index = smallest;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.entries.swap(a, b);
+        self.positions.insert(self.entries[a].0.clone(), a);
+        self.positions.insert(self.entries[b].0.clone(), b);
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> Default for BinaryHeap<K, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: fmt::Debug, P: fmt::Debug> fmt::Debug for BinaryHeap<K, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.entries.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pop_in_priority_order() {
+        let mut heap: BinaryHeap<i32, i32> = BinaryHeap::new();
+        for (key, priority) in [(1, 9), (2, 4), (3, 7), (4, 1), (5, 6)] {
+            assert!(heap.push(key, priority));
+        }
+        assert!(!heap.push(2, 0));
+        assert_eq!(heap.len(), 5);
+
+        let popped: Vec<(i32, i32)> = std::iter::from_fn(|| heap.pop()).collect();
+        assert_eq!(popped, vec![(4, 1), (2, 4), (5, 6), (3, 7), (1, 9)]);
+        assert!(heap.is_empty());
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut heap: BinaryHeap<&str, u32> = BinaryHeap::new();
+        heap.push("a", 10);
+        heap.push("b", 20);
+        heap.push("c", 30);
+
+        assert!(heap.decrease_key(&"c", 5));
+        assert_eq!(heap.priority(&"c"), Some(&5));
+        assert_eq!(heap.peek(), Some((&"c", &5)));
+
+        // Increasing or keeping the priority, and unknown keys, are rejected.
+        assert!(!heap.decrease_key(&"a", 15));
+        assert!(!heap.decrease_key(&"a", 10));
+        assert!(!heap.decrease_key(&"z", 1));
+
+        assert_eq!(heap.pop(), Some(("c", 5)));
+        assert!(!heap.contains_key(&"c"));
+        assert_eq!(heap.pop(), Some(("a", 10)));
+        assert_eq!(heap.pop(), Some(("b", 20)));
+    }
+}
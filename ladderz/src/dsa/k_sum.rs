@@ -0,0 +1,343 @@
+use super::CheckedInt;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+/// Returns every pair of indices `(i, j)` with `i < j` whose numbers sum to `target`.
+///
+/// Unlike [`super::two_sum`], which stops at the first pair, this keeps a `HashMap` from each
+/// number to the indices it was seen at, so every matching pair is reported in ascending order.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::all_two_sum_pairs;
+///
+/// let result = all_two_sum_pairs(&[1, 3, 2, 2, 3], 5);
+/// assert_eq!(result, vec![(1, 2), (1, 3), (2, 4), (3, 4)]);
+/// ```
+pub fn all_two_sum_pairs<T>(nums: &[T], target: T) -> Vec<(usize, usize)>
+where
+    T: CheckedInt + Hash,
+{
+    let mut seen: HashMap<T, Vec<usize>> = HashMap::new();
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    for (j, &num) in nums.iter().enumerate() {
+        // If the difference doesn't fit in `T`, no earlier number can complete the pair.
+        if let Some(indices) = target.checked_sub(num).and_then(|diff| seen.get(&diff)) {
+            pairs.extend(indices.iter().map(|&i| (i, j)));
+        }
+        seen.entry(num).or_default().push(j);
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+/// Returns the indices of two numbers in an ascending `nums` that sum to `target`.
+///
+/// Two pointers start at both ends and move towards each other: if the current sum is too small
+/// the left pointer moves right, and if it is too large the right pointer moves left. This takes
+/// O(n) time and O(1) space, but `nums` must already be sorted.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::two_sum_sorted;
+///
+/// assert_eq!(two_sum_sorted(&[1, 2, 4, 7, 11], 9), Some((1, 3)));
+/// assert_eq!(two_sum_sorted(&[1, 2, 4], 10), None);
+/// ```
+pub fn two_sum_sorted<T>(nums: &[T], target: T) -> Option<(usize, usize)>
+where
+    T: CheckedInt,
+{
+    if nums.is_empty() {
+        return None;
+    }
+    let target = target.widen();
+    let (mut left, mut right) = (0, nums.len() - 1);
+    while left < right {
+        let sum = nums[left].widen() + nums[right].widen();
+        if sum == target {
+            return Some((left, right));
+        } else if sum < target {
+            left += 1;
+        } else {
+            right -= 1;
+        }
+    }
+    None
+}
+
+/// Returns every unique triplet of numbers from `nums` that sums to `target`.
+///
+/// Each triplet is sorted and the list of triplets is sorted, so equal values at different
+/// indices are only reported once. See [`k_sum`] for the general algorithm.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::three_sum;
+///
+/// let result = three_sum(&[-1, 0, 1, 2, -1, -4], 0);
+/// assert_eq!(result, vec![[-1, -1, 2], [-1, 0, 1]]);
+/// ```
+pub fn three_sum<T>(nums: &[T], target: T) -> Vec<[T; 3]>
+where
+    T: CheckedInt,
+{
+    k_sum(nums, 3, target)
+        .into_iter()
+        .map(|c| [c[0], c[1], c[2]])
+        .collect()
+}
+
+/// Returns every unique quadruplet of numbers from `nums` that sums to `target`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::four_sum;
+///
+/// let result = four_sum(&[1, 0, -1, 0, -2, 2], 0);
+/// assert_eq!(result, vec![[-2, -1, 1, 2], [-2, 0, 0, 2], [-1, 0, 0, 1]]);
+/// ```
+pub fn four_sum<T>(nums: &[T], target: T) -> Vec<[T; 4]>
+where
+    T: CheckedInt,
+{
+    k_sum(nums, 4, target)
+        .into_iter()
+        .map(|c| [c[0], c[1], c[2], c[3]])
+        .collect()
+}
+
+/// Returns every unique combination of `k` numbers from `nums` that sums to `target`.
+///
+/// The numbers are sorted once. Each level of recursion fixes the smallest remaining number
+/// (skipping values equal to the one fixed before it, which removes duplicates) and solves
+/// `(k - 1)`-sum on the rest, until the base case is solved with two pointers. This takes
+/// O(n<sup>k - 1</sup>) time. Returns an empty list when `k` is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::k_sum;
+///
+/// let result = k_sum(&[1, 2, 3, 4, 5], 2, 6);
+/// assert_eq!(result, vec![vec![1, 5], vec![2, 4]]);
+/// ```
+pub fn k_sum<T>(nums: &[T], k: usize, target: T) -> Vec<Vec<T>>
+where
+    T: CheckedInt,
+{
+    let mut sorted: Vec<T> = nums.to_vec();
+    sorted.sort_unstable();
+    let mut combinations: Vec<Vec<T>> = Vec::new();
+    if k > 0 {
+        k_sum_sorted(
+            &sorted,
+            k,
+            target.widen(),
+            &mut Vec::new(),
+            &mut combinations,
+        );
+    }
+    combinations
+}
+
+/// Solves k-Sum on a sorted `nums`, with `target` widened so that the targets left for the
+/// later numbers can't overflow.
+fn k_sum_sorted<T>(nums: &[T], k: usize, target: i128, chosen: &mut Vec<T>, out: &mut Vec<Vec<T>>)
+where
+    T: CheckedInt,
+{
+    if nums.len() < k {
+        return;
+    }
+    if k == 1 {
+        if let Ok(i) = nums.binary_search_by(|num| num.widen().cmp(&target)) {
+            out.push([chosen.as_slice(), &[nums[i]]].concat());
+        }
+        return;
+    }
+    if k == 2 {
+        let (mut left, mut right) = (0, nums.len() - 1);
+        while left < right {
+            let sum = nums[left].widen() + nums[right].widen();
+            if sum < target || (left > 0 && nums[left] == nums[left - 1]) {
+                left += 1;
+            } else if sum > target || (right < nums.len() - 1 && nums[right] == nums[right + 1]) {
+                right -= 1;
+            } else {
+                out.push([chosen.as_slice(), &[nums[left], nums[right]]].concat());
+                left += 1;
+                right -= 1;
+            }
+        }
+        return;
+    }
+    for i in 0..=nums.len() - k {
+        if i > 0 && nums[i] == nums[i - 1] {
+            continue;
+        }
+        chosen.push(nums[i]);
+        k_sum_sorted(&nums[i + 1..], k - 1, target - nums[i].widen(), chosen, out);
+        chosen.pop();
+    }
+}
+
+/// Counts the contiguous subarrays of `nums` whose numbers sum to `k`.
+///
+/// A running prefix sum is kept along with a `HashMap` counting how often each prefix sum has
+/// been seen. A subarray ending at the current index sums to `k` exactly when an earlier prefix
+/// sum equals `prefix - k`, so the answer is found in a single O(n) pass. The prefix sums are
+/// kept as `i128`, so they can't overflow.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::subarray_sum_equals_k;
+///
+/// // [1, 2] and [3]
+/// assert_eq!(subarray_sum_equals_k(&[1, 2, 3], 3), 2);
+/// assert_eq!(subarray_sum_equals_k(&[1, -1, 1, -1], 0), 4);
+/// ```
+pub fn subarray_sum_equals_k<T>(nums: &[T], k: T) -> usize
+where
+    T: CheckedInt,
+{
+    let mut prefix_counts: HashMap<i128, usize> = HashMap::new();
+    prefix_counts.insert(0, 1);
+    let mut prefix: i128 = 0;
+    let mut count: usize = 0;
+    for &num in nums.iter() {
+        prefix += num.widen();
+        count += prefix_counts
+            .get(&(prefix - k.widen()))
+            .copied()
+            .unwrap_or(0);
+        *prefix_counts.entry(prefix).or_default() += 1;
+    }
+    count
+}
+
+/// Returns the largest sum of a non-empty contiguous subarray and its index range (Kadane's algorithm).
+///
+/// The best sum ending at each index is either the number itself or the number added to the best
+/// sum ending at the previous index. The first subarray with the largest sum is returned, or
+/// `None` if `nums` is empty. The sum is an `i128`, since it may not fit in `T`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::max_subarray;
+///
+/// let nums = [-2, 1, -3, 4, -1, 2, 1, -5, 4];
+/// let (sum, range) = max_subarray(&nums).unwrap();
+/// assert_eq!(sum, 6);
+/// assert_eq!(&nums[range], &[4, -1, 2, 1]);
+/// ```
+pub fn max_subarray<T>(nums: &[T]) -> Option<(i128, Range<usize>)>
+where
+    T: CheckedInt,
+{
+    let (&first, rest) = nums.split_first()?;
+    let (mut best, mut best_range) = (first.widen(), 0..1);
+    let (mut current, mut current_start) = (first.widen(), 0);
+    for (i, &num) in rest.iter().enumerate().map(|(i, n)| (i + 1, n)) {
+        let num = num.widen();
+        if current < 0 {
+            current = num;
+            current_start = i;
+        } else {
+            current += num;
+        }
+        if current > best {
+            best = current;
+            best_range = current_start..i + 1;
+        }
+    }
+    Some((best, best_range))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_two_sum_pairs() {
+        assert_eq!(all_two_sum_pairs(&[2, 3, 8, 5], 8), vec![(1, 3)]);
+        assert_eq!(
+            all_two_sum_pairs(&[4, 4, 4], 8),
+            vec![(0, 1), (0, 2), (1, 2)]
+        );
+        assert_eq!(all_two_sum_pairs(&[1, 2], 10), vec![]);
+    }
+
+    #[test]
+    fn test_two_sum_sorted() {
+        assert_eq!(two_sum_sorted(&[-3, -1, 0, 2, 5], 4), Some((1, 4)));
+        assert_eq!(two_sum_sorted(&[3, 3], 6), Some((0, 1)));
+        assert_eq!(two_sum_sorted(&[3], 6), None);
+        assert_eq!(two_sum_sorted::<i32>(&[], 0), None);
+    }
+
+    #[test]
+    fn test_three_sum() {
+        assert_eq!(three_sum(&[0, 0, 0, 0], 0), vec![[0, 0, 0]]);
+        assert_eq!(three_sum(&[1, 2, 3, 4, 5], 9), vec![[1, 3, 5], [2, 3, 4]]);
+        assert_eq!(three_sum(&[1, 2], 3), Vec::<[i32; 3]>::new());
+    }
+
+    #[test]
+    fn test_four_sum() {
+        assert_eq!(four_sum(&[2, 2, 2, 2, 2], 8), vec![[2, 2, 2, 2]]);
+        assert_eq!(four_sum(&[1, 2, 3], 6), Vec::<[i32; 4]>::new());
+    }
+
+    #[test]
+    fn test_k_sum() {
+        assert_eq!(k_sum(&[3, 1, 3], 1, 3), vec![vec![3]]);
+        assert_eq!(k_sum(&[1, 2, 3], 0, 0), Vec::<Vec<i32>>::new());
+        assert_eq!(
+            k_sum(&[4, 3, 3, 2, 2, 1, 1], 5, 10),
+            vec![vec![1, 1, 2, 2, 4], vec![1, 1, 2, 3, 3]]
+        );
+    }
+
+    #[test]
+    fn test_subarray_sum_equals_k() {
+        assert_eq!(subarray_sum_equals_k(&[1, 1, 1], 2), 2);
+        assert_eq!(subarray_sum_equals_k(&[3, 4, 7, 2, -3, 1, 4, 2], 7), 4);
+        assert_eq!(subarray_sum_equals_k::<i32>(&[], 0), 0);
+    }
+
+    #[test]
+    fn test_max_subarray() {
+        assert_eq!(max_subarray(&[1]), Some((1, 0..1)));
+        assert_eq!(max_subarray(&[-3, -1, -2]), Some((-1, 1..2)));
+        assert_eq!(max_subarray(&[5, 4, -1, 7, 8]), Some((23, 0..5)));
+        assert_eq!(max_subarray::<i32>(&[]), None);
+    }
+
+    #[test]
+    fn test_sums_at_the_limits() {
+        let (max, min) = (i32::MAX, i32::MIN);
+        assert_eq!(all_two_sum_pairs(&[max, 1, min], -1), vec![(0, 2)]);
+        assert_eq!(all_two_sum_pairs(&[max, 1], min), vec![]);
+        assert_eq!(two_sum_sorted(&[1, max - 1, max], max), Some((0, 1)));
+        assert_eq!(two_sum_sorted(&[min, -1, max], -1), Some((0, 2)));
+        assert_eq!(three_sum(&[max, 1, 1], 0), Vec::<[i32; 3]>::new());
+        assert_eq!(three_sum(&[min, 1, max], 0), vec![[min, 1, max]]);
+        assert_eq!(
+            four_sum(&[max, max, min, min], -2),
+            vec![[min, min, max, max]]
+        );
+        assert_eq!(k_sum(&[u8::MAX, u8::MAX, 2], 2, 0), Vec::<Vec<u8>>::new());
+        assert_eq!(subarray_sum_equals_k(&[max, 1, -1], max), 2);
+        assert_eq!(subarray_sum_equals_k(&[max, max, min, min, 2], 0), 1);
+        assert_eq!(max_subarray(&[max, 1]), Some((max as i128 + 1, 0..2)));
+        assert_eq!(max_subarray(&[min, min]), Some((min as i128, 0..1)));
+    }
+}
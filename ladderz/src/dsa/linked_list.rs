@@ -0,0 +1,432 @@
+use std::fmt;
+
+type Link<T> = Option<Box<SinglyNode<T>>>;
+
+struct SinglyNode<T> {
+    value: T,
+    next: Link<T>,
+}
+
+/// A singly linked list that supports O(1) pushing and popping at the front.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::SinglyLinkedList;
+///
+/// let mut list: SinglyLinkedList<i32> = SinglyLinkedList::new();
+/// list.push_front(3);
+/// list.push_front(2);
+/// list.push_front(1);
+/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+/// list.reverse();
+/// assert_eq!(list.pop_front(), Some(3));
+/// ```
+pub struct SinglyLinkedList<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+impl<T> SinglyLinkedList<T> {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        SinglyLinkedList { head: None, len: 0 }
+    }
+
+    /// Adds an element to the front of the list.
+    pub fn push_front(&mut self, value: T) {
+        let next = self.head.take();
+        self.head = Some(Box::new(SinglyNode { value, next }));
+        self.len += 1;
+    }
+
+    /// Removes and returns the element at the front of the list, or `None` if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.value
+        })
+    }
+
+    /// Returns a reference to the element at the front of the list.
+    pub fn peek_front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns a mutable reference to the element at the front of the list.
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.value)
+    }
+
+    /// Reverses the order of the elements in place.
+    pub fn reverse(&mut self) {
+        let mut previous: Link<T> = None;
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+            node.next = previous;
+            previous = Some(node);
+        }
+        self.head = previous;
+    }
+
+    /// Returns `true` if the list contains an element equal to `value`.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an iterator over the elements from front to back.
+    pub fn iter(&self) -> SinglyIter<'_, T> {
+        SinglyIter {
+            next: self.head.as_deref(),
+        }
+    }
+}
+
+impl<T> Drop for SinglyLinkedList<T> {
+    // Drop the nodes iteratively so that long lists don't overflow the stack.
+    fn drop(&mut self) {
+        let mut current = self.head.take();
+        while let Some(mut node) = current {
+            current = node.next.take();
+        }
+    }
+}
+
+impl<T> Default for SinglyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for SinglyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = SinglyLinkedList::new();
+        for value in iter {
+            list.push_front(value);
+        }
+        list.reverse();
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SinglyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// An iterator over the elements of a [`SinglyLinkedList`].
+pub struct SinglyIter<'a, T> {
+    next: Option<&'a SinglyNode<T>>,
+}
+
+impl<'a, T> Iterator for SinglyIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.value
+        })
+    }
+}
+
+struct DoublyNode<T> {
+    value: Option<T>,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// A doubly linked list that supports O(1) pushing and popping at both ends.
+///
+/// The nodes are stored in an arena (`Vec`) and link to each other by index, which avoids
+/// reference counting and `unsafe` code. Slots freed by popping are reused by later pushes.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::DoublyLinkedList;
+///
+/// let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+/// list.push_back(2);
+/// list.push_front(1);
+/// list.push_back(3);
+/// assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+/// assert_eq!(list.iter().rev().copied().collect::<Vec<i32>>(), vec![3, 2, 1]);
+/// assert_eq!(list.pop_back(), Some(3));
+/// ```
+pub struct DoublyLinkedList<T> {
+    nodes: Vec<DoublyNode<T>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
+}
+
+impl<T> DoublyLinkedList<T> {
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        DoublyLinkedList {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+
+    /// Adds an element to the front of the list.
+    pub fn push_front(&mut self, value: T) {
+        let index = self.allocate(value, None, self.head);
+        match self.head {
+            Some(head) => self.nodes[head].prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+
+    /// Adds an element to the back of the list.
+    pub fn push_back(&mut self, value: T) {
+        let index = self.allocate(value, self.tail, None);
+        match self.tail {
+            Some(tail) => self.nodes[tail].next = Some(index),
+            None => self.head = Some(index),
+        }
+        self.tail = Some(index);
+    }
+
+    /// Removes and returns the element at the front of the list, or `None` if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let index = self.head?;
+        self.head = self.nodes[index].next;
+        match self.head {
+            Some(head) => self.nodes[head].prev = None,
+            None => self.tail = None,
+        }
+        self.release(index)
+    }
+
+    /// Removes and returns the element at the back of the list, or `None` if it is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let index = self.tail?;
+        self.tail = self.nodes[index].prev;
+        match self.tail {
+            Some(tail) => self.nodes[tail].next = None,
+            None => self.head = None,
+        }
+        self.release(index)
+    }
+
+    /// Returns a reference to the element at the front of the list.
+    pub fn peek_front(&self) -> Option<&T> {
+        self.head.and_then(|index| self.nodes[index].value.as_ref())
+    }
+
+    /// Returns a reference to the element at the back of the list.
+    pub fn peek_back(&self) -> Option<&T> {
+        self.tail.and_then(|index| self.nodes[index].value.as_ref())
+    }
+
+    /// Returns `true` if the list contains an element equal to `value`.
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a double-ended iterator over the elements from front to back.
+    pub fn iter(&self) -> DoublyIter<'_, T> {
+        DoublyIter {
+            list: self,
+            front: self.head,
+            back: self.tail,
+            remaining: self.len,
+        }
+    }
+
+    fn allocate(&mut self, value: T, prev: Option<usize>, next: Option<usize>) -> usize {
+        let node = DoublyNode {
+            value: Some(value),
+            prev,
+            next,
+        };
+        self.len += 1;
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn release(&mut self, index: usize) -> Option<T> {
+        self.len -= 1;
+        self.free.push(index);
+        self.nodes[index].value.take()
+    }
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        for value in iter {
+            list.push_back(value);
+        }
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A double-ended iterator over the elements of a [`DoublyLinkedList`].
+pub struct DoublyIter<'a, T> {
+    list: &'a DoublyLinkedList<T>,
+    front: Option<usize>,
+    back: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for DoublyIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = &self.list.nodes[self.front?];
+        self.front = node.next;
+        self.remaining -= 1;
+        node.value.as_ref()
+    }
+}
+
+impl<T> DoubleEndedIterator for DoublyIter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = &self.list.nodes[self.back?];
+        self.back = node.prev;
+        self.remaining -= 1;
+        node.value.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_singly_linked_list() {
+        let mut list: SinglyLinkedList<i32> = SinglyLinkedList::new();
+        assert_eq!(list.pop_front(), None);
+        list.push_front(2);
+        list.push_front(1);
+        assert_eq!(list.peek_front(), Some(&1));
+        if let Some(value) = list.peek_front_mut() {
+            *value = 10;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<i32>>(), vec![10, 2]);
+        assert!(list.contains(&2));
+        assert!(!list.contains(&1));
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_singly_linked_list_reverse() {
+        let mut list: SinglyLinkedList<i32> = (1..=4).collect();
+        assert_eq!(format!("{:?}", list), "[1, 2, 3, 4]");
+        list.reverse();
+        assert_eq!(format!("{:?}", list), "[4, 3, 2, 1]");
+
+        let mut empty: SinglyLinkedList<i32> = SinglyLinkedList::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_singly_linked_list_long_drop() {
+        let list: SinglyLinkedList<u32> = (0..200_000).collect();
+        assert_eq!(list.len(), 200_000);
+    }
+
+    #[test]
+    fn test_doubly_linked_list() {
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert_eq!(list.pop_back(), None);
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+        assert_eq!(list.peek_front(), Some(&1));
+        assert_eq!(list.peek_back(), Some(&3));
+        assert_eq!(list.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3]);
+        assert_eq!(
+            list.iter().rev().copied().collect::<Vec<i32>>(),
+            vec![3, 2, 1]
+        );
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert!(list.is_empty());
+        assert_eq!(list.peek_front(), None);
+    }
+
+    #[test]
+    fn test_doubly_linked_list_reuses_slots() {
+        let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+        list.pop_front();
+        list.pop_front();
+        list.push_back(4);
+        list.push_front(0);
+        assert_eq!(list.nodes.len(), 3);
+        assert_eq!(format!("{:?}", list), "[0, 3, 4]");
+        assert!(list.contains(&4));
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), None);
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+/// A fixed-capacity cache that evicts the least recently used entry when it is full.
+///
+/// Entries live in a `Vec` and form a doubly linked list (by index) ordered from most to least
+/// recently used, while a `HashMap` maps each key to its entry. Both `get` and `put` are O(1).
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::LruCache;
+///
+/// let mut cache: LruCache<i32, &str> = LruCache::new(2);
+/// cache.put(1, "one");
+/// cache.put(2, "two");
+/// assert_eq!(cache.get(&1), Some(&"one"));
+/// // 2 is now the least recently used key, so it is evicted.
+/// assert_eq!(cache.put(3, "three"), Some((2, "two")));
+/// assert_eq!(cache.get(&2), None);
+/// ```
+pub struct LruCache<K, V> {
+    capacity: usize,
+    map: HashMap<K, usize>,
+    entries: Vec<Entry<K, V>>,
+    head: Option<usize>,
+    tail: Option<usize>,
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// Creates an empty cache that holds at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        LruCache {
+            capacity,
+            map: HashMap::new(),
+            entries: Vec::new(),
+            head: None,
+            tail: None,
+        }
+    }
+
+    /// Returns the value for `key` and marks it as the most recently used entry.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let index = *self.map.get(key)?;
+        self.move_to_front(index);
+        Some(&self.entries[index].value)
+    }
+
+    /// Returns the value for `key` without changing how recently it was used.
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.map.get(key).map(|&index| &self.entries[index].value)
+    }
+
+    /// Inserts or updates `key` and marks it as the most recently used entry.
+    ///
+    /// Returns the evicted `(key, value)` pair if inserting a new key exceeded the capacity.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(&index) = self.map.get(&key) {
+            self.entries[index].value = value;
+            self.move_to_front(index);
+            return None;
+        }
+        if self.capacity == 0 {
+            return Some((key, value));
+        }
+        if self.entries.len() < self.capacity {
+            self.entries.push(Entry {
+                key: key.clone(),
+                value,
+                prev: None,
+                next: None,
+            });
+            let index = self.entries.len() - 1;
+            self.map.insert(key, index);
+            self.push_front(index);
+            return None;
+        }
+
+        // Reuse the least recently used entry's slot for the new key.
+        let index = self.tail.expect("full cache has a tail");
+        self.unlink(index);
+        let evicted = std::mem::replace(
+            &mut self.entries[index],
+            Entry {
+                key: key.clone(),
+                value,
+                prev: None,
+                next: None,
+            },
+        );
+        self.map.remove(&evicted.key);
+        self.map.insert(key, index);
+        self.push_front(index);
+        Some((evicted.key, evicted.value))
+    }
+
+    /// Returns `true` if `key` is in the cache.
+    pub fn contains(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Returns the maximum number of entries.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the entries from most to least recently used.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut next = self.head;
+        std::iter::from_fn(move || {
+            let entry = &self.entries[next?];
+            next = entry.next;
+            Some((&entry.key, &entry.value))
+        })
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = (self.entries[index].prev, self.entries[index].next);
+        match prev {
+            Some(prev) => self.entries[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.entries[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        self.entries[index].prev = None;
+        self.entries[index].next = self.head;
+        match self.head {
+            Some(head) => self.entries[head].prev = Some(index),
+            None => self.tail = Some(index),
+        }
+        self.head = Some(index);
+    }
+
+    fn move_to_front(&mut self, index: usize) {
+        if self.head != Some(index) {
+            self.unlink(index);
+            self.push_front(index);
+        }
+    }
+}
+
+impl<K: fmt::Debug + Hash + Eq + Clone, V: fmt::Debug> fmt::Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eviction_order() {
+        let mut cache: LruCache<i32, i32> = LruCache::new(2);
+        assert_eq!(cache.put(1, 1), None);
+        assert_eq!(cache.put(2, 2), None);
+        assert_eq!(cache.get(&1), Some(&1));
+        assert_eq!(cache.put(3, 3), Some((2, 2)));
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.put(4, 4), Some((1, 1)));
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.get(&3), Some(&3));
+        assert_eq!(cache.get(&4), Some(&4));
+        assert_eq!(format!("{:?}", cache), "{4: 4, 3: 3}");
+    }
+
+    #[test]
+    fn test_update_and_peek() {
+        let mut cache: LruCache<&str, u32> = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        // Updating refreshes "a", so "b" is evicted next.
+        assert_eq!(cache.put("a", 10), None);
+        // Peeking does not refresh "b".
+        assert_eq!(cache.peek(&"b"), Some(&2));
+        assert_eq!(cache.put("c", 3), Some(("b", 2)));
+        assert_eq!(cache.peek(&"a"), Some(&10));
+        assert!(cache.contains(&"c"));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut cache: LruCache<i32, i32> = LruCache::new(0);
+        assert_eq!(cache.put(1, 1), Some((1, 1)));
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 0);
+    }
+}
@@ -0,0 +1,104 @@
+use super::RingBuffer;
+use std::fmt;
+
+/// A first-in, first-out queue backed by a [`RingBuffer`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::Queue;
+///
+/// let mut queue: Queue<i32> = Queue::new();
+/// queue.push(1);
+/// queue.push(2);
+/// assert_eq!(queue.peek(), Some(&1));
+/// assert_eq!(queue.pop(), Some(1));
+/// assert_eq!(queue.pop(), Some(2));
+/// assert_eq!(queue.pop(), None);
+/// ```
+pub struct Queue<T> {
+    buffer: RingBuffer<T>,
+}
+
+impl<T> Queue<T> {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Queue {
+            buffer: RingBuffer::new(),
+        }
+    }
+
+    /// Adds an element to the back of the queue.
+    pub fn push(&mut self, value: T) {
+        self.buffer.push_back(value);
+    }
+
+    /// Removes and returns the element at the front of the queue, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.buffer.pop_front()
+    }
+
+    /// Returns a reference to the element at the front of the queue.
+    pub fn peek(&self) -> Option<&T> {
+        self.buffer.front()
+    }
+
+    /// Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns an iterator over the elements from the front to the back of the queue.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.buffer.iter()
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Queue {
+            buffer: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Queue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue() {
+        let mut queue: Queue<i32> = Queue::new();
+        for i in 1..=5 {
+            queue.push(i);
+        }
+        assert_eq!(queue.pop(), Some(1));
+        assert_eq!(queue.pop(), Some(2));
+        // Pushing after popping wraps around the underlying ring buffer.
+        queue.push(6);
+        queue.push(7);
+        assert_eq!(format!("{:?}", queue), "[3, 4, 5, 6, 7]");
+        assert_eq!(queue.peek(), Some(&3));
+        assert_eq!(queue.len(), 5);
+        let drained: Vec<i32> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(drained, vec![3, 4, 5, 6, 7]);
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+/// A growable circular buffer that supports pushing and popping at both ends in O(1).
+///
+/// Elements are stored in a fixed-size `Vec` and the logical start of the buffer (`head`)
+/// wraps around the end of the allocation. When the buffer is full its capacity is doubled
+/// and the elements are laid out contiguously again.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::RingBuffer;
+///
+/// let mut buffer: RingBuffer<i32> = RingBuffer::new();
+/// buffer.push_back(2);
+/// buffer.push_back(3);
+/// buffer.push_front(1);
+/// assert_eq!(buffer.iter().copied().collect::<Vec<i32>>(), vec![1, 2, 3]);
+/// assert_eq!(buffer.pop_front(), Some(1));
+/// assert_eq!(buffer.pop_back(), Some(3));
+/// ```
+pub struct RingBuffer<T> {
+    slots: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// Creates an empty ring buffer.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates an empty ring buffer with room for `capacity` elements before it needs to grow.
+    pub fn with_capacity(capacity: usize) -> Self {
+        RingBuffer {
+            slots: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements the buffer can hold without growing.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the number of elements in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds an element to the back of the buffer.
+    pub fn push_back(&mut self, value: T) {
+        self.grow_if_full();
+        let index = self.physical_index(self.len);
+        self.slots[index] = Some(value);
+        self.len += 1;
+    }
+
+    /// Adds an element to the front of the buffer.
+    pub fn push_front(&mut self, value: T) {
+        self.grow_if_full();
+        self.head = (self.head + self.capacity() - 1) % self.capacity();
+        self.slots[self.head] = Some(value);
+        self.len += 1;
+    }
+
+    /// Removes and returns the element at the front of the buffer, or `None` if it is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = self.slots[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        value
+    }
+
+    /// Removes and returns the element at the back of the buffer, or `None` if it is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = self.physical_index(self.len - 1);
+        self.len -= 1;
+        self.slots[index].take()
+    }
+
+    /// Returns a reference to the element at the front of the buffer.
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the element at the back of the buffer.
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|i| self.get(i))
+    }
+
+    /// Returns a reference to the element at logical position `index` (0 is the front).
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        self.slots[self.physical_index(index)].as_ref()
+    }
+
+    /// Returns an iterator over the elements from front to back.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        (0..self.len).map(move |i| {
+            self.slots[self.physical_index(i)]
+                .as_ref()
+                .expect("occupied slot")
+        })
+    }
+
+    fn physical_index(&self, index: usize) -> usize {
+        (self.head + index) % self.capacity()
+    }
+
+    fn grow_if_full(&mut self) {
+        if self.len < self.capacity() {
+            return;
+        }
+        let new_capacity = (self.capacity() * 2).max(4);
+        let mut slots: Vec<Option<T>> = Vec::with_capacity(new_capacity);
+        for i in 0..self.len {
+            let index = self.physical_index(i);
+            slots.push(self.slots[index].take());
+        }
+        slots.resize_with(new_capacity, || None);
+        self.slots = slots;
+        self.head = 0;
+    }
+}
+
+impl<T> Default for RingBuffer<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for RingBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut buffer = RingBuffer::new();
+        for value in iter {
+            buffer.push_back(value);
+        }
+        buffer
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RingBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_and_pop_both_ends() {
+        let mut buffer: RingBuffer<i32> = RingBuffer::new();
+        assert_eq!(buffer.pop_front(), None);
+        assert_eq!(buffer.pop_back(), None);
+
+        buffer.push_back(2);
+        buffer.push_front(1);
+        buffer.push_back(3);
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.front(), Some(&1));
+        assert_eq!(buffer.back(), Some(&3));
+        assert_eq!(buffer.pop_front(), Some(1));
+        assert_eq!(buffer.pop_back(), Some(3));
+        assert_eq!(buffer.pop_back(), Some(2));
+        assert!(buffer.is_empty());
+    }
+
+    #[test]
+    fn test_wraps_around_and_grows() {
+        let mut buffer: RingBuffer<i32> = RingBuffer::with_capacity(4);
+        for i in 0..4 {
+            buffer.push_back(i);
+        }
+        buffer.pop_front();
+        buffer.pop_front();
+        buffer.push_back(4);
+        buffer.push_back(5);
+        assert_eq!(buffer.capacity(), 4);
+        assert_eq!(
+            buffer.iter().copied().collect::<Vec<i32>>(),
+            vec![2, 3, 4, 5]
+        );
+
+        buffer.push_front(1);
+        assert_eq!(buffer.capacity(), 8);
+        assert_eq!(
+            buffer.iter().copied().collect::<Vec<i32>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+        assert_eq!(buffer.get(4), Some(&5));
+        assert_eq!(buffer.get(5), None);
+    }
+}
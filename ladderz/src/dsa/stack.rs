@@ -0,0 +1,100 @@
+use super::RingBuffer;
+use std::fmt;
+
+/// A last-in, first-out stack backed by a [`RingBuffer`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::Stack;
+///
+/// let mut stack: Stack<i32> = Stack::new();
+/// stack.push(1);
+/// stack.push(2);
+/// assert_eq!(stack.peek(), Some(&2));
+/// assert_eq!(stack.pop(), Some(2));
+/// assert_eq!(stack.pop(), Some(1));
+/// assert_eq!(stack.pop(), None);
+/// ```
+pub struct Stack<T> {
+    buffer: RingBuffer<T>,
+}
+
+impl<T> Stack<T> {
+    /// Creates an empty stack.
+    pub fn new() -> Self {
+        Stack {
+            buffer: RingBuffer::new(),
+        }
+    }
+
+    /// Pushes an element onto the top of the stack.
+    pub fn push(&mut self, value: T) {
+        self.buffer.push_back(value);
+    }
+
+    /// Removes and returns the element on the top of the stack, or `None` if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.buffer.pop_back()
+    }
+
+    /// Returns a reference to the element on the top of the stack.
+    pub fn peek(&self) -> Option<&T> {
+        self.buffer.back()
+    }
+
+    /// Returns the number of elements in the stack.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns `true` if the stack contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns an iterator over the elements from the bottom to the top of the stack.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.buffer.iter()
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Stack {
+            buffer: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Stack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack() {
+        let mut stack: Stack<i32> = (1..=3).collect();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        stack.push(4);
+        assert_eq!(format!("{:?}", stack), "[1, 2, 4]");
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert!(stack.is_empty());
+        assert_eq!(stack.peek(), None);
+    }
+}
@@ -0,0 +1,737 @@
+use super::is_anagram2;
+use std::fmt;
+
+/// Computes the KMP prefix function (failure table) of `pattern`.
+///
+/// `table[i]` is the length of the longest proper prefix of `pattern[..=i]` that is also a suffix
+/// of it. Positions are counted in `char`s.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::prefix_function;
+///
+/// assert_eq!(prefix_function("abacab"), vec![0, 0, 1, 0, 1, 2]);
+/// ```
+pub fn prefix_function(pattern: &str) -> Vec<usize> {
+    let p: Vec<char> = pattern.chars().collect();
+    let mut table: Vec<usize> = vec![0; p.len()];
+    for i in 1..p.len() {
+        let mut k = table[i - 1];
+        while k > 0 && p[i] != p[k] {
+            k = table[k - 1];
+        }
+        if p[i] == p[k] {
+            k += 1;
+        }
+        table[i] = k;
+    }
+    table
+}
+
+/// Finds every (possibly overlapping) occurrence of `pattern` in `text` with Knuth–Morris–Pratt.
+///
+/// The prefix function of the pattern tells the search how far it can fall back after a
+/// mismatch, so no character of `text` is compared more than twice and the search runs in
+/// O(n + m) time. Returns the starting `char` index of each match. An empty pattern matches at
+/// every position.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::kmp_search;
+///
+/// assert_eq!(kmp_search("abababa", "aba"), vec![0, 2, 4]);
+/// ```
+pub fn kmp_search(text: &str, pattern: &str) -> Vec<usize> {
+    let t: Vec<char> = text.chars().collect();
+    let p: Vec<char> = pattern.chars().collect();
+    if p.is_empty() {
+        return (0..=t.len()).collect();
+    }
+    let table = prefix_function(pattern);
+    let mut matches: Vec<usize> = Vec::new();
+    let mut k: usize = 0;
+    for (i, &c) in t.iter().enumerate() {
+        while k > 0 && c != p[k] {
+            k = table[k - 1];
+        }
+        if c == p[k] {
+            k += 1;
+        }
+        if k == p.len() {
+            matches.push(i + 1 - k);
+            k = table[k - 1];
+        }
+    }
+    matches
+}
+
+fn z_array<T: PartialEq>(s: &[T]) -> Vec<usize> {
+    let n = s.len();
+    let mut z: Vec<usize> = vec![0; n];
+    if n == 0 {
+        return z;
+    }
+    z[0] = n;
+    let (mut left, mut right) = (0, 0);
+    for i in 1..n {
+        if i < right {
+            z[i] = z[i - left].min(right - i);
+        }
+        while i + z[i] < n && s[z[i]] == s[i + z[i]] {
+            z[i] += 1;
+        }
+        if i + z[i] > right {
+            left = i;
+            right = i + z[i];
+        }
+    }
+    z
+}
+
+/// Computes the Z-array of `s`.
+///
+/// `z[i]` is the length of the longest common prefix of `s` and the suffix of `s` starting at
+/// `char` index `i`. By convention `z[0]` is the length of `s`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::z_function;
+///
+/// assert_eq!(z_function("aabxaab"), vec![7, 1, 0, 0, 3, 1, 0]);
+/// ```
+pub fn z_function(s: &str) -> Vec<usize> {
+    z_array(&s.chars().collect::<Vec<char>>())
+}
+
+/// Finds every occurrence of `pattern` in `text` with the Z-algorithm.
+///
+/// The Z-array of `pattern`, a separator that matches nothing, and `text` is computed in O(n + m)
+/// time; a match starts wherever a Z-value reaches the length of the pattern. Returns `char`
+/// indices into `text`. An empty pattern matches at every position.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::z_search;
+///
+/// assert_eq!(z_search("abababa", "aba"), vec![0, 2, 4]);
+/// ```
+pub fn z_search(text: &str, pattern: &str) -> Vec<usize> {
+    let m = pattern.chars().count();
+    if m == 0 {
+        return (0..=text.chars().count()).collect();
+    }
+    let combined: Vec<Option<char>> = pattern
+        .chars()
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain(text.chars().map(Some))
+        .collect();
+    z_array(&combined)
+        .into_iter()
+        .enumerate()
+        .skip(m + 1)
+        .filter(|&(_, z)| z >= m)
+        .map(|(i, _)| i - m - 1)
+        .collect()
+}
+
+/// Finds every occurrence of `pattern` in `text` with Rabin–Karp.
+///
+/// A polynomial hash of each window of `text` is updated in O(1) as the window slides by one
+/// `char`: the leading character's contribution is subtracted and the next one is added.
+/// Windows whose hash equals the pattern's hash are then compared directly, so hash
+/// collisions never produce false matches. Returns `char` indices into `text`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::rabin_karp;
+///
+/// assert_eq!(rabin_karp("abababa", "aba"), vec![0, 2, 4]);
+/// ```
+pub fn rabin_karp(text: &str, pattern: &str) -> Vec<usize> {
+    const BASE: u64 = 1_114_112; // One more than the largest `char`.
+    const MODULUS: u64 = 1_000_000_007;
+
+    let t: Vec<char> = text.chars().collect();
+    let p: Vec<char> = pattern.chars().collect();
+    let m = p.len();
+    if m == 0 {
+        return (0..=t.len()).collect();
+    }
+    if m > t.len() {
+        return Vec::new();
+    }
+
+    let hash = |chars: &[char]| {
+        chars
+            .iter()
+            .fold(0, |h, &c| (h * BASE + c as u64) % MODULUS)
+    };
+    // BASE^(m - 1), the weight of the leading character in a window.
+    let lead_weight = (1..m).fold(1, |w, _| w * BASE % MODULUS);
+    let pattern_hash = hash(&p);
+    let mut window_hash = hash(&t[..m]);
+
+    let mut matches: Vec<usize> = Vec::new();
+    for start in 0..=t.len() - m {
+        if window_hash == pattern_hash && t[start..start + m] == p[..] {
+            matches.push(start);
+        }
+        if start + m < t.len() {
+            let lead = t[start] as u64 * lead_weight % MODULUS;
+            window_hash = (window_hash + MODULUS - lead) % MODULUS;
+            window_hash = (window_hash * BASE + t[start + m] as u64) % MODULUS;
+        }
+    }
+    matches
+}
+
+/// Returns a longest common subsequence of `a` and `b`.
+///
+/// A subsequence keeps the order of characters but need not be contiguous. The DP table stores
+/// the LCS length of every pair of prefixes and is walked back from the bottom-right corner to
+/// recover the characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::longest_common_subsequence;
+///
+/// assert_eq!(longest_common_subsequence("ABCBDAB", "BDCABA"), "BCBA");
+/// ```
+pub fn longest_common_subsequence(a: &str, b: &str) -> String {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let table = lcs_table(&a, &b);
+
+    let mut result: Vec<char> = Vec::new();
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 && j > 0 {
+        if a[i - 1] == b[j - 1] {
+            result.push(a[i - 1]);
+            i -= 1;
+            j -= 1;
+        } else if table[i - 1][j] >= table[i][j - 1] {
+            i -= 1;
+        } else {
+            j -= 1;
+        }
+    }
+    result.into_iter().rev().collect()
+}
+
+/// Builds the LCS length table, where `table[i][j]` is the LCS length of `a[..i]` and `b[..j]`.
+pub(crate) fn lcs_table(a: &[char], b: &[char]) -> Vec<Vec<usize>> {
+    let mut table: Vec<Vec<usize>> = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            table[i][j] = if a[i - 1] == b[j - 1] {
+                table[i - 1][j - 1] + 1
+            } else {
+                table[i - 1][j].max(table[i][j - 1])
+            };
+        }
+    }
+    table
+}
+
+/// Returns the first longest common substring (contiguous run of characters) of `a` and `b`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::longest_common_substring;
+///
+/// assert_eq!(longest_common_substring("xabcdey", "zbcdq"), "bcd");
+/// ```
+pub fn longest_common_substring(a: &str, b: &str) -> String {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // `previous[j]` is the length of the common suffix of a[..i - 1] and b[..j].
+    let mut previous: Vec<usize> = vec![0; b.len() + 1];
+    let (mut best_len, mut best_end) = (0, 0);
+    for i in 1..=a.len() {
+        let mut current: Vec<usize> = vec![0; b.len() + 1];
+        for j in 1..=b.len() {
+            if a[i - 1] == b[j - 1] {
+                current[j] = previous[j - 1] + 1;
+                if current[j] > best_len {
+                    best_len = current[j];
+                    best_end = i;
+                }
+            }
+        }
+        previous = current;
+    }
+    a[best_end - best_len..best_end].iter().collect()
+}
+
+/// A single step in an [`Alignment`] that turns one string into another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOp {
+    /// The character is the same in both strings.
+    Match(char),
+    /// The first character is replaced by the second.
+    Substitute(char, char),
+    /// The character is inserted into the first string.
+    Insert(char),
+    /// The character is deleted from the first string.
+    Delete(char),
+    /// Two adjacent characters of the first string are swapped.
+    Transpose(char, char),
+}
+
+impl EditOp {
+    /// Returns `0` for a match and `1` for every other operation.
+    pub fn cost(&self) -> usize {
+        match self {
+            EditOp::Match(_) => 0,
+            _ => 1,
+        }
+    }
+}
+
+/// An optimal sequence of edit operations between two strings, as returned by
+/// [`levenshtein_alignment`] and [`damerau_levenshtein_alignment`].
+///
+/// Displaying an alignment prints three lines: the first string with `-` for insertions, a
+/// marker line, and the second string with `-` for deletions. Markers are `|` for a match, `.`
+/// for a substitution, a space for an insertion or deletion, and `~~` for a transposition.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::levenshtein_alignment;
+///
+/// let alignment = levenshtein_alignment("kitten", "sitting");
+/// assert_eq!(alignment.distance, 3);
+/// assert_eq!(alignment.to_string(), "kitten-\n.|||.| \nsitting");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alignment {
+    /// The number of non-matching operations.
+    pub distance: usize,
+    /// The operations in order from the start of the strings.
+    pub operations: Vec<EditOp>,
+}
+
+impl Alignment {
+    /// Returns the top, marker and bottom lines of the alignment.
+    pub fn rows(&self) -> (String, String, String) {
+        let (mut top, mut markers, mut bottom) = (String::new(), String::new(), String::new());
+        for op in self.operations.iter() {
+            let (t, m, b) = match *op {
+                EditOp::Match(c) => (c.to_string(), "|", c.to_string()),
+                EditOp::Substitute(x, y) => (x.to_string(), ".", y.to_string()),
+                EditOp::Insert(c) => ("-".to_owned(), " ", c.to_string()),
+                EditOp::Delete(c) => (c.to_string(), " ", "-".to_owned()),
+                EditOp::Transpose(x, y) => (format!("{x}{y}"), "~~", format!("{y}{x}")),
+            };
+            top.push_str(&t);
+            markers.push_str(m);
+            bottom.push_str(&b);
+        }
+        (top, markers, bottom)
+    }
+}
+
+impl fmt::Display for Alignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (top, markers, bottom) = self.rows();
+        write!(f, "{top}\n{markers}\n{bottom}")
+    }
+}
+
+/// Builds the edit distance table, where `table[i][j]` is the distance between `a[..i]` and `b[..j]`.
+///
+/// With `transpositions`, swapping two adjacent characters also costs 1 (optimal string alignment).
+pub(crate) fn edit_distance_grid(a: &[char], b: &[char], transpositions: bool) -> Vec<Vec<usize>> {
+    let mut table: Vec<Vec<usize>> = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in table.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in table[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let substitution = usize::from(a[i - 1] != b[j - 1]);
+            table[i][j] = (table[i - 1][j] + 1)
+                .min(table[i][j - 1] + 1)
+                .min(table[i - 1][j - 1] + substitution);
+            if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                table[i][j] = table[i][j].min(table[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    table
+}
+
+fn edit_alignment(a: &str, b: &str, transpositions: bool) -> Alignment {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let table = edit_distance_grid(&a, &b, transpositions);
+
+    let mut operations: Vec<EditOp> = Vec::new();
+    let (mut i, mut j) = (a.len(), b.len());
+    while i > 0 || j > 0 {
+        let d = table[i][j];
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && d == table[i - 1][j - 1] {
+            operations.push(EditOp::Match(a[i - 1]));
+            i -= 1;
+            j -= 1;
+        } else if transpositions
+            && i > 1
+            && j > 1
+            && a[i - 1] == b[j - 2]
+            && a[i - 2] == b[j - 1]
+            && d == table[i - 2][j - 2] + 1
+        {
+            operations.push(EditOp::Transpose(a[i - 2], a[i - 1]));
+            i -= 2;
+            j -= 2;
+        } else if i > 0 && j > 0 && d == table[i - 1][j - 1] + 1 {
+            operations.push(EditOp::Substitute(a[i - 1], b[j - 1]));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && d == table[i - 1][j] + 1 {
+            operations.push(EditOp::Delete(a[i - 1]));
+            i -= 1;
+        } else {
+            operations.push(EditOp::Insert(b[j - 1]));
+            j -= 1;
+        }
+    }
+    operations.reverse();
+    Alignment {
+        distance: table[a.len()][b.len()],
+        operations,
+    }
+}
+
+/// Returns the Levenshtein distance between `a` and `b`.
+///
+/// This is the minimum number of single-character insertions, deletions and substitutions that
+/// turn `a` into `b`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::levenshtein;
+///
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    levenshtein_alignment(a, b).distance
+}
+
+/// Returns the Levenshtein distance between `a` and `b` with an optimal alignment.
+///
+/// See [`Alignment`] for an example of the alignment output.
+pub fn levenshtein_alignment(a: &str, b: &str) -> Alignment {
+    edit_alignment(a, b, false)
+}
+
+/// Returns the Damerau–Levenshtein distance between `a` and `b`.
+///
+/// Like [`levenshtein`], but swapping two adjacent characters also counts as a single edit. This
+/// is the optimal string alignment variant, in which no substring is edited more than once.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::{damerau_levenshtein, levenshtein};
+///
+/// assert_eq!(damerau_levenshtein("form", "from"), 1);
+/// assert_eq!(levenshtein("form", "from"), 2);
+/// ```
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    damerau_levenshtein_alignment(a, b).distance
+}
+
+/// Returns the Damerau–Levenshtein distance between `a` and `b` with an optimal alignment.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::damerau_levenshtein_alignment;
+///
+/// let alignment = damerau_levenshtein_alignment("form", "from");
+/// assert_eq!(alignment.to_string(), "form\n|~~|\nfrom");
+/// ```
+pub fn damerau_levenshtein_alignment(a: &str, b: &str) -> Alignment {
+    edit_alignment(a, b, true)
+}
+
+/// Returns the first longest palindromic substring of `s` using Manacher's algorithm.
+///
+/// A separator is placed between every pair of characters (and at both ends) so that odd and
+/// even length palindromes can be handled alike. The radius of the palindrome centred at each
+/// position is initialised from its mirror around the rightmost palindrome found so far, which
+/// makes the whole scan O(n).
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::longest_palindromic_substring;
+///
+/// assert_eq!(longest_palindromic_substring("babad"), "bab");
+/// assert_eq!(longest_palindromic_substring("cbbd"), "bb");
+/// ```
+pub fn longest_palindromic_substring(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut t: Vec<Option<char>> = vec![None];
+    for &c in chars.iter() {
+        t.push(Some(c));
+        t.push(None);
+    }
+
+    let mut radius: Vec<usize> = vec![0; t.len()];
+    let (mut center, mut right) = (0, 0);
+    for i in 0..t.len() {
+        if i < right {
+            radius[i] = radius[2 * center - i].min(right - i);
+        }
+        while i > radius[i]
+            && i + radius[i] + 1 < t.len()
+            && t[i - radius[i] - 1] == t[i + radius[i] + 1]
+        {
+            radius[i] += 1;
+        }
+        if i + radius[i] > right {
+            center = i;
+            right = i + radius[i];
+        }
+    }
+
+    // In the separated string the radius equals the palindrome's length in `s`.
+    let (best, &length) = radius
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|&(_, r)| r)
+        .unwrap_or((0, &0));
+    let start = (best - length) / 2;
+    chars[start..start + length].iter().collect()
+}
+
+/// Groups words that are anagrams of each other.
+///
+/// Each word is compared with [`is_anagram2`] against the first word of every existing group.
+/// Groups are ordered by their first appearance and keep the words in input order.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::group_anagrams;
+///
+/// let result = group_anagrams(&["eat", "tea", "tan", "ate", "nat", "bat"]);
+/// assert_eq!(result, vec![vec!["eat", "tea", "ate"], vec!["tan", "nat"], vec!["bat"]]);
+/// ```
+pub fn group_anagrams<S: AsRef<str>>(words: &[S]) -> Vec<Vec<String>> {
+    let mut groups: Vec<Vec<String>> = Vec::new();
+    for word in words.iter().map(|w| w.as_ref()) {
+        match groups.iter_mut().find(|group| is_anagram2(&group[0], word)) {
+            Some(group) => group.push(word.to_owned()),
+            None => groups.push(vec![word.to_owned()]),
+        }
+    }
+    groups
+}
+
+/// Returns the suffix array of `s`: the starting `char` index of every suffix, in sorted order.
+///
+/// Suffixes are sorted by prefix doubling. After round `k` every suffix has a rank by its first
+/// 2<sup>k</sup> characters, and pairs of ranks give the order by the first 2<sup>k+1</sup>
+/// characters. This takes O(n log<sup>2</sup> n) time.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::suffix_array;
+///
+/// // a, ana, anana, banana, na, nana
+/// assert_eq!(suffix_array("banana"), vec![5, 3, 1, 0, 4, 2]);
+/// ```
+pub fn suffix_array(s: &str) -> Vec<usize> {
+    let n = s.chars().count();
+    let mut sa: Vec<usize> = (0..n).collect();
+    let mut rank: Vec<usize> = s.chars().map(|c| c as usize).collect();
+    if n < 2 {
+        return sa;
+    }
+    let mut k = 1;
+    loop {
+        // Suffixes shorter than k sort first, hence the `+ 1` on real ranks.
+        let key = |i: usize, rank: &[usize]| (rank[i], rank.get(i + k).map_or(0, |r| r + 1));
+        sa.sort_by_key(|&i| key(i, &rank));
+        let mut next_rank: Vec<usize> = vec![0; n];
+        for w in 1..n {
+            let step = usize::from(key(sa[w - 1], &rank) < key(sa[w], &rank));
+            next_rank[sa[w]] = next_rank[sa[w - 1]] + step;
+        }
+        rank = next_rank;
+        if rank[sa[n - 1]] == n - 1 {
+            break;
+        }
+        k *= 2;
+    }
+    sa
+}
+
+/// Returns the LCP array for `s` and its `suffix_array` using Kasai's algorithm.
+///
+/// `lcp[i]` is the length of the longest common prefix of the suffixes at `suffix_array[i - 1]`
+/// and `suffix_array[i]`, and `lcp[0]` is 0. Runs in O(n).
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::{lcp_array, suffix_array};
+///
+/// let sa = suffix_array("banana");
+/// assert_eq!(lcp_array("banana", &sa), vec![0, 1, 3, 0, 0, 2]);
+/// ```
+pub fn lcp_array(s: &str, suffix_array: &[usize]) -> Vec<usize> {
+    let chars: Vec<char> = s.chars().collect();
+    let n = chars.len();
+    let mut rank: Vec<usize> = vec![0; n];
+    for (i, &suffix) in suffix_array.iter().enumerate() {
+        rank[suffix] = i;
+    }
+    let mut lcp: Vec<usize> = vec![0; n];
+    let mut h: usize = 0;
+    for i in 0..n {
+        if rank[i] == 0 {
+            h = 0;
+            continue;
+        }
+        let j = suffix_array[rank[i] - 1];
+        while i + h < n && j + h < n && chars[i + h] == chars[j + h] {
+            h += 1;
+        }
+        lcp[rank[i]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::collections::HashMap;
+
+    fn naive_search(text: &str, pattern: &str) -> Vec<usize> {
+        let t: Vec<char> = text.chars().collect();
+        let p: Vec<char> = pattern.chars().collect();
+        if p.len() > t.len() {
+            return Vec::new();
+        }
+        (0..=t.len() - p.len())
+            .filter(|&i| t[i..i + p.len()] == p[..])
+            .collect()
+    }
+
+    #[test]
+    fn test_searches_agree() {
+        let cases = [
+            ("aaaaa", "aa"),
+            ("abcabcabc", "cab"),
+            ("mississippi", "issi"),
+            ("héllo wörld héllo", "héllo"),
+            ("short", "longer pattern"),
+            ("abc", "d"),
+        ];
+        for (text, pattern) in cases {
+            let expected = naive_search(text, pattern);
+            assert_eq!(kmp_search(text, pattern), expected);
+            assert_eq!(z_search(text, pattern), expected);
+            assert_eq!(rabin_karp(text, pattern), expected);
+        }
+        assert_eq!(kmp_search("ab", ""), vec![0, 1, 2]);
+        assert_eq!(z_search("ab", ""), vec![0, 1, 2]);
+        assert_eq!(rabin_karp("ab", ""), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_longest_common() {
+        assert_eq!(longest_common_subsequence("AGGTAB", "GXTXAYB"), "GTAB");
+        assert_eq!(longest_common_subsequence("abc", "def"), "");
+        assert_eq!(longest_common_substring("abcdxyz", "xyzabcd"), "abcd");
+        assert_eq!(longest_common_substring("", "abc"), "");
+    }
+
+    #[test]
+    fn test_edit_distances() {
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(damerau_levenshtein("ca", "abc"), 3);
+        assert_eq!(damerau_levenshtein("abcdef", "abdcef"), 1);
+
+        let alignment = levenshtein_alignment("flaw", "lawn");
+        assert_eq!(alignment.to_string(), "flaw-\n ||| \n-lawn");
+        let cost: usize = alignment.operations.iter().map(EditOp::cost).sum();
+        assert_eq!(cost, alignment.distance);
+    }
+
+    #[test]
+    fn test_longest_palindromic_substring() {
+        assert_eq!(longest_palindromic_substring(""), "");
+        assert_eq!(longest_palindromic_substring("a"), "a");
+        assert_eq!(
+            longest_palindromic_substring("forgeeksskeegfor"),
+            "geeksskeeg"
+        );
+        assert_eq!(longest_palindromic_substring("abacdfgdcaba"), "aba");
+        assert_eq!(longest_palindromic_substring("ésé!"), "ésé");
+    }
+
+    #[test]
+    fn test_group_anagrams() {
+        let empty: [&str; 0] = [];
+        assert_eq!(group_anagrams(&empty), Vec::<Vec<String>>::new());
+        assert_eq!(
+            group_anagrams(&[
+                "listen".to_owned(),
+                "silent".to_owned(),
+                "enlist".to_owned()
+            ]),
+            vec![vec!["listen", "silent", "enlist"]]
+        );
+    }
+
+    #[test]
+    fn test_suffix_and_lcp_arrays() {
+        assert_eq!(suffix_array(""), Vec::<usize>::new());
+        assert_eq!(suffix_array("a"), vec![0]);
+        assert_eq!(suffix_array("aaaa"), vec![3, 2, 1, 0]);
+        let sa = suffix_array("mississippi");
+        assert_eq!(sa, vec![10, 7, 4, 1, 0, 9, 8, 6, 3, 5, 2]);
+        assert_eq!(
+            lcp_array("mississippi", &sa),
+            vec![0, 1, 1, 4, 0, 0, 1, 0, 2, 1, 3]
+        );
+        let mut counts: HashMap<usize, usize> = HashMap::new();
+        for i in sa {
+            *counts.entry(i).or_default() += 1;
+        }
+        assert!(counts.values().all(|&c| c == 1));
+    }
+
+    // On a two-letter alphabet random patterns match often, including overlapping matches.
+    proptest! {
+        #[test]
+        fn prop_searches_agree_with_naive_search(text in "[ab]{0,40}", pattern in "[ab]{0,4}") {
+            let expected = naive_search(&text, &pattern);
+            prop_assert_eq!(kmp_search(&text, &pattern), expected.clone());
+            prop_assert_eq!(z_search(&text, &pattern), expected.clone());
+            prop_assert_eq!(rabin_karp(&text, &pattern), expected);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Default)]
+struct Node {
+    children: BTreeMap<char, Node>,
+    terminal: bool,
+}
+
+/// A prefix tree of words that supports fast prefix queries.
+///
+/// Each edge is labelled with a `char` and each word is the path from the root to a node marked
+/// as terminal. Children are kept in a `BTreeMap`, so words are always listed alphabetically.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::Trie;
+///
+/// let mut trie: Trie = ["car", "cart", "cat", "dog"].into_iter().collect();
+/// assert!(trie.contains("cart"));
+/// assert!(!trie.contains("ca"));
+/// assert!(trie.starts_with("ca"));
+/// assert_eq!(trie.words_with_prefix("car"), vec!["car", "cart"]);
+/// ```
+#[derive(Default)]
+pub struct Trie {
+    root: Node,
+    len: usize,
+}
+
+impl Trie {
+    /// Creates an empty trie.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts `word` into the trie, returning `false` if it was already present.
+    pub fn insert(&mut self, word: &str) -> bool {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        if node.terminal {
+            return false;
+        }
+        node.terminal = true;
+        self.len += 1;
+        true
+    }
+
+    /// Returns `true` if `word` was inserted into the trie.
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.terminal)
+    }
+
+    /// Returns `true` if any word in the trie starts with `prefix`.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        // Removal prunes empty branches, so only the root can lead to no word.
+        self.find(prefix)
+            .is_some_and(|node| node.terminal || !node.children.is_empty())
+    }
+
+    /// Returns every word that starts with `prefix` in alphabetical order.
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words: Vec<String> = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            collect(node, &mut prefix.to_owned(), &mut words);
+        }
+        words
+    }
+
+    /// Returns every word in the trie in alphabetical order.
+    pub fn words(&self) -> Vec<String> {
+        self.words_with_prefix("")
+    }
+
+    /// Removes `word` from the trie, returning `true` if it was present.
+    ///
+    /// Nodes that no longer lead to any word are pruned.
+    pub fn remove(&mut self, word: &str) -> bool {
+        let chars: Vec<char> = word.chars().collect();
+        let removed = remove(&mut self.root, &chars);
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+
+    /// Returns the number of words in the trie.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the trie contains no words.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+fn collect(node: &Node, prefix: &mut String, words: &mut Vec<String>) {
+    if node.terminal {
+        words.push(prefix.clone());
+    }
+    for (&c, child) in node.children.iter() {
+        prefix.push(c);
+        collect(child, prefix, words);
+        prefix.pop();
+    }
+}
+
+fn remove(node: &mut Node, chars: &[char]) -> bool {
+    let Some((first, rest)) = chars.split_first() else {
+        let was_terminal = node.terminal;
+        node.terminal = false;
+        return was_terminal;
+    };
+    let Some(child) = node.children.get_mut(first) else {
+        return false;
+    };
+    let removed = remove(child, rest);
+    if removed && !child.terminal && child.children.is_empty() {
+        node.children.remove(first);
+    }
+    removed
+}
+
+impl<'a> FromIterator<&'a str> for Trie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for word in iter {
+            trie.insert(word);
+        }
+        trie
+    }
+}
+
+impl fmt::Debug for Trie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.words()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_query() {
+        let mut trie = Trie::new();
+        assert!(trie.insert("tea"));
+        assert!(trie.insert("ten"));
+        assert!(trie.insert("to"));
+        assert!(!trie.insert("to"));
+        assert_eq!(trie.len(), 3);
+        assert!(trie.contains("ten"));
+        assert!(!trie.contains("te"));
+        assert!(trie.starts_with("te"));
+        assert!(!trie.starts_with("x"));
+        assert!(trie.starts_with(""));
+        assert!(!Trie::new().starts_with(""));
+        assert_eq!(trie.words_with_prefix("te"), vec!["tea", "ten"]);
+        assert_eq!(trie.words_with_prefix("z"), Vec::<String>::new());
+        assert_eq!(format!("{:?}", trie), r#"{"tea", "ten", "to"}"#);
+    }
+
+    #[test]
+    fn test_remove_prunes() {
+        let mut trie: Trie = ["car", "cart"].into_iter().collect();
+        assert!(trie.remove("cart"));
+        assert!(!trie.remove("cart"));
+        assert!(!trie.starts_with("cart"));
+        assert!(trie.contains("car"));
+        assert!(!trie.remove("ca"));
+        assert!(trie.remove("car"));
+        assert!(trie.root.children.is_empty());
+        assert!(trie.is_empty());
+        assert!(!trie.starts_with(""));
+    }
+
+    #[test]
+    fn test_unicode_and_empty_word() {
+        let mut trie = Trie::new();
+        assert!(trie.insert(""));
+        assert!(trie.insert("héllo"));
+        assert!(trie.contains(""));
+        assert_eq!(trie.words(), vec!["", "héllo"]);
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// A disjoint-set (union-find) forest over arbitrary hashable elements.
+///
+/// Elements are mapped to indices on insertion. [`UnionFind::find`] compresses paths so every
+/// visited element points directly at its representative, and [`UnionFind::union`] attaches the
+/// shorter tree under the taller one (union by rank), giving near-constant amortized time.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::dsa::UnionFind;
+///
+/// let mut sets: UnionFind<&str> = UnionFind::new();
+/// sets.union("a", "b");
+/// sets.union("c", "d");
+/// assert!(sets.connected(&"a", &"b"));
+/// assert!(!sets.connected(&"a", &"c"));
+/// assert_eq!(sets.set_count(), 2);
+/// ```
+pub struct UnionFind<T> {
+    indices: HashMap<T, usize>,
+    elements: Vec<T>,
+    parent: Vec<usize>,
+    rank: Vec<usize>,
+    set_count: usize,
+}
+
+impl<T: Hash + Eq + Clone> UnionFind<T> {
+    /// Creates an empty union-find.
+    pub fn new() -> Self {
+        UnionFind {
+            indices: HashMap::new(),
+            elements: Vec::new(),
+            parent: Vec::new(),
+            rank: Vec::new(),
+            set_count: 0,
+        }
+    }
+
+    /// Adds `element` as a new singleton set, returning `false` if it was already present.
+    pub fn insert(&mut self, element: T) -> bool {
+        if self.indices.contains_key(&element) {
+            return false;
+        }
+        self.index_of(element);
+        true
+    }
+
+    /// Returns the representative of the set containing `element`, or `None` if it is unknown.
+    pub fn find(&mut self, element: &T) -> Option<&T> {
+        let index = *self.indices.get(element)?;
+        let root = self.find_root(index);
+        Some(&self.elements[root])
+    }
+
+    /// Merges the sets containing `a` and `b`, inserting either element if it is unknown.
+    ///
+    /// Returns `false` if `a` and `b` were already in the same set.
+    pub fn union(&mut self, a: T, b: T) -> bool {
+        let a = self.index_of(a);
+        let b = self.index_of(b);
+        let (root_a, root_b) = (self.find_root(a), self.find_root(b));
+        if root_a == root_b {
+            return false;
+        }
+        let (child, parent) = if self.rank[root_a] < self.rank[root_b] {
+            (root_a, root_b)
+        } else {
+            (root_b, root_a)
+        };
+        self.parent[child] = parent;
+        if self.rank[child] == self.rank[parent] {
+            self.rank[parent] += 1;
+        }
+        self.set_count -= 1;
+        true
+    }
+
+    /// Returns `true` if `a` and `b` are both known and belong to the same set.
+    pub fn connected(&mut self, a: &T, b: &T) -> bool {
+        match (self.indices.get(a).copied(), self.indices.get(b).copied()) {
+            (Some(a), Some(b)) => self.find_root(a) == self.find_root(b),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Returns the number of elements across all sets.
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if no elements have been added.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns the sets, each listed in insertion order and ordered by their first element.
+    pub fn sets(&self) -> Vec<Vec<&T>> {
+        let mut groups: Vec<Vec<&T>> = Vec::new();
+        let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+        for (index, element) in self.elements.iter().enumerate() {
+            let mut root = index;
+            while self.parent[root] != root {
+                root = self.parent[root];
+            }
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(element);
+        }
+        groups
+    }
+
+    fn index_of(&mut self, element: T) -> usize {
+        if let Some(&index) = self.indices.get(&element) {
+            return index;
+        }
+        let index = self.elements.len();
+        self.indices.insert(element.clone(), index);
+        self.elements.push(element);
+        self.parent.push(index);
+        self.rank.push(0);
+        self.set_count += 1;
+        index
+    }
+
+    fn find_root(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Path compression: point every element on the path directly at the root.
+        let mut current = index;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+}
+
+impl<T: Hash + Eq + Clone> Default for UnionFind<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug + Hash + Eq + Clone> fmt::Debug for UnionFind<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sets = self.sets();
+        f.debug_list()
+            .entries(sets.iter().map(|set| DebugSet(set)))
+            .finish()
+    }
+}
+
+struct DebugSet<'a, T>(&'a [&'a T]);
+
+impl<T: fmt::Debug> fmt::Debug for DebugSet<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.0.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_union_and_find() {
+        let mut sets: UnionFind<u32> = UnionFind::new();
+        for i in 0..6 {
+            assert!(sets.insert(i));
+        }
+        assert!(!sets.insert(0));
+        assert_eq!(sets.set_count(), 6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert_eq!(sets.set_count(), 3);
+        assert!(sets.connected(&0, &3));
+        assert!(!sets.connected(&0, &4));
+        assert!(!sets.connected(&0, &42));
+        let root = *sets.find(&0).unwrap();
+        assert_eq!(sets.find(&3), Some(&root));
+        assert_eq!(sets.find(&42), None);
+        assert_eq!(format!("{:?}", sets), "[{0, 1, 2, 3}, {4}, {5}]");
+    }
+
+    #[test]
+    fn test_path_compression() {
+        let mut sets: UnionFind<usize> = UnionFind::new();
+        for i in 1..100 {
+            sets.union(i - 1, i);
+        }
+        let root = *sets.find(&99).unwrap();
+        assert_eq!(sets.parent[sets.indices[&99]], sets.indices[&root]);
+        assert_eq!(sets.len(), 100);
+        assert_eq!(sets.set_count(), 1);
+    }
+}
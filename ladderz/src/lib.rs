@@ -27,6 +27,9 @@
 //!
 //! Choose a module to view its available functions.
 
+// The implementations spell out divisibility checks as `n % d == 0` on purpose.
+#![allow(clippy::manual_is_multiple_of)]
+
 /// Various pre-algebra implementations including factor pairs, factors, multiples, and more.
 ///
 /// # Example
@@ -44,7 +47,8 @@
 ///
 pub mod prealgebra;
 
-/// Various data structures and algorithms implementations.
+/// Various data structures and algorithms implementations, including linked lists, stacks, queues,
+/// heaps, search trees, tries, union-find and an LRU cache.
 ///
 /// # Example
 ///
@@ -52,7 +56,7 @@ pub mod prealgebra;
 /// use ladderz::dsa::contains_duplicate;
 ///
 /// let nums: Vec<i32> = vec![2, 3, 4, 2];
-/// let result = contains_duplicate(nums.clone());
+/// let result = contains_duplicate(&nums);
 /// println!("The vector {:?} {} contain a duplicate.", &nums, if result { "does" } else { "does not" });
 /// ```
 ///
@@ -61,3 +65,61 @@ pub mod prealgebra;
 /// ```
 ///
 pub mod dsa;
+
+/// Various precalculus implementations including function composition, trigonometry, triangle
+/// solving, logarithms, and series.
+///
+/// # Example
+///
+/// ```rust
+/// use ladderz::precalculus::{solve_sss, TrigFunction};
+///
+/// println!("sin(60°) = {}", TrigFunction::Sin.exact_value(60).unwrap());
+/// println!("{}", solve_sss(3.0, 4.0, 5.0).unwrap());
+/// ```
+///
+/// ```console
+/// sin(60°) = √3/2
+/// a = 3, b = 4, c = 5, A = 36.8699°, B = 53.1301°, C = 90°
+/// ```
+///
+pub mod precalculus;
+
+/// Various bioinformatics implementations including DNA/RNA/protein sequences, GC content,
+/// translation, k-mer counting, FASTA/FASTQ files, pairwise alignment, and more.
+///
+/// # Example
+///
+/// ```rust
+/// use ladderz::bioinformatics::Sequence;
+///
+/// let dna = Sequence::dna("ATGGCCTGA").unwrap();
+/// println!("The protein encoded by {dna} is {}.", dna.translate().unwrap());
+/// ```
+///
+/// ```console
+/// The protein encoded by ATGGCCTGA is MA*.
+/// ```
+///
+pub mod bioinformatics;
+
+/// Seeded practice problem generators with difficulty levels and answer checking.
+///
+/// # Example
+///
+/// ```rust
+/// use ladderz::practice::prealgebra::generate_problems;
+/// use ladderz::practice::Difficulty;
+///
+/// for problem in generate_problems(3, 42, Some(Difficulty::Medium), &[]) {
+///     println!("{problem} {}", problem.answer());
+/// }
+/// ```
+///
+/// ```console
+/// Is 119 prime? no
+/// Find the prime factorization of 60. 2^2 × 3 × 5
+/// Find all multiples of 20 from 97 to 157. 100, 120, 140
+/// ```
+///
+pub mod practice;
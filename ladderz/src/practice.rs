@@ -0,0 +1,216 @@
+/// Practice problems for the pre-algebra concepts in [`crate::prealgebra`].
+pub mod prealgebra;
+
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// How hard a generated problem is, which sets how large its numbers are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    /// Every difficulty, from easiest to hardest.
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    /// Parses `easy`, `medium` or `hard`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(format!(
+                "unknown difficulty {s:?}, expected easy, medium or hard"
+            )),
+        }
+    }
+}
+
+/// A small seeded pseudorandom number generator (SplitMix64), so the same seed always generates
+/// the same problems on every platform and version.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::practice::Rng;
+///
+/// let mut a = Rng::new(42);
+/// let mut b = Rng::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64());
+///
+/// let roll = a.range(1..=6);
+/// assert!((1..=6).contains(&roll));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// Returns the next pseudorandom 64-bit number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u32>) -> u32 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "the range must not be empty");
+        let width = (end - start) as u64 + 1;
+        // Scale a 64-bit number down to the width, which is far less biased than `%`.
+        start + ((self.next_u64() as u128 * width as u128) >> 64) as u32
+    }
+
+    /// Returns `true` or `false` with equal chance.
+    pub fn coin_flip(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+
+    /// Shuffles `items` in place with the Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i as u32) as usize);
+        }
+    }
+
+    /// Returns a random item of `items`, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() as u32 - 1) as usize]
+    }
+}
+
+/// An error from reading a student's answer, e.g. `"maybe"` for a yes or no question.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerError {
+    pub message: String,
+}
+
+impl AnswerError {
+    fn new(message: impl Into<String>) -> Self {
+        AnswerError {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for AnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for AnswerError {}
+
+/// Reads a yes or no answer such as `yes`, `n`, `true` or `False`.
+fn parse_yes_no(answer: &str) -> Result<bool, AnswerError> {
+    match answer.trim().to_ascii_lowercase().as_str() {
+        "yes" | "y" | "true" | "t" => Ok(true),
+        "no" | "n" | "false" | "f" => Ok(false),
+        _ => Err(AnswerError::new(format!(
+            "expected yes or no, but found {:?}",
+            answer.trim()
+        ))),
+    }
+}
+
+/// Reads every whole number in an answer like `1, 2, 4` or `{(1, 4), (2, 2)}`, ignoring the
+/// punctuation between them.
+fn parse_numbers(answer: &str) -> Result<Vec<u32>, AnswerError> {
+    let invalid = answer
+        .chars()
+        .find(|c| !(c.is_ascii_digit() || c.is_whitespace() || ",;(){}[]".contains(*c)));
+    if let Some(c) = invalid {
+        return Err(AnswerError::new(format!(
+            "expected whole numbers, but found {c:?}"
+        )));
+    }
+    answer
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|digits| !digits.is_empty())
+        .map(|digits| {
+            digits
+                .parse()
+                .map_err(|_| AnswerError::new(format!("{digits} is too large")))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_reproducible() {
+        let first: Vec<u64> = (0..5)
+            .scan(Rng::new(7), |rng, _| Some(rng.next_u64()))
+            .collect();
+        let second: Vec<u64> = (0..5)
+            .scan(Rng::new(7), |rng, _| Some(rng.next_u64()))
+            .collect();
+        assert_eq!(first, second);
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+        // SplitMix64's first output for a seed of 0, so the sequence never silently changes.
+        assert_eq!(Rng::new(0).next_u64(), 0xE220_A839_7B1D_CDAF);
+    }
+
+    #[test]
+    fn test_rng_range_covers_bounds() {
+        let mut rng = Rng::new(1);
+        let rolls: Vec<u32> = (0..200).map(|_| rng.range(1..=6)).collect();
+        assert!(rolls.iter().all(|roll| (1..=6).contains(roll)));
+        assert!(rolls.contains(&1) && rolls.contains(&6));
+        assert_eq!(rng.range(5..=5), 5);
+        // The full range doesn't overflow the width.
+        rng.range(0..=u32::MAX);
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        assert_eq!(parse_yes_no(" Yes "), Ok(true));
+        assert_eq!(parse_yes_no("f"), Ok(false));
+        assert!(parse_yes_no("maybe").is_err());
+        assert_eq!(parse_numbers("{1, 2,4}"), Ok(vec![1, 2, 4]));
+        assert_eq!(parse_numbers("(1, 4) (2, 2)"), Ok(vec![1, 4, 2, 2]));
+        assert_eq!(parse_numbers(""), Ok(vec![]));
+        assert!(parse_numbers("1, -2").is_err());
+        assert!(parse_numbers("99999999999").is_err());
+    }
+
+    #[test]
+    fn test_difficulty_names() {
+        for difficulty in Difficulty::ALL {
+            assert_eq!(difficulty.to_string().parse(), Ok(difficulty));
+        }
+        assert_eq!("HARD".parse(), Ok(Difficulty::Hard));
+        assert!("expert".parse::<Difficulty>().is_err());
+    }
+}
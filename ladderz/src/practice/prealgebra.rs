@@ -0,0 +1,818 @@
+use super::{parse_numbers, parse_yes_no, AnswerError, Difficulty, Rng};
+use crate::prealgebra::{
+    get_factor_pairs, get_factors, get_multiples_in_range, get_prime_factorization,
+    get_primes_in_range, is_composite, is_factor, is_multiple, is_prime,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// A pre-algebra concept that problems can be generated for, one per function in
+/// [`crate::prealgebra`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Concept {
+    FactorPairs,
+    Factors,
+    IsFactor,
+    IsMultiple,
+    MultiplesInRange,
+    IsPrime,
+    IsComposite,
+    PrimesInRange,
+    PrimeFactorization,
+}
+
+impl Concept {
+    /// Every concept, in the order of the functions in [`crate::prealgebra`].
+    pub const ALL: [Concept; 9] = [
+        Concept::FactorPairs,
+        Concept::Factors,
+        Concept::IsFactor,
+        Concept::IsMultiple,
+        Concept::MultiplesInRange,
+        Concept::IsPrime,
+        Concept::IsComposite,
+        Concept::PrimesInRange,
+        Concept::PrimeFactorization,
+    ];
+
+    /// Returns the concept's name, which matches its `lz prealgebra` command, e.g. `factor-pairs`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Concept::FactorPairs => "factor-pairs",
+            Concept::Factors => "factors",
+            Concept::IsFactor => "is-factor",
+            Concept::IsMultiple => "is-multiple",
+            Concept::MultiplesInRange => "multiples-in-range",
+            Concept::IsPrime => "is-prime",
+            Concept::IsComposite => "is-composite",
+            Concept::PrimesInRange => "primes-in-range",
+            Concept::PrimeFactorization => "prime-factorization",
+        }
+    }
+
+    /// Returns the name of the function in [`crate::prealgebra`] that solves the concept, e.g.
+    /// `get_prime_factorization`.
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            Concept::FactorPairs => "get_factor_pairs",
+            Concept::Factors => "get_factors",
+            Concept::IsFactor => "is_factor",
+            Concept::IsMultiple => "is_multiple",
+            Concept::MultiplesInRange => "get_multiples_in_range",
+            Concept::IsPrime => "is_prime",
+            Concept::IsComposite => "is_composite",
+            Concept::PrimesInRange => "get_primes_in_range",
+            Concept::PrimeFactorization => "get_prime_factorization",
+        }
+    }
+}
+
+impl fmt::Display for Concept {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Concept {
+    type Err = String;
+
+    /// Parses a concept by its name, e.g. `prime-factorization`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Concept::ALL
+            .into_iter()
+            .find(|concept| concept.name() == s.to_ascii_lowercase())
+            .ok_or_else(|| {
+                let names: Vec<_> = Concept::ALL.iter().map(Concept::name).collect();
+                format!(
+                    "unknown concept {s:?}, expected one of {}",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// The question a [`Problem`] asks, with the numbers it asks about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Question {
+    FactorPairs { n: u32 },
+    Factors { n: u32 },
+    IsFactor { x: u32, y: u32 },
+    IsMultiple { x: u32, y: u32 },
+    MultiplesInRange { n: u32, start: u32, end: u32 },
+    IsPrime { n: u32 },
+    IsComposite { n: u32 },
+    PrimesInRange { start: u32, end: u32 },
+    PrimeFactorization { n: u32 },
+}
+
+/// A generated practice problem that can check a student's answer with the solvers in
+/// [`crate::prealgebra`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::practice::prealgebra::{Problem, Question};
+/// use ladderz::practice::Difficulty;
+///
+/// let problem = Problem::new(Question::Factors { n: 84 }, Difficulty::Medium);
+/// assert_eq!(problem.prompt(), "Find all factors of 84.");
+/// assert_eq!(problem.answer(), "1, 2, 3, 4, 6, 7, 12, 14, 21, 28, 42, 84");
+/// assert_eq!(problem.check("{84, 42, 28, 21, 14, 12, 7, 6, 4, 3, 2, 1}"), Ok(true));
+/// assert_eq!(problem.check("1, 2, 3, 4, 6, 7, 12"), Ok(false));
+/// assert!(problem.check("one, two").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Problem {
+    pub question: Question,
+    pub difficulty: Difficulty,
+}
+
+impl Problem {
+    /// Creates a problem asking `question`.
+    pub fn new(question: Question, difficulty: Difficulty) -> Self {
+        Problem {
+            question,
+            difficulty,
+        }
+    }
+
+    /// Returns the concept the problem practices.
+    pub fn concept(&self) -> Concept {
+        match self.question {
+            Question::FactorPairs { .. } => Concept::FactorPairs,
+            Question::Factors { .. } => Concept::Factors,
+            Question::IsFactor { .. } => Concept::IsFactor,
+            Question::IsMultiple { .. } => Concept::IsMultiple,
+            Question::MultiplesInRange { .. } => Concept::MultiplesInRange,
+            Question::IsPrime { .. } => Concept::IsPrime,
+            Question::IsComposite { .. } => Concept::IsComposite,
+            Question::PrimesInRange { .. } => Concept::PrimesInRange,
+            Question::PrimeFactorization { .. } => Concept::PrimeFactorization,
+        }
+    }
+
+    /// Returns the problem as a question for the student.
+    pub fn prompt(&self) -> String {
+        match self.question {
+            Question::FactorPairs { n } => format!("Find all factor pairs of {n}."),
+            Question::Factors { n } => format!("Find all factors of {n}."),
+            Question::IsFactor { x, y } => format!("Is {x} a factor of {y}?"),
+            Question::IsMultiple { x, y } => format!("Is {x} a multiple of {y}?"),
+            Question::MultiplesInRange { n, start, end } => {
+                format!("Find all multiples of {n} from {start} to {end}.")
+            }
+            Question::IsPrime { n } => format!("Is {n} prime?"),
+            Question::IsComposite { n } => format!("Is {n} composite?"),
+            Question::PrimesInRange { start, end } => {
+                format!("Find all primes from {start} to {end}.")
+            }
+            Question::PrimeFactorization { n } => {
+                format!("Find the prime factorization of {n}.")
+            }
+        }
+    }
+
+    /// Returns the correct answer, as computed by the solvers in [`crate::prealgebra`].
+    pub fn answer(&self) -> String {
+        let yes_no = |answer: bool| if answer { "yes" } else { "no" }.to_owned();
+        match self.question {
+            Question::FactorPairs { n } => {
+                let pairs: Vec<String> = factor_pairs(n)
+                    .iter()
+                    .map(|(a, b)| format!("({a}, {b})"))
+                    .collect();
+                pairs.join(", ")
+            }
+            Question::Factors { n } => list(get_factors(n)),
+            Question::IsFactor { x, y } => yes_no(is_factor(x, y)),
+            Question::IsMultiple { x, y } => yes_no(is_multiple(x, y)),
+            Question::MultiplesInRange { n, start, end } => {
+                list(get_multiples_in_range(n, start, end))
+            }
+            Question::IsPrime { n } => yes_no(is_prime(n)),
+            Question::IsComposite { n } => yes_no(is_composite(n)),
+            Question::PrimesInRange { start, end } => list(get_primes_in_range(start, end)),
+            Question::PrimeFactorization { n } => {
+                let factorization: BTreeMap<_, _> =
+                    get_prime_factorization(n).into_iter().collect();
+                let powers: Vec<String> = factorization
+                    .iter()
+                    .map(|(prime, exponent)| match exponent {
+                        1 => prime.to_string(),
+                        _ => format!("{prime}^{exponent}"),
+                    })
+                    .collect();
+                powers.join(" × ")
+            }
+        }
+    }
+
+    /// Returns whether `answer` is correct, or an error if it can't be read.
+    ///
+    /// Lists of numbers may be in any order and separated by commas or spaces, with `none` for an
+    /// empty list. Yes or no questions accept `yes`, `no`, `y`, `n`, `true` and `false`. Prime
+    /// factorizations may use powers, like `2^3 × 3^2 × 5` (or with `*`), or list every prime,
+    /// like `2 2 2 3 3 5`.
+    pub fn check(&self, answer: &str) -> Result<bool, AnswerError> {
+        match self.question {
+            Question::FactorPairs { n } => {
+                let numbers = parse_list(answer)?;
+                if numbers.len() % 2 != 0 {
+                    return Err(AnswerError::new(
+                        "expected pairs of numbers, but found an odd count of numbers",
+                    ));
+                }
+                let pairs: BTreeSet<_> = numbers
+                    .chunks(2)
+                    .map(|pair| (pair[0].min(pair[1]), pair[0].max(pair[1])))
+                    .collect();
+                Ok(pairs == factor_pairs(n))
+            }
+            Question::Factors { n } => Ok(set(parse_list(answer)?) == set(get_factors(n))),
+            Question::IsFactor { x, y } => Ok(parse_yes_no(answer)? == is_factor(x, y)),
+            Question::IsMultiple { x, y } => Ok(parse_yes_no(answer)? == is_multiple(x, y)),
+            Question::MultiplesInRange { n, start, end } => {
+                Ok(set(parse_list(answer)?) == set(get_multiples_in_range(n, start, end)))
+            }
+            Question::IsPrime { n } => Ok(parse_yes_no(answer)? == is_prime(n)),
+            Question::IsComposite { n } => Ok(parse_yes_no(answer)? == is_composite(n)),
+            Question::PrimesInRange { start, end } => {
+                Ok(set(parse_list(answer)?) == set(get_primes_in_range(start, end)))
+            }
+            Question::PrimeFactorization { n } => {
+                Ok(parse_factorization(answer)? == get_prime_factorization(n))
+            }
+        }
+    }
+
+    /// Returns the steps of a worked solution, one sentence each, ending with the answer.
+    ///
+    /// Showing the steps one at a time gives hints that lead to the answer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ladderz::practice::prealgebra::{Problem, Question};
+    /// use ladderz::practice::Difficulty;
+    ///
+    /// let problem = Problem::new(Question::PrimeFactorization { n: 60 }, Difficulty::Easy);
+    /// assert_eq!(
+    ///     problem.steps(),
+    ///     [
+    ///         "Divide 60 by its smallest prime factor: 60 ÷ 2 = 30.",
+    ///         "Divide 30 by its smallest prime factor: 30 ÷ 2 = 15.",
+    ///         "Divide 15 by its smallest prime factor: 15 ÷ 3 = 5.",
+    ///         "Divide 5 by its smallest prime factor: 5 ÷ 5 = 1.",
+    ///         "The primes divided by are the prime factorization: 2^2 × 3 × 5.",
+    ///     ]
+    /// );
+    /// ```
+    pub fn steps(&self) -> Vec<String> {
+        let answer = self.answer();
+        let yes_no = |answer: bool| if answer { "yes" } else { "no" };
+        let mut steps = Vec::new();
+        match self.question {
+            Question::FactorPairs { n } | Question::Factors { n } => {
+                let root = n.isqrt();
+                steps.push(format!(
+                    "Divide {n} by each number from 1 to {root}, the largest number whose square \
+                     is at most {n}."
+                ));
+                for (a, b) in factor_pairs(n) {
+                    steps.push(format!(
+                        "{n} ÷ {a} = {b}, so {a} and {b} are factors of {n}."
+                    ));
+                }
+                steps.push(match self.question {
+                    Question::FactorPairs { .. } => format!("The factor pairs are {answer}."),
+                    _ => format!("The factors are {answer}."),
+                });
+            }
+            Question::IsFactor { x, y } | Question::IsMultiple { x: y, y: x } => {
+                steps.push(format!(
+                    "Divide {y} by {x}: {y} ÷ {x} = {} remainder {}.",
+                    y / x,
+                    y % x
+                ));
+                let (remainder, is) = match y % x {
+                    0 => ("is 0", "is"),
+                    _ => ("isn't 0", "isn't"),
+                };
+                steps.push(match self.question {
+                    Question::IsFactor { .. } => format!(
+                        "The remainder {remainder}, so {x} {is} a factor of {y} and the answer \
+                         is {answer}."
+                    ),
+                    _ => format!(
+                        "The remainder {remainder}, so {y} {is} a multiple of {x} and the answer \
+                         is {answer}."
+                    ),
+                });
+            }
+            Question::MultiplesInRange { n, start, end } => {
+                let multiples: Vec<u32> = set(get_multiples_in_range(n, start, end))
+                    .into_iter()
+                    .collect();
+                if let Some(first) = multiples.first() {
+                    steps.push(format!(
+                        "The smallest multiple of {n} that is at least {start} is {} × {n} = \
+                         {first}.",
+                        first / n
+                    ));
+                    steps.push(format!("Keep adding {n} while the total is at most {end}."));
+                }
+                steps.push(format!("The multiples are {answer}."));
+            }
+            Question::IsPrime { n } | Question::IsComposite { n } => {
+                let is_prime = is_prime(n);
+                if n < 2 {
+                    steps.push(format!(
+                        "{n} is less than 2, so it's neither prime nor composite."
+                    ));
+                } else {
+                    let root = n.isqrt();
+                    steps.push(format!(
+                        "Check whether any number from 2 to {root}, the largest number whose \
+                         square is at most {n}, divides {n}."
+                    ));
+                    steps.push(match smallest_divisor(n) {
+                        Some(divisor) => format!(
+                            "{n} ÷ {divisor} = {}, so {n} has a factor other than 1 and itself, \
+                             which makes it composite.",
+                            n / divisor
+                        ),
+                        None => format!(
+                            "No number from 2 to {root} divides {n}, so its only factors are 1 \
+                             and itself, which makes it prime."
+                        ),
+                    });
+                }
+                let composite = n >= 2 && !is_prime;
+                steps.push(match self.question {
+                    Question::IsPrime { .. } => {
+                        format!(
+                            "So the answer to whether {n} is prime is {}.",
+                            yes_no(is_prime)
+                        )
+                    }
+                    _ => format!(
+                        "So the answer to whether {n} is composite is {}.",
+                        yes_no(composite)
+                    ),
+                });
+            }
+            Question::PrimesInRange { start, end } => {
+                if start < 2 {
+                    steps.push("Skip any numbers less than 2, which aren't prime.".to_owned());
+                }
+                let crossed: Vec<String> = (start.max(2)..=end)
+                    .filter_map(|n| {
+                        smallest_divisor(n)
+                            .map(|divisor| format!("{n} = {divisor} × {}", n / divisor))
+                    })
+                    .collect();
+                if !crossed.is_empty() {
+                    steps.push(format!(
+                        "Cross out each number with a factor other than 1 and itself: {}.",
+                        crossed.join(", ")
+                    ));
+                }
+                steps.push(format!("The numbers left are the primes: {answer}."));
+            }
+            Question::PrimeFactorization { n } => {
+                let mut rest = n;
+                while let Some(prime) = smallest_divisor(rest).or((rest > 1).then_some(rest)) {
+                    steps.push(format!(
+                        "Divide {rest} by its smallest prime factor: {rest} ÷ {prime} = {}.",
+                        rest / prime
+                    ));
+                    rest /= prime;
+                }
+                steps.push(match n < 2 {
+                    true => format!("{n} has no prime factors."),
+                    false => {
+                        format!("The primes divided by are the prime factorization: {answer}.")
+                    }
+                });
+            }
+        }
+        steps
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.prompt())
+    }
+}
+
+/// Returns the factor pairs of `n` with the smaller factor first, in order.
+fn factor_pairs(n: u32) -> BTreeSet<(u32, u32)> {
+    get_factor_pairs(n)
+        .into_iter()
+        .map(|(a, b)| (a.min(b), a.max(b)))
+        .collect()
+}
+
+/// Returns the smallest factor of `n` from 2 to its square root, if any.
+fn smallest_divisor(n: u32) -> Option<u32> {
+    (2..=n.isqrt()).find(|divisor| n % divisor == 0)
+}
+
+fn set(numbers: impl IntoIterator<Item = u32>) -> BTreeSet<u32> {
+    numbers.into_iter().collect()
+}
+
+/// Writes numbers in ascending order separated by commas, or `none` if there are none.
+fn list(numbers: impl IntoIterator<Item = u32>) -> String {
+    let numbers: Vec<String> = set(numbers).iter().map(u32::to_string).collect();
+    match numbers.is_empty() {
+        true => "none".to_owned(),
+        false => numbers.join(", "),
+    }
+}
+
+/// Reads a list of numbers, where `none` is an empty list.
+fn parse_list(answer: &str) -> Result<Vec<u32>, AnswerError> {
+    match answer.trim().eq_ignore_ascii_case("none") {
+        true => Ok(Vec::new()),
+        false => parse_numbers(answer),
+    }
+}
+
+/// Reads a prime factorization like `2^3 × 3^2 × 5`, `2^3 * 3^2 * 5` or `2 2 2 3 3 5` as a map
+/// from each factor to its exponent.
+fn parse_factorization(answer: &str) -> Result<HashMap<u32, u32>, AnswerError> {
+    let normalized = answer
+        .replace(['×', '*', '·', ','], " ")
+        .replace(" ^", "^")
+        .replace("^ ", "^");
+    let mut factorization = HashMap::new();
+    for term in normalized.split_whitespace() {
+        let (base, exponent) = term.split_once('^').unwrap_or((term, "1"));
+        let number = |digits: &str| {
+            digits.parse::<u32>().map_err(|_| {
+                AnswerError::new(format!(
+                    "expected a factor like 5 or 2^3, but found {term:?}"
+                ))
+            })
+        };
+        let (base, exponent) = (number(base)?, number(exponent)?);
+        if base > 1 && exponent > 0 {
+            *factorization.entry(base).or_insert(0) += exponent;
+        }
+    }
+    Ok(factorization)
+}
+
+/// The range of numbers a problem of `difficulty` asks about.
+fn number_range(difficulty: Difficulty) -> RangeInclusive<u32> {
+    match difficulty {
+        Difficulty::Easy => 2..=30,
+        Difficulty::Medium => 31..=200,
+        Difficulty::Hard => 201..=2000,
+    }
+}
+
+/// The range of divisors a problem of `difficulty` asks about.
+fn divisor_range(difficulty: Difficulty) -> RangeInclusive<u32> {
+    match difficulty {
+        Difficulty::Easy => 2..=10,
+        Difficulty::Medium => 3..=20,
+        Difficulty::Hard => 7..=50,
+    }
+}
+
+/// Returns the smallest prime at least `n`.
+fn next_prime(n: u32) -> u32 {
+    (n..).find(|&candidate| is_prime(candidate)).unwrap()
+}
+
+/// Returns a random composite number in `numbers`. Above easy `difficulty`, the number has no
+/// factor of 2, 3 or 5, so it takes more than a glance to tell it isn't prime (like 91).
+fn composite(difficulty: Difficulty, numbers: RangeInclusive<u32>, rng: &mut Rng) -> u32 {
+    loop {
+        let n = rng.range(numbers.clone());
+        let obvious = [2, 3, 5].iter().any(|p| n % p == 0);
+        if is_composite(n) && (difficulty == Difficulty::Easy || !obvious) {
+            return n;
+        }
+    }
+}
+
+/// Generates random problems for one concept at one difficulty.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::practice::prealgebra::{Concept, Generator};
+/// use ladderz::practice::{Difficulty, Rng};
+///
+/// let generator = Generator::new(Concept::IsPrime, Difficulty::Hard);
+/// let problem = generator.generate(&mut Rng::new(42));
+/// assert_eq!(problem.difficulty, Difficulty::Hard);
+/// assert!(problem.prompt().starts_with("Is "));
+/// assert_eq!(problem.check(&problem.answer()), Ok(true));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Generator {
+    pub concept: Concept,
+    pub difficulty: Difficulty,
+}
+
+impl Generator {
+    /// Creates a generator of `concept` problems at `difficulty`.
+    pub fn new(concept: Concept, difficulty: Difficulty) -> Self {
+        Generator {
+            concept,
+            difficulty,
+        }
+    }
+
+    /// Generates a problem using `rng`.
+    pub fn generate(&self, rng: &mut Rng) -> Problem {
+        let difficulty = self.difficulty;
+        let numbers = number_range(difficulty);
+        let question = match self.concept {
+            Concept::FactorPairs => Question::FactorPairs {
+                n: composite(Difficulty::Easy, numbers, rng),
+            },
+            Concept::Factors => Question::Factors {
+                n: composite(Difficulty::Easy, numbers, rng),
+            },
+            Concept::IsFactor | Concept::IsMultiple => {
+                let divisor = rng.range(divisor_range(difficulty));
+                // Half of the time the answer is yes.
+                let multiple = match rng.coin_flip() {
+                    true => divisor * rng.range(2..=numbers.end() / divisor),
+                    false => rng.range(numbers.clone()),
+                };
+                match self.concept {
+                    Concept::IsFactor => Question::IsFactor {
+                        x: divisor,
+                        y: multiple,
+                    },
+                    _ => Question::IsMultiple {
+                        x: multiple,
+                        y: divisor,
+                    },
+                }
+            }
+            Concept::MultiplesInRange => {
+                let width = match difficulty {
+                    Difficulty::Easy => 20,
+                    Difficulty::Medium => 60,
+                    Difficulty::Hard => 150,
+                };
+                let start = rng.range(1..=numbers.end() - width);
+                Question::MultiplesInRange {
+                    n: rng.range(divisor_range(difficulty)),
+                    start,
+                    end: start + width,
+                }
+            }
+            Concept::IsPrime | Concept::IsComposite => {
+                let n = match rng.coin_flip() {
+                    true => next_prime(rng.range(numbers)),
+                    false => composite(difficulty, numbers, rng),
+                };
+                match self.concept {
+                    Concept::IsPrime => Question::IsPrime { n },
+                    _ => Question::IsComposite { n },
+                }
+            }
+            Concept::PrimesInRange => {
+                let width = match difficulty {
+                    Difficulty::Easy => 10,
+                    Difficulty::Medium => 20,
+                    Difficulty::Hard => 30,
+                };
+                let start = rng.range(1..=numbers.end() - width);
+                Question::PrimesInRange {
+                    start,
+                    end: start + width,
+                }
+            }
+            Concept::PrimeFactorization => {
+                let (primes, count, limit): (&[u32], _, _) = match difficulty {
+                    Difficulty::Easy => (&[2, 3, 5, 7], 2..=3, 100),
+                    Difficulty::Medium => (&[2, 3, 5, 7, 11, 13], 3..=5, 1_000),
+                    Difficulty::Hard => (&[2, 3, 5, 7, 11, 13, 17, 19, 23], 4..=6, 10_000),
+                };
+                let mut n = 1;
+                for _ in 0..rng.range(count) {
+                    let prime = *rng.choose(primes);
+                    if n * prime <= limit {
+                        n *= prime;
+                    }
+                }
+                Question::PrimeFactorization { n: n.max(4) }
+            }
+        };
+        Problem::new(question, difficulty)
+    }
+}
+
+/// Generates `count` problems from `seed`, cycling through `concepts` (or every concept if it's
+/// empty) in a shuffled order. Without a `difficulty`, each problem gets a random one.
+///
+/// The same arguments always generate the same problems.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::practice::prealgebra::{generate_problems, Concept};
+/// use ladderz::practice::Difficulty;
+///
+/// let problems = generate_problems(9, 42, Some(Difficulty::Easy), &[]);
+/// assert_eq!(problems.len(), 9);
+/// // Every concept appears once before any repeats.
+/// for concept in Concept::ALL {
+///     assert!(problems.iter().any(|problem| problem.concept() == concept));
+/// }
+/// assert_eq!(problems, generate_problems(9, 42, Some(Difficulty::Easy), &[]));
+/// ```
+pub fn generate_problems(
+    count: usize,
+    seed: u64,
+    difficulty: Option<Difficulty>,
+    concepts: &[Concept],
+) -> Vec<Problem> {
+    let mut rng = Rng::new(seed);
+    let mut order: Vec<Concept> = match concepts.is_empty() {
+        true => Concept::ALL.to_vec(),
+        false => concepts.to_vec(),
+    };
+    let mut problems = Vec::with_capacity(count);
+    while problems.len() < count {
+        rng.shuffle(&mut order);
+        for &concept in order.iter().take(count - problems.len()) {
+            let difficulty = difficulty.unwrap_or_else(|| *rng.choose(&Difficulty::ALL));
+            problems.push(Generator::new(concept, difficulty).generate(&mut rng));
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_answers_check() {
+        for seed in 0..50 {
+            for difficulty in Difficulty::ALL {
+                for concept in Concept::ALL {
+                    let problem = Generator::new(concept, difficulty).generate(&mut Rng::new(seed));
+                    assert_eq!(problem.concept(), concept);
+                    assert_eq!(problem.check(&problem.answer()), Ok(true), "{problem}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_difficulty_sets_number_size() {
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            let easy = Generator::new(Concept::IsPrime, Difficulty::Easy).generate(&mut rng);
+            let hard = Generator::new(Concept::IsPrime, Difficulty::Hard).generate(&mut rng);
+            match (easy.question, hard.question) {
+                (Question::IsPrime { n: easy }, Question::IsPrime { n: hard }) => {
+                    assert!(easy <= 31 && hard > 200, "{easy} {hard}")
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_check_answers() {
+        let pairs = Problem::new(Question::FactorPairs { n: 12 }, Difficulty::Easy);
+        assert_eq!(pairs.answer(), "(1, 12), (2, 6), (3, 4)");
+        assert_eq!(pairs.check("(4, 3), (12, 1), (2, 6)"), Ok(true));
+        assert_eq!(pairs.check("(1, 12), (2, 6)"), Ok(false));
+        assert!(pairs.check("(1, 12), (2)").is_err());
+
+        let prime = Problem::new(Question::IsPrime { n: 91 }, Difficulty::Medium);
+        assert_eq!(prime.answer(), "no");
+        assert_eq!(prime.check("No"), Ok(true));
+        assert_eq!(prime.check("yes"), Ok(false));
+
+        let factorization = Problem::new(Question::PrimeFactorization { n: 360 }, Difficulty::Hard);
+        assert_eq!(factorization.answer(), "2^3 × 3^2 × 5");
+        assert_eq!(factorization.check("2^3 * 3 ^ 2 * 5"), Ok(true));
+        assert_eq!(factorization.check("2 2 2 3 3 5"), Ok(true));
+        assert_eq!(factorization.check("5 × 3^2 × 2^3"), Ok(true));
+        assert_eq!(factorization.check("2^3 × 45"), Ok(false));
+        assert!(factorization.check("2^x").is_err());
+
+        let primes = Problem::new(
+            Question::PrimesInRange { start: 24, end: 28 },
+            Difficulty::Easy,
+        );
+        assert_eq!(primes.answer(), "none");
+        assert_eq!(primes.check("None"), Ok(true));
+        assert_eq!(primes.check(""), Ok(true));
+    }
+
+    #[test]
+    fn test_concept_names() {
+        for concept in Concept::ALL {
+            assert_eq!(concept.name().parse(), Ok(concept));
+        }
+        assert!("factoring".parse::<Concept>().is_err());
+        assert_eq!(
+            Concept::PrimeFactorization.function_name(),
+            "get_prime_factorization"
+        );
+    }
+
+    #[test]
+    fn test_steps_end_with_answer() {
+        for seed in 0..20 {
+            for concept in Concept::ALL {
+                let problem =
+                    Generator::new(concept, Difficulty::Medium).generate(&mut Rng::new(seed));
+                let steps = problem.steps();
+                assert!(steps.len() >= 2, "{problem}");
+                assert!(
+                    steps.last().unwrap().contains(&problem.answer()),
+                    "{problem}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_steps() {
+        let factors = Problem::new(Question::Factors { n: 12 }, Difficulty::Easy);
+        assert_eq!(
+            factors.steps(),
+            [
+                "Divide 12 by each number from 1 to 3, the largest number whose square is at most 12.",
+                "12 ÷ 1 = 12, so 1 and 12 are factors of 12.",
+                "12 ÷ 2 = 6, so 2 and 6 are factors of 12.",
+                "12 ÷ 3 = 4, so 3 and 4 are factors of 12.",
+                "The factors are 1, 2, 3, 4, 6, 12.",
+            ]
+        );
+
+        let multiple = Problem::new(Question::IsMultiple { x: 20, y: 6 }, Difficulty::Easy);
+        assert_eq!(
+            multiple.steps(),
+            [
+                "Divide 20 by 6: 20 ÷ 6 = 3 remainder 2.",
+                "The remainder isn't 0, so 20 isn't a multiple of 6 and the answer is no.",
+            ]
+        );
+
+        let composite = Problem::new(Question::IsComposite { n: 91 }, Difficulty::Medium);
+        assert_eq!(
+            composite.steps()[1],
+            "91 ÷ 7 = 13, so 91 has a factor other than 1 and itself, which makes it composite."
+        );
+
+        let primes = Problem::new(
+            Question::PrimesInRange { start: 1, end: 10 },
+            Difficulty::Easy,
+        );
+        assert_eq!(
+            primes.steps(),
+            [
+                "Skip any numbers less than 2, which aren't prime.",
+                "Cross out each number with a factor other than 1 and itself: 4 = 2 × 2, \
+                 6 = 2 × 3, 8 = 2 × 4, 9 = 3 × 3, 10 = 2 × 5.",
+                "The numbers left are the primes: 2, 3, 5, 7.",
+            ]
+        );
+
+        let multiples = Problem::new(
+            Question::MultiplesInRange {
+                n: 7,
+                start: 10,
+                end: 30,
+            },
+            Difficulty::Easy,
+        );
+        assert_eq!(
+            multiples.steps()[0],
+            "The smallest multiple of 7 that is at least 10 is 2 × 7 = 14."
+        );
+    }
+
+    #[test]
+    fn test_generate_problems_with_concepts() {
+        let problems = generate_problems(5, 1, None, &[Concept::IsPrime, Concept::Factors]);
+        assert_eq!(problems.len(), 5);
+        assert!(problems
+            .iter()
+            .all(|problem| matches!(problem.concept(), Concept::IsPrime | Concept::Factors)));
+        assert_ne!(
+            problems,
+            generate_problems(5, 2, None, &[Concept::IsPrime, Concept::Factors])
+        );
+    }
+}
@@ -1,3 +1,17 @@
+/// Number base conversion and other representations of numbers.
+pub mod bases;
+
+/// Data-parallel versions of the range functions, built with the `parallel` feature.
+#[cfg(feature = "parallel")]
+pub mod parallel;
+
+pub use bases::{
+    convert_base, fraction_to_base, from_balanced_ternary, from_base, from_roman,
+    from_twos_complement, parse_int_literal, to_balanced_ternary, to_base, to_roman,
+    to_scientific_notation, to_twos_complement, twos_complement_range, BaseError, BaseExpansion,
+    ScientificNotation, MAX_FRACTION_DIGITS,
+};
+
 use std::collections::{HashMap, HashSet};
 
 /// Finds all factor pairs for a positive integer `n`.
@@ -222,6 +236,95 @@ pub fn get_primes_in_range(start: u32, end: u32) -> HashSet<u32> {
     primes
 }
 
+/// How many numbers [`sieve_primes_in_range`] crosses out at a time, small enough for a segment
+/// to stay in the CPU cache.
+const SEGMENT_SIZE: u32 = 1 << 15;
+
+/// Returns the primes up to and including `limit` with the sieve of Eratosthenes.
+fn base_primes(limit: u32) -> Vec<u32> {
+    let limit = limit as usize;
+    let mut composite = vec![false; limit + 1];
+    let mut primes = Vec::new();
+    for n in 2..=limit {
+        if composite[n] {
+            continue;
+        }
+        primes.push(n as u32);
+        for multiple in (n * n..=limit).step_by(n) {
+            composite[multiple] = true;
+        }
+    }
+    primes
+}
+
+/// Splits `[start, end]` into the segments of at most [`SEGMENT_SIZE`] numbers that are sieved
+/// one at a time.
+fn segments(start: u32, end: u32) -> impl Iterator<Item = (u32, u32)> {
+    (start as u64..=end as u64)
+        .step_by(SEGMENT_SIZE as usize)
+        .map(move |low| {
+            (
+                low as u32,
+                (low + SEGMENT_SIZE as u64 - 1).min(end as u64) as u32,
+            )
+        })
+}
+
+/// Returns the primes in the segment `[low, high]` by crossing out the multiples of each of
+/// `base`, which must hold every prime up to the square root of `high`.
+fn sieve_segment(low: u32, high: u32, base: &[u32]) -> Vec<u32> {
+    let (low, high) = (low.max(2) as u64, high as u64);
+    if low > high {
+        return Vec::new();
+    }
+    let mut composite = vec![false; (high - low + 1) as usize];
+    for &prime in base {
+        let prime = prime as u64;
+        if prime * prime > high {
+            break;
+        }
+        // Smaller multiples were crossed out by smaller primes.
+        let first = (prime * prime).max(low.div_ceil(prime) * prime);
+        for multiple in (first..=high).step_by(prime as usize) {
+            composite[(multiple - low) as usize] = true;
+        }
+    }
+    (low..=high)
+        .filter(|&n| !composite[(n - low) as usize])
+        .map(|n| n as u32)
+        .collect()
+}
+
+/// Returns all prime numbers in the range [start, end] with a segmented sieve of Eratosthenes.
+///
+/// This finds the same primes as [`get_primes_in_range`] for ranges starting at 1 or more, but
+/// crosses out multiples instead of testing each number, so it's much faster for large ranges.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::sieve_primes_in_range;
+/// use std::collections::HashSet;
+///
+/// let result: HashSet<u32> = sieve_primes_in_range(2, 10);
+/// let expected: HashSet<u32> = [2, 3, 5, 7].into();
+/// assert_eq!(result, expected);
+/// ```
+///
+/// # Note
+///
+/// The primes up to the square root of `end` are found first, and then the range is sieved in
+/// segments of 32,768 numbers so memory use stays small however large the range is.
+pub fn sieve_primes_in_range(start: u32, end: u32) -> HashSet<u32> {
+    if start > end {
+        return HashSet::new();
+    }
+    let base = base_primes(end.isqrt());
+    segments(start, end)
+        .flat_map(|(low, high)| sieve_segment(low, high, &base))
+        .collect()
+}
+
 /// Returns the prime factorization of a positive integer `n`.
 ///
 /// For example the prime factorization of 12 is 2<sup>2</sup> * 3<sup>1</sup>, and the output is a HashMap of the form
@@ -261,6 +364,7 @@ pub fn get_prime_factorization(n: u32) -> HashMap<u32, u32> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_get_factor_pairs() {
@@ -362,6 +466,23 @@ mod tests {
         assert_eq!(result_4, expected_4);
     }
 
+    #[test]
+    fn test_sieve_primes_in_range() {
+        let result: HashSet<u32> = sieve_primes_in_range(1, 50);
+        assert_eq!(result, get_primes_in_range(1, 50));
+
+        // Crosses the boundary between the first two segments.
+        let result_2: HashSet<u32> = sieve_primes_in_range(32_000, 33_500);
+        assert_eq!(result_2, get_primes_in_range(32_000, 33_500));
+
+        let result_3: HashSet<u32> = sieve_primes_in_range(u32::MAX - 10, u32::MAX);
+        let expected_3: HashSet<u32> = [4_294_967_291].into();
+        assert_eq!(result_3, expected_3);
+
+        let result_4: HashSet<u32> = sieve_primes_in_range(10, 1);
+        assert_eq!(result_4, HashSet::new());
+    }
+
     #[test]
     fn test_get_prime_factorization() {
         let result: HashMap<u32, u32> = get_prime_factorization(1);
@@ -384,4 +505,50 @@ mod tests {
         let expected_5: HashMap<u32, u32> = [(3, 1), (17, 1)].into();
         assert_eq!(result_5, expected_5);
     }
+
+    // Properties that hold for every input, checked on random inputs by proptest. The bounds
+    // keep the trial division in `is_prime` fast enough for the unoptimized test build.
+    proptest! {
+        #[test]
+        fn prop_prime_factorization_multiplies_to_n(n in 1u32..2_000) {
+            let factorization = get_prime_factorization(n);
+            let product: u32 = factorization
+                .iter()
+                .map(|(&prime, &exponent)| prime.pow(exponent))
+                .product();
+            prop_assert_eq!(product, n);
+            prop_assert!(factorization.keys().all(|&prime| is_prime(prime)));
+        }
+
+        #[test]
+        fn prop_factors_divide_n(n in 1u32..20_000) {
+            let factors = get_factors(n);
+            prop_assert!(factors.iter().all(|&factor| n % factor == 0));
+            let paired: HashSet<u32> =
+                get_factor_pairs(n).into_iter().flat_map(|(a, b)| [a, b]).collect();
+            prop_assert_eq!(factors, paired);
+        }
+
+        #[test]
+        fn prop_composite_is_not_prime(n in 1u32..20_000) {
+            prop_assert_eq!(is_composite(n), !is_prime(n) && n > 1);
+        }
+
+        #[test]
+        fn prop_multiples_in_range(n in 1u32..100, start in 0u32..1_000, length in 0u32..1_000) {
+            let end = start + length;
+            let multiples = get_multiples_in_range(n, start, end);
+            let expected: HashSet<u32> = (start..=end).filter(|num| num % n == 0).collect();
+            prop_assert_eq!(multiples, expected);
+        }
+
+        #[test]
+        fn prop_sieve_matches_trial_division(start in 1u32..3_000, length in 0u32..3_000) {
+            let end = start + length;
+            prop_assert_eq!(
+                sieve_primes_in_range(start, end),
+                get_primes_in_range(start, end)
+            );
+        }
+    }
 }
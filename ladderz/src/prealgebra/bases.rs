@@ -0,0 +1,625 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+const DIGITS: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// An error from converting a number between bases or representations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaseError {
+    /// A base outside 2 to 36.
+    InvalidBase(u32),
+    /// A digit that isn't valid in the base.
+    InvalidDigit { digit: char, base: u32 },
+    /// An empty number.
+    Empty,
+    /// A number that doesn't fit in the result type.
+    Overflow,
+    /// A number that can't be represented, e.g. 0 in Roman numerals.
+    OutOfRange(String),
+    /// A string that isn't a valid Roman numeral in standard form.
+    InvalidRoman(String),
+    /// A fraction with a denominator of 0.
+    ZeroDenominator,
+}
+
+impl fmt::Display for BaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaseError::InvalidBase(base) => write!(f, "base {base} is not between 2 and 36"),
+            BaseError::InvalidDigit { digit, base } => {
+                write!(f, "{digit:?} is not a digit in base {base}")
+            }
+            BaseError::Empty => write!(f, "the number is empty"),
+            BaseError::Overflow => write!(f, "the number is too large"),
+            BaseError::OutOfRange(message) => write!(f, "{message}"),
+            BaseError::InvalidRoman(numeral) => {
+                write!(f, "{numeral:?} is not a valid Roman numeral")
+            }
+            BaseError::ZeroDenominator => write!(f, "the denominator must not be 0"),
+        }
+    }
+}
+
+impl Error for BaseError {}
+
+fn check_base(base: u32) -> Result<(), BaseError> {
+    match (2..=36).contains(&base) {
+        true => Ok(()),
+        false => Err(BaseError::InvalidBase(base)),
+    }
+}
+
+fn digit_value(digit: char, base: u32) -> Result<u32, BaseError> {
+    digit
+        .to_digit(36)
+        .filter(|&d| d < base)
+        .ok_or(BaseError::InvalidDigit { digit, base })
+}
+
+/// Writes an integer in any base from 2 to 36, using the digits `0-9` then `A-Z`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::to_base;
+///
+/// assert_eq!(to_base(255, 16).unwrap(), "FF");
+/// assert_eq!(to_base(-10, 2).unwrap(), "-1010");
+/// assert_eq!(to_base(35, 36).unwrap(), "Z");
+/// assert!(to_base(10, 1).is_err());
+/// ```
+pub fn to_base(n: i64, base: u32) -> Result<String, BaseError> {
+    check_base(base)?;
+    let mut magnitude = n.unsigned_abs();
+    if magnitude == 0 {
+        return Ok("0".to_owned());
+    }
+    let mut digits = Vec::new();
+    while magnitude > 0 {
+        digits.push(DIGITS[(magnitude % base as u64) as usize]);
+        magnitude /= base as u64;
+    }
+    if n < 0 {
+        digits.push(b'-');
+    }
+    digits.reverse();
+    Ok(String::from_utf8(digits).expect("digits are ASCII"))
+}
+
+/// Reads an integer written in any base from 2 to 36. Digits are case-insensitive, and a
+/// leading `-` makes the number negative.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::from_base;
+///
+/// assert_eq!(from_base("ff", 16).unwrap(), 255);
+/// assert_eq!(from_base("-1010", 2).unwrap(), -10);
+/// assert!(from_base("12", 2).is_err());
+/// ```
+pub fn from_base(s: &str, base: u32) -> Result<i64, BaseError> {
+    check_base(base)?;
+    let (negative, digits) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    if digits.is_empty() {
+        return Err(BaseError::Empty);
+    }
+    let mut magnitude: i128 = 0;
+    for digit in digits.chars() {
+        magnitude = magnitude * base as i128 + digit_value(digit, base)? as i128;
+        if magnitude > i64::MAX as i128 + 1 {
+            return Err(BaseError::Overflow);
+        }
+    }
+    let value = if negative { -magnitude } else { magnitude };
+    i64::try_from(value).map_err(|_| BaseError::Overflow)
+}
+
+/// Converts an integer written in base `from` to base `to`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::convert_base;
+///
+/// assert_eq!(convert_base("377", 8, 2).unwrap(), "11111111");
+/// ```
+pub fn convert_base(s: &str, from: u32, to: u32) -> Result<String, BaseError> {
+    to_base(from_base(s, from)?, to)
+}
+
+/// Reads an integer literal in decimal, or in hexadecimal, octal or binary with a `0x`, `0o` or
+/// `0b` prefix. A leading `-` and `_` digit separators are allowed.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::parse_int_literal;
+///
+/// assert_eq!(parse_int_literal("0xff").unwrap(), 255);
+/// assert_eq!(parse_int_literal("0o17").unwrap(), 15);
+/// assert_eq!(parse_int_literal("-0b1_0000").unwrap(), -16);
+/// assert_eq!(parse_int_literal("42").unwrap(), 42);
+/// assert!(parse_int_literal("0xfg").is_err());
+/// ```
+pub fn parse_int_literal(s: &str) -> Result<i64, BaseError> {
+    let s = s.trim().replace('_', "");
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.as_str()),
+    };
+    let lower = rest.to_ascii_lowercase();
+    let (base, digits) = match lower.get(..2) {
+        Some("0x") => (16, &rest[2..]),
+        Some("0o") => (8, &rest[2..]),
+        Some("0b") => (2, &rest[2..]),
+        _ => (10, rest),
+    };
+    if digits.starts_with('-') {
+        return Err(BaseError::InvalidDigit { digit: '-', base });
+    }
+    let sign = if negative { "-" } else { "" };
+    from_base(&format!("{sign}{digits}"), base)
+}
+
+/// Writes an integer in balanced ternary, where each digit is -1, 0 or 1, written `T`, `0` and
+/// `1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::to_balanced_ternary;
+///
+/// // 8 = 9 + 0 - 1
+/// assert_eq!(to_balanced_ternary(8), "10T");
+/// assert_eq!(to_balanced_ternary(-8), "T01");
+/// assert_eq!(to_balanced_ternary(0), "0");
+/// ```
+pub fn to_balanced_ternary(n: i64) -> String {
+    if n == 0 {
+        return "0".to_owned();
+    }
+    let mut n = n as i128;
+    let mut digits = Vec::new();
+    while n != 0 {
+        match n.rem_euclid(3) {
+            0 => digits.push('0'),
+            1 => {
+                digits.push('1');
+                n -= 1;
+            }
+            _ => {
+                digits.push('T');
+                n += 1;
+            }
+        }
+        n /= 3;
+    }
+    digits.iter().rev().collect()
+}
+
+/// Reads a balanced ternary number written with the digits `T` (or `-`), `0` and `1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::from_balanced_ternary;
+///
+/// assert_eq!(from_balanced_ternary("10T").unwrap(), 8);
+/// assert_eq!(from_balanced_ternary("-01").unwrap(), -8);
+/// assert!(from_balanced_ternary("102").is_err());
+/// ```
+pub fn from_balanced_ternary(s: &str) -> Result<i64, BaseError> {
+    if s.is_empty() {
+        return Err(BaseError::Empty);
+    }
+    let mut value: i128 = 0;
+    for digit in s.chars() {
+        let d = match digit {
+            'T' | 't' | '-' => -1,
+            '0' => 0,
+            '1' => 1,
+            _ => return Err(BaseError::InvalidDigit { digit, base: 3 }),
+        };
+        value = value * 3 + d;
+        if value.abs() > i64::MAX as i128 {
+            return Err(BaseError::Overflow);
+        }
+    }
+    Ok(value as i64)
+}
+
+const ROMAN: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Writes a number from 1 to 3999 in Roman numerals.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::to_roman;
+///
+/// assert_eq!(to_roman(1994).unwrap(), "MCMXCIV");
+/// assert!(to_roman(0).is_err());
+/// assert!(to_roman(4000).is_err());
+/// ```
+pub fn to_roman(n: u32) -> Result<String, BaseError> {
+    if !(1..=3999).contains(&n) {
+        return Err(BaseError::OutOfRange(format!(
+            "{n} can't be written in Roman numerals, which range from 1 to 3999"
+        )));
+    }
+    let mut remaining = n;
+    let mut numeral = String::new();
+    for (value, symbol) in ROMAN {
+        while remaining >= value {
+            numeral.push_str(symbol);
+            remaining -= value;
+        }
+    }
+    Ok(numeral)
+}
+
+/// Reads a Roman numeral in standard form, such as `MCMXCIV`. Letters are case-insensitive, but
+/// non-standard forms like `IIII` or `IC` are rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::from_roman;
+///
+/// assert_eq!(from_roman("mcmxciv").unwrap(), 1994);
+/// assert!(from_roman("IC").is_err());
+/// ```
+pub fn from_roman(s: &str) -> Result<u32, BaseError> {
+    let upper = s.to_ascii_uppercase();
+    let invalid = || BaseError::InvalidRoman(s.to_owned());
+    let mut rest = upper.as_str();
+    let mut value = 0;
+    for (amount, symbol) in ROMAN {
+        while let Some(after) = rest.strip_prefix(symbol) {
+            value += amount;
+            rest = after;
+        }
+    }
+    if !rest.is_empty() || value == 0 {
+        return Err(invalid());
+    }
+    // Greedy parsing accepts forms like "IIII" and "VV", so only keep canonical numerals.
+    match to_roman(value) {
+        Ok(canonical) if canonical == upper => Ok(value),
+        _ => Err(invalid()),
+    }
+}
+
+/// A number written as `coefficient × 10^exponent`, where `1 <= |coefficient| < 10`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScientificNotation {
+    pub coefficient: f64,
+    pub exponent: i32,
+}
+
+impl ScientificNotation {
+    /// Returns the number the notation represents.
+    pub fn value(&self) -> f64 {
+        self.coefficient * 10f64.powi(self.exponent)
+    }
+}
+
+impl fmt::Display for ScientificNotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} × 10^{}", self.coefficient, self.exponent)
+    }
+}
+
+/// Writes `x` in scientific notation with the coefficient rounded to `significant_digits` digits.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::to_scientific_notation;
+///
+/// assert_eq!(to_scientific_notation(12345.0, 3).to_string(), "1.23 × 10^4");
+/// assert_eq!(to_scientific_notation(-0.00098765, 2).to_string(), "-9.9 × 10^-4");
+/// assert_eq!(to_scientific_notation(9.999, 2).to_string(), "1 × 10^1");
+/// assert_eq!(to_scientific_notation(0.0, 3).to_string(), "0 × 10^0");
+/// ```
+pub fn to_scientific_notation(x: f64, significant_digits: u32) -> ScientificNotation {
+    if x == 0.0 || !x.is_finite() {
+        return ScientificNotation {
+            coefficient: x,
+            exponent: 0,
+        };
+    }
+    let mut exponent = x.abs().log10().floor() as i32;
+    let scale = 10f64.powi(significant_digits.max(1) as i32 - 1);
+    let round = |exponent: i32| (x / 10f64.powi(exponent) * scale).round() / scale;
+    let mut coefficient = round(exponent);
+    // Rounding can carry into another digit, e.g. 9.999 to 10.0, and log10 can be off by one for
+    // exact powers of ten.
+    if coefficient.abs() >= 10.0 {
+        exponent += 1;
+        coefficient = round(exponent);
+    } else if coefficient.abs() < 1.0 {
+        exponent -= 1;
+        coefficient = round(exponent);
+    }
+    ScientificNotation {
+        coefficient,
+        exponent,
+    }
+}
+
+/// Writes `n` as a two's complement bit string `bits` wide, or returns an error if `n` doesn't fit.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::to_twos_complement;
+///
+/// assert_eq!(to_twos_complement(-5, 8).unwrap(), "11111011");
+/// assert_eq!(to_twos_complement(5, 4).unwrap(), "0101");
+/// assert!(to_twos_complement(128, 8).is_err());
+/// ```
+pub fn to_twos_complement(n: i64, bits: u32) -> Result<String, BaseError> {
+    if !(1..=64).contains(&bits) {
+        return Err(BaseError::OutOfRange(format!(
+            "{bits} bits is not between 1 and 64"
+        )));
+    }
+    let (min, max) = twos_complement_range(bits);
+    if n < min || n > max {
+        return Err(BaseError::OutOfRange(format!(
+            "{n} doesn't fit in {bits} bits, which hold {min} to {max}"
+        )));
+    }
+    let pattern = (n as u64) & (u64::MAX >> (64 - bits));
+    Ok(format!("{pattern:0width$b}", width = bits as usize))
+}
+
+/// Reads a two's complement bit string, where the first bit is the sign bit.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::from_twos_complement;
+///
+/// assert_eq!(from_twos_complement("11111011").unwrap(), -5);
+/// assert_eq!(from_twos_complement("0101").unwrap(), 5);
+/// ```
+pub fn from_twos_complement(bits: &str) -> Result<i64, BaseError> {
+    if bits.is_empty() {
+        return Err(BaseError::Empty);
+    }
+    if bits.len() > 64 {
+        return Err(BaseError::Overflow);
+    }
+    let mut pattern: u64 = 0;
+    for digit in bits.chars() {
+        pattern = (pattern << 1) | digit_value(digit, 2)? as u64;
+    }
+    // Shift the sign bit to the top and back to sign-extend the pattern.
+    let unused = 64 - bits.len() as u32;
+    Ok(((pattern << unused) as i64) >> unused)
+}
+
+/// Returns the smallest and largest numbers a `bits`-wide two's complement number holds.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::twos_complement_range;
+///
+/// assert_eq!(twos_complement_range(8), (-128, 127));
+/// ```
+pub fn twos_complement_range(bits: u32) -> (i64, i64) {
+    let max = (i64::MAX as u64 >> (64 - bits.clamp(1, 64))) as i64;
+    (-max - 1, max)
+}
+
+/// The digits of a fraction in some base, with the repeating part separated out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseExpansion {
+    /// The digits before the point.
+    pub integer: String,
+    /// The digits after the point that come before the repeating part.
+    pub non_repeating: String,
+    /// The digits that repeat forever, or an empty string if the expansion terminates.
+    pub repeating: String,
+}
+
+impl fmt::Display for BaseExpansion {
+    /// Writes the repeating digits in parentheses, e.g. `0.1(6)` for 1/6 in base 10.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.integer)?;
+        if !self.non_repeating.is_empty() || !self.repeating.is_empty() {
+            write!(f, ".{}", self.non_repeating)?;
+        }
+        if !self.repeating.is_empty() {
+            write!(f, "({})", self.repeating)?;
+        }
+        Ok(())
+    }
+}
+
+/// The most digits after the point that [`fraction_to_base`] writes before giving up.
+///
+/// The repeating part of `1 / d` can be up to `d - 1` digits long, so without a limit a large
+/// prime denominator would take billions of steps.
+pub const MAX_FRACTION_DIGITS: usize = 10_000;
+
+/// Expands `numerator / denominator` in `base` by long division, detecting the repeating digits
+/// by remembering each remainder.
+///
+/// Returns an error if the expansion has more than [`MAX_FRACTION_DIGITS`] digits after the
+/// point before it terminates or repeats.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::fraction_to_base;
+///
+/// assert_eq!(fraction_to_base(1, 6, 10).unwrap().to_string(), "0.1(6)");
+/// assert_eq!(fraction_to_base(1, 10, 2).unwrap().to_string(), "0.0(0011)");
+/// assert_eq!(fraction_to_base(21, 8, 2).unwrap().to_string(), "10.101");
+/// assert_eq!(fraction_to_base(1, 3, 3).unwrap().to_string(), "0.1");
+/// assert!(fraction_to_base(1, 1_000_000_007, 10).is_err());
+/// ```
+pub fn fraction_to_base(
+    numerator: u64,
+    denominator: u64,
+    base: u32,
+) -> Result<BaseExpansion, BaseError> {
+    check_base(base)?;
+    if denominator == 0 {
+        return Err(BaseError::ZeroDenominator);
+    }
+    let integer = to_base(
+        i64::try_from(numerator / denominator).map_err(|_| BaseError::Overflow)?,
+        base,
+    )?;
+    let mut remainder = (numerator % denominator) as u128;
+    let mut digits = Vec::new();
+    let mut seen: HashMap<u128, usize> = HashMap::new();
+    while remainder != 0 {
+        if let Some(&start) = seen.get(&remainder) {
+            let repeating = digits.split_off(start);
+            return Ok(BaseExpansion {
+                integer,
+                non_repeating: String::from_utf8(digits).expect("digits are ASCII"),
+                repeating: String::from_utf8(repeating).expect("digits are ASCII"),
+            });
+        }
+        if digits.len() == MAX_FRACTION_DIGITS {
+            return Err(BaseError::OutOfRange(format!(
+                "{numerator}/{denominator} has more than {MAX_FRACTION_DIGITS} digits after the \
+                 point in base {base} before it repeats"
+            )));
+        }
+        seen.insert(remainder, digits.len());
+        remainder *= base as u128;
+        digits.push(DIGITS[(remainder / denominator as u128) as usize]);
+        remainder %= denominator as u128;
+    }
+    Ok(BaseExpansion {
+        integer,
+        non_repeating: String::from_utf8(digits).expect("digits are ASCII"),
+        repeating: String::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_round_trips() {
+        for base in 2..=36 {
+            for n in [0, 1, -1, 35, 36, 1000, -123456789, i64::MAX, i64::MIN] {
+                assert_eq!(from_base(&to_base(n, base).unwrap(), base).unwrap(), n);
+            }
+        }
+        assert_eq!(from_base("-", 10), Err(BaseError::Empty));
+        assert_eq!(
+            from_base("9223372036854775808", 10),
+            Err(BaseError::Overflow)
+        );
+        assert_eq!(
+            from_base("1z", 35),
+            Err(BaseError::InvalidDigit {
+                digit: 'z',
+                base: 35
+            })
+        );
+        assert_eq!(convert_base("ff", 37, 2), Err(BaseError::InvalidBase(37)));
+    }
+
+    #[test]
+    fn test_int_literals() {
+        assert_eq!(parse_int_literal("0XFF"), Ok(255));
+        assert_eq!(parse_int_literal(" 1_000 "), Ok(1000));
+        assert_eq!(parse_int_literal("0x"), Err(BaseError::Empty));
+        assert!(parse_int_literal("0x-1").is_err());
+        assert!(parse_int_literal("0b102").is_err());
+    }
+
+    #[test]
+    fn test_balanced_ternary_and_roman() {
+        for n in -500..=500 {
+            assert_eq!(from_balanced_ternary(&to_balanced_ternary(n)), Ok(n));
+        }
+        for n in 1..=3999 {
+            assert_eq!(from_roman(&to_roman(n).unwrap()), Ok(n));
+        }
+        for invalid in ["", "IIII", "VV", "IM", "XLX", "MMMM", "ABC"] {
+            assert!(from_roman(invalid).is_err(), "{invalid}");
+        }
+        assert_eq!(to_roman(3888).unwrap(), "MMMDCCCLXXXVIII");
+    }
+
+    #[test]
+    fn test_scientific_notation() {
+        let s = to_scientific_notation(1000.0, 3);
+        assert_eq!((s.coefficient, s.exponent), (1.0, 3));
+        assert_eq!(
+            to_scientific_notation(6.02214076e23, 4).to_string(),
+            "6.022 × 10^23"
+        );
+        assert!((to_scientific_notation(0.000123, 3).value() - 0.000123).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_twos_complement() {
+        for bits in [1, 4, 8, 16, 64] {
+            let (min, max) = twos_complement_range(bits);
+            for n in [min, -1, 0, max] {
+                if (min..=max).contains(&n) {
+                    let encoded = to_twos_complement(n, bits).unwrap();
+                    assert_eq!(encoded.len(), bits as usize);
+                    assert_eq!(from_twos_complement(&encoded), Ok(n));
+                }
+            }
+        }
+        assert_eq!(twos_complement_range(1), (-1, 0));
+        assert!(to_twos_complement(1, 0).is_err());
+        assert!(from_twos_complement("012").is_err());
+    }
+
+    #[test]
+    fn test_fraction_expansion() {
+        assert_eq!(
+            fraction_to_base(22, 7, 10).unwrap().to_string(),
+            "3.(142857)"
+        );
+        assert_eq!(fraction_to_base(5, 1, 16).unwrap().to_string(), "5");
+        assert_eq!(fraction_to_base(255, 256, 16).unwrap().to_string(), "0.FF");
+        assert_eq!(fraction_to_base(1, 0, 10), Err(BaseError::ZeroDenominator));
+        // The repeating part of 1/9967 in base 10 is 9966 digits long, just under the limit.
+        assert_eq!(fraction_to_base(1, 9967, 10).unwrap().repeating.len(), 9966);
+        assert_eq!(
+            fraction_to_base(1, 1_000_000_007, 10),
+            Err(BaseError::OutOfRange(
+                "1/1000000007 has more than 10000 digits after the point in base 10 before it \
+                 repeats"
+                    .to_owned()
+            ))
+        );
+    }
+}
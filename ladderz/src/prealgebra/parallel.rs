@@ -0,0 +1,157 @@
+//! Data-parallel versions of the range functions in [`crate::prealgebra`], which split a range
+//! across the threads of the [rayon](https://docs.rs/rayon) thread pool.
+//!
+//! Each function returns exactly what its sequential version does. The global pool uses a
+//! thread per CPU unless `RAYON_NUM_THREADS` is set, and a function can be run on a pool of
+//! its own with [`rayon::ThreadPool::install`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use ladderz::prealgebra::parallel::get_primes_in_range;
+//!
+//! let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+//! let primes = pool.install(|| get_primes_in_range(1, 100));
+//! assert_eq!(primes.len(), 25);
+//! ```
+
+use super::{base_primes, is_prime, segments, sieve_segment};
+use rayon::prelude::*;
+use std::collections::HashSet;
+
+/// Finds all the multiples of a positive integer `n` starting from `start` and ending at `end`
+/// (inclusive), in parallel.
+///
+/// This is the parallel version of [`super::get_multiples_in_range`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::parallel::get_multiples_in_range;
+/// use std::collections::HashSet;
+///
+/// let result: HashSet<u32> = get_multiples_in_range(2, 5, 10);
+/// let expected: HashSet<u32> = [6, 8, 10].into();
+/// assert_eq!(result, expected);
+/// ```
+pub fn get_multiples_in_range(n: u32, start: u32, end: u32) -> HashSet<u32> {
+    let (n, end) = (n as u64, end as u64);
+    let initial = (start as u64).div_ceil(n) * n;
+    if initial > end {
+        return HashSet::new();
+    }
+    (0..=(end - initial) / n)
+        .into_par_iter()
+        .map(|i| (initial + i * n) as u32)
+        .collect()
+}
+
+/// Returns all prime numbers in the range [start, end], testing the numbers in parallel.
+///
+/// This is the parallel version of [`super::get_primes_in_range`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::parallel::get_primes_in_range;
+/// use std::collections::HashSet;
+///
+/// let result: HashSet<u32> = get_primes_in_range(2, 10);
+/// let expected: HashSet<u32> = [2, 3, 5, 7].into();
+/// assert_eq!(result, expected);
+/// ```
+pub fn get_primes_in_range(start: u32, end: u32) -> HashSet<u32> {
+    (start..=end)
+        .into_par_iter()
+        .filter(|&num| is_prime(num))
+        .collect()
+}
+
+/// Returns all prime numbers in the range [start, end] with a segmented sieve of Eratosthenes,
+/// sieving the segments in parallel.
+///
+/// This is the parallel version of [`super::sieve_primes_in_range`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::prealgebra::parallel::sieve_primes_in_range;
+/// use std::collections::HashSet;
+///
+/// let result: HashSet<u32> = sieve_primes_in_range(2, 10);
+/// let expected: HashSet<u32> = [2, 3, 5, 7].into();
+/// assert_eq!(result, expected);
+/// ```
+pub fn sieve_primes_in_range(start: u32, end: u32) -> HashSet<u32> {
+    if start > end {
+        return HashSet::new();
+    }
+    let base = base_primes(end.isqrt());
+    segments(start, end)
+        .par_bridge()
+        .flat_map_iter(|(low, high)| sieve_segment(low, high, &base))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prealgebra;
+    use proptest::prelude::*;
+
+    /// Runs `f` on a pool of four threads, so the work is split even on a single-CPU machine.
+    fn on_four_threads<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        pool.install(f)
+    }
+
+    #[test]
+    fn test_get_multiples_in_range() {
+        for (n, start, end) in [(2, 10, 20), (5, 23, 34), (7, 10, 11), (3, 0, 1000)] {
+            let result = on_four_threads(|| get_multiples_in_range(n, start, end));
+            assert_eq!(result, prealgebra::get_multiples_in_range(n, start, end));
+        }
+        let result = on_four_threads(|| get_multiples_in_range(u32::MAX, 1, u32::MAX));
+        assert_eq!(result, [u32::MAX].into());
+    }
+
+    #[test]
+    fn test_get_primes_in_range() {
+        for (start, end) in [(2, 10), (1, 1), (0, 5), (1, 2000), (10, 1)] {
+            let result = on_four_threads(|| get_primes_in_range(start, end));
+            assert_eq!(result, prealgebra::get_primes_in_range(start, end));
+        }
+    }
+
+    #[test]
+    fn test_sieve_primes_in_range() {
+        for (start, end) in [(1, 50), (32_000, 33_500), (1, 200_000), (10, 1)] {
+            let result = on_four_threads(|| sieve_primes_in_range(start, end));
+            assert_eq!(result, prealgebra::sieve_primes_in_range(start, end));
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parallel_matches_sequential(
+            n in 1u32..100,
+            start in 0u32..5_000,
+            length in 0u32..5_000,
+        ) {
+            let end = start + length;
+            let (multiples, primes, sieved) = on_four_threads(|| {
+                (
+                    get_multiples_in_range(n, start, end),
+                    get_primes_in_range(start, end),
+                    sieve_primes_in_range(start, end),
+                )
+            });
+            prop_assert_eq!(multiples, prealgebra::get_multiples_in_range(n, start, end));
+            prop_assert_eq!(primes, prealgebra::get_primes_in_range(start, end));
+            prop_assert_eq!(sieved, prealgebra::sieve_primes_in_range(start, end));
+        }
+    }
+}
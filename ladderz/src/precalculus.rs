@@ -0,0 +1,44 @@
+/// Function expressions, composition and inverse checking.
+pub mod functions;
+/// Logarithms and the logarithm and exponent identities.
+pub mod logarithms;
+/// Arithmetic and geometric sequences and series.
+pub mod series;
+/// Solving triangles with the laws of sines and cosines.
+pub mod triangles;
+/// Trigonometric functions, angle conversion and exact unit circle values.
+pub mod trigonometry;
+
+pub use functions::{
+    compare_inverses, compose, is_inverse_on, sample_domain, Builtin, Expression,
+    InverseComparison, Operator, ParseExpressionError,
+};
+pub use logarithms::{exponent_identities, log, log_identities, IdentityCheck};
+pub use series::{
+    arithmetic_sequence, arithmetic_sum, arithmetic_term, geometric_sequence, geometric_series_sum,
+    geometric_sum, geometric_term,
+};
+pub use triangles::{
+    law_of_cosines_angle, law_of_cosines_side, law_of_sines_side, solve_aas, solve_asa, solve_sas,
+    solve_ssa, solve_sss, Triangle, TriangleError,
+};
+pub use trigonometry::{
+    degrees_to_pi_fraction, degrees_to_radians, radians_to_degrees, unit_circle_point, ExactValue,
+    TrigFunction, UnitCircleValue,
+};
+
+/// Rounds `x` to `places` decimal places, so that printing it hides floating point noise.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::round_to;
+///
+/// assert_eq!(round_to(0.1 + 0.2, 4), 0.3);
+/// assert_eq!(round_to(-0.00001, 4).to_string(), "0");
+/// ```
+pub fn round_to(x: f64, places: i32) -> f64 {
+    let scale = 10f64.powi(places);
+    // Adding 0.0 turns -0.0 into 0.0.
+    (x * scale).round() / scale + 0.0
+}
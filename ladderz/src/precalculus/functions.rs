@@ -0,0 +1,643 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A built-in function that can be called in an [`Expression`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Builtin {
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Sqrt,
+    Abs,
+    Exp,
+    /// The natural logarithm.
+    Ln,
+    /// The base 10 logarithm.
+    Log,
+}
+
+impl Builtin {
+    const ALL: [Builtin; 11] = [
+        Builtin::Sin,
+        Builtin::Cos,
+        Builtin::Tan,
+        Builtin::Asin,
+        Builtin::Acos,
+        Builtin::Atan,
+        Builtin::Sqrt,
+        Builtin::Abs,
+        Builtin::Exp,
+        Builtin::Ln,
+        Builtin::Log,
+    ];
+
+    /// Returns the name used to call the function.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Builtin::Sin => "sin",
+            Builtin::Cos => "cos",
+            Builtin::Tan => "tan",
+            Builtin::Asin => "asin",
+            Builtin::Acos => "acos",
+            Builtin::Atan => "atan",
+            Builtin::Sqrt => "sqrt",
+            Builtin::Abs => "abs",
+            Builtin::Exp => "exp",
+            Builtin::Ln => "ln",
+            Builtin::Log => "log",
+        }
+    }
+
+    /// Applies the function to `x`, with angles in radians.
+    pub fn apply(&self, x: f64) -> f64 {
+        match self {
+            Builtin::Sin => x.sin(),
+            Builtin::Cos => x.cos(),
+            Builtin::Tan => x.tan(),
+            Builtin::Asin => x.asin(),
+            Builtin::Acos => x.acos(),
+            Builtin::Atan => x.atan(),
+            Builtin::Sqrt => x.sqrt(),
+            Builtin::Abs => x.abs(),
+            Builtin::Exp => x.exp(),
+            Builtin::Ln => x.ln(),
+            Builtin::Log => x.log10(),
+        }
+    }
+}
+
+/// A binary operator in an [`Expression`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Power,
+}
+
+impl Operator {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Add => " + ",
+            Operator::Subtract => " - ",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Power => "^",
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Operator::Add | Operator::Subtract => 1,
+            Operator::Multiply | Operator::Divide => 2,
+            Operator::Power => 4,
+        }
+    }
+}
+
+/// A real function of one variable `x`, such as `2x + 1` or `sin(x)^2`.
+///
+/// Expressions are parsed from strings with numbers, `x`, the constants `pi` and `e`, the
+/// operators `+ - * / ^`, parentheses, and the functions `sin cos tan asin acos atan sqrt abs
+/// exp ln log`. A number or closing parenthesis directly followed by `x`, a constant, a function
+/// or an opening parenthesis is multiplied, so `2x` means `2*x`. `^` binds tighter than a leading
+/// minus sign and groups from the right, so `-x^2` is `-(x^2)` and `2^3^2` is `2^9`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::Expression;
+///
+/// let f: Expression = "2x + 1".parse().unwrap();
+/// assert_eq!(f.evaluate(3.0), 7.0);
+/// assert_eq!(f.to_string(), "2*x + 1");
+///
+/// let g: Expression = "sqrt(x - 1)".parse().unwrap();
+/// assert_eq!(g.compose(&f).to_string(), "sqrt(2*x + 1 - 1)");
+/// assert!("2 +".parse::<Expression>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Number(f64),
+    /// The variable `x`.
+    X,
+    Pi,
+    E,
+    Negate(Box<Expression>),
+    Binary(Operator, Box<Expression>, Box<Expression>),
+    Call(Builtin, Box<Expression>),
+}
+
+impl Expression {
+    /// Evaluates the expression at `x`. The result is `NaN` or infinite where the function is
+    /// undefined, such as `ln(x)` for `x <= 0`.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        match self {
+            Expression::Number(n) => *n,
+            Expression::X => x,
+            Expression::Pi => std::f64::consts::PI,
+            Expression::E => std::f64::consts::E,
+            Expression::Negate(inner) => -inner.evaluate(x),
+            Expression::Binary(op, left, right) => {
+                let (left, right) = (left.evaluate(x), right.evaluate(x));
+                match op {
+                    Operator::Add => left + right,
+                    Operator::Subtract => left - right,
+                    Operator::Multiply => left * right,
+                    Operator::Divide => left / right,
+                    Operator::Power => left.powf(right),
+                }
+            }
+            Expression::Call(function, argument) => function.apply(argument.evaluate(x)),
+        }
+    }
+
+    /// Returns `self ∘ inner`, the expression with every `x` replaced by `inner`.
+    pub fn compose(&self, inner: &Expression) -> Expression {
+        match self {
+            Expression::X => inner.clone(),
+            Expression::Negate(e) => Expression::Negate(Box::new(e.compose(inner))),
+            Expression::Binary(op, left, right) => Expression::Binary(
+                *op,
+                Box::new(left.compose(inner)),
+                Box::new(right.compose(inner)),
+            ),
+            Expression::Call(function, argument) => {
+                Expression::Call(*function, Box::new(argument.compose(inner)))
+            }
+            constant => constant.clone(),
+        }
+    }
+
+    /// Returns `true` if the expression doesn't depend on `x`, like `pi/6`.
+    pub fn is_constant(&self) -> bool {
+        match self {
+            Expression::X => false,
+            Expression::Negate(e) | Expression::Call(_, e) => e.is_constant(),
+            Expression::Binary(_, left, right) => left.is_constant() && right.is_constant(),
+            _ => true,
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression::Binary(op, _, _) => op.precedence(),
+            Expression::Negate(_) => 3,
+            _ => 5,
+        }
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let child = |f: &mut fmt::Formatter<'_>, e: &Expression, min: u8| {
+            if e.precedence() < min {
+                write!(f, "({e})")
+            } else {
+                write!(f, "{e}")
+            }
+        };
+        match self {
+            Expression::Number(n) => write!(f, "{n}"),
+            Expression::X => write!(f, "x"),
+            Expression::Pi => write!(f, "pi"),
+            Expression::E => write!(f, "e"),
+            Expression::Negate(inner) => {
+                write!(f, "-")?;
+                child(f, inner, 3)
+            }
+            Expression::Binary(op, left, right) => {
+                let p = op.precedence();
+                // Left-associative operators need parentheses around an equal-precedence right
+                // operand, and `^` needs them around an equal-precedence left operand.
+                let (left_min, right_min) = match op {
+                    Operator::Power => (p + 1, 3),
+                    _ => (p, p + 1),
+                };
+                child(f, left, left_min)?;
+                write!(f, "{}", op.symbol())?;
+                child(f, right, right_min)
+            }
+            Expression::Call(function, argument) => write!(f, "{}({argument})", function.name()),
+        }
+    }
+}
+
+/// An error from parsing an [`Expression`], at a 0-based character position.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseExpressionError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl Error for ParseExpressionError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Symbol(char),
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    index: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn tokenize(input: &str) -> Result<Self, ParseExpressionError> {
+        let chars: Vec<char> = input.chars().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let start = i;
+            if c.is_whitespace() {
+                i += 1;
+            } else if c.is_ascii_digit() || c == '.' {
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let number = text.parse().map_err(|_| ParseExpressionError {
+                    position: start,
+                    message: format!("invalid number {text:?}"),
+                })?;
+                tokens.push((start, Token::Number(number)));
+            } else if c.is_alphabetic() {
+                while i < chars.len() && chars[i].is_alphanumeric() {
+                    i += 1;
+                }
+                let name: String = chars[start..i].iter().collect();
+                tokens.push((start, Token::Name(name.to_lowercase())));
+            } else if "+-*/^()".contains(c) {
+                tokens.push((start, Token::Symbol(c)));
+                i += 1;
+            } else {
+                return Err(ParseExpressionError {
+                    position: start,
+                    message: format!("unexpected character {c:?}"),
+                });
+            }
+        }
+        Ok(Parser {
+            tokens,
+            index: 0,
+            end: chars.len(),
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.index).map(|(_, t)| t)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens.get(self.index).map_or(self.end, |(p, _)| *p)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, ParseExpressionError> {
+        Err(ParseExpressionError {
+            position: self.position(),
+            message: message.into(),
+        })
+    }
+
+    fn eat(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.index += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expression(&mut self) -> Result<Expression, ParseExpressionError> {
+        let mut left = self.term()?;
+        loop {
+            let op = if self.eat('+') {
+                Operator::Add
+            } else if self.eat('-') {
+                Operator::Subtract
+            } else {
+                return Ok(left);
+            };
+            left = Expression::Binary(op, Box::new(left), Box::new(self.term()?));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expression, ParseExpressionError> {
+        let mut left = self.unary()?;
+        loop {
+            let op = if self.eat('*') {
+                Operator::Multiply
+            } else if self.eat('/') {
+                Operator::Divide
+            } else if matches!(
+                self.peek(),
+                Some(Token::Number(_) | Token::Name(_) | Token::Symbol('('))
+            ) {
+                Operator::Multiply
+            } else {
+                return Ok(left);
+            };
+            left = Expression::Binary(op, Box::new(left), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expression, ParseExpressionError> {
+        if self.eat('-') {
+            return Ok(Expression::Negate(Box::new(self.unary()?)));
+        }
+        if self.eat('+') {
+            return self.unary();
+        }
+        let base = self.primary()?;
+        if self.eat('^') {
+            let exponent = self.unary()?;
+            return Ok(Expression::Binary(
+                Operator::Power,
+                Box::new(base),
+                Box::new(exponent),
+            ));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Expression, ParseExpressionError> {
+        let Some(token) = self.peek().cloned() else {
+            return self.error("unexpected end of expression");
+        };
+        match token {
+            Token::Number(n) => {
+                self.index += 1;
+                Ok(Expression::Number(n))
+            }
+            Token::Symbol('(') => {
+                self.index += 1;
+                let inner = self.expression()?;
+                if !self.eat(')') {
+                    return self.error("expected ')'");
+                }
+                Ok(inner)
+            }
+            Token::Name(name) => {
+                let constant = match name.as_str() {
+                    "x" => Some(Expression::X),
+                    "pi" => Some(Expression::Pi),
+                    "e" => Some(Expression::E),
+                    _ => None,
+                };
+                if let Some(constant) = constant {
+                    self.index += 1;
+                    return Ok(constant);
+                }
+                let Some(function) = Builtin::ALL.into_iter().find(|f| f.name() == name) else {
+                    return self.error(format!("unknown name {name:?}"));
+                };
+                self.index += 1;
+                if !self.eat('(') {
+                    return self.error(format!("expected '(' after {name}"));
+                }
+                let argument = self.expression()?;
+                if !self.eat(')') {
+                    return self.error("expected ')'");
+                }
+                Ok(Expression::Call(function, Box::new(argument)))
+            }
+            Token::Symbol(c) => self.error(format!("unexpected {c:?}")),
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseExpressionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::tokenize(s)?;
+        let expression = parser.expression()?;
+        if parser.index < parser.tokens.len() {
+            return parser.error("unexpected input after the expression");
+        }
+        Ok(expression)
+    }
+}
+
+/// Returns `f ∘ g`, the function that applies `g` and then `f`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::compose;
+///
+/// let add_one_then_square = compose(|x: i32| x * x, |x: i32| x + 1);
+/// assert_eq!(add_one_then_square(2), 9);
+/// ```
+pub fn compose<A, B, C>(f: impl Fn(B) -> C, g: impl Fn(A) -> B) -> impl Fn(A) -> C {
+    move |x| f(g(x))
+}
+
+/// Returns `samples` evenly spaced points from `start` to `end`, including both ends.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::sample_domain;
+///
+/// assert_eq!(sample_domain(0.0, 1.0, 5), vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+/// ```
+pub fn sample_domain(start: f64, end: f64, samples: usize) -> Vec<f64> {
+    match samples {
+        0 => vec![],
+        1 => vec![start],
+        _ => (0..samples)
+            .map(|i| start + (end - start) * i as f64 / (samples - 1) as f64)
+            .collect(),
+    }
+}
+
+/// The result of comparing two functions as inverses on sampled points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InverseComparison {
+    /// `g(f(x))` and `f(g(x))` equal `x` at every sample where they are defined, and at least
+    /// one of them was defined at `compared` samples.
+    Inverses { compared: usize },
+    /// `g(f(x))` or `f(g(x))` differs from `x` at this sample.
+    Counterexample(f64),
+    /// Neither `g(f(x))` nor `f(g(x))` is defined at any sample, so nothing could be compared.
+    Undetermined,
+}
+
+/// Compares `g(f(x))` and `f(g(x))` with `x` at each sample in `domain`, stopping at the first
+/// that differs by more than `tolerance` (relative to the size of `x`).
+///
+/// Round trips that are undefined (`NaN` or infinite) are skipped, and only samples where at
+/// least one round trip is defined count as compared.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::{compare_inverses, sample_domain, InverseComparison};
+///
+/// let domain = sample_domain(-10.0, 10.0, 101);
+/// assert_eq!(
+///     compare_inverses(|x| 2.0 * x + 1.0, |y| (y - 1.0) / 2.0, &domain, 1e-9),
+///     InverseComparison::Inverses { compared: 101 }
+/// );
+/// // Squaring only undoes `sqrt` for non-negative `x`.
+/// assert_eq!(
+///     compare_inverses(|x: f64| x * x, f64::sqrt, &domain, 1e-9),
+///     InverseComparison::Counterexample(-10.0)
+/// );
+/// // `sqrt(-1 - x^2)` is never defined.
+/// assert_eq!(
+///     compare_inverses(|x| x, |x: f64| (-1.0 - x * x).sqrt(), &domain, 1e-9),
+///     InverseComparison::Undetermined
+/// );
+/// ```
+pub fn compare_inverses(
+    f: impl Fn(f64) -> f64,
+    g: impl Fn(f64) -> f64,
+    domain: &[f64],
+    tolerance: f64,
+) -> InverseComparison {
+    let mut compared = 0;
+    for &x in domain {
+        let round_trips = [g(f(x)), f(g(x))].map(|y| Some(y).filter(|y| y.is_finite()));
+        if round_trips.iter().all(Option::is_none) {
+            continue;
+        }
+        compared += 1;
+        let differs =
+            |y: &Option<f64>| y.is_some_and(|y| (y - x).abs() > tolerance * (1.0 + x.abs()));
+        if round_trips.iter().any(differs) {
+            return InverseComparison::Counterexample(x);
+        }
+    }
+    match compared {
+        0 => InverseComparison::Undetermined,
+        compared => InverseComparison::Inverses { compared },
+    }
+}
+
+/// Checks whether `f` and `g` are inverses of each other on the sampled `domain`.
+///
+/// Returns `false` when nothing could be compared. See [`compare_inverses`] for how samples are
+/// compared.
+pub fn is_inverse_on(
+    f: impl Fn(f64) -> f64,
+    g: impl Fn(f64) -> f64,
+    domain: &[f64],
+    tolerance: f64,
+) -> bool {
+    matches!(
+        compare_inverses(f, g, domain, tolerance),
+        InverseComparison::Inverses { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Expression {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let cases = [
+            ("x^2 + 3x - 4", "x^2 + 3*x - 4"),
+            ("-x^2", "-x^2"),
+            ("(-x)^2", "(-x)^2"),
+            ("2^3^2", "2^3^2"),
+            ("(2^3)^2", "(2^3)^2"),
+            ("x - (x - 1)", "x - (x - 1)"),
+            ("x/(2*x)", "x/(2*x)"),
+            ("2(x+1)", "2*(x + 1)"),
+            ("SIN(pi x)", "sin(pi*x)"),
+            ("e^-x", "e^-x"),
+        ];
+        for (input, display) in cases {
+            let expression = parse(input);
+            assert_eq!(expression.to_string(), display);
+            assert_eq!(parse(display), expression);
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        assert_eq!(parse("2^3^2").evaluate(0.0), 512.0);
+        assert_eq!(parse("-x^2").evaluate(3.0), -9.0);
+        assert_eq!(parse("10 - 4 - 3").evaluate(0.0), 3.0);
+        assert!((parse("sin(pi/6)").evaluate(0.0) - 0.5).abs() < 1e-12);
+        assert!(parse("ln(x)").evaluate(-1.0).is_nan());
+        assert!(parse("sqrt(pi/6)").is_constant());
+        assert!(!parse("2 + sin(x)").is_constant());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |s: &str| s.parse::<Expression>().unwrap_err().to_string();
+        assert_eq!(error(""), "unexpected end of expression at position 0");
+        assert_eq!(error("2 $ x"), "unexpected character '$' at position 2");
+        assert_eq!(error("foo(x)"), "unknown name \"foo\" at position 0");
+        assert_eq!(error("(x + 1"), "expected ')' at position 6");
+        assert_eq!(error("sin x"), "expected '(' after sin at position 4");
+        assert_eq!(
+            error("x )"),
+            "unexpected input after the expression at position 2"
+        );
+        assert_eq!(error("1..2"), "invalid number \"1..2\" at position 0");
+    }
+
+    #[test]
+    fn test_compose_and_inverse() {
+        let (f, g) = (parse("x^2"), parse("x + 1"));
+        let fg = f.compose(&g);
+        assert_eq!(fg.to_string(), "(x + 1)^2");
+        assert_eq!(
+            fg.evaluate(2.0),
+            compose(|x| f.evaluate(x), |x| g.evaluate(x))(2.0)
+        );
+
+        let domain = sample_domain(0.1, 10.0, 50);
+        let (exp, ln) = (parse("exp(x)"), parse("ln(x)"));
+        assert!(is_inverse_on(
+            |x| exp.evaluate(x),
+            |x| ln.evaluate(x),
+            &domain,
+            1e-9
+        ));
+        assert!(!is_inverse_on(|x| x + 1.0, |x| x - 2.0, &domain, 1e-9));
+        assert!(!is_inverse_on(
+            |x| x,
+            |x| (-1.0 - x * x).sqrt(),
+            &domain,
+            1e-9
+        ));
+        // Both round trips are undefined for negative x, so only 0, 0.5 and 1 are compared.
+        let (sqrt, square) = (parse("sqrt(x)"), parse("sqrt(x)^4"));
+        assert_eq!(
+            compare_inverses(
+                |x| sqrt.evaluate(x),
+                |x| square.evaluate(x),
+                &sample_domain(-1.0, 1.0, 5),
+                1e-9
+            ),
+            InverseComparison::Inverses { compared: 3 }
+        );
+        assert!(sample_domain(0.0, 1.0, 0).is_empty());
+    }
+}
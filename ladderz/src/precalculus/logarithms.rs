@@ -0,0 +1,204 @@
+use std::fmt;
+
+/// Returns `log_base(x)` by the change of base formula, or `None` if `base` is not positive or is
+/// 1, or `x` is not positive.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::log;
+///
+/// assert_eq!(log(2.0, 8.0), Some(3.0));
+/// assert_eq!(log(1.0, 8.0), None);
+/// assert_eq!(log(10.0, -1.0), None);
+/// ```
+pub fn log(base: f64, x: f64) -> Option<f64> {
+    if base <= 0.0 || base == 1.0 || x <= 0.0 {
+        return None;
+    }
+    // Exact powers of 2 and 10 are computed directly to avoid rounding, e.g. log_10(1000) = 3.
+    Some(if base == 2.0 {
+        x.log2()
+    } else if base == 10.0 {
+        x.log10()
+    } else {
+        x.ln() / base.ln()
+    })
+}
+
+/// An identity with both of its sides evaluated for particular values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentityCheck {
+    /// The identity's name, e.g. `product rule`.
+    pub name: &'static str,
+    /// The identity with the values substituted in.
+    pub statement: String,
+    /// The value of the left-hand side.
+    pub left: f64,
+    /// The value of the right-hand side.
+    pub right: f64,
+}
+
+impl IdentityCheck {
+    /// Checks whether both sides agree up to floating point rounding.
+    pub fn holds(&self) -> bool {
+        (self.left - self.right).abs() <= 1e-9 * (1.0 + self.left.abs().max(self.right.abs()))
+    }
+}
+
+impl fmt::Display for IdentityCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} ({} = {})",
+            self.name,
+            self.statement,
+            super::round_to(self.left, 4),
+            super::round_to(self.right, 4)
+        )
+    }
+}
+
+/// Evaluates the logarithm identities for `base`, `x` and `y`, or returns `None` if `base`, `x`
+/// or `y` is outside the logarithm's domain.
+///
+/// The identities are the product, quotient and power rules, the change of base formula, and
+/// `b^(log_b(x)) = x`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::log_identities;
+///
+/// let identities = log_identities(2.0, 8.0, 4.0).unwrap();
+/// assert_eq!(identities[0].statement, "log_2(8·4) = log_2(8) + log_2(4)");
+/// assert_eq!((identities[0].left, identities[0].right), (5.0, 5.0));
+/// assert!(identities.iter().all(|identity| identity.holds()));
+/// assert!(log_identities(2.0, -8.0, 4.0).is_none());
+/// ```
+pub fn log_identities(base: f64, x: f64, y: f64) -> Option<Vec<IdentityCheck>> {
+    let l = |v: f64| log(base, v);
+    let (lx, ly) = (l(x)?, l(y)?);
+    Some(vec![
+        IdentityCheck {
+            name: "product rule",
+            statement: format!("log_{base}({x}·{y}) = log_{base}({x}) + log_{base}({y})"),
+            left: l(x * y)?,
+            right: lx + ly,
+        },
+        IdentityCheck {
+            name: "quotient rule",
+            statement: format!("log_{base}({x}/{y}) = log_{base}({x}) - log_{base}({y})"),
+            left: l(x / y)?,
+            right: lx - ly,
+        },
+        IdentityCheck {
+            name: "power rule",
+            statement: format!("log_{base}({x}^{y}) = {y}·log_{base}({x})"),
+            left: l(x.powf(y))?,
+            right: y * lx,
+        },
+        IdentityCheck {
+            name: "change of base",
+            statement: format!("log_{base}({x}) = ln({x})/ln({base})"),
+            left: lx,
+            right: x.ln() / base.ln(),
+        },
+        IdentityCheck {
+            name: "inverse",
+            statement: format!("{base}^(log_{base}({x})) = {x}"),
+            left: base.powf(lx),
+            right: x,
+        },
+    ])
+}
+
+/// Evaluates the exponent identities for `base`, `m` and `n`.
+///
+/// The identities are the product, quotient and power rules, negative exponents, and the zero
+/// exponent. For a negative base, fractional exponents are undefined and those identities don't
+/// hold.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::exponent_identities;
+///
+/// let identities = exponent_identities(2.0, 3.0, 2.0);
+/// assert_eq!(identities[2].statement, "(2^3)^2 = 2^(3·2)");
+/// assert_eq!((identities[2].left, identities[2].right), (64.0, 64.0));
+/// assert!(identities.iter().all(|identity| identity.holds()));
+/// ```
+pub fn exponent_identities(base: f64, m: f64, n: f64) -> Vec<IdentityCheck> {
+    let p = |e: f64| base.powf(e);
+    vec![
+        IdentityCheck {
+            name: "product rule",
+            statement: format!("{base}^{m}·{base}^{n} = {base}^({m}+{n})"),
+            left: p(m) * p(n),
+            right: p(m + n),
+        },
+        IdentityCheck {
+            name: "quotient rule",
+            statement: format!("{base}^{m}/{base}^{n} = {base}^({m}-{n})"),
+            left: p(m) / p(n),
+            right: p(m - n),
+        },
+        IdentityCheck {
+            name: "power rule",
+            statement: format!("({base}^{m})^{n} = {base}^({m}·{n})"),
+            left: p(m).powf(n),
+            right: p(m * n),
+        },
+        IdentityCheck {
+            name: "negative exponent",
+            statement: format!("{base}^-{m} = 1/{base}^{m}"),
+            left: p(-m),
+            right: 1.0 / p(m),
+        },
+        IdentityCheck {
+            name: "zero exponent",
+            statement: format!("{base}^0 = 1"),
+            left: p(0.0),
+            right: 1.0,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log() {
+        assert_eq!(log(10.0, 1000.0), Some(3.0));
+        assert!((log(3.0, 81.0).unwrap() - 4.0).abs() < 1e-12);
+        assert!((log(0.5, 4.0).unwrap() + 2.0).abs() < 1e-12);
+        assert_eq!(log(0.0, 4.0), None);
+    }
+
+    #[test]
+    fn test_identities() {
+        for (base, x, y) in [(10.0, 0.5, 3.0), (std::f64::consts::E, 7.0, 2.5)] {
+            assert!(log_identities(base, x, y)
+                .unwrap()
+                .iter()
+                .all(|i| i.holds()));
+            assert!(exponent_identities(base, x, y).iter().all(|i| i.holds()));
+        }
+        let negative = exponent_identities(-2.0, 0.5, 2.0);
+        assert!(!negative[0].holds());
+        assert!(negative[4].holds());
+        assert_eq!(
+            exponent_identities(2.0, 3.0, 2.0)[0].to_string(),
+            "product rule: 2^3·2^2 = 2^(3+2) (32 = 32)"
+        );
+        assert!(!IdentityCheck {
+            name: "",
+            statement: String::new(),
+            left: 1.0,
+            right: 1.1
+        }
+        .holds());
+    }
+}
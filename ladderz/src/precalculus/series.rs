@@ -0,0 +1,131 @@
+/// Returns the `n`th term (counting from 1) of the arithmetic sequence that starts at `first` and
+/// changes by `difference`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::arithmetic_term;
+///
+/// assert_eq!(arithmetic_term(3.0, 4.0, 5), 19.0);
+/// ```
+pub fn arithmetic_term(first: f64, difference: f64, n: u32) -> f64 {
+    first + difference * (n as f64 - 1.0)
+}
+
+/// Returns the first `n` terms of an arithmetic sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::arithmetic_sequence;
+///
+/// assert_eq!(arithmetic_sequence(3.0, 4.0, 4), vec![3.0, 7.0, 11.0, 15.0]);
+/// ```
+pub fn arithmetic_sequence(first: f64, difference: f64, n: u32) -> Vec<f64> {
+    (1..=n)
+        .map(|i| arithmetic_term(first, difference, i))
+        .collect()
+}
+
+/// Returns the sum of the first `n` terms of an arithmetic sequence, `n(2a₁ + (n - 1)d)/2`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::arithmetic_sum;
+///
+/// assert_eq!(arithmetic_sum(1.0, 1.0, 100), 5050.0);
+/// ```
+pub fn arithmetic_sum(first: f64, difference: f64, n: u32) -> f64 {
+    let n = n as f64;
+    n * (2.0 * first + (n - 1.0) * difference) / 2.0
+}
+
+/// Returns the `n`th term (counting from 1) of the geometric sequence that starts at `first` and
+/// is multiplied by `ratio`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::geometric_term;
+///
+/// assert_eq!(geometric_term(3.0, 2.0, 5), 48.0);
+/// ```
+pub fn geometric_term(first: f64, ratio: f64, n: u32) -> f64 {
+    first * ratio.powi(n as i32 - 1)
+}
+
+/// Returns the first `n` terms of a geometric sequence.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::geometric_sequence;
+///
+/// assert_eq!(geometric_sequence(1.0, -2.0, 4), vec![1.0, -2.0, 4.0, -8.0]);
+/// ```
+pub fn geometric_sequence(first: f64, ratio: f64, n: u32) -> Vec<f64> {
+    (1..=n).map(|i| geometric_term(first, ratio, i)).collect()
+}
+
+/// Returns the sum of the first `n` terms of a geometric sequence, `a₁(1 - rⁿ)/(1 - r)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::geometric_sum;
+///
+/// assert_eq!(geometric_sum(1.0, 2.0, 10), 1023.0);
+/// assert_eq!(geometric_sum(5.0, 1.0, 3), 15.0);
+/// ```
+pub fn geometric_sum(first: f64, ratio: f64, n: u32) -> f64 {
+    if ratio == 1.0 {
+        return first * n as f64;
+    }
+    first * (1.0 - ratio.powi(n as i32)) / (1.0 - ratio)
+}
+
+/// Returns the sum of an infinite geometric series, `a₁/(1 - r)`, or `None` if it diverges
+/// because `|r| >= 1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::geometric_series_sum;
+///
+/// assert_eq!(geometric_series_sum(1.0, 0.5), Some(2.0));
+/// assert_eq!(geometric_series_sum(1.0, -1.0), None);
+/// ```
+pub fn geometric_series_sum(first: f64, ratio: f64) -> Option<f64> {
+    match ratio.abs() < 1.0 {
+        true => Some(first / (1.0 - ratio)),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sums_match_sequences() {
+        for (first, step) in [(2.0, 3.0), (-1.5, 0.5), (10.0, -2.0)] {
+            for n in 0..12 {
+                let arithmetic: f64 = arithmetic_sequence(first, step, n).iter().sum();
+                assert!((arithmetic - arithmetic_sum(first, step, n)).abs() < 1e-9);
+                let geometric: f64 = geometric_sequence(first, step, n).iter().sum();
+                assert!((geometric - geometric_sum(first, step, n)).abs() < 1e-6);
+            }
+        }
+        assert!(arithmetic_sequence(1.0, 1.0, 0).is_empty());
+        assert_eq!(geometric_sum(3.0, 0.5, 0), 0.0);
+    }
+
+    #[test]
+    fn test_infinite_series() {
+        assert_eq!(geometric_series_sum(3.0, -0.5), Some(2.0));
+        assert_eq!(geometric_series_sum(3.0, 2.0), None);
+        let partial = geometric_sum(1.0, 0.9, 400);
+        assert!((partial - geometric_series_sum(1.0, 0.9).unwrap()).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,326 @@
+use super::round_to;
+use std::error::Error;
+use std::fmt;
+
+/// How close two lengths or angles must be to count as equal.
+const EPSILON: f64 = 1e-9;
+
+/// A solved triangle. Side `a` is opposite angle `alpha`, `b` opposite `beta`, and `c` opposite
+/// `gamma`. Angles are in degrees.
+///
+/// Displaying a triangle rounds every value to 4 decimal places.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub alpha: f64,
+    pub beta: f64,
+    pub gamma: f64,
+}
+
+impl Triangle {
+    /// Returns the perimeter.
+    pub fn perimeter(&self) -> f64 {
+        self.a + self.b + self.c
+    }
+
+    /// Returns the area, `½·b·c·sin(α)`.
+    pub fn area(&self) -> f64 {
+        0.5 * self.b * self.c * self.alpha.to_radians().sin()
+    }
+}
+
+impl fmt::Display for Triangle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a = {}, b = {}, c = {}, A = {}°, B = {}°, C = {}°",
+            round_to(self.a, 4),
+            round_to(self.b, 4),
+            round_to(self.c, 4),
+            round_to(self.alpha, 4),
+            round_to(self.beta, 4),
+            round_to(self.gamma, 4)
+        )
+    }
+}
+
+/// An error from solving a triangle with impossible measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriangleError {
+    /// A side or angle that is zero, negative, or not a number.
+    NonPositive,
+    /// Given angles that add up to 180° or more.
+    AngleSum,
+    /// Sides where one is at least as long as the other two together.
+    TriangleInequality,
+}
+
+impl fmt::Display for TriangleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TriangleError::NonPositive => write!(f, "every side and angle must be positive"),
+            TriangleError::AngleSum => write!(f, "the angles must add up to less than 180°"),
+            TriangleError::TriangleInequality => write!(
+                f,
+                "each side must be shorter than the other two sides together"
+            ),
+        }
+    }
+}
+
+impl Error for TriangleError {}
+
+fn check_positive(values: &[f64]) -> Result<(), TriangleError> {
+    match values.iter().all(|&v| v > 0.0) {
+        true => Ok(()),
+        false => Err(TriangleError::NonPositive),
+    }
+}
+
+fn check_angles(angles: &[f64]) -> Result<(), TriangleError> {
+    match angles.iter().sum::<f64>() < 180.0 - EPSILON {
+        true => Ok(()),
+        false => Err(TriangleError::AngleSum),
+    }
+}
+
+/// Returns side `a` from the sides `b` and `c` and the angle `alpha` between them, by the law of
+/// cosines `a² = b² + c² - 2bc·cos(α)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::law_of_cosines_side;
+///
+/// assert!((law_of_cosines_side(3.0, 4.0, 90.0) - 5.0).abs() < 1e-12);
+/// ```
+pub fn law_of_cosines_side(b: f64, c: f64, alpha: f64) -> f64 {
+    (b * b + c * c - 2.0 * b * c * alpha.to_radians().cos()).sqrt()
+}
+
+/// Returns the angle `alpha` opposite side `a` from all three sides, by the law of cosines.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::law_of_cosines_angle;
+///
+/// assert!((law_of_cosines_angle(1.0, 1.0, 1.0) - 60.0).abs() < 1e-12);
+/// ```
+pub fn law_of_cosines_angle(a: f64, b: f64, c: f64) -> f64 {
+    let cos = (b * b + c * c - a * a) / (2.0 * b * c);
+    cos.clamp(-1.0, 1.0).acos().to_degrees()
+}
+
+/// Returns side `b` opposite `beta` from side `a` opposite `alpha`, by the law of sines
+/// `a / sin(α) = b / sin(β)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::law_of_sines_side;
+///
+/// assert!((law_of_sines_side(1.0, 30.0, 90.0) - 2.0).abs() < 1e-12);
+/// ```
+pub fn law_of_sines_side(a: f64, alpha: f64, beta: f64) -> f64 {
+    a * beta.to_radians().sin() / alpha.to_radians().sin()
+}
+
+/// Solves a triangle from its three sides (SSS).
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::solve_sss;
+///
+/// let triangle = solve_sss(3.0, 4.0, 5.0).unwrap();
+/// assert_eq!(triangle.to_string(), "a = 3, b = 4, c = 5, A = 36.8699°, B = 53.1301°, C = 90°");
+/// assert!(solve_sss(1.0, 2.0, 3.0).is_err());
+/// ```
+pub fn solve_sss(a: f64, b: f64, c: f64) -> Result<Triangle, TriangleError> {
+    check_positive(&[a, b, c])?;
+    if a + b <= c + EPSILON || a + c <= b + EPSILON || b + c <= a + EPSILON {
+        return Err(TriangleError::TriangleInequality);
+    }
+    let alpha = law_of_cosines_angle(a, b, c);
+    let beta = law_of_cosines_angle(b, c, a);
+    Ok(Triangle {
+        a,
+        b,
+        c,
+        alpha,
+        beta,
+        gamma: 180.0 - alpha - beta,
+    })
+}
+
+/// Solves a triangle from two sides `b` and `c` and the angle `alpha` between them (SAS).
+pub fn solve_sas(b: f64, alpha: f64, c: f64) -> Result<Triangle, TriangleError> {
+    check_positive(&[b, alpha, c])?;
+    check_angles(&[alpha])?;
+    let a = law_of_cosines_side(b, c, alpha);
+    let beta = law_of_cosines_angle(b, c, a);
+    Ok(Triangle {
+        a,
+        b,
+        c,
+        alpha,
+        beta,
+        gamma: 180.0 - alpha - beta,
+    })
+}
+
+/// Solves a triangle from two angles `alpha` and `beta` and the side `c` between them (ASA).
+pub fn solve_asa(alpha: f64, c: f64, beta: f64) -> Result<Triangle, TriangleError> {
+    check_positive(&[alpha, c, beta])?;
+    check_angles(&[alpha, beta])?;
+    let gamma = 180.0 - alpha - beta;
+    Ok(Triangle {
+        a: law_of_sines_side(c, gamma, alpha),
+        b: law_of_sines_side(c, gamma, beta),
+        c,
+        alpha,
+        beta,
+        gamma,
+    })
+}
+
+/// Solves a triangle from two angles `alpha` and `beta` and the side `a` opposite `alpha` (AAS).
+pub fn solve_aas(alpha: f64, beta: f64, a: f64) -> Result<Triangle, TriangleError> {
+    check_positive(&[alpha, beta, a])?;
+    check_angles(&[alpha, beta])?;
+    let gamma = 180.0 - alpha - beta;
+    Ok(Triangle {
+        a,
+        b: law_of_sines_side(a, alpha, beta),
+        c: law_of_sines_side(a, alpha, gamma),
+        alpha,
+        beta,
+        gamma,
+    })
+}
+
+/// Solves a triangle from two sides `a` and `b` and the angle `alpha` opposite `a` (SSA).
+///
+/// This is the ambiguous case: depending on the measurements there may be no triangle, one, or
+/// two, which are returned in order of increasing `beta`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::solve_ssa;
+///
+/// // Side a is long enough to reach the base in two places.
+/// let triangles = solve_ssa(6.0, 8.0, 30.0).unwrap();
+/// assert_eq!(triangles.len(), 2);
+/// assert_eq!(
+///     triangles[0].to_string(),
+///     "a = 6, b = 8, c = 11.4003, A = 30°, B = 41.8103°, C = 108.1897°"
+/// );
+/// assert_eq!(
+///     triangles[1].to_string(),
+///     "a = 6, b = 8, c = 2.4561, A = 30°, B = 138.1897°, C = 11.8103°"
+/// );
+///
+/// // Side a is exactly the height, so the triangle has a right angle.
+/// assert_eq!(solve_ssa(4.0, 8.0, 30.0).unwrap().len(), 1);
+/// // Side a is too short to reach the base.
+/// assert!(solve_ssa(3.0, 8.0, 30.0).unwrap().is_empty());
+/// ```
+pub fn solve_ssa(a: f64, b: f64, alpha: f64) -> Result<Vec<Triangle>, TriangleError> {
+    check_positive(&[a, b, alpha])?;
+    check_angles(&[alpha])?;
+    let sin_beta = b * alpha.to_radians().sin() / a;
+    if sin_beta > 1.0 + EPSILON {
+        return Ok(vec![]);
+    }
+    // When sin(β) is 1 the two candidate angles meet at 90°. Comparing sin(β) rather than β
+    // matters because asin magnifies rounding errors near 1.
+    if sin_beta >= 1.0 - EPSILON {
+        return Ok(ssa_triangles(a, b, alpha, vec![90.0]));
+    }
+    let beta = sin_beta.asin().to_degrees();
+    Ok(ssa_triangles(a, b, alpha, vec![beta, 180.0 - beta]))
+}
+
+/// Builds the SSA triangles for each candidate `beta` that leaves room for a third angle.
+fn ssa_triangles(a: f64, b: f64, alpha: f64, betas: Vec<f64>) -> Vec<Triangle> {
+    betas
+        .into_iter()
+        .filter(|&beta| alpha + beta < 180.0 - EPSILON)
+        .map(|beta| {
+            let gamma = 180.0 - alpha - beta;
+            Triangle {
+                a,
+                b,
+                c: law_of_sines_side(a, alpha, gamma),
+                alpha,
+                beta,
+                gamma,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: &Triangle, right: &Triangle) {
+        let (l, r) = (
+            [left.a, left.b, left.c, left.alpha, left.beta, left.gamma],
+            [
+                right.a,
+                right.b,
+                right.c,
+                right.alpha,
+                right.beta,
+                right.gamma,
+            ],
+        );
+        for (x, y) in l.iter().zip(r.iter()) {
+            assert!((x - y).abs() < 1e-9, "{left} != {right}");
+        }
+    }
+
+    #[test]
+    fn test_solvers_agree() {
+        let t = solve_sss(7.0, 8.0, 9.0).unwrap();
+        assert_close(&solve_sas(t.b, t.alpha, t.c).unwrap(), &t);
+        assert_close(&solve_asa(t.alpha, t.c, t.beta).unwrap(), &t);
+        assert_close(&solve_aas(t.alpha, t.beta, t.a).unwrap(), &t);
+        let ssa = solve_ssa(t.a, t.b, t.alpha).unwrap();
+        assert!(ssa.iter().any(|s| (s.c - t.c).abs() < 1e-9));
+        assert!((t.area() - 26.832815729997478).abs() < 1e-9);
+        assert_eq!(t.perimeter(), 24.0);
+    }
+
+    #[test]
+    fn test_ssa_cases() {
+        // An obtuse angle has one triangle when its side is the longest, and none otherwise.
+        assert_eq!(solve_ssa(10.0, 8.0, 120.0).unwrap().len(), 1);
+        assert!(solve_ssa(8.0, 10.0, 120.0).unwrap().is_empty());
+        // a >= b gives one triangle for an acute angle.
+        assert_eq!(solve_ssa(8.0, 8.0, 40.0).unwrap().len(), 1);
+        assert_eq!(solve_ssa(9.0, 8.0, 40.0).unwrap().len(), 1);
+        let right = solve_ssa(4.0, 8.0, 30.0).unwrap();
+        assert!((right[0].beta - 90.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_invalid_triangles() {
+        assert_eq!(
+            solve_sss(1.0, 1.0, 3.0),
+            Err(TriangleError::TriangleInequality)
+        );
+        assert_eq!(solve_sss(0.0, 1.0, 1.0), Err(TriangleError::NonPositive));
+        assert_eq!(solve_asa(90.0, 1.0, 90.0), Err(TriangleError::AngleSum));
+        assert_eq!(solve_sas(1.0, 180.0, 1.0), Err(TriangleError::AngleSum));
+        assert_eq!(
+            solve_ssa(1.0, f64::NAN, 30.0),
+            Err(TriangleError::NonPositive)
+        );
+    }
+}
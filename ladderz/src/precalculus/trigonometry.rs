@@ -0,0 +1,355 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Converts an angle from degrees to radians.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::degrees_to_radians;
+///
+/// assert_eq!(degrees_to_radians(180.0), std::f64::consts::PI);
+/// ```
+pub fn degrees_to_radians(degrees: f64) -> f64 {
+    degrees.to_radians()
+}
+
+/// Converts an angle from radians to degrees.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::radians_to_degrees;
+///
+/// assert_eq!(radians_to_degrees(std::f64::consts::FRAC_PI_2), 90.0);
+/// ```
+pub fn radians_to_degrees(radians: f64) -> f64 {
+    radians.to_degrees()
+}
+
+/// Returns a whole number of degrees in radians as a reduced fraction of π, `(numerator,
+/// denominator)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::degrees_to_pi_fraction;
+///
+/// assert_eq!(degrees_to_pi_fraction(30), (1, 6));
+/// assert_eq!(degrees_to_pi_fraction(-270), (-3, 2));
+/// assert_eq!(degrees_to_pi_fraction(0), (0, 1));
+/// ```
+pub fn degrees_to_pi_fraction(degrees: i64) -> (i64, i64) {
+    let divisor = gcd(degrees.unsigned_abs(), 180) as i64;
+    (degrees / divisor, 180 / divisor)
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// An exact real number of the form `numerator * √radicand / denominator`.
+///
+/// Values are always in lowest terms: the radicand has no square factors, the numerator and
+/// denominator share no factors, and only the numerator carries a sign.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::ExactValue;
+///
+/// let value = ExactValue::new(2, 12, 8);
+/// assert_eq!(value, ExactValue::new(1, 3, 2));
+/// assert_eq!(value.to_string(), "√3/2");
+/// assert_eq!(ExactValue::new(-4, 1, 2).to_string(), "-2");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExactValue {
+    numerator: i64,
+    radicand: u64,
+    denominator: u64,
+}
+
+impl ExactValue {
+    /// Creates `numerator * √radicand / denominator` in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radicand` or `denominator` is 0.
+    pub fn new(numerator: i64, radicand: u64, denominator: u64) -> Self {
+        assert!(radicand > 0, "the radicand must be positive");
+        assert!(denominator > 0, "the denominator must be positive");
+        if numerator == 0 {
+            return ExactValue {
+                numerator: 0,
+                radicand: 1,
+                denominator: 1,
+            };
+        }
+        let (mut numerator, mut radicand) = (numerator, radicand);
+        let mut k = 2;
+        while k * k <= radicand {
+            while radicand % (k * k) == 0 {
+                radicand /= k * k;
+                numerator *= k as i64;
+            }
+            k += 1;
+        }
+        let divisor = gcd(numerator.unsigned_abs(), denominator);
+        ExactValue {
+            numerator: numerator / divisor as i64,
+            radicand,
+            denominator: denominator / divisor,
+        }
+    }
+
+    /// Returns the value as a float.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 * (self.radicand as f64).sqrt() / self.denominator as f64
+    }
+
+    /// Returns `true` if the value is 0.
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    fn negate(self) -> Self {
+        ExactValue {
+            numerator: -self.numerator,
+            ..self
+        }
+    }
+
+    /// Divides `self` by `other`, rationalizing the denominator, or returns `None` if `other` is 0.
+    fn divide(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        // (a√r / b) / (c√s / d) = (a·d·√(r·s)) / (b·c·s)
+        let sign = other.numerator.signum();
+        Some(ExactValue::new(
+            self.numerator * other.denominator as i64 * sign,
+            self.radicand * other.radicand,
+            self.denominator * other.numerator.unsigned_abs() * other.radicand,
+        ))
+    }
+}
+
+impl fmt::Display for ExactValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.numerator, self.radicand) {
+            (n, 1) => write!(f, "{n}")?,
+            (1, r) => write!(f, "√{r}")?,
+            (-1, r) => write!(f, "-√{r}")?,
+            (n, r) => write!(f, "{n}√{r}")?,
+        }
+        if self.denominator != 1 {
+            write!(f, "/{}", self.denominator)?;
+        }
+        Ok(())
+    }
+}
+
+/// The six trigonometric functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrigFunction {
+    Sin,
+    Cos,
+    Tan,
+    Csc,
+    Sec,
+    Cot,
+}
+
+/// The value of a trigonometric function at a unit circle angle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitCircleValue {
+    Exact(ExactValue),
+    /// The function has an asymptote at the angle, like `tan(90°)`.
+    Undefined,
+}
+
+impl fmt::Display for UnitCircleValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitCircleValue::Exact(value) => write!(f, "{value}"),
+            UnitCircleValue::Undefined => write!(f, "undefined"),
+        }
+    }
+}
+
+/// Returns the exact point `(cos θ, sin θ)` on the unit circle for an angle in degrees, if `θ` is
+/// a multiple of 30° or 45°.
+///
+/// # Examples
+///
+/// ```rust
+/// use ladderz::precalculus::{unit_circle_point, ExactValue};
+///
+/// let (x, y) = unit_circle_point(150).unwrap();
+/// assert_eq!((x.to_string(), y.to_string()), ("-√3/2".to_owned(), "1/2".to_owned()));
+/// assert_eq!(unit_circle_point(-45).unwrap().1, ExactValue::new(-1, 2, 2));
+/// assert!(unit_circle_point(20).is_none());
+/// ```
+pub fn unit_circle_point(degrees: i64) -> Option<(ExactValue, ExactValue)> {
+    // (cos, sin) for each reference angle in the first quadrant.
+    let reference = |angle: i64| match angle {
+        0 => Some((ExactValue::new(1, 1, 1), ExactValue::new(0, 1, 1))),
+        30 => Some((ExactValue::new(1, 3, 2), ExactValue::new(1, 1, 2))),
+        45 => Some((ExactValue::new(1, 2, 2), ExactValue::new(1, 2, 2))),
+        60 => Some((ExactValue::new(1, 1, 2), ExactValue::new(1, 3, 2))),
+        90 => Some((ExactValue::new(0, 1, 1), ExactValue::new(1, 1, 1))),
+        _ => None,
+    };
+    let angle = degrees.rem_euclid(360);
+    Some(match angle {
+        0..=90 => reference(angle)?,
+        91..=180 => {
+            let (cos, sin) = reference(180 - angle)?;
+            (cos.negate(), sin)
+        }
+        181..=270 => {
+            let (cos, sin) = reference(angle - 180)?;
+            (cos.negate(), sin.negate())
+        }
+        _ => {
+            let (cos, sin) = reference(360 - angle)?;
+            (cos, sin.negate())
+        }
+    })
+}
+
+impl TrigFunction {
+    /// Returns the function's abbreviated name, e.g. `sin`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TrigFunction::Sin => "sin",
+            TrigFunction::Cos => "cos",
+            TrigFunction::Tan => "tan",
+            TrigFunction::Csc => "csc",
+            TrigFunction::Sec => "sec",
+            TrigFunction::Cot => "cot",
+        }
+    }
+
+    /// Evaluates the function at an angle in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ladderz::precalculus::TrigFunction;
+    ///
+    /// assert!((TrigFunction::Sec.evaluate(0.0) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn evaluate(&self, radians: f64) -> f64 {
+        match self {
+            TrigFunction::Sin => radians.sin(),
+            TrigFunction::Cos => radians.cos(),
+            TrigFunction::Tan => radians.tan(),
+            TrigFunction::Csc => 1.0 / radians.sin(),
+            TrigFunction::Sec => 1.0 / radians.cos(),
+            TrigFunction::Cot => 1.0 / radians.tan(),
+        }
+    }
+
+    /// Returns the exact value of the function at an angle in degrees, if the angle is a
+    /// multiple of 30° or 45°.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ladderz::precalculus::{TrigFunction, UnitCircleValue};
+    ///
+    /// let exact = |f: TrigFunction, degrees| f.exact_value(degrees).unwrap().to_string();
+    /// assert_eq!(exact(TrigFunction::Sin, 60), "√3/2");
+    /// assert_eq!(exact(TrigFunction::Tan, 30), "√3/3");
+    /// assert_eq!(exact(TrigFunction::Csc, 240), "-2√3/3");
+    /// assert_eq!(TrigFunction::Tan.exact_value(90), Some(UnitCircleValue::Undefined));
+    /// assert_eq!(TrigFunction::Cos.exact_value(10), None);
+    /// ```
+    pub fn exact_value(&self, degrees: i64) -> Option<UnitCircleValue> {
+        let (cos, sin) = unit_circle_point(degrees)?;
+        let one = ExactValue::new(1, 1, 1);
+        let value = match self {
+            TrigFunction::Sin => Some(sin),
+            TrigFunction::Cos => Some(cos),
+            TrigFunction::Tan => sin.divide(cos),
+            TrigFunction::Csc => one.divide(sin),
+            TrigFunction::Sec => one.divide(cos),
+            TrigFunction::Cot => cos.divide(sin),
+        };
+        Some(value.map_or(UnitCircleValue::Undefined, UnitCircleValue::Exact))
+    }
+}
+
+impl fmt::Display for TrigFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for TrigFunction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            TrigFunction::Sin,
+            TrigFunction::Cos,
+            TrigFunction::Tan,
+            TrigFunction::Csc,
+            TrigFunction::Sec,
+            TrigFunction::Cot,
+        ]
+        .into_iter()
+        .find(|f| f.name() == s.to_lowercase())
+        .ok_or_else(|| format!("unknown trigonometric function {s:?}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_values_match_floats() {
+        let functions = ["sin", "cos", "tan", "csc", "sec", "cot"];
+        for degrees in (-720..=720).filter(|d| d % 15 == 0) {
+            for function in functions.map(|f| f.parse::<TrigFunction>().unwrap()) {
+                let float = function.evaluate(degrees_to_radians(degrees as f64));
+                match function.exact_value(degrees) {
+                    Some(UnitCircleValue::Exact(value)) => {
+                        assert!(
+                            (value.to_f64() - float).abs() < 1e-9,
+                            "{function}({degrees})"
+                        )
+                    }
+                    Some(UnitCircleValue::Undefined) => assert!(float.abs() > 1e10),
+                    None => assert!(degrees % 30 != 0 && degrees % 45 != 0),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_exact_value_display() {
+        assert_eq!(ExactValue::new(0, 5, 3).to_string(), "0");
+        assert_eq!(ExactValue::new(3, 8, 4).to_string(), "3√2/2");
+        assert_eq!(ExactValue::new(-6, 3, 3).to_string(), "-2√3");
+        assert_eq!(
+            TrigFunction::Cot.exact_value(-30).unwrap().to_string(),
+            "-√3"
+        );
+        assert!("arcsin".parse::<TrigFunction>().is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert!((degrees_to_radians(radians_to_degrees(1.25)) - 1.25).abs() < 1e-12);
+        assert_eq!(degrees_to_pi_fraction(540), (3, 1));
+        assert_eq!(degrees_to_pi_fraction(-45), (-1, 4));
+    }
+}
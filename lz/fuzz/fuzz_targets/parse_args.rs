@@ -0,0 +1,26 @@
+//! Fuzzes how `lz` parses its arguments, from splitting a batch line into words to matching
+//! them against every subject, function and flag.
+//!
+//! Run with `cargo +nightly fuzz run parse_args` from the `lz` directory.
+
+#![no_main]
+
+use clap::Parser;
+use libfuzzer_sys::fuzz_target;
+use lz::registry::split_words;
+use lz::Cli;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(line) = std::str::from_utf8(data) else {
+        return;
+    };
+    // Lines are split the way `lz batch` splits them, which is how a shell would quote them.
+    let Ok(words) = split_words(line) else {
+        return;
+    };
+    let args = std::iter::once("lz".to_owned()).chain(words);
+    if let Err(e) = Cli::try_parse_from(args) {
+        // Rendering the error runs clap's suggestions and usage formatting too.
+        let _ = e.to_string();
+    }
+});
@@ -0,0 +1,558 @@
+use clap::{Args, Subcommand, ValueEnum};
+use ladderz::bioinformatics::{
+    align_with_matrix, AlignmentMode, Alphabet, FastaReader, FastaRecord, FastaWriter, FastqReader,
+    GapPenalty, RecordError, Scoring, ScoringMatrix, Sequence,
+};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::PathBuf;
+
+/// A sequence given on the command line, or the records of a FASTA or FASTQ file.
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+pub struct SequenceInput {
+    /// The sequence.
+    sequence: Option<String>,
+    /// Read every record from a FASTA file instead.
+    #[arg(long, value_name = "FILE")]
+    fasta: Option<PathBuf>,
+    /// Read every record from a FASTQ file instead.
+    #[arg(long, value_name = "FILE")]
+    fastq: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+#[command(arg_required_else_help(true))]
+pub enum Bioinformatics {
+    /// Finds the fraction of a DNA or RNA sequence's nucleotides that are G or C.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz bioinformatics gc-content AGCTATAG
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The GC content of AGCTATAG is 37.50%.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 0.375
+    /// ```
+    ///
+    /// ## FASTA/FASTQ Input (use `--fasta` or `--fastq`)
+    ///
+    /// Prints one result per record, labelled by the record's identifier.
+    ///
+    /// ```bash
+    /// lz bioinformatics gc-content --fasta reads.fa
+    /// ```
+    ///
+    /// ```bash
+    /// The GC content of read1 is 37.50%.
+    /// The GC content of read2 is 60.00%.
+    /// ```
+    ///
+    /// With `--raw`, each line is the record identifier and its GC content separated by a tab.
+    GcContent {
+        #[command(flatten)]
+        input: SequenceInput,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Finds the reverse complement of a DNA or RNA sequence.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz bioinformatics reverse-complement AAAACCCGGT
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The reverse complement of AAAACCCGGT is ACCGGGTTTT.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// ACCGGGTTTT
+    /// ```
+    ///
+    /// Use `--fasta` or `--fastq` to run on every record in a file. With `--raw`, the results are
+    /// written as FASTA records.
+    ReverseComplement {
+        #[command(flatten)]
+        input: SequenceInput,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Transcribes a DNA sequence into RNA.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz bioinformatics transcribe GATGGAACTTGACTACGTAAATT
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The RNA transcribed from GATGGAACTTGACTACGTAAATT is GAUGGAACUUGACUACGUAAAUU.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// GAUGGAACUUGACUACGUAAAUU
+    /// ```
+    ///
+    /// Use `--fasta` or `--fastq` to run on every record in a file. With `--raw`, the results are
+    /// written as FASTA records.
+    Transcribe {
+        #[command(flatten)]
+        input: SequenceInput,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Translates a DNA or RNA sequence into protein with the standard genetic code.
+    ///
+    /// Stop codons are shown as `*`.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz bioinformatics translate AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The protein translated from AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA is MAMAPRTEINSTRING*.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// MAMAPRTEINSTRING*
+    /// ```
+    ///
+    /// Use `--fasta` or `--fastq` to run on every record in a file. With `--raw`, the results are
+    /// written as FASTA records.
+    Translate {
+        #[command(flatten)]
+        input: SequenceInput,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Counts every substring of length k (k-mer) in a sequence.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz bioinformatics kmer-counts ACGACG 3
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The 3-mer counts of ACGACG are {"ACG": 2, "CGA": 1, "GAC": 1}.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// {"ACG": 2, "CGA": 1, "GAC": 1}
+    /// ```
+    KmerCounts {
+        /// The sequence to count k-mers in.
+        sequence: String,
+        /// The length of each k-mer.
+        k: usize,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Counts the positions at which two sequences of equal length differ.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz bioinformatics hamming-distance GAGCCTACTAACGGGAT CATCGTAATGACGGCCT
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The Hamming distance between GAGCCTACTAACGGGAT and CATCGTAATGACGGCCT is 7.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 7
+    /// ```
+    HammingDistance {
+        /// The first sequence.
+        a: String,
+        /// The second sequence.
+        b: String,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Finds the 0-based positions of every (possibly overlapping) occurrence of a motif.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz bioinformatics find-motif GATATATGCATATACTT ATAT
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The motif ATAT occurs in GATATATGCATATACTT at positions [1, 3, 9].
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// [1, 3, 9]
+    /// ```
+    FindMotif {
+        /// The sequence to search.
+        sequence: String,
+        /// The motif to search for.
+        motif: String,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Aligns two sequences and shows the alignment's score.
+    ///
+    /// The alignment is shown with each sequence's 1-based start and end positions. Between the
+    /// sequences, `|` marks identical residues, `:` a positive score, `.` a score of zero or less,
+    /// and a space a gap.
+    ///
+    /// Nucleotide sequences are scored +2 for a match, -1 for a mismatch and 1 per gap position.
+    /// Other sequences are scored with BLOSUM62, a gap open penalty of 11 and an extend penalty
+    /// of 1. Use `--matrix` to pick `dna`, `blosum62`, `pam250`, or a matrix file in the NCBI
+    /// format.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz bioinformatics align ACGT AGT --mode local
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The local alignment of ACGT and AGT has a score of 5:
+    /// 1 ACGT 4
+    ///   | ||
+    /// 1 A-GT 3
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 5
+    /// ```
+    ///
+    /// ## DP Matrix (use `-t` or `--table`)
+    ///
+    /// Cells on the traceback path are marked with `*`.
+    ///
+    /// ```bash
+    ///     ε   A   G   T
+    /// ε  0*   0   0   0
+    /// A   0  2*   1   0
+    /// C   0  1*   1   0
+    /// G   0   0  3*   2
+    /// T   0  -1   2  5*
+    /// ```
+    Align {
+        /// The first sequence.
+        a: String,
+        /// The second sequence.
+        b: String,
+        /// Whether to align whole sequences or their best-matching regions.
+        #[arg(short = 'm', long, value_enum, default_value_t = Mode::Global)]
+        mode: Mode,
+        /// The scoring matrix: `dna`, `blosum62`, `pam250`, or the path to a matrix file.
+        #[arg(long)]
+        matrix: Option<String>,
+        /// The penalty for the first position of a gap.
+        #[arg(long)]
+        gap_open: Option<i32>,
+        /// The penalty for each further position of a gap (defaults to the open penalty for `dna`).
+        #[arg(long)]
+        gap_extend: Option<i32>,
+        /// Whether or not to also print the DP matrix.
+        #[arg(short = 't', long)]
+        table: bool,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+}
+
+/// Whether to align whole sequences or their best-matching regions.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Mode {
+    /// Needleman–Wunsch: align both sequences end to end.
+    Global,
+    /// Smith–Waterman: align the highest-scoring pair of substrings.
+    Local,
+}
+
+/// Returns the value, or prints the error and exits.
+fn or_exit<T, E: Display>(result: Result<T, E>) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    })
+}
+
+/// Returns the value, or prints the error (naming the record it came from, if any) and exits.
+fn or_exit_in<T, E: Display>(record: Option<&FastaRecord>, result: Result<T, E>) -> T {
+    match record {
+        Some(record) => or_exit(result.map_err(|e| format!("record {}: {e}", record.id))),
+        None => or_exit(result),
+    }
+}
+
+/// Parses a sequence as DNA, RNA or protein, in that order of preference.
+fn parse_sequence(sequence: &str) -> Sequence {
+    or_exit(Sequence::infer(sequence))
+}
+
+/// Calls `f` with each input sequence and, if it was read from a file, its record.
+///
+/// Records are read one at a time, so results are printed as the file streams in.
+fn for_each_sequence(input: SequenceInput, mut f: impl FnMut(Option<&FastaRecord>, &str)) {
+    let open = |path: &PathBuf| {
+        BufReader::new(or_exit(
+            File::open(path).map_err(|e| format!("{}: {e}", path.display())),
+        ))
+    };
+    let (path, records): (
+        PathBuf,
+        Box<dyn Iterator<Item = Result<FastaRecord, RecordError>>>,
+    ) = match input {
+        SequenceInput {
+            sequence: Some(sequence),
+            ..
+        } => return f(None, &sequence),
+        SequenceInput {
+            fasta: Some(path), ..
+        } => {
+            let reader = FastaReader::new(open(&path));
+            (path, Box::new(reader))
+        }
+        SequenceInput {
+            fastq: Some(path), ..
+        } => {
+            let reader = FastqReader::new(open(&path)).map(|r| r.map(FastaRecord::from));
+            (path, Box::new(reader))
+        }
+        _ => unreachable!("clap requires exactly one input"),
+    };
+    for record in records {
+        let record = or_exit(record.map_err(|e| format!("{}: {e}", path.display())));
+        f(Some(&record), &record.sequence);
+    }
+}
+
+/// Prints a sequence computed from `input`. Raw output for a file record is written as FASTA.
+fn print_sequence(
+    record: Option<&FastaRecord>,
+    input: &Sequence,
+    result: &Sequence,
+    description: &str,
+    raw: bool,
+) {
+    match (raw, record) {
+        (true, None) => println!("{result}"),
+        (true, Some(record)) => or_exit(FastaWriter::new(io::stdout().lock()).write_record(
+            &FastaRecord {
+                sequence: result.to_string(),
+                ..record.clone()
+            },
+        )),
+        (false, _) => println!(
+            "The {description} {} is {result}.",
+            record.map_or(input.as_str(), |r| r.id.as_str())
+        ),
+    }
+}
+
+/// Picks the scoring for `align`: the named or file matrix, or else DNA scoring for two
+/// nucleotide sequences and BLOSUM62 otherwise, with any gap penalties overridden.
+fn alignment_scoring(
+    a: &str,
+    b: &str,
+    matrix: Option<&str>,
+    gap_open: Option<i32>,
+    gap_extend: Option<i32>,
+) -> Scoring {
+    let nucleotides = |s: &str| Sequence::infer(s).is_ok_and(|s| s.alphabet() != Alphabet::Protein);
+    let mut scoring = match matrix.map(|m| m.to_ascii_lowercase()).as_deref() {
+        Some("dna") => Scoring::dna(),
+        Some("blosum62") => Scoring::blosum62(),
+        Some("pam250") => Scoring::pam250(),
+        Some(_) => {
+            let path = matrix.unwrap_or_default();
+            let text = or_exit(std::fs::read_to_string(path).map_err(|e| format!("{path}: {e}")));
+            Scoring {
+                matrix: or_exit(ScoringMatrix::parse(&text)),
+                ..Scoring::blosum62()
+            }
+        }
+        None if nucleotides(a) && nucleotides(b) => Scoring::dna(),
+        None => Scoring::blosum62(),
+    };
+    if let Some(open) = gap_open {
+        let extend = if scoring.gap.open == scoring.gap.extend {
+            open
+        } else {
+            scoring.gap.extend
+        };
+        scoring.gap = GapPenalty::affine(open, extend);
+    }
+    if let Some(extend) = gap_extend {
+        scoring.gap.extend = extend;
+    }
+    scoring
+}
+
+pub fn match_bioinformatics(function: Option<Bioinformatics>) {
+    match function {
+        Some(Bioinformatics::GcContent { input, raw }) => {
+            for_each_sequence(input, |record, sequence| {
+                let sequence = or_exit_in(record, Sequence::infer(sequence));
+                let gc = or_exit_in(record, sequence.gc_content());
+                match (raw, record) {
+                    (true, None) => println!("{gc:?}"),
+                    (true, Some(record)) => println!("{}\t{gc:?}", record.id),
+                    (false, _) => println!(
+                        "The GC content of {} is {:.2}%.",
+                        record.map_or(sequence.as_str(), |r| r.id.as_str()),
+                        gc * 100.0
+                    ),
+                }
+            })
+        }
+        Some(Bioinformatics::ReverseComplement { input, raw }) => {
+            for_each_sequence(input, |record, sequence| {
+                let sequence = or_exit_in(record, Sequence::infer(sequence));
+                let result = or_exit_in(record, sequence.reverse_complement());
+                print_sequence(record, &sequence, &result, "reverse complement of", raw);
+            })
+        }
+        Some(Bioinformatics::Transcribe { input, raw }) => {
+            for_each_sequence(input, |record, sequence| {
+                let sequence = or_exit_in(record, Sequence::dna(sequence));
+                let result = or_exit_in(record, sequence.transcribe());
+                print_sequence(record, &sequence, &result, "RNA transcribed from", raw);
+            })
+        }
+        Some(Bioinformatics::Translate { input, raw }) => {
+            for_each_sequence(input, |record, sequence| {
+                let sequence = or_exit_in(record, Sequence::infer(sequence));
+                let result = or_exit_in(record, sequence.translate());
+                print_sequence(record, &sequence, &result, "protein translated from", raw);
+            })
+        }
+        Some(Bioinformatics::KmerCounts { sequence, k, raw }) => {
+            let sequence = parse_sequence(&sequence);
+            let counts: BTreeMap<String, u32> = sequence.kmer_counts(k).into_iter().collect();
+            match raw {
+                true => println!("{counts:?}"),
+                false => println!("The {k}-mer counts of {sequence} are {counts:?}."),
+            }
+        }
+        Some(Bioinformatics::HammingDistance { a, b, raw }) => {
+            let (a, b) = (parse_sequence(&a), parse_sequence(&b));
+            let distance = or_exit(a.hamming_distance(&b));
+            match raw {
+                true => println!("{distance}"),
+                false => println!("The Hamming distance between {a} and {b} is {distance}."),
+            }
+        }
+        Some(Bioinformatics::FindMotif {
+            sequence,
+            motif,
+            raw,
+        }) => {
+            let sequence = parse_sequence(&sequence);
+            let positions = sequence.find_motif(&motif);
+            match raw {
+                true => println!("{positions:?}"),
+                false => println!(
+                    "The motif {} occurs in {sequence} at positions {positions:?}.",
+                    motif.to_ascii_uppercase()
+                ),
+            }
+        }
+        Some(Bioinformatics::Align {
+            a,
+            b,
+            mode,
+            matrix,
+            gap_open,
+            gap_extend,
+            table,
+            raw,
+        }) => {
+            let scoring = alignment_scoring(&a, &b, matrix.as_deref(), gap_open, gap_extend);
+            let mode = match mode {
+                Mode::Global => AlignmentMode::Global,
+                Mode::Local => AlignmentMode::Local,
+            };
+            let alignment = or_exit(align_with_matrix(&a, &b, mode, &scoring));
+            match raw {
+                true => println!("{}", alignment.score),
+                false => println!(
+                    "The {mode} alignment of {} and {} has a score of {}:\n{alignment}",
+                    a.to_ascii_uppercase(),
+                    b.to_ascii_uppercase(),
+                    alignment.score
+                ),
+            }
+            if let (true, Some(matrix)) = (table, &alignment.matrix) {
+                println!("\n{matrix}");
+            }
+        }
+        None => println!("Please provide a function to use."),
+    }
+}
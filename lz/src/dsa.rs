@@ -1,147 +1,2082 @@
-use clap::Subcommand;
-
-#[derive(Subcommand)]
-#[command(arg_required_else_help(true))]
-pub enum Dsa {
-    /// Returns true or false based on whether the vector has a duplicate.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz dsa contains-duplicate 1,2,3,2
-    /// # Alternatively you may delimit the numbers with spaces:
-    /// lz dsa contains-duplicate 1 2 3 2
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// The vector [1, 2, 3, 2] does contain a duplicate.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// true
-    /// ```
-    ContainsDuplicate {
-        /// The vector of numbers to detect whether it has a duplicate.
-        #[arg(value_delimiter = ',', num_args = 1)]
-        n: Vec<i32>,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Returns true or false based on whether string a is an anagram of string b.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz dsa is-anagram marc cram
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// "marc" is an anagram of "cram".
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// true
-    /// ```
-    IsAnagram {
-        /// The first string to compare against.
-        a: String,
-        /// The second string to compare against.
-        b: String,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Returns the indices of two numbers in a vector that sum to a target number.
-    ///
-    /// There is an assumption that there must always exist at least two numbers that
-    /// sum to the target number in the given vector of numbers.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz dsa two-sum 1,2,3 5
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// [1, 2]
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// [1, 2]
-    /// ```
-    TwoSum {
-        /// The vector of numbers as a comma-delimited string.
-        nums: String,
-        /// The number that two numbers from nums must sum to.
-        target: i32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-}
-
-pub fn match_dsa(function: Option<Dsa>) {
-    use ladderz::dsa::*;
-    match function {
-        Some(Dsa::ContainsDuplicate { n, raw }) => match raw {
-            true => println!("{:?}", contains_duplicate(n)),
-            false => {
-                let result = contains_duplicate(n.clone());
-                println!(
-                    "The vector {:?} {} contain a duplicate.",
-                    &n,
-                    if result { "does" } else { "does not" }
-                )
-            }
-        },
-        Some(Dsa::IsAnagram { a, b, raw }) => match raw {
-            true => println!("{:?}", is_anagram(a, b)),
-            false => {
-                let result = is_anagram(a.clone(), b.clone());
-                println!(
-                    "{:?} {} an anagram of {:?}.",
-                    a,
-                    if result { "is" } else { "is not" },
-                    b,
-                )
-            }
-        },
-        Some(Dsa::TwoSum { target, nums, raw }) => {
-            let nums_vec: Vec<i32> = nums
-                .split(',')
-                .map(|num| num.trim())
-                .map(|num| num.parse::<i32>().unwrap())
-                .collect();
-
-            match raw {
-                true => println!("{:?}", two_sum(nums_vec, target)),
-                false => println!(
-                    "The pair of indices of the two numbers that sum to {target} is: {:?}.",
-                    two_sum(nums_vec, target)
-                ),
+use crate::output::{LzOutput, Sentence, ToOutput};
+use crate::registry::{Entry, Function, Subject};
+use clap::Args;
+use ladderz::dsa::*;
+use std::fmt::Display;
+use std::ops::Range;
+use std::str::FromStr;
+
+mod script;
+use script::Replay;
+
+/// The `lz dsa` subject.
+pub const SUBJECT: Subject = Subject {
+    name: "dsa",
+    about: "Data structures and algorithms",
+    functions: &[
+        Entry::of::<ContainsDuplicate>(),
+        Entry::of::<IsAnagram>(),
+        Entry::of::<TwoSum>(),
+        Entry::of::<AllTwoSumPairs>(),
+        Entry::of::<TwoSumSorted>(),
+        Entry::of::<ThreeSum>(),
+        Entry::of::<FourSum>(),
+        Entry::of::<KSum>(),
+        Entry::of::<SubarraySumEqualsK>(),
+        Entry::of::<MaxSubarray>(),
+        Entry::of::<KmpSearch>(),
+        Entry::of::<ZSearch>(),
+        Entry::of::<RabinKarp>(),
+        Entry::of::<LongestCommonSubsequence>(),
+        Entry::of::<LongestCommonSubstring>(),
+        Entry::of::<Levenshtein>(),
+        Entry::of::<DamerauLevenshtein>(),
+        Entry::of::<LongestPalindromicSubstring>(),
+        Entry::of::<GroupAnagrams>(),
+        Entry::of::<SuffixArray>(),
+        Entry::of::<Knapsack>(),
+        Entry::of::<CoinChange>(),
+        Entry::of::<LongestIncreasingSubsequence>(),
+        Entry::of::<MatrixChain>(),
+        Entry::of::<EditDistance>(),
+        Entry::of::<RodCutting>(),
+        Entry::of::<SinglyLinkedList>(),
+        Entry::of::<DoublyLinkedList>(),
+        Entry::of::<Stack>(),
+        Entry::of::<Queue>(),
+        Entry::of::<BinaryHeap>(),
+        Entry::of::<BinarySearchTree>(),
+        Entry::of::<AvlTree>(),
+        Entry::of::<Trie>(),
+        Entry::of::<UnionFind>(),
+        Entry::of::<LruCache>(),
+    ],
+};
+
+/// Returns true or false based on whether the vector has a duplicate.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa contains-duplicate 1,2,3,2
+/// # Alternatively you may delimit the numbers with spaces:
+/// lz dsa contains-duplicate 1 2 3 2
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The vector [1, 2, 3, 2] does contain a duplicate.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// true
+/// ```
+#[derive(Args)]
+pub struct ContainsDuplicate {
+    /// The vector of numbers to detect whether it has a duplicate.
+    #[arg(value_delimiter = ',', num_args = 1)]
+    n: Vec<i32>,
+}
+
+impl Function for ContainsDuplicate {
+    const NAME: &'static str = "contains-duplicate";
+    type Output = bool;
+
+    fn run(&self) -> Result<bool, String> {
+        Ok(contains_duplicate(&self.n))
+    }
+
+    fn prose(&self, output: &bool) -> Sentence {
+        let template = match output {
+            true => "The vector {} does contain a duplicate.",
+            false => "The vector {} does not contain a duplicate.",
+        };
+        Sentence::new(template, [self.n.to_output()])
+    }
+}
+
+/// Returns true or false based on whether string a is an anagram of string b.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa is-anagram marc cram
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// "marc" is an anagram of "cram".
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// true
+/// ```
+///
+/// ## Unicode Mode (use `-u` or `--unicode`)
+///
+/// Ignores case and compares user-perceived characters (grapheme clusters), so
+/// `lz dsa is-anagram -u Café ÉFAC` is an anagram.
+#[derive(Args)]
+pub struct IsAnagram {
+    /// The first string to compare against.
+    a: String,
+    /// The second string to compare against.
+    b: String,
+    /// Whether or not to ignore case and compare grapheme clusters.
+    #[arg(short = 'u', long)]
+    unicode: bool,
+}
+
+impl Function for IsAnagram {
+    const NAME: &'static str = "is-anagram";
+    type Output = bool;
+
+    fn run(&self) -> Result<bool, String> {
+        Ok(if self.unicode {
+            is_anagram_unicode(&self.a, &self.b)
+        } else {
+            is_anagram(&self.a, &self.b)
+        })
+    }
+
+    fn prose(&self, output: &bool) -> Sentence {
+        let template = match output {
+            true => "{} is an anagram of {}.",
+            false => "{} is not an anagram of {}.",
+        };
+        Sentence::new(template, [self.a.to_output(), self.b.to_output()])
+    }
+}
+
+/// Returns the indices of two numbers in a vector that sum to a target number, with the later
+/// index first, or an empty vector if no two numbers do.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa two-sum 1,2,3 5
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The pair of indices of the two numbers that sum to 5 is: [2, 1].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [2, 1]
+/// ```
+#[derive(Args)]
+pub struct TwoSum {
+    /// The vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+    /// The number that two numbers from nums must sum to.
+    #[arg(allow_hyphen_values = true)]
+    target: i32,
+}
+
+impl Function for TwoSum {
+    const NAME: &'static str = "two-sum";
+    type Output = Vec<usize>;
+
+    fn run(&self) -> Result<Vec<usize>, String> {
+        Ok(two_sum(&self.nums.0, self.target))
+    }
+
+    fn prose(&self, output: &Vec<usize>) -> Sentence {
+        if output.is_empty() {
+            return Sentence::new("No two numbers sum to {}.", [self.target.to_output()]);
+        }
+        Sentence::new(
+            "The pair of indices of the two numbers that sum to {} is: {}.",
+            [self.target.to_output(), output.to_output()],
+        )
+    }
+}
+
+/// Returns the indices of every pair of numbers in a vector that sum to a target number.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa all-two-sum-pairs 1,3,2,2,3 5
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The pairs of indices of the numbers that sum to 5 are: [(1, 2), (1, 3), (2, 4), (3, 4)].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [(1, 2), (1, 3), (2, 4), (3, 4)]
+/// ```
+#[derive(Args)]
+pub struct AllTwoSumPairs {
+    /// The vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+    /// The number that each pair of numbers from nums must sum to.
+    #[arg(allow_hyphen_values = true)]
+    target: i32,
+}
+
+impl Function for AllTwoSumPairs {
+    const NAME: &'static str = "all-two-sum-pairs";
+    type Output = Vec<(usize, usize)>;
+
+    fn run(&self) -> Result<Vec<(usize, usize)>, String> {
+        Ok(all_two_sum_pairs(&self.nums.0, self.target))
+    }
+
+    fn prose(&self, output: &Vec<(usize, usize)>) -> Sentence {
+        Sentence::new(
+            "The pairs of indices of the numbers that sum to {} are: {}.",
+            [self.target.to_output(), output.to_output()],
+        )
+    }
+}
+
+/// Returns the indices of two numbers in a sorted vector that sum to a target number.
+///
+/// The numbers must be in ascending order. Two pointers move in from both ends of the vector.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa two-sum-sorted 1,2,4,7,11 9
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The pair of indices of the two numbers that sum to 9 is: (1, 3).
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// (1, 3)
+/// ```
+#[derive(Args)]
+pub struct TwoSumSorted {
+    /// The ascending vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+    /// The number that two numbers from nums must sum to.
+    #[arg(allow_hyphen_values = true)]
+    target: i32,
+}
+
+impl Function for TwoSumSorted {
+    const NAME: &'static str = "two-sum-sorted";
+    type Output = Option<(usize, usize)>;
+
+    fn run(&self) -> Result<Option<(usize, usize)>, String> {
+        Ok(two_sum_sorted(&self.nums.0, self.target))
+    }
+
+    fn prose(&self, output: &Option<(usize, usize)>) -> Sentence {
+        match output {
+            Some(pair) => Sentence::new(
+                "The pair of indices of the two numbers that sum to {} is: {}.",
+                [self.target.to_output(), pair.to_output()],
+            ),
+            None => Sentence::new("No two numbers sum to {}.", [self.target.to_output()]),
+        }
+    }
+}
+
+/// Returns the unique triplets of numbers in a vector that sum to a target number.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa three-sum -1,0,1,2,-1,-4 0
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The unique triplets of numbers that sum to 0 are: [[-1, -1, 2], [-1, 0, 1]].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [[-1, -1, 2], [-1, 0, 1]]
+/// ```
+#[derive(Args)]
+pub struct ThreeSum {
+    /// The vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+    /// The number that each triplet of numbers from nums must sum to.
+    #[arg(allow_hyphen_values = true)]
+    target: i32,
+}
+
+impl Function for ThreeSum {
+    const NAME: &'static str = "three-sum";
+    type Output = Vec<[i32; 3]>;
+
+    fn run(&self) -> Result<Vec<[i32; 3]>, String> {
+        Ok(three_sum(&self.nums.0, self.target))
+    }
+
+    fn prose(&self, output: &Vec<[i32; 3]>) -> Sentence {
+        Sentence::new(
+            "The unique triplets of numbers that sum to {} are: {}.",
+            [self.target.to_output(), output.to_output()],
+        )
+    }
+}
+
+/// Returns the unique quadruplets of numbers in a vector that sum to a target number.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa four-sum 1,0,-1,0,-2,2 0
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The unique quadruplets of numbers that sum to 0 are: [[-2, -1, 1, 2], [-2, 0, 0, 2], [-1, 0, 0, 1]].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [[-2, -1, 1, 2], [-2, 0, 0, 2], [-1, 0, 0, 1]]
+/// ```
+#[derive(Args)]
+pub struct FourSum {
+    /// The vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+    /// The number that each quadruplet of numbers from nums must sum to.
+    #[arg(allow_hyphen_values = true)]
+    target: i32,
+}
+
+impl Function for FourSum {
+    const NAME: &'static str = "four-sum";
+    type Output = Vec<[i32; 4]>;
+
+    fn run(&self) -> Result<Vec<[i32; 4]>, String> {
+        Ok(four_sum(&self.nums.0, self.target))
+    }
+
+    fn prose(&self, output: &Vec<[i32; 4]>) -> Sentence {
+        Sentence::new(
+            "The unique quadruplets of numbers that sum to {} are: {}.",
+            [self.target.to_output(), output.to_output()],
+        )
+    }
+}
+
+/// Returns the unique combinations of k numbers in a vector that sum to a target number.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa k-sum 1,2,3,4,5 2 6
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The unique combinations of 2 numbers that sum to 6 are: [[1, 5], [2, 4]].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [[1, 5], [2, 4]]
+/// ```
+#[derive(Args)]
+pub struct KSum {
+    /// The vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+    /// How many numbers each combination must contain.
+    k: usize,
+    /// The number that each combination of numbers from nums must sum to.
+    #[arg(allow_hyphen_values = true)]
+    target: i32,
+}
+
+impl Function for KSum {
+    const NAME: &'static str = "k-sum";
+    type Output = Vec<Vec<i32>>;
+
+    fn run(&self) -> Result<Vec<Vec<i32>>, String> {
+        Ok(k_sum(&self.nums.0, self.k, self.target))
+    }
+
+    fn prose(&self, output: &Vec<Vec<i32>>) -> Sentence {
+        Sentence::new(
+            "The unique combinations of {} numbers that sum to {} are: {}.",
+            [
+                self.k.to_output(),
+                self.target.to_output(),
+                output.to_output(),
+            ],
+        )
+    }
+}
+
+/// Counts the contiguous subarrays of a vector whose numbers sum to k.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa subarray-sum-equals-k 1,2,3 3
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// There are 2 contiguous subarrays that sum to 3.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 2
+/// ```
+#[derive(Args)]
+pub struct SubarraySumEqualsK {
+    /// The vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+    /// The number that each subarray must sum to.
+    #[arg(allow_hyphen_values = true)]
+    k: i32,
+}
+
+impl Function for SubarraySumEqualsK {
+    const NAME: &'static str = "subarray-sum-equals-k";
+    type Output = usize;
+
+    fn run(&self) -> Result<usize, String> {
+        Ok(subarray_sum_equals_k(&self.nums.0, self.k))
+    }
+
+    fn prose(&self, output: &usize) -> Sentence {
+        let template = match output {
+            1 => "There is {} contiguous subarray that sums to {}.",
+            _ => "There are {} contiguous subarrays that sum to {}.",
+        };
+        Sentence::new(template, [output.to_output(), self.k.to_output()])
+    }
+}
+
+/// Returns the largest sum of a contiguous subarray of a vector (Kadane's algorithm).
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa max-subarray -2,1,-3,4,-1,2,1,-5,4
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The maximum subarray sum is 6, from index 3 to 6: [4, -1, 2, 1].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 6
+/// ```
+#[derive(Args)]
+pub struct MaxSubarray {
+    /// The vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+}
+
+impl Function for MaxSubarray {
+    const NAME: &'static str = "max-subarray";
+    type Output = Option<Subarray>;
+
+    fn run(&self) -> Result<Option<Subarray>, String> {
+        Ok(max_subarray(&self.nums.0).map(|(sum, range)| Subarray { sum, range }))
+    }
+
+    fn prose(&self, output: &Option<Subarray>) -> Sentence {
+        match output {
+            Some(Subarray { sum, range }) => Sentence::new(
+                "The maximum subarray sum is {}, from index {} to {}: {}.",
+                [
+                    sum.to_output(),
+                    range.start.to_output(),
+                    (range.end - 1).to_output(),
+                    self.nums.0[range.clone()].to_output(),
+                ],
+            ),
+            None => Sentence::new("The vector is empty.", []),
+        }
+    }
+}
+
+/// Finds the starting character indices of a pattern in a text using Knuth–Morris–Pratt.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa kmp-search abababa aba
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The pattern "aba" occurs in "abababa" at indices [0, 2, 4].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [0, 2, 4]
+/// ```
+#[derive(Args)]
+pub struct KmpSearch {
+    /// The text to search in.
+    text: String,
+    /// The pattern to search for.
+    pattern: String,
+}
+
+impl Function for KmpSearch {
+    const NAME: &'static str = "kmp-search";
+    type Output = Vec<usize>;
+
+    fn run(&self) -> Result<Vec<usize>, String> {
+        Ok(kmp_search(&self.text, &self.pattern))
+    }
+
+    fn prose(&self, output: &Vec<usize>) -> Sentence {
+        search_sentence(&self.text, &self.pattern, output)
+    }
+}
+
+/// Finds the starting character indices of a pattern in a text using the Z-algorithm.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa z-search abababa aba
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The pattern "aba" occurs in "abababa" at indices [0, 2, 4].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [0, 2, 4]
+/// ```
+#[derive(Args)]
+pub struct ZSearch {
+    /// The text to search in.
+    text: String,
+    /// The pattern to search for.
+    pattern: String,
+}
+
+impl Function for ZSearch {
+    const NAME: &'static str = "z-search";
+    type Output = Vec<usize>;
+
+    fn run(&self) -> Result<Vec<usize>, String> {
+        Ok(z_search(&self.text, &self.pattern))
+    }
+
+    fn prose(&self, output: &Vec<usize>) -> Sentence {
+        search_sentence(&self.text, &self.pattern, output)
+    }
+}
+
+/// Finds the starting character indices of a pattern in a text using Rabin–Karp with a rolling hash.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa rabin-karp abababa aba
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The pattern "aba" occurs in "abababa" at indices [0, 2, 4].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [0, 2, 4]
+/// ```
+#[derive(Args)]
+pub struct RabinKarp {
+    /// The text to search in.
+    text: String,
+    /// The pattern to search for.
+    pattern: String,
+}
+
+impl Function for RabinKarp {
+    const NAME: &'static str = "rabin-karp";
+    type Output = Vec<usize>;
+
+    fn run(&self) -> Result<Vec<usize>, String> {
+        Ok(rabin_karp(&self.text, &self.pattern))
+    }
+
+    fn prose(&self, output: &Vec<usize>) -> Sentence {
+        search_sentence(&self.text, &self.pattern, output)
+    }
+}
+
+/// Finds a longest common subsequence of two strings.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa longest-common-subsequence ABCBDAB BDCABA
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The longest common subsequence of "ABCBDAB" and "BDCABA" is "BCBA".
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// BCBA
+/// ```
+#[derive(Args)]
+pub struct LongestCommonSubsequence {
+    /// The first string to compare.
+    a: String,
+    /// The second string to compare.
+    b: String,
+}
+
+impl Function for LongestCommonSubsequence {
+    const NAME: &'static str = "longest-common-subsequence";
+    type Output = String;
+
+    fn run(&self) -> Result<String, String> {
+        Ok(longest_common_subsequence(&self.a, &self.b))
+    }
+
+    fn prose(&self, output: &String) -> Sentence {
+        Sentence::new(
+            "The longest common subsequence of {} and {} is {}.",
+            [self.a.to_output(), self.b.to_output(), output.to_output()],
+        )
+    }
+}
+
+/// Finds the longest common substring of two strings.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa longest-common-substring xabcdey zbcdq
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The longest common substring of "xabcdey" and "zbcdq" is "bcd".
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// bcd
+/// ```
+#[derive(Args)]
+pub struct LongestCommonSubstring {
+    /// The first string to compare.
+    a: String,
+    /// The second string to compare.
+    b: String,
+}
+
+impl Function for LongestCommonSubstring {
+    const NAME: &'static str = "longest-common-substring";
+    type Output = String;
+
+    fn run(&self) -> Result<String, String> {
+        Ok(longest_common_substring(&self.a, &self.b))
+    }
+
+    fn prose(&self, output: &String) -> Sentence {
+        Sentence::new(
+            "The longest common substring of {} and {} is {}.",
+            [self.a.to_output(), self.b.to_output(), output.to_output()],
+        )
+    }
+}
+
+/// Finds the Levenshtein (edit) distance between two strings and shows an optimal alignment.
+///
+/// In the alignment `|` marks a match, `.` a substitution, and `-` an insertion or deletion.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa levenshtein kitten sitting
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The Levenshtein distance between "kitten" and "sitting" is 3:
+/// kitten-
+/// .|||.|
+/// sitting
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 3
+/// ```
+#[derive(Args)]
+pub struct Levenshtein {
+    /// The first string to compare.
+    a: String,
+    /// The second string to compare.
+    b: String,
+}
+
+impl Function for Levenshtein {
+    const NAME: &'static str = "levenshtein";
+    type Output = Alignment;
+
+    fn run(&self) -> Result<Alignment, String> {
+        Ok(levenshtein_alignment(&self.a, &self.b))
+    }
+
+    fn prose(&self, output: &Alignment) -> Sentence {
+        alignment_sentence("Levenshtein", &self.a, &self.b, output)
+    }
+}
+
+/// Finds the Damerau–Levenshtein distance between two strings and shows an optimal alignment.
+///
+/// Swapping two adjacent characters counts as one edit and is marked with `~~` in the alignment.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa damerau-levenshtein form from
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The Damerau–Levenshtein distance between "form" and "from" is 1:
+/// form
+/// |~~|
+/// from
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 1
+/// ```
+#[derive(Args)]
+pub struct DamerauLevenshtein {
+    /// The first string to compare.
+    a: String,
+    /// The second string to compare.
+    b: String,
+}
+
+impl Function for DamerauLevenshtein {
+    const NAME: &'static str = "damerau-levenshtein";
+    type Output = Alignment;
+
+    fn run(&self) -> Result<Alignment, String> {
+        Ok(damerau_levenshtein_alignment(&self.a, &self.b))
+    }
+
+    fn prose(&self, output: &Alignment) -> Sentence {
+        alignment_sentence("Damerau–Levenshtein", &self.a, &self.b, output)
+    }
+}
+
+/// Finds the longest palindromic substring of a string using Manacher's algorithm.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa longest-palindromic-substring babad
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The longest palindromic substring of "babad" is "bab".
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// bab
+/// ```
+#[derive(Args)]
+pub struct LongestPalindromicSubstring {
+    /// The string to search for palindromes.
+    s: String,
+}
+
+impl Function for LongestPalindromicSubstring {
+    const NAME: &'static str = "longest-palindromic-substring";
+    type Output = String;
+
+    fn run(&self) -> Result<String, String> {
+        Ok(longest_palindromic_substring(&self.s))
+    }
+
+    fn prose(&self, output: &String) -> Sentence {
+        Sentence::new(
+            "The longest palindromic substring of {} is {}.",
+            [self.s.to_output(), output.to_output()],
+        )
+    }
+}
+
+/// Groups words that are anagrams of each other.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa group-anagrams eat,tea,tan,ate,nat,bat
+/// # Alternatively you may delimit the words with spaces:
+/// lz dsa group-anagrams eat tea tan ate nat bat
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The anagram groups are [["eat", "tea", "ate"], ["tan", "nat"], ["bat"]].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [["eat", "tea", "ate"], ["tan", "nat"], ["bat"]]
+/// ```
+#[derive(Args)]
+pub struct GroupAnagrams {
+    /// The words to group.
+    #[arg(value_delimiter = ',', num_args = 1..)]
+    words: Vec<String>,
+}
+
+impl Function for GroupAnagrams {
+    const NAME: &'static str = "group-anagrams";
+    type Output = Vec<Vec<String>>;
+
+    fn run(&self) -> Result<Vec<Vec<String>>, String> {
+        Ok(group_anagrams(&self.words))
+    }
+
+    fn prose(&self, output: &Vec<Vec<String>>) -> Sentence {
+        Sentence::new("The anagram groups are {}.", [output.to_output()])
+    }
+}
+
+/// Builds the suffix array and LCP array of a string.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa suffix-array banana
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// Rank  Index  LCP  Suffix
+///    0      5    0  a
+///    1      3    1  ana
+///    2      1    3  anana
+///    3      0    0  banana
+///    4      4    0  na
+///    5      2    2  nana
+/// ```
+///
+/// ## JSON Output (use `--format json`)
+///
+/// ```bash
+/// [{"Index":5,"LCP":0,"Rank":0,"Suffix":"a"},{"Index":3,"LCP":1,"Rank":1,"Suffix":"ana"},{"Index":1,"LCP":3,"Rank":2,"Suffix":"anana"},{"Index":0,"LCP":0,"Rank":3,"Suffix":"banana"},{"Index":4,"LCP":0,"Rank":4,"Suffix":"na"},{"Index":2,"LCP":2,"Rank":5,"Suffix":"nana"}]
+/// ```
+#[derive(Args)]
+pub struct SuffixArray {
+    /// The string to build the suffix array of.
+    s: String,
+}
+
+impl Function for SuffixArray {
+    const NAME: &'static str = "suffix-array";
+    type Output = LzOutput;
+
+    fn run(&self) -> Result<LzOutput, String> {
+        let suffix_array = suffix_array(&self.s);
+        let lcp = lcp_array(&self.s, &suffix_array);
+        let chars: Vec<char> = self.s.chars().collect();
+        let rows = suffix_array
+            .iter()
+            .zip(&lcp)
+            .enumerate()
+            .map(|(rank, (&index, &prefix))| {
+                let suffix: String = chars[index..].iter().collect();
+                vec![
+                    rank.to_output(),
+                    index.to_output(),
+                    prefix.to_output(),
+                    LzOutput::Literal(suffix),
+                ]
+            })
+            .collect();
+        let headers = ["Rank", "Index", "LCP", "Suffix"]
+            .map(String::from)
+            .to_vec();
+        Ok(LzOutput::Table { headers, rows })
+    }
+
+    fn prose(&self, output: &LzOutput) -> Sentence {
+        Sentence::new("{}", [output.clone()])
+    }
+}
+
+/// Finds the largest total value of items that fit in a knapsack of a given capacity.
+///
+/// Each item is taken at most once (0/1 knapsack) unless `-u` or `--unbounded` is used.
+///
+/// Use `-t` or `--table` to also print the DP table as an aligned grid, where `-` marks an empty cell.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa knapsack 1,3,4 15,20,30 4 --table
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The maximum value of items that fit in a knapsack of capacity 4 is 35.
+///
+///                       0   1   2   3   4
+/// no items              0   0   0   0   0
+/// + item 1 (w=1, v=15)  0  15  15  15  15
+/// + item 2 (w=3, v=20)  0  15  15  20  35
+/// + item 3 (w=4, v=30)  0  15  15  20  35
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 35
+/// ```
+#[derive(Args)]
+pub struct Knapsack {
+    /// The weight of each item as a comma-delimited string.
+    #[arg(value_parser = parse_nums::<usize>)]
+    weights: Nums<usize>,
+    /// The value of each item as a comma-delimited string.
+    #[arg(value_parser = parse_nums::<u64>)]
+    values: Nums<u64>,
+    /// The largest total weight the knapsack can hold.
+    capacity: usize,
+    /// Whether or not each item may be taken any number of times.
+    #[arg(short = 'u', long)]
+    unbounded: bool,
+    /// Whether or not to print the DP table as an aligned grid.
+    #[arg(short = 't', long)]
+    table: bool,
+}
+
+impl Knapsack {
+    fn dp(&self) -> DpTable {
+        if self.unbounded {
+            unbounded_knapsack_table(&self.weights.0, &self.values.0, self.capacity)
+        } else {
+            knapsack_01_table(&self.weights.0, &self.values.0, self.capacity)
+        }
+    }
+}
+
+impl Function for Knapsack {
+    const NAME: &'static str = "knapsack";
+    type Output = u64;
+
+    fn run(&self) -> Result<u64, String> {
+        let (weights, values) = (self.weights.0.len(), self.values.0.len());
+        if weights != values {
+            return Err(format!(
+                "every item needs both a weight and a value, but the weights list {weights} \
+                 items and the values list {values}"
+            ));
+        }
+        Ok(self.dp().last().unwrap_or(0))
+    }
+
+    fn prose(&self, output: &u64) -> Sentence {
+        Sentence::new(
+            "The maximum value of items that fit in a knapsack of capacity {} is {}.",
+            [self.capacity.to_output(), output.to_output()],
+        )
+    }
+
+    fn details(&self, _output: &u64) -> Option<LzOutput> {
+        self.table.then(|| self.dp().to_output())
+    }
+}
+
+/// Counts the ways to make change for an amount, or finds the fewest coins needed.
+///
+/// Use `-m` or `--min-coins` to find the fewest coins that make the amount instead.
+///
+/// Use `-t` or `--table` to also print the DP table as an aligned grid, where `-` marks an empty cell.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa coin-change 1,2,5 5
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// There are 4 ways to make 5 from the coins [1, 2, 5].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 4
+/// ```
+#[derive(Args)]
+pub struct CoinChange {
+    /// The coin denominations as a comma-delimited string.
+    #[arg(value_parser = parse_nums::<usize>)]
+    coins: Nums<usize>,
+    /// The amount to make change for.
+    amount: usize,
+    /// Whether or not to find the fewest coins instead of counting the ways.
+    #[arg(short = 'm', long)]
+    min_coins: bool,
+    /// Whether or not to print the DP table as an aligned grid.
+    #[arg(short = 't', long)]
+    table: bool,
+}
+
+impl CoinChange {
+    fn dp(&self) -> DpTable {
+        if self.min_coins {
+            coin_change_min_coins_table(&self.coins.0, self.amount)
+        } else {
+            coin_change_ways_table(&self.coins.0, self.amount)
+        }
+    }
+}
+
+impl Function for CoinChange {
+    const NAME: &'static str = "coin-change";
+    type Output = Change;
+
+    fn run(&self) -> Result<Change, String> {
+        Ok(if self.min_coins {
+            Change::Fewest(self.dp().last())
+        } else {
+            Change::Ways(self.dp().last().unwrap_or(0))
+        })
+    }
+
+    fn prose(&self, output: &Change) -> Sentence {
+        let (coins, amount) = (self.coins.0.to_output(), self.amount.to_output());
+        match output {
+            Change::Fewest(Some(fewest)) => Sentence::new(
+                "The fewest coins from {} that make {} is {}.",
+                [coins, amount, fewest.to_output()],
+            ),
+            Change::Fewest(None) => {
+                Sentence::new("{} can't be made from the coins {}.", [amount, coins])
             }
+            Change::Ways(1) => Sentence::new(
+                "There is {} way to make {} from the coins {}.",
+                [LzOutput::Int(1), amount, coins],
+            ),
+            Change::Ways(ways) => Sentence::new(
+                "There are {} ways to make {} from the coins {}.",
+                [ways.to_output(), amount, coins],
+            ),
         }
-        None => {
-            println!("Please provide a function to use.");
+    }
+
+    fn details(&self, _output: &Change) -> Option<LzOutput> {
+        self.table.then(|| self.dp().to_output())
+    }
+}
+
+/// Finds the longest strictly increasing subsequence of a vector.
+///
+/// Use `-t` or `--table` to also print the DP table as an aligned grid, where `-` marks an empty cell.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa longest-increasing-subsequence 10,9,2,5,3,7,101,18
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The longest increasing subsequence of [10, 9, 2, 5, 3, 7, 101, 18] is [2, 5, 7, 101].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [2, 5, 7, 101]
+/// ```
+#[derive(Args)]
+pub struct LongestIncreasingSubsequence {
+    /// The vector of numbers as a comma-delimited string.
+    #[arg(allow_hyphen_values = true, value_parser = parse_nums::<i32>)]
+    nums: Nums<i32>,
+    /// Whether or not to print the DP table as an aligned grid.
+    #[arg(short = 't', long)]
+    table: bool,
+}
+
+impl Function for LongestIncreasingSubsequence {
+    const NAME: &'static str = "longest-increasing-subsequence";
+    type Output = Vec<i32>;
+
+    fn run(&self) -> Result<Vec<i32>, String> {
+        Ok(longest_increasing_subsequence(&self.nums.0))
+    }
+
+    fn prose(&self, output: &Vec<i32>) -> Sentence {
+        Sentence::new(
+            "The longest increasing subsequence of {} is {}.",
+            [self.nums.0.to_output(), output.to_output()],
+        )
+    }
+
+    fn details(&self, _output: &Vec<i32>) -> Option<LzOutput> {
+        self.table
+            .then(|| longest_increasing_subsequence_table(&self.nums.0).to_output())
+    }
+}
+
+/// Finds the fewest scalar multiplications needed to multiply a chain of matrices.
+///
+/// Matrix `Ai` has `dims[i - 1]` rows and `dims[i]` columns, so at least 2 dimensions are needed.
+///
+/// Use `-t` or `--table` to also print the DP table as an aligned grid, where `-` marks an empty cell.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa matrix-chain 10,30,5,60
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// Multiplying the matrices as ((A1A2)A3) takes 4500 scalar multiplications.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 4500
+/// ```
+#[derive(Args)]
+pub struct MatrixChain {
+    /// The matrix dimensions as a comma-delimited string.
+    #[arg(value_parser = parse_nums::<u64>)]
+    dims: Nums<u64>,
+    /// Whether or not to print the DP table as an aligned grid.
+    #[arg(short = 't', long)]
+    table: bool,
+}
+
+impl Function for MatrixChain {
+    const NAME: &'static str = "matrix-chain";
+    type Output = u64;
+
+    fn run(&self) -> Result<u64, String> {
+        if self.dims.0.len() < 2 {
+            return Err("a chain of matrices needs at least 2 dimensions".to_owned());
+        }
+        Ok(matrix_chain_multiplication(&self.dims.0))
+    }
+
+    fn prose(&self, output: &u64) -> Sentence {
+        Sentence::new(
+            "Multiplying the matrices as {} takes {} scalar multiplications.",
+            [
+                LzOutput::Literal(matrix_chain_order(&self.dims.0)),
+                output.to_output(),
+            ],
+        )
+    }
+
+    fn details(&self, _output: &u64) -> Option<LzOutput> {
+        self.table
+            .then(|| matrix_chain_table(&self.dims.0).to_output())
+    }
+}
+
+/// Finds the edit (Levenshtein) distance between two strings.
+///
+/// Use `-t` or `--table` to also print the DP table as an aligned grid, where `-` marks an empty cell.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa edit-distance ab b --table
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The edit distance between "ab" and "b" is 1.
+///
+///    ε  b
+/// ε  0  1
+/// a  1  1
+/// b  2  1
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 1
+/// ```
+#[derive(Args)]
+pub struct EditDistance {
+    /// The first string to compare.
+    a: String,
+    /// The second string to compare.
+    b: String,
+    /// Whether or not to print the DP table as an aligned grid.
+    #[arg(short = 't', long)]
+    table: bool,
+}
+
+impl Function for EditDistance {
+    const NAME: &'static str = "edit-distance";
+    type Output = u64;
+
+    fn run(&self) -> Result<u64, String> {
+        Ok(edit_distance_table(&self.a, &self.b).last().unwrap_or(0))
+    }
+
+    fn prose(&self, output: &u64) -> Sentence {
+        Sentence::new(
+            "The edit distance between {} and {} is {}.",
+            [self.a.to_output(), self.b.to_output(), output.to_output()],
+        )
+    }
+
+    fn details(&self, _output: &u64) -> Option<LzOutput> {
+        self.table
+            .then(|| edit_distance_table(&self.a, &self.b).to_output())
+    }
+}
+
+/// Finds the best revenue from cutting a rod into pieces with given prices.
+///
+/// The price of a piece of length `i` is the `i`-th price.
+///
+/// Use `-t` or `--table` to also print the DP table as an aligned grid, where `-` marks an empty cell.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa rod-cutting 1,5,8,9 4 --table
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The best revenue from cutting a rod of length 4 is 10.
+///
+///             0  1  2  3   4
+/// best price  0  1  5  8  10
+/// first cut   -  1  2  3   2
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 10
+/// ```
+#[derive(Args)]
+pub struct RodCutting {
+    /// The price of each piece length, starting from length 1, as a comma-delimited string.
+    #[arg(value_parser = parse_nums::<u64>)]
+    prices: Nums<u64>,
+    /// The length of the rod to cut.
+    length: usize,
+    /// Whether or not to print the DP table as an aligned grid.
+    #[arg(short = 't', long)]
+    table: bool,
+}
+
+impl Function for RodCutting {
+    const NAME: &'static str = "rod-cutting";
+    type Output = u64;
+
+    fn run(&self) -> Result<u64, String> {
+        Ok(rod_cutting(&self.prices.0, self.length))
+    }
+
+    fn prose(&self, output: &u64) -> Sentence {
+        Sentence::new(
+            "The best revenue from cutting a rod of length {} is {}.",
+            [self.length.to_output(), output.to_output()],
+        )
+    }
+
+    fn details(&self, _output: &u64) -> Option<LzOutput> {
+        self.table
+            .then(|| rod_cutting_table(&self.prices.0, self.length).to_output())
+    }
+}
+
+/// Replays a script of operations on a singly linked list of integers and prints the resulting list.
+///
+/// Operations: `push <n>` (to the front), `pop`, `peek`, `reverse`, `len`.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa singly-linked-list "push 3; push 2; push 1; reverse; pop"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// push 3
+/// push 2
+/// push 1
+/// reverse
+/// pop => 3
+/// The resulting singly linked list is [2, 1].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [2, 1]
+/// ```
+#[derive(Args)]
+pub struct SinglyLinkedList {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for SinglyLinkedList {
+    const NAME: &'static str = "singly-linked-list";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::singly_linked_list(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("singly linked list", output)
+    }
+}
+
+/// Replays a script of operations on a doubly linked list of integers and prints the resulting list.
+///
+/// Operations: `push-front <n>`, `push-back <n>`, `pop-front`, `pop-back`, `peek-front`,
+/// `peek-back`, `len`.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa doubly-linked-list "push-back 2; push-front 1; push-back 3; pop-back"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// push-back 2
+/// push-front 1
+/// push-back 3
+/// pop-back => 3
+/// The resulting doubly linked list is [1, 2].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [1, 2]
+/// ```
+#[derive(Args)]
+pub struct DoublyLinkedList {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for DoublyLinkedList {
+    const NAME: &'static str = "doubly-linked-list";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::doubly_linked_list(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("doubly linked list", output)
+    }
+}
+
+/// Replays a script of operations on a stack of integers and prints the resulting stack (bottom to top).
+///
+/// Operations: `push <n>`, `pop`, `peek`, `len`.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa stack "push 3; push 4; pop; peek"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// push 3
+/// push 4
+/// pop => 4
+/// peek => 3
+/// The resulting stack is [3].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [3]
+/// ```
+#[derive(Args)]
+pub struct Stack {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for Stack {
+    const NAME: &'static str = "stack";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::stack(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("stack", output)
+    }
+}
+
+/// Replays a script of operations on a queue of integers and prints the resulting queue (front to back).
+///
+/// Operations: `push <n>` (to the back), `pop` (from the front), `peek`, `len`.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa queue "push 3; push 4; pop; peek"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// push 3
+/// push 4
+/// pop => 3
+/// peek => 4
+/// The resulting queue is [4].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [4]
+/// ```
+#[derive(Args)]
+pub struct Queue {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for Queue {
+    const NAME: &'static str = "queue";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::queue(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("queue", output)
+    }
+}
+
+/// Replays a script of operations on a binary min-heap of integer keys and priorities.
+///
+/// Operations: `push <key> <priority>`, `pop`, `peek`, `decrease-key <key> <priority>`, `len`.
+/// The resulting heap is printed as its underlying array of `(key, priority)` entries.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa binary-heap "push 1 5; push 2 3; decrease-key 1 1; pop"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// push 1 5 => true
+/// push 2 3 => true
+/// decrease-key 1 1 => true
+/// pop => (1, 1)
+/// The resulting binary heap is [(2, 3)].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [(2, 3)]
+/// ```
+#[derive(Args)]
+pub struct BinaryHeap {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for BinaryHeap {
+    const NAME: &'static str = "binary-heap";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::binary_heap(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("binary heap", output)
+    }
+}
+
+/// Replays a script of operations on a binary search tree of integers and draws the resulting tree.
+///
+/// Operations: `insert <n>`, `remove <n>`, `contains <n>`, `min`, `max`, `height`, `len`.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa binary-search-tree "insert 5; insert 3; insert 8; contains 3"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// insert 5 => true
+/// insert 3 => true
+/// insert 8 => true
+/// contains 3 => true
+/// The resulting binary search tree is:
+/// 5
+/// ├── L: 3
+/// └── R: 8
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 5
+/// ├── L: 3
+/// └── R: 8
+/// ```
+#[derive(Args)]
+pub struct BinarySearchTree {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for BinarySearchTree {
+    const NAME: &'static str = "binary-search-tree";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::binary_search_tree(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("binary search tree", output)
+    }
+}
+
+/// Replays a script of operations on an AVL tree of integers and draws the resulting tree.
+///
+/// Operations: `insert <n>`, `remove <n>`, `contains <n>`, `min`, `max`, `height`, `len`.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa avl-tree "insert 1; insert 2; insert 3; height"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// insert 1 => true
+/// insert 2 => true
+/// insert 3 => true
+/// height => 2
+/// The resulting AVL tree is:
+/// 2
+/// ├── L: 1
+/// └── R: 3
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// 2
+/// ├── L: 1
+/// └── R: 3
+/// ```
+#[derive(Args)]
+pub struct AvlTree {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for AvlTree {
+    const NAME: &'static str = "avl-tree";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::avl_tree(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("AVL tree", output)
+    }
+}
+
+/// Replays a script of operations on a trie of words and prints the words it contains.
+///
+/// Operations: `insert <word>`, `remove <word>`, `contains <word>`, `starts-with <prefix>`,
+/// `words <prefix>`, `len`.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa trie "insert car; insert cart; insert dog; words car"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// insert car => true
+/// insert cart => true
+/// insert dog => true
+/// words car => ["car", "cart"]
+/// The resulting trie is {"car", "cart", "dog"}.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// {"car", "cart", "dog"}
+/// ```
+#[derive(Args)]
+pub struct Trie {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for Trie {
+    const NAME: &'static str = "trie";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::trie(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("trie", output)
+    }
+}
+
+/// Replays a script of operations on a union-find of named elements and prints the resulting sets.
+///
+/// Operations: `insert <x>`, `union <x> <y>`, `find <x>`, `connected <x> <y>`, `count`.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa union-find "union a b; union c d; connected a c; count"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// union a b => true
+/// union c d => true
+/// connected a c => false
+/// count => 2
+/// The resulting union-find is [{"a", "b"}, {"c", "d"}].
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// [{"a", "b"}, {"c", "d"}]
+/// ```
+#[derive(Args)]
+pub struct UnionFind {
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for UnionFind {
+    const NAME: &'static str = "union-find";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::union_find(&self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("union-find", output)
+    }
+}
+
+/// Replays a script of operations on an LRU cache of integer keys and values.
+///
+/// Operations: `put <key> <value>`, `get <key>`, `peek <key>`, `len`. The resulting cache is
+/// printed from the most to the least recently used entry.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz dsa lru-cache 2 "put 1 10; put 2 20; get 1; put 3 30"
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// put 1 10
+/// put 2 20
+/// get 1 => 10
+/// put 3 30 => evicted (2, 20)
+/// The resulting LRU cache is {3: 30, 1: 10}.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// {3: 30, 1: 10}
+/// ```
+#[derive(Args)]
+pub struct LruCache {
+    /// The maximum number of entries the cache can hold.
+    capacity: usize,
+    /// The operations to run, separated by semicolons.
+    script: String,
+}
+
+impl Function for LruCache {
+    const NAME: &'static str = "lru-cache";
+    type Output = Replay;
+
+    fn run(&self) -> Result<Replay, String> {
+        script::lru_cache(self.capacity, &self.script)
+    }
+
+    fn prose(&self, output: &Replay) -> Sentence {
+        replay_sentence("LRU cache", output)
+    }
+}
+
+/// A comma-delimited list of numbers such as `1, 2,3`.
+#[derive(Clone)]
+pub struct Nums<T>(Vec<T>);
+
+/// Parses a comma-delimited string of numbers such as `1, 2,3`.
+fn parse_nums<T: FromStr>(nums: &str) -> Result<Nums<T>, String>
+where
+    T::Err: Display,
+{
+    nums.split(',')
+        .map(|num| num.trim())
+        .map(|num| {
+            num.parse::<T>()
+                .map_err(|e| format!("{num:?} is not a valid number: {e}"))
+        })
+        .collect::<Result<_, _>>()
+        .map(Nums)
+}
+
+/// Describes the indices at which a substring search found `pattern` in `text`.
+fn search_sentence(text: &str, pattern: &str, matches: &[usize]) -> Sentence {
+    match matches {
+        [] => Sentence::new(
+            "The pattern {} does not occur in {}.",
+            [pattern.to_output(), text.to_output()],
+        ),
+        _ => Sentence::new(
+            "The pattern {} occurs in {} at indices {}.",
+            [pattern.to_output(), text.to_output(), matches.to_output()],
+        ),
+    }
+}
+
+/// Describes an edit distance between `a` and `b` and draws its alignment.
+fn alignment_sentence(name: &str, a: &str, b: &str, alignment: &Alignment) -> Sentence {
+    Sentence::new(
+        &format!("The {name} distance between {{}} and {{}} is {{}}:\n{{}}"),
+        [
+            a.to_output(),
+            b.to_output(),
+            alignment.distance.to_output(),
+            LzOutput::Literal(alignment.to_string()),
+        ],
+    )
+}
+
+/// Lists each step of a replayed script followed by the resulting structure.
+fn replay_sentence(name: &str, replay: &Replay) -> Sentence {
+    let template = match replay.structure.contains('\n') {
+        true => format!("The resulting {name} is:\n{{}}"),
+        false => format!("The resulting {name} is {{}}."),
+    };
+    Sentence::new(&template, [replay.to_output()]).after_lines(&replay.steps)
+}
+
+impl ToOutput for Replay {
+    /// The structure after every operation, as drawn by the script.
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Literal(self.structure.clone())
+    }
+}
+
+impl ToOutput for Alignment {
+    /// The edit distance, since the alignment is drawn in the prose.
+    fn to_output(&self) -> LzOutput {
+        self.distance.to_output()
+    }
+}
+
+impl ToOutput for DpTable {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Matrix {
+            row_labels: self.row_labels.clone(),
+            column_labels: self.column_labels.clone(),
+            cells: self
+                .cells
+                .iter()
+                .map(|row| row.iter().map(ToOutput::to_output).collect())
+                .collect(),
+        }
+    }
+}
+
+/// The largest sum of a contiguous subarray and the indices of that subarray.
+pub struct Subarray {
+    sum: i128,
+    range: Range<usize>,
+}
+
+impl ToOutput for Subarray {
+    fn to_output(&self) -> LzOutput {
+        self.sum.to_output()
+    }
+}
+
+/// The answer to `lz dsa coin-change`, which depends on `--min-coins`.
+pub enum Change {
+    Ways(u64),
+    Fewest(Option<u64>),
+}
+
+impl ToOutput for Change {
+    fn to_output(&self) -> LzOutput {
+        match self {
+            Change::Ways(ways) => ways.to_output(),
+            Change::Fewest(fewest) => fewest.to_output(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Format;
+
+    #[test]
+    fn test_two_sum_without_a_pair() {
+        let function = TwoSum {
+            nums: Nums(vec![i32::MAX, 1]),
+            target: i32::MIN,
+        };
+        let output = function.run().unwrap();
+        assert_eq!(output, Vec::<usize>::new());
+        assert_eq!(
+            function.prose(&output).render(Format::Prose),
+            "No two numbers sum to -2147483648."
+        );
+    }
+
+    #[test]
+    fn test_max_subarray_past_i32() {
+        let function = MaxSubarray {
+            nums: Nums(vec![i32::MAX, 1]),
+        };
+        let output = function.run().unwrap();
+        assert_eq!(output.to_output(), LzOutput::Int(i32::MAX as i128 + 1));
+        assert_eq!(
+            function.prose(&output).render(Format::Prose),
+            "The maximum subarray sum is 2147483648, from index 0 to 1: [2147483647, 1]."
+        );
+    }
+
+    #[test]
+    fn test_dp_rejects_mismatched_inputs() {
+        let knapsack = |weights: Vec<usize>, values: Vec<u64>| Knapsack {
+            weights: Nums(weights),
+            values: Nums(values),
+            capacity: 5,
+            unbounded: false,
+            table: false,
+        };
+        assert_eq!(knapsack(vec![1, 2], vec![3, 4]).run(), Ok(7));
+        assert_eq!(
+            knapsack(vec![1, 2], vec![3]).run(),
+            Err(
+                "every item needs both a weight and a value, but the weights list 2 items and \
+                 the values list 1"
+                    .to_owned()
+            )
+        );
+
+        let matrix_chain = |dims: Vec<u64>| MatrixChain {
+            dims: Nums(dims),
+            table: false,
+        };
+        assert_eq!(matrix_chain(vec![10, 30]).run(), Ok(0));
+        for dims in [vec![], vec![10]] {
+            assert_eq!(
+                matrix_chain(dims).run(),
+                Err("a chain of matrices needs at least 2 dimensions".to_owned())
+            );
         }
     }
 }
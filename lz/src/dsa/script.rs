@@ -0,0 +1,380 @@
+//! Replays operation scripts such as `push 3; pop; peek` against the `ladderz::dsa` data structures.
+
+use ladderz::dsa::*;
+use std::fmt::Debug;
+use std::str::FromStr;
+
+/// A single operation from a script, e.g. `push 3`.
+pub struct Operation<'a> {
+    pub name: &'a str,
+    pub args: Vec<&'a str>,
+}
+
+/// The outcome of replaying a script.
+pub struct Replay {
+    /// Each operation as written, followed by its result if it returns one (e.g. `pop => 3`).
+    pub steps: Vec<String>,
+    /// The structure after every operation has been applied.
+    pub structure: String,
+}
+
+/// Splits a script into operations on `;` and each operation into its name and arguments on whitespace.
+pub fn parse(script: &str) -> Vec<Operation<'_>> {
+    script
+        .split(';')
+        .filter_map(|step| {
+            let mut words = step.split_whitespace();
+            words.next().map(|name| Operation {
+                name,
+                args: words.collect(),
+            })
+        })
+        .collect()
+}
+
+fn expect_args(op: &Operation, count: usize) -> Result<(), String> {
+    if op.args.len() != count {
+        return Err(format!(
+            "`{}` expects {} argument(s) but got {}.",
+            op.name,
+            count,
+            op.args.len()
+        ));
+    }
+    Ok(())
+}
+
+fn arg<T: FromStr>(op: &Operation, index: usize) -> Result<T, String> {
+    op.args[index]
+        .parse::<T>()
+        .map_err(|_| format!("Invalid argument {:?} for `{}`.", op.args[index], op.name))
+}
+
+fn show<T: Debug>(value: Option<T>) -> String {
+    match value {
+        Some(value) => format!("{:?}", value),
+        None => "None".to_owned(),
+    }
+}
+
+fn unknown(op: &Operation, known: &str) -> String {
+    format!(
+        "Unknown operation `{}`. Available operations: {}.",
+        op.name, known
+    )
+}
+
+/// Applies every operation in `script` to `structure`, recording a step for each one.
+///
+/// `apply` returns the operation's result (if it has one) or an error message. Each entry in
+/// `signatures` is an operation name followed by its argument count, which is checked first.
+fn replay<S>(
+    script: &str,
+    structure: &mut S,
+    signatures: &[(&str, usize)],
+    apply: impl Fn(&mut S, &Operation) -> Result<Option<String>, String>,
+) -> Result<Vec<String>, String> {
+    let known: Vec<&str> = signatures.iter().map(|(name, _)| *name).collect();
+    let mut steps: Vec<String> = Vec::new();
+    for op in parse(script) {
+        let Some(&(_, count)) = signatures.iter().find(|(name, _)| *name == op.name) else {
+            return Err(unknown(&op, &known.join(", ")));
+        };
+        expect_args(&op, count)?;
+        let written = std::iter::once(op.name)
+            .chain(op.args.iter().copied())
+            .collect::<Vec<&str>>()
+            .join(" ");
+        steps.push(match apply(structure, &op)? {
+            Some(result) => format!("{written} => {result}"),
+            None => written,
+        });
+    }
+    Ok(steps)
+}
+
+pub fn singly_linked_list(script: &str) -> Result<Replay, String> {
+    let mut list: SinglyLinkedList<i32> = SinglyLinkedList::new();
+    let signatures = [
+        ("push", 1),
+        ("pop", 0),
+        ("peek", 0),
+        ("reverse", 0),
+        ("len", 0),
+    ];
+    let steps = replay(script, &mut list, &signatures, |list, op| {
+        Ok(match op.name {
+            "push" => {
+                list.push_front(arg(op, 0)?);
+                None
+            }
+            "pop" => Some(show(list.pop_front())),
+            "peek" => Some(show(list.peek_front())),
+            "reverse" => {
+                list.reverse();
+                None
+            }
+            _ => Some(list.len().to_string()),
+        })
+    })?;
+    Ok(Replay {
+        steps,
+        structure: format!("{:?}", list),
+    })
+}
+
+pub fn doubly_linked_list(script: &str) -> Result<Replay, String> {
+    let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+    let signatures = [
+        ("push-front", 1),
+        ("push-back", 1),
+        ("pop-front", 0),
+        ("pop-back", 0),
+        ("peek-front", 0),
+        ("peek-back", 0),
+        ("len", 0),
+    ];
+    let steps = replay(script, &mut list, &signatures, |list, op| {
+        Ok(match op.name {
+            "push-front" => {
+                list.push_front(arg(op, 0)?);
+                None
+            }
+            "push-back" => {
+                list.push_back(arg(op, 0)?);
+                None
+            }
+            "pop-front" => Some(show(list.pop_front())),
+            "pop-back" => Some(show(list.pop_back())),
+            "peek-front" => Some(show(list.peek_front())),
+            "peek-back" => Some(show(list.peek_back())),
+            _ => Some(list.len().to_string()),
+        })
+    })?;
+    Ok(Replay {
+        steps,
+        structure: format!("{:?}", list),
+    })
+}
+
+pub fn stack(script: &str) -> Result<Replay, String> {
+    let mut stack: Stack<i32> = Stack::new();
+    let signatures = [("push", 1), ("pop", 0), ("peek", 0), ("len", 0)];
+    let steps = replay(script, &mut stack, &signatures, |stack, op| {
+        Ok(match op.name {
+            "push" => {
+                stack.push(arg(op, 0)?);
+                None
+            }
+            "pop" => Some(show(stack.pop())),
+            "peek" => Some(show(stack.peek())),
+            _ => Some(stack.len().to_string()),
+        })
+    })?;
+    Ok(Replay {
+        steps,
+        structure: format!("{:?}", stack),
+    })
+}
+
+pub fn queue(script: &str) -> Result<Replay, String> {
+    let mut queue: Queue<i32> = Queue::new();
+    let signatures = [("push", 1), ("pop", 0), ("peek", 0), ("len", 0)];
+    let steps = replay(script, &mut queue, &signatures, |queue, op| {
+        Ok(match op.name {
+            "push" => {
+                queue.push(arg(op, 0)?);
+                None
+            }
+            "pop" => Some(show(queue.pop())),
+            "peek" => Some(show(queue.peek())),
+            _ => Some(queue.len().to_string()),
+        })
+    })?;
+    Ok(Replay {
+        steps,
+        structure: format!("{:?}", queue),
+    })
+}
+
+pub fn binary_heap(script: &str) -> Result<Replay, String> {
+    let mut heap: BinaryHeap<i32, i32> = BinaryHeap::new();
+    let signatures = [
+        ("push", 2),
+        ("pop", 0),
+        ("peek", 0),
+        ("decrease-key", 2),
+        ("len", 0),
+    ];
+    let steps = replay(script, &mut heap, &signatures, |heap, op| {
+        Ok(match op.name {
+            "push" => Some(heap.push(arg(op, 0)?, arg(op, 1)?).to_string()),
+            "pop" => Some(show(heap.pop())),
+            "peek" => Some(show(heap.peek())),
+            "decrease-key" => Some(heap.decrease_key(&arg(op, 0)?, arg(op, 1)?).to_string()),
+            _ => Some(heap.len().to_string()),
+        })
+    })?;
+    Ok(Replay {
+        steps,
+        structure: format!("{:?}", heap),
+    })
+}
+
+const TREE_SIGNATURES: [(&str, usize); 7] = [
+    ("insert", 1),
+    ("remove", 1),
+    ("contains", 1),
+    ("min", 0),
+    ("max", 0),
+    ("height", 0),
+    ("len", 0),
+];
+
+pub fn binary_search_tree(script: &str) -> Result<Replay, String> {
+    let mut tree: BinarySearchTree<i32> = BinarySearchTree::new();
+    let steps = replay(script, &mut tree, &TREE_SIGNATURES, |tree, op| {
+        Ok(Some(match op.name {
+            "insert" => tree.insert(arg(op, 0)?).to_string(),
+            "remove" => tree.remove(&arg(op, 0)?).to_string(),
+            "contains" => tree.contains(&arg(op, 0)?).to_string(),
+            "min" => show(tree.min()),
+            "max" => show(tree.max()),
+            "height" => tree.height().to_string(),
+            _ => tree.len().to_string(),
+        }))
+    })?;
+    Ok(Replay {
+        steps,
+        structure: tree.to_string(),
+    })
+}
+
+pub fn avl_tree(script: &str) -> Result<Replay, String> {
+    let mut tree: AvlTree<i32> = AvlTree::new();
+    let steps = replay(script, &mut tree, &TREE_SIGNATURES, |tree, op| {
+        Ok(Some(match op.name {
+            "insert" => tree.insert(arg(op, 0)?).to_string(),
+            "remove" => tree.remove(&arg(op, 0)?).to_string(),
+            "contains" => tree.contains(&arg(op, 0)?).to_string(),
+            "min" => show(tree.min()),
+            "max" => show(tree.max()),
+            "height" => tree.height().to_string(),
+            _ => tree.len().to_string(),
+        }))
+    })?;
+    Ok(Replay {
+        steps,
+        structure: tree.to_string(),
+    })
+}
+
+pub fn trie(script: &str) -> Result<Replay, String> {
+    let mut trie = Trie::new();
+    let signatures = [
+        ("insert", 1),
+        ("remove", 1),
+        ("contains", 1),
+        ("starts-with", 1),
+        ("words", 1),
+        ("len", 0),
+    ];
+    let steps = replay(script, &mut trie, &signatures, |trie, op| {
+        Ok(Some(match op.name {
+            "insert" => trie.insert(op.args[0]).to_string(),
+            "remove" => trie.remove(op.args[0]).to_string(),
+            "contains" => trie.contains(op.args[0]).to_string(),
+            "starts-with" => trie.starts_with(op.args[0]).to_string(),
+            "words" => format!("{:?}", trie.words_with_prefix(op.args[0])),
+            _ => trie.len().to_string(),
+        }))
+    })?;
+    Ok(Replay {
+        steps,
+        structure: format!("{:?}", trie),
+    })
+}
+
+pub fn union_find(script: &str) -> Result<Replay, String> {
+    let mut sets: UnionFind<String> = UnionFind::new();
+    let signatures = [
+        ("insert", 1),
+        ("union", 2),
+        ("find", 1),
+        ("connected", 2),
+        ("count", 0),
+    ];
+    let steps = replay(script, &mut sets, &signatures, |sets, op| {
+        let owned = |i: usize| op.args[i].to_owned();
+        Ok(Some(match op.name {
+            "insert" => sets.insert(owned(0)).to_string(),
+            "union" => sets.union(owned(0), owned(1)).to_string(),
+            "find" => show(sets.find(&owned(0))),
+            "connected" => sets.connected(&owned(0), &owned(1)).to_string(),
+            _ => sets.set_count().to_string(),
+        }))
+    })?;
+    Ok(Replay {
+        steps,
+        structure: format!("{:?}", sets),
+    })
+}
+
+pub fn lru_cache(capacity: usize, script: &str) -> Result<Replay, String> {
+    let mut cache: LruCache<i32, i32> = LruCache::new(capacity);
+    let signatures = [("put", 2), ("get", 1), ("peek", 1), ("len", 0)];
+    let steps = replay(script, &mut cache, &signatures, |cache, op| {
+        Ok(match op.name {
+            "put" => cache
+                .put(arg(op, 0)?, arg(op, 1)?)
+                .map(|evicted| format!("evicted {:?}", evicted)),
+            "get" => Some(show(cache.get(&arg(op, 0)?))),
+            "peek" => Some(show(cache.peek(&arg(op, 0)?))),
+            _ => Some(cache.len().to_string()),
+        })
+    })?;
+    Ok(Replay {
+        steps,
+        structure: format!("{:?}", cache),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let ops = parse(" push 3;pop ; ;peek");
+        let names: Vec<&str> = ops.iter().map(|op| op.name).collect();
+        assert_eq!(names, vec!["push", "pop", "peek"]);
+        assert_eq!(ops[0].args, vec!["3"]);
+    }
+
+    #[test]
+    fn test_stack_replay() {
+        let replay = stack("push 3; push 4; pop; peek").unwrap();
+        assert_eq!(
+            replay.steps,
+            vec!["push 3", "push 4", "pop => 4", "peek => 3"]
+        );
+        assert_eq!(replay.structure, "[3]");
+    }
+
+    #[test]
+    fn test_replay_errors() {
+        assert!(stack("push").is_err());
+        assert!(stack("push x").is_err());
+        assert!(stack("shove 3").is_err());
+    }
+
+    #[test]
+    fn test_lru_cache_replay() {
+        let replay = lru_cache(1, "put 1 10; put 2 20; get 1").unwrap();
+        assert_eq!(
+            replay.steps,
+            vec!["put 1 10", "put 2 20 => evicted (1, 10)", "get 1 => None"]
+        );
+        assert_eq!(replay.structure, "{2: 20}");
+    }
+}
@@ -0,0 +1,443 @@
+use ladderz::prealgebra::BaseError;
+use std::collections::BTreeSet;
+use std::fmt;
+
+mod functions;
+use functions::FUNCTIONS;
+mod parser;
+pub use parser::{parse, BinaryOp, Expr, ParseError, ParseErrorKind};
+mod value;
+pub use value::{Type, Value};
+
+/// What went wrong while evaluating.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalErrorKind {
+    /// A call to a function that doesn't exist.
+    UnknownFunction(String),
+    /// A call with the wrong number of arguments.
+    ArgumentCount {
+        function: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    /// A value of the wrong type for an operator or argument, e.g. a set for `+`.
+    TypeMismatch {
+        operation: String,
+        expected: &'static [Type],
+        found: Type,
+    },
+    /// An integer argument outside the range a function accepts, e.g. `factors(0)`.
+    OutOfRange {
+        argument: String,
+        value: i64,
+        expected: &'static str,
+    },
+    /// An integer result that doesn't fit in 64 bits.
+    Overflow,
+    DivisionByZero,
+    /// `max` or `min` of an empty set.
+    EmptySet(&'static str),
+    /// An error from a `ladderz::prealgebra` base conversion.
+    Base(BaseError),
+}
+
+/// An error from evaluating an expression, at the character index of the operator or function
+/// call that failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvalError {
+    pub position: usize,
+    pub kind: EvalErrorKind,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            EvalErrorKind::UnknownFunction(name) => {
+                write!(f, "unknown function `{name}`, see `lz eval --functions`")
+            }
+            EvalErrorKind::ArgumentCount {
+                function,
+                expected,
+                found,
+            } => {
+                let plural = if *expected == 1 { "" } else { "s" };
+                write!(
+                    f,
+                    "{function} takes {expected} argument{plural}, but was given {found}"
+                )
+            }
+            EvalErrorKind::TypeMismatch {
+                operation,
+                expected,
+                found,
+            } => {
+                let expected: Vec<String> = expected.iter().map(Type::to_string).collect();
+                write!(
+                    f,
+                    "expected {} for {operation}, but found {found}",
+                    expected.join(" or ")
+                )
+            }
+            EvalErrorKind::OutOfRange {
+                argument,
+                value,
+                expected,
+            } => write!(f, "{argument} must be {expected}, but is {value}"),
+            EvalErrorKind::Overflow => write!(f, "the result doesn't fit in a 64-bit integer"),
+            EvalErrorKind::DivisionByZero => write!(f, "division by zero"),
+            EvalErrorKind::EmptySet(function) => {
+                write!(f, "{function} of an empty set is undefined")
+            }
+            EvalErrorKind::Base(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// An error from parsing or evaluating an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    Eval(EvalError),
+}
+
+impl Error {
+    /// Returns the character index in the expression where the error is.
+    pub fn position(&self) -> usize {
+        match self {
+            Error::Parse(e) => e.position,
+            Error::Eval(e) => e.position,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{e}"),
+            Error::Eval(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Returns the slice of just `t`, for type errors that expect the other operand's type.
+fn only(t: Type) -> &'static [Type] {
+    match t {
+        Type::Bool => &[Type::Bool],
+        Type::Int => &[Type::Int],
+        Type::Str => &[Type::Str],
+        Type::Tuple => &[Type::Tuple],
+        Type::Set => &[Type::Set],
+        Type::Map => &[Type::Map],
+    }
+}
+
+/// Divides and rounds down, so `-7 / 2` is `-4` and `-7 % 2` is `1`.
+fn floor_div_rem(a: i64, b: i64) -> Result<(i64, i64), EvalErrorKind> {
+    if b == 0 {
+        return Err(EvalErrorKind::DivisionByZero);
+    }
+    let quotient = a.checked_div(b).ok_or(EvalErrorKind::Overflow)?;
+    let remainder = a % b;
+    match remainder != 0 && (remainder < 0) != (b < 0) {
+        true => Ok((quotient - 1, remainder + b)),
+        false => Ok((quotient, remainder)),
+    }
+}
+
+fn binary(op: BinaryOp, left: Value, right: Value) -> Result<Value, EvalErrorKind> {
+    use BinaryOp::*;
+    let overflow = |n: Option<i64>| n.map(Value::Int).ok_or(EvalErrorKind::Overflow);
+    let set = |items: BTreeSet<&Value>| Value::Set(items.into_iter().cloned().collect());
+    match (op, &left, &right) {
+        (Add, Value::Int(a), Value::Int(b)) => overflow(a.checked_add(*b)),
+        (Subtract, Value::Int(a), Value::Int(b)) => overflow(a.checked_sub(*b)),
+        (Multiply, Value::Int(a), Value::Int(b)) => overflow(a.checked_mul(*b)),
+        (Divide, Value::Int(a), Value::Int(b)) => Ok(Value::Int(floor_div_rem(*a, *b)?.0)),
+        (Remainder, Value::Int(a), Value::Int(b)) => Ok(Value::Int(floor_div_rem(*a, *b)?.1)),
+        (Power, Value::Int(a), Value::Int(b)) => {
+            let exponent = u32::try_from(*b).map_err(|_| match *b < 0 {
+                true => EvalErrorKind::OutOfRange {
+                    argument: "the exponent of `^`".to_owned(),
+                    value: *b,
+                    expected: "at least 0",
+                },
+                false => EvalErrorKind::Overflow,
+            })?;
+            overflow(a.checked_pow(exponent))
+        }
+        (Subtract, Value::Set(a), Value::Set(b)) => Ok(set(a.difference(b).collect())),
+        (Intersection, Value::Set(a), Value::Set(b)) => Ok(set(a.intersection(b).collect())),
+        (Union, Value::Set(a), Value::Set(b)) => Ok(set(a.union(b).collect())),
+        (Intersection, Value::Bool(a), Value::Bool(b)) => Ok(Value::Bool(*a && *b)),
+        (Union, Value::Bool(a), Value::Bool(b)) => Ok(Value::Bool(*a || *b)),
+        (Equal | NotEqual, a, b) if a.type_of() == b.type_of() => {
+            Ok(Value::Bool((a == b) == (op == Equal)))
+        }
+        (Less, Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a < b)),
+        (LessOrEqual, Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a <= b)),
+        (Greater, Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a > b)),
+        (GreaterOrEqual, Value::Int(a), Value::Int(b)) => Ok(Value::Bool(a >= b)),
+        _ => {
+            let allowed: &'static [Type] = match op {
+                Subtract => &[Type::Int, Type::Set],
+                Intersection | Union => &[Type::Set, Type::Bool],
+                Equal | NotEqual => only(left.type_of()),
+                _ => &[Type::Int],
+            };
+            // Blame the left operand if it can't be used at all, or else the right one for not
+            // matching it.
+            let (expected, found) = match allowed.contains(&left.type_of()) {
+                true => (only(left.type_of()), right.type_of()),
+                false => (allowed, left.type_of()),
+            };
+            Err(EvalErrorKind::TypeMismatch {
+                operation: format!("`{op}`"),
+                expected,
+                found,
+            })
+        }
+    }
+}
+
+/// Evaluates a parsed expression.
+pub fn eval(expr: &Expr) -> Result<Value, EvalError> {
+    let at = |position: usize| move |kind: EvalErrorKind| EvalError { position, kind };
+    match expr {
+        Expr::Int(n) => Ok(Value::Int(*n)),
+        Expr::Bool(b) => Ok(Value::Bool(*b)),
+        Expr::Str(s) => Ok(Value::Str(s.clone())),
+        Expr::Set(items) => Ok(Value::Set(
+            items.iter().map(eval).collect::<Result<_, _>>()?,
+        )),
+        Expr::Negate { operand, position } => match eval(operand)? {
+            Value::Int(n) => n
+                .checked_neg()
+                .map(Value::Int)
+                .ok_or_else(|| at(*position)(EvalErrorKind::Overflow)),
+            value => Err(at(*position)(EvalErrorKind::TypeMismatch {
+                operation: "`-`".to_owned(),
+                expected: &[Type::Int],
+                found: value.type_of(),
+            })),
+        },
+        Expr::Binary {
+            op,
+            left,
+            right,
+            position,
+        } => binary(*op, eval(left)?, eval(right)?).map_err(at(*position)),
+        Expr::Call {
+            name,
+            args,
+            position,
+        } => {
+            let function = functions::find(name)
+                .ok_or_else(|| at(*position)(EvalErrorKind::UnknownFunction(name.clone())))?;
+            let args = args.iter().map(eval).collect::<Result<Vec<_>, _>>()?;
+            function.call(&args).map_err(at(*position))
+        }
+    }
+}
+
+/// Parses and evaluates an expression, e.g. `sum(factors(12) & factors(18))` is `12`.
+pub fn evaluate(source: &str) -> Result<Value, Error> {
+    let expr = parse(source).map_err(Error::Parse)?;
+    eval(&expr).map_err(Error::Eval)
+}
+
+/// Describes an error with the expression and a caret under where it happened.
+fn describe_error(source: &str, error: &Error) -> String {
+    format!(
+        "{error}\n  {source}\n  {:>width$}",
+        "^",
+        width = error.position() + 1
+    )
+}
+
+/// Lists every function with what it returns, lined up in columns.
+fn list_functions() -> String {
+    let width = FUNCTIONS
+        .iter()
+        .map(|function| function.usage().len())
+        .max()
+        .unwrap_or_default();
+    FUNCTIONS
+        .iter()
+        .map(|function| format!("{:<width$}  {}\n", function.usage(), function.summary))
+        .collect()
+}
+
+pub fn match_eval(expression: Option<String>, functions: bool, raw: bool) {
+    if functions {
+        print!("{}", list_functions());
+        return;
+    }
+    let Some(expression) = expression else {
+        println!("Please provide an expression to evaluate.");
+        return;
+    };
+    match evaluate(&expression) {
+        Ok(value) if raw => println!("{value}"),
+        Ok(value) => println!("{} = {value}", expression.trim()),
+        Err(e) => {
+            eprintln!("Error: {}", describe_error(&expression, &e));
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn show(source: &str) -> String {
+        evaluate(source).unwrap().to_string()
+    }
+
+    fn error(source: &str) -> EvalErrorKind {
+        match evaluate(source) {
+            Err(Error::Eval(e)) => e.kind,
+            result => panic!("expected an evaluation error, but got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(show("1 + 2 * 3 ^ 2"), "19");
+        assert_eq!(show("(1 + 2) * 3"), "9");
+        assert_eq!(show("-2^2"), "-4");
+        assert_eq!(show("-7 / 2"), "-4");
+        assert_eq!(show("-7 % 2"), "1");
+        assert_eq!(show("7 % -2"), "-1");
+        assert_eq!(show("0xff + 0b1"), "256");
+        assert_eq!(show("len(factors(12)) == 6"), "true");
+        assert_eq!(show("3 >= 4"), "false");
+    }
+
+    #[test]
+    fn test_prealgebra_calls() {
+        assert_eq!(show("factors(12)"), "{1, 2, 3, 4, 6, 12}");
+        assert_eq!(show("get_factors(12)"), "{1, 2, 3, 4, 6, 12}");
+        assert_eq!(show("factor_pairs(12)"), "{(1, 12), (2, 6), (3, 4)}");
+        assert_eq!(show("prime_factorization(360)"), "{2: 3, 3: 2, 5: 1}");
+        assert_eq!(show("is_prime(97) & is_composite(91)"), "true");
+        assert_eq!(show("multiples_in_range(7, 1, 30)"), "{7, 14, 21, 28}");
+        assert_eq!(show("primes_in_range(10, 20)"), "{11, 13, 17, 19}");
+        assert_eq!(show("is_factor(3, 12) | is_multiple(5, 3)"), "true");
+        assert_eq!(show(r#"from_base("ff", 16) + 1"#), "256");
+        assert_eq!(show("to_roman(1994)"), "MCMXCIV");
+        assert_eq!(show("to_scientific_notation(12345, 3)"), "1.23 × 10^4");
+        assert_eq!(show("twos_complement_range(8)"), "(-128, 127)");
+        assert_eq!(show("fraction_to_base(1, 6, 10)"), "0.1(6)");
+    }
+
+    #[test]
+    fn test_sets_and_aggregates() {
+        assert_eq!(show("factors(12) & factors(18)"), "{1, 2, 3, 6}");
+        assert_eq!(
+            show("factors(12) | factors(18)"),
+            "{1, 2, 3, 4, 6, 9, 12, 18}"
+        );
+        assert_eq!(show("factors(12) - {1, 12}"), "{2, 3, 4, 6}");
+        // The sum of the prime factors of the LCM of 12 and 18.
+        assert_eq!(show("sum(keys(prime_factorization(lcm(12, 18))))"), "5");
+        assert_eq!(show("max(factors(360) - {360})"), "180");
+        assert_eq!(show("min({3, 1, 2})"), "1");
+        assert_eq!(show("len(prime_factorization(360))"), "3");
+        assert_eq!(show(r#"len("abc")"#), "3");
+        assert_eq!(show("gcd(-12, 18)"), "6");
+        assert_eq!(show("sum({})"), "0");
+        assert_eq!(show(r#"{"a", 1}"#), r#"{1, "a"}"#);
+    }
+
+    #[test]
+    fn test_evaluation_errors() {
+        assert_eq!(
+            error("factors(12) + 1"),
+            EvalErrorKind::TypeMismatch {
+                operation: "`+`".to_owned(),
+                expected: &[Type::Int],
+                found: Type::Set,
+            }
+        );
+        assert_eq!(
+            error("{1} - 1"),
+            EvalErrorKind::TypeMismatch {
+                operation: "`-`".to_owned(),
+                expected: &[Type::Set],
+                found: Type::Int,
+            }
+        );
+        assert_eq!(
+            error("factors({1})"),
+            EvalErrorKind::TypeMismatch {
+                operation: "`n` of factors".to_owned(),
+                expected: &[Type::Int],
+                found: Type::Set,
+            }
+        );
+        assert_eq!(
+            error("factors(0)"),
+            EvalErrorKind::OutOfRange {
+                argument: "`n` of factors".to_owned(),
+                value: 0,
+                expected: "between 1 and 4294967295",
+            }
+        );
+        assert_eq!(
+            error("is_prime(1, 2)"),
+            EvalErrorKind::ArgumentCount {
+                function: "is_prime",
+                expected: 1,
+                found: 2,
+            }
+        );
+        assert_eq!(
+            error("fact(1)"),
+            EvalErrorKind::UnknownFunction("fact".to_owned())
+        );
+        assert_eq!(
+            error("get_is_prime(7)"),
+            EvalErrorKind::UnknownFunction("get_is_prime".to_owned())
+        );
+        assert_eq!(error("1 / 0"), EvalErrorKind::DivisionByZero);
+        assert_eq!(error("2 ^ 64"), EvalErrorKind::Overflow);
+        assert_eq!(error("max({})"), EvalErrorKind::EmptySet("max"));
+        assert_eq!(
+            error("sum(factor_pairs(4))"),
+            EvalErrorKind::TypeMismatch {
+                operation: "the items of sum".to_owned(),
+                expected: &[Type::Int],
+                found: Type::Tuple,
+            }
+        );
+        assert!(matches!(
+            error(r#"from_base("12", 2)"#),
+            EvalErrorKind::Base(BaseError::InvalidDigit { .. })
+        ));
+    }
+
+    #[test]
+    fn test_error_messages_point_at_the_problem() {
+        let source = "len(factors(12)) + {1}";
+        let error = evaluate(source).unwrap_err();
+        assert_eq!(
+            describe_error(source, &error),
+            "expected int for `+`, but found set\n  len(factors(12)) + {1}\n                   ^"
+        );
+        let source = "factors(0)";
+        assert_eq!(
+            describe_error(source, &evaluate(source).unwrap_err()),
+            "`n` of factors must be between 1 and 4294967295, but is 0\n  factors(0)\n  ^"
+        );
+        let functions = list_functions();
+        assert_eq!(functions.lines().count(), FUNCTIONS.len());
+        assert!(functions
+            .lines()
+            .any(|line| line.starts_with("factors(n) ") && line.ends_with("  the factors of n")));
+    }
+}
@@ -0,0 +1,434 @@
+//! The functions `lz eval` expressions can call: every `ladderz::prealgebra` function, plus
+//! aggregates and helpers for working with their results.
+
+use super::value::{Type, Value};
+use super::EvalErrorKind;
+use ladderz::prealgebra::*;
+use std::collections::BTreeSet;
+
+/// A function that expressions can call.
+pub struct Function {
+    pub name: &'static str,
+    /// The names of the parameters, e.g. `["n"]`.
+    pub params: &'static [&'static str],
+    /// What the function returns, in a few words.
+    pub summary: &'static str,
+    apply: fn(&Args) -> Result<Value, EvalErrorKind>,
+}
+
+impl Function {
+    /// Returns how the function is called, e.g. `factors(n)`.
+    pub fn usage(&self) -> String {
+        format!("{}({})", self.name, self.params.join(", "))
+    }
+
+    /// Calls the function after checking the number of arguments.
+    pub fn call(&'static self, values: &[Value]) -> Result<Value, EvalErrorKind> {
+        if values.len() != self.params.len() {
+            return Err(EvalErrorKind::ArgumentCount {
+                function: self.name,
+                expected: self.params.len(),
+                found: values.len(),
+            });
+        }
+        (self.apply)(&Args {
+            function: self,
+            values,
+        })
+    }
+}
+
+/// The arguments of a call, with conversions that report which argument has the wrong type or
+/// is out of range.
+struct Args<'a> {
+    function: &'static Function,
+    values: &'a [Value],
+}
+
+impl Args<'_> {
+    fn argument(&self, i: usize) -> String {
+        format!("`{}` of {}", self.function.params[i], self.function.name)
+    }
+
+    fn mismatch(&self, i: usize, expected: &'static [Type]) -> EvalErrorKind {
+        EvalErrorKind::TypeMismatch {
+            operation: self.argument(i),
+            expected,
+            found: self.values[i].type_of(),
+        }
+    }
+
+    fn int(&self, i: usize) -> Result<i64, EvalErrorKind> {
+        match self.values[i] {
+            Value::Int(n) => Ok(n),
+            _ => Err(self.mismatch(i, &[Type::Int])),
+        }
+    }
+
+    /// Returns a whole number between `min` and `max` that `expected` describes.
+    fn int_in<T: TryFrom<i64>>(
+        &self,
+        i: usize,
+        min: i64,
+        max: i64,
+        expected: &'static str,
+    ) -> Result<T, EvalErrorKind> {
+        let n = self.int(i)?;
+        match (min..=max).contains(&n) {
+            true => T::try_from(n).map_err(|_| unreachable!("the range fits the type")),
+            false => Err(EvalErrorKind::OutOfRange {
+                argument: self.argument(i),
+                value: n,
+                expected,
+            }),
+        }
+    }
+
+    /// A positive integer for the `ladderz::prealgebra` functions, which take a `u32`.
+    fn positive(&self, i: usize) -> Result<u32, EvalErrorKind> {
+        self.int_in(i, 1, u32::MAX as i64, "between 1 and 4294967295")
+    }
+
+    fn u32(&self, i: usize) -> Result<u32, EvalErrorKind> {
+        self.int_in(i, 0, u32::MAX as i64, "between 0 and 4294967295")
+    }
+
+    fn nonnegative(&self, i: usize) -> Result<u64, EvalErrorKind> {
+        self.int_in(i, 0, i64::MAX, "at least 0")
+    }
+
+    fn str(&self, i: usize) -> Result<&str, EvalErrorKind> {
+        match &self.values[i] {
+            Value::Str(s) => Ok(s),
+            _ => Err(self.mismatch(i, &[Type::Str])),
+        }
+    }
+
+    fn set(&self, i: usize) -> Result<&BTreeSet<Value>, EvalErrorKind> {
+        match &self.values[i] {
+            Value::Set(items) => Ok(items),
+            _ => Err(self.mismatch(i, &[Type::Set])),
+        }
+    }
+
+    /// Returns the largest or smallest item of a non-empty set.
+    fn extreme(&self, largest: bool) -> Result<Value, EvalErrorKind> {
+        let items = self.set(0)?;
+        let item = match largest {
+            true => items.last(),
+            false => items.first(),
+        };
+        item.cloned()
+            .ok_or(EvalErrorKind::EmptySet(self.function.name))
+    }
+}
+
+fn bool(b: bool) -> Result<Value, EvalErrorKind> {
+    Ok(Value::Bool(b))
+}
+
+fn int(n: impl Into<i64>) -> Result<Value, EvalErrorKind> {
+    Ok(Value::Int(n.into()))
+}
+
+fn string(s: Result<String, BaseError>) -> Result<Value, EvalErrorKind> {
+    s.map(Value::Str).map_err(EvalErrorKind::Base)
+}
+
+fn gcd(a: i64, b: i64) -> u64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Every function, in the order they're listed by `lz eval --functions`.
+pub static FUNCTIONS: &[Function] = &[
+    Function {
+        name: "factor_pairs",
+        params: &["n"],
+        summary: "the factor pairs of n, as a set of tuples",
+        apply: |args| {
+            let pairs = get_factor_pairs(args.positive(0)?);
+            Ok(Value::Set(
+                pairs
+                    .into_iter()
+                    .map(|(a, b)| Value::Tuple(vec![Value::Int(a.into()), Value::Int(b.into())]))
+                    .collect(),
+            ))
+        },
+    },
+    Function {
+        name: "factors",
+        params: &["n"],
+        summary: "the factors of n",
+        apply: |args| Ok(Value::int_set(get_factors(args.positive(0)?))),
+    },
+    Function {
+        name: "is_factor",
+        params: &["x", "y"],
+        summary: "whether x is a factor of y",
+        apply: |args| bool(is_factor(args.positive(0)?, args.u32(1)?)),
+    },
+    Function {
+        name: "is_multiple",
+        params: &["x", "y"],
+        summary: "whether x is a multiple of y",
+        apply: |args| bool(is_multiple(args.u32(0)?, args.positive(1)?)),
+    },
+    Function {
+        name: "multiples_in_range",
+        params: &["n", "start", "end"],
+        summary: "the multiples of n from start to end",
+        apply: |args| {
+            let multiples = get_multiples_in_range(args.positive(0)?, args.u32(1)?, args.u32(2)?);
+            Ok(Value::int_set(multiples))
+        },
+    },
+    Function {
+        name: "is_prime",
+        params: &["n"],
+        summary: "whether n is prime",
+        apply: |args| bool(is_prime(args.positive(0)?)),
+    },
+    Function {
+        name: "is_composite",
+        params: &["n"],
+        summary: "whether n is composite",
+        apply: |args| bool(is_composite(args.positive(0)?)),
+    },
+    Function {
+        name: "primes_in_range",
+        params: &["start", "end"],
+        summary: "the primes from start to end",
+        apply: |args| {
+            Ok(Value::int_set(get_primes_in_range(
+                args.positive(0)?,
+                args.u32(1)?,
+            )))
+        },
+    },
+    Function {
+        name: "prime_factorization",
+        params: &["n"],
+        summary: "the prime factorization of n, as a map from each prime to its exponent",
+        apply: |args| {
+            let factorization = get_prime_factorization(args.positive(0)?);
+            Ok(Value::Map(
+                factorization
+                    .into_iter()
+                    .map(|(prime, exponent)| {
+                        (Value::Int(prime.into()), Value::Int(exponent.into()))
+                    })
+                    .collect(),
+            ))
+        },
+    },
+    Function {
+        name: "to_base",
+        params: &["n", "base"],
+        summary: "n written in base 2 to 36",
+        apply: |args| string(to_base(args.int(0)?, args.u32(1)?)),
+    },
+    Function {
+        name: "from_base",
+        params: &["digits", "base"],
+        summary: "the number the digits represent in base 2 to 36",
+        apply: |args| {
+            from_base(args.str(0)?, args.u32(1)?)
+                .map(Value::Int)
+                .map_err(EvalErrorKind::Base)
+        },
+    },
+    Function {
+        name: "convert_base",
+        params: &["digits", "from", "to"],
+        summary: "the digits converted from one base to another",
+        apply: |args| string(convert_base(args.str(0)?, args.u32(1)?, args.u32(2)?)),
+    },
+    Function {
+        name: "parse_int_literal",
+        params: &["literal"],
+        summary: "the number a literal like \"0xff\" or \"-0b101\" represents",
+        apply: |args| {
+            parse_int_literal(args.str(0)?)
+                .map(Value::Int)
+                .map_err(EvalErrorKind::Base)
+        },
+    },
+    Function {
+        name: "to_balanced_ternary",
+        params: &["n"],
+        summary: "n in balanced ternary, with T for -1",
+        apply: |args| Ok(Value::Str(to_balanced_ternary(args.int(0)?))),
+    },
+    Function {
+        name: "from_balanced_ternary",
+        params: &["digits"],
+        summary: "the number balanced ternary digits represent",
+        apply: |args| {
+            from_balanced_ternary(args.str(0)?)
+                .map(Value::Int)
+                .map_err(EvalErrorKind::Base)
+        },
+    },
+    Function {
+        name: "to_roman",
+        params: &["n"],
+        summary: "n in Roman numerals",
+        apply: |args| string(to_roman(args.u32(0)?)),
+    },
+    Function {
+        name: "from_roman",
+        params: &["numeral"],
+        summary: "the number a Roman numeral represents",
+        apply: |args| {
+            from_roman(args.str(0)?)
+                .map(|n| Value::Int(n.into()))
+                .map_err(EvalErrorKind::Base)
+        },
+    },
+    Function {
+        name: "to_scientific_notation",
+        params: &["n", "digits"],
+        summary: "n in scientific notation with that many significant digits",
+        apply: |args| {
+            let notation = to_scientific_notation(args.int(0)? as f64, args.u32(1)?);
+            Ok(Value::Str(notation.to_string()))
+        },
+    },
+    Function {
+        name: "to_twos_complement",
+        params: &["n", "bits"],
+        summary: "n as a two's complement bit string",
+        apply: |args| string(to_twos_complement(args.int(0)?, args.u32(1)?)),
+    },
+    Function {
+        name: "from_twos_complement",
+        params: &["bits"],
+        summary: "the number a two's complement bit string represents",
+        apply: |args| {
+            from_twos_complement(args.str(0)?)
+                .map(Value::Int)
+                .map_err(EvalErrorKind::Base)
+        },
+    },
+    Function {
+        name: "twos_complement_range",
+        params: &["bits"],
+        summary: "the smallest and largest numbers that many bits hold, as a tuple",
+        apply: |args| {
+            let (min, max) = twos_complement_range(args.int_in(0, 1, 64, "between 1 and 64")?);
+            Ok(Value::Tuple(vec![Value::Int(min), Value::Int(max)]))
+        },
+    },
+    Function {
+        name: "fraction_to_base",
+        params: &["numerator", "denominator", "base"],
+        summary: "the fraction's digits in a base, with repeating digits in parentheses",
+        apply: |args| {
+            let expansion =
+                fraction_to_base(args.nonnegative(0)?, args.nonnegative(1)?, args.u32(2)?);
+            string(expansion.map(|expansion| expansion.to_string()))
+        },
+    },
+    Function {
+        name: "sum",
+        params: &["set"],
+        summary: "the sum of a set of integers",
+        apply: |args| {
+            let mut total: i64 = 0;
+            for item in args.set(0)? {
+                let Value::Int(n) = item else {
+                    return Err(EvalErrorKind::TypeMismatch {
+                        operation: "the items of sum".to_owned(),
+                        expected: &[Type::Int],
+                        found: item.type_of(),
+                    });
+                };
+                total = total.checked_add(*n).ok_or(EvalErrorKind::Overflow)?;
+            }
+            int(total)
+        },
+    },
+    Function {
+        name: "len",
+        params: &["collection"],
+        summary: "the number of items in a set, map or tuple, or characters in a string",
+        apply: |args| {
+            let len = match &args.values[0] {
+                Value::Set(items) => items.len(),
+                Value::Map(entries) => entries.len(),
+                Value::Tuple(items) => items.len(),
+                Value::Str(s) => s.chars().count(),
+                _ => return Err(args.mismatch(0, &[Type::Set, Type::Map, Type::Tuple, Type::Str])),
+            };
+            int(len as i64)
+        },
+    },
+    Function {
+        name: "max",
+        params: &["set"],
+        summary: "the largest item of a set",
+        apply: |args| args.extreme(true),
+    },
+    Function {
+        name: "min",
+        params: &["set"],
+        summary: "the smallest item of a set",
+        apply: |args| args.extreme(false),
+    },
+    Function {
+        name: "keys",
+        params: &["map"],
+        summary: "the keys of a map, such as the primes of a prime factorization",
+        apply: |args| match &args.values[0] {
+            Value::Map(entries) => Ok(Value::Set(entries.keys().cloned().collect())),
+            _ => Err(args.mismatch(0, &[Type::Map])),
+        },
+    },
+    Function {
+        name: "gcd",
+        params: &["a", "b"],
+        summary: "the greatest common divisor of a and b",
+        apply: |args| {
+            let divisor = gcd(args.int(0)?, args.int(1)?);
+            int(i64::try_from(divisor).map_err(|_| EvalErrorKind::Overflow)?)
+        },
+    },
+    Function {
+        name: "lcm",
+        params: &["a", "b"],
+        summary: "the least common multiple of a and b",
+        apply: |args| {
+            let (a, b) = (args.int(0)?, args.int(1)?);
+            if a == 0 || b == 0 {
+                return int(0);
+            }
+            let multiple = (a.unsigned_abs() / gcd(a, b)).checked_mul(b.unsigned_abs());
+            int(multiple
+                .and_then(|multiple| i64::try_from(multiple).ok())
+                .ok_or(EvalErrorKind::Overflow)?)
+        },
+    },
+];
+
+/// The functions whose `ladderz::prealgebra` names start with `get_`.
+const GETTERS: [&str; 5] = [
+    "factor_pairs",
+    "factors",
+    "multiples_in_range",
+    "primes_in_range",
+    "prime_factorization",
+];
+
+/// Finds a function by name. The `ladderz::prealgebra` functions that start with `get_` may be
+/// written with or without it, e.g. `get_factors` or `factors`.
+pub fn find(name: &str) -> Option<&'static Function> {
+    let name = match name.strip_prefix("get_") {
+        Some(getter) if GETTERS.contains(&getter) => getter,
+        _ => name,
+    };
+    FUNCTIONS.iter().find(|function| function.name == name)
+}
@@ -0,0 +1,627 @@
+//! Tokenizes and parses `lz eval` expressions such as `sum(factors(12) & factors(18))`.
+//!
+//! From lowest to highest precedence, the operators are comparisons (`==`, `!=`, `<`, `<=`, `>`,
+//! `>=`), union (`|`), intersection (`&`), `+` and `-`, `*`, `/` and `%`, negation, and `^`.
+
+use ladderz::prealgebra::parse_int_literal;
+use std::fmt;
+
+/// A binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+    Intersection,
+    Union,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            BinaryOp::Add => "+",
+            BinaryOp::Subtract => "-",
+            BinaryOp::Multiply => "*",
+            BinaryOp::Divide => "/",
+            BinaryOp::Remainder => "%",
+            BinaryOp::Power => "^",
+            BinaryOp::Intersection => "&",
+            BinaryOp::Union => "|",
+            BinaryOp::Equal => "==",
+            BinaryOp::NotEqual => "!=",
+            BinaryOp::Less => "<",
+            BinaryOp::LessOrEqual => "<=",
+            BinaryOp::Greater => ">",
+            BinaryOp::GreaterOrEqual => ">=",
+        };
+        write!(f, "{symbol}")
+    }
+}
+
+/// A parsed expression. `position` is the character index of the operator or function name, for
+/// pointing at errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Int(i64),
+    Bool(bool),
+    Str(String),
+    /// A set literal, e.g. `{1, 2, 3}`.
+    Set(Vec<Expr>),
+    Negate {
+        operand: Box<Expr>,
+        position: usize,
+    },
+    Binary {
+        op: BinaryOp,
+        left: Box<Expr>,
+        right: Box<Expr>,
+        position: usize,
+    },
+    Call {
+        name: String,
+        args: Vec<Expr>,
+        position: usize,
+    },
+}
+
+/// What went wrong while parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A character that can't start a token.
+    UnexpectedCharacter(char),
+    /// A string literal without its closing `"`.
+    UnterminatedString,
+    /// A number that isn't a valid integer literal or doesn't fit in 64 bits.
+    InvalidNumber(String),
+    /// A token other than the one expected, e.g. `expected `)`, found `,``.
+    Expected {
+        expected: &'static str,
+        found: String,
+    },
+    /// Parentheses, calls, sets, negations, powers or chained operators nested more than
+    /// [`MAX_DEPTH`] levels deep.
+    TooDeeplyNested,
+}
+
+/// The deepest nesting the parser accepts, which keeps deeply nested input from overflowing the
+/// stack. Each level takes several frames, so this leaves room on a 2 MiB thread stack even in a
+/// debug build.
+pub const MAX_DEPTH: usize = 128;
+
+/// An error from parsing an expression, at a character index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub position: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character {c:?}"),
+            ParseErrorKind::UnterminatedString => write!(f, "the string is missing its closing \""),
+            ParseErrorKind::InvalidNumber(number) => write!(f, "{number} is not a valid integer"),
+            ParseErrorKind::Expected { expected, found } => {
+                write!(f, "expected {expected}, but found {found}")
+            }
+            ParseErrorKind::TooDeeplyNested => {
+                write!(
+                    f,
+                    "the expression is nested more than {MAX_DEPTH} levels deep"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Int(i64),
+    Str(String),
+    Ident(String),
+    Op(BinaryOp),
+    LeftParen,
+    RightParen,
+    LeftBrace,
+    RightBrace,
+    Comma,
+    End,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Int(n) => write!(f, "{n}"),
+            Token::Str(s) => write!(f, "{s:?}"),
+            Token::Ident(name) => write!(f, "`{name}`"),
+            Token::Op(op) => write!(f, "`{op}`"),
+            Token::LeftParen => write!(f, "`(`"),
+            Token::RightParen => write!(f, "`)`"),
+            Token::LeftBrace => write!(f, "`{{`"),
+            Token::RightBrace => write!(f, "`}}`"),
+            Token::Comma => write!(f, "`,`"),
+            Token::End => write!(f, "the end of the expression"),
+        }
+    }
+}
+
+/// Splits `source` into tokens, each with the character index it starts at.
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let start = i;
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        i += 1;
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '0'..='9' => {
+                // Take the whole literal, including `0x`, `0b`, `0o` prefixes and `_` separators.
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let literal: String = chars[start..i].iter().collect();
+                match parse_int_literal(&literal) {
+                    Ok(n) => Token::Int(n),
+                    Err(_) => {
+                        return Err(ParseError {
+                            position: start,
+                            kind: ParseErrorKind::InvalidNumber(literal),
+                        })
+                    }
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                Token::Ident(chars[start..i].iter().collect())
+            }
+            '"' => {
+                let Some(length) = chars[i..].iter().position(|&c| c == '"') else {
+                    return Err(ParseError {
+                        position: start,
+                        kind: ParseErrorKind::UnterminatedString,
+                    });
+                };
+                let string = chars[i..i + length].iter().collect();
+                i += length + 1;
+                Token::Str(string)
+            }
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '{' => Token::LeftBrace,
+            '}' => Token::RightBrace,
+            ',' => Token::Comma,
+            '+' => Token::Op(BinaryOp::Add),
+            '-' => Token::Op(BinaryOp::Subtract),
+            '*' => Token::Op(BinaryOp::Multiply),
+            '/' => Token::Op(BinaryOp::Divide),
+            '%' => Token::Op(BinaryOp::Remainder),
+            '^' => Token::Op(BinaryOp::Power),
+            '&' => Token::Op(BinaryOp::Intersection),
+            '|' => Token::Op(BinaryOp::Union),
+            '=' | '!' | '<' | '>' => {
+                let op = match (c, next) {
+                    ('=', Some('=')) => BinaryOp::Equal,
+                    ('!', Some('=')) => BinaryOp::NotEqual,
+                    ('<', Some('=')) => BinaryOp::LessOrEqual,
+                    ('>', Some('=')) => BinaryOp::GreaterOrEqual,
+                    ('<', _) => BinaryOp::Less,
+                    ('>', _) => BinaryOp::Greater,
+                    _ => {
+                        return Err(ParseError {
+                            position: start,
+                            kind: ParseErrorKind::UnexpectedCharacter(c),
+                        })
+                    }
+                };
+                if next == Some('=') {
+                    i += 1;
+                }
+                Token::Op(op)
+            }
+            _ => {
+                return Err(ParseError {
+                    position: start,
+                    kind: ParseErrorKind::UnexpectedCharacter(c),
+                })
+            }
+        };
+        tokens.push((token, start));
+    }
+    tokens.push((Token::End, chars.len()));
+    Ok(tokens)
+}
+
+/// A recursive descent parser with one function per precedence level.
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    index: usize,
+    /// How many nested expressions are being parsed, up to [`MAX_DEPTH`].
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index].0
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.index].1
+    }
+
+    fn advance(&mut self) -> (Token, usize) {
+        let token = self.tokens[self.index].clone();
+        if token.0 != Token::End {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn expected<T>(&self, expected: &'static str) -> Result<T, ParseError> {
+        Err(ParseError {
+            position: self.position(),
+            kind: ParseErrorKind::Expected {
+                expected,
+                found: self.peek().to_string(),
+            },
+        })
+    }
+
+    fn expect(&mut self, token: Token, expected: &'static str) -> Result<(), ParseError> {
+        match *self.peek() == token {
+            true => {
+                self.advance();
+                Ok(())
+            }
+            false => self.expected(expected),
+        }
+    }
+
+    /// Goes one level deeper, or returns an error if that's deeper than [`MAX_DEPTH`].
+    fn descend(&mut self) -> Result<(), ParseError> {
+        if self.depth == MAX_DEPTH {
+            return Err(ParseError {
+                position: self.position(),
+                kind: ParseErrorKind::TooDeeplyNested,
+            });
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Parses a nested expression with `parse`, or returns an error if it's too deep.
+    fn nested(
+        &mut self,
+        parse: fn(&mut Parser) -> Result<Expr, ParseError>,
+    ) -> Result<Expr, ParseError> {
+        self.descend()?;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Parses a left-associative level where `ops` join operands parsed by `operand`.
+    fn left_associative(
+        &mut self,
+        ops: &[BinaryOp],
+        operand: fn(&mut Parser) -> Result<Expr, ParseError>,
+    ) -> Result<Expr, ParseError> {
+        let depth = self.depth;
+        let mut left = operand(self)?;
+        while let Token::Op(op) = *self.peek() {
+            if !ops.contains(&op) {
+                break;
+            }
+            // Each operator nests the operands before it one level deeper.
+            self.descend()?;
+            let (_, position) = self.advance();
+            left = Expr::Binary {
+                op,
+                left: Box::new(left),
+                right: Box::new(operand(self)?),
+                position,
+            };
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    /// A comparison doesn't chain, so `1 < 2 < 3` is an error.
+    fn comparison(&mut self) -> Result<Expr, ParseError> {
+        let left = self.union()?;
+        match *self.peek() {
+            Token::Op(
+                op @ (BinaryOp::Equal
+                | BinaryOp::NotEqual
+                | BinaryOp::Less
+                | BinaryOp::LessOrEqual
+                | BinaryOp::Greater
+                | BinaryOp::GreaterOrEqual),
+            ) => {
+                let (_, position) = self.advance();
+                Ok(Expr::Binary {
+                    op,
+                    left: Box::new(left),
+                    right: Box::new(self.union()?),
+                    position,
+                })
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn union(&mut self) -> Result<Expr, ParseError> {
+        self.left_associative(&[BinaryOp::Union], Parser::intersection)
+    }
+
+    fn intersection(&mut self) -> Result<Expr, ParseError> {
+        self.left_associative(&[BinaryOp::Intersection], Parser::additive)
+    }
+
+    fn additive(&mut self) -> Result<Expr, ParseError> {
+        self.left_associative(&[BinaryOp::Add, BinaryOp::Subtract], Parser::multiplicative)
+    }
+
+    fn multiplicative(&mut self) -> Result<Expr, ParseError> {
+        self.left_associative(
+            &[BinaryOp::Multiply, BinaryOp::Divide, BinaryOp::Remainder],
+            Parser::unary,
+        )
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        match self.peek() {
+            Token::Op(BinaryOp::Subtract) => {
+                let (_, position) = self.advance();
+                Ok(Expr::Negate {
+                    operand: Box::new(self.nested(Parser::unary)?),
+                    position,
+                })
+            }
+            _ => self.power(),
+        }
+    }
+
+    /// `^` is right-associative and binds tighter than negation, so `-2^2` is `-(2^2)`.
+    fn power(&mut self) -> Result<Expr, ParseError> {
+        let base = self.primary()?;
+        match self.peek() {
+            Token::Op(BinaryOp::Power) => {
+                let (_, position) = self.advance();
+                Ok(Expr::Binary {
+                    op: BinaryOp::Power,
+                    left: Box::new(base),
+                    right: Box::new(self.nested(Parser::unary)?),
+                    position,
+                })
+            }
+            _ => Ok(base),
+        }
+    }
+
+    /// Parses expressions separated by commas until `close`, allowing a trailing comma.
+    fn list(&mut self, close: Token, expected: &'static str) -> Result<Vec<Expr>, ParseError> {
+        let mut items = Vec::new();
+        while *self.peek() != close {
+            items.push(self.nested(Parser::comparison)?);
+            if *self.peek() != Token::Comma {
+                break;
+            }
+            self.advance();
+        }
+        self.expect(close, expected)?;
+        Ok(items)
+    }
+
+    fn primary(&mut self) -> Result<Expr, ParseError> {
+        match self.peek().clone() {
+            Token::Int(n) => {
+                self.advance();
+                Ok(Expr::Int(n))
+            }
+            Token::Str(s) => {
+                self.advance();
+                Ok(Expr::Str(s))
+            }
+            Token::Ident(name) if name == "true" || name == "false" => {
+                self.advance();
+                Ok(Expr::Bool(name == "true"))
+            }
+            Token::Ident(name) => {
+                let (_, position) = self.advance();
+                self.expect(Token::LeftParen, "`(` after the function name")?;
+                let args = self.list(Token::RightParen, "`,` or `)`")?;
+                Ok(Expr::Call {
+                    name,
+                    args,
+                    position,
+                })
+            }
+            Token::LeftParen => {
+                self.advance();
+                let expr = self.nested(Parser::comparison)?;
+                self.expect(Token::RightParen, "`)`")?;
+                Ok(expr)
+            }
+            Token::LeftBrace => {
+                self.advance();
+                Ok(Expr::Set(self.list(Token::RightBrace, "`,` or `}`")?))
+            }
+            _ => self.expected("a number, string, set, function call or `(`"),
+        }
+    }
+}
+
+/// Parses an expression such as `len(factors(360)) * 2`.
+pub fn parse(source: &str) -> Result<Expr, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        index: 0,
+        depth: 0,
+    };
+    let expr = parser.comparison()?;
+    match parser.peek() {
+        Token::End => Ok(expr),
+        _ => parser.expected("an operator or the end of the expression"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary(op: BinaryOp, left: Expr, right: Expr, position: usize) -> Expr {
+        Expr::Binary {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+            position,
+        }
+    }
+
+    #[test]
+    fn test_precedence() {
+        // 1 + 2 * 3 ^ 2
+        assert_eq!(
+            parse("1 + 2 * 3 ^ 2").unwrap(),
+            binary(
+                BinaryOp::Add,
+                Expr::Int(1),
+                binary(
+                    BinaryOp::Multiply,
+                    Expr::Int(2),
+                    binary(BinaryOp::Power, Expr::Int(3), Expr::Int(2), 10),
+                    6
+                ),
+                2
+            )
+        );
+        // Subtraction is left-associative and `^` is right-associative.
+        assert_eq!(
+            parse("8-4-2").unwrap(),
+            binary(
+                BinaryOp::Subtract,
+                binary(BinaryOp::Subtract, Expr::Int(8), Expr::Int(4), 1),
+                Expr::Int(2),
+                3
+            )
+        );
+        assert_eq!(
+            parse("2^3^2").unwrap(),
+            binary(
+                BinaryOp::Power,
+                Expr::Int(2),
+                binary(BinaryOp::Power, Expr::Int(3), Expr::Int(2), 3),
+                1
+            )
+        );
+        // `&` binds tighter than `|`.
+        assert!(matches!(
+            parse("{1} | {2} & {3}").unwrap(),
+            Expr::Binary {
+                op: BinaryOp::Union,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_calls_and_literals() {
+        assert_eq!(
+            parse(r#"from_base("ff", 0x10)"#).unwrap(),
+            Expr::Call {
+                name: "from_base".to_owned(),
+                args: vec![Expr::Str("ff".to_owned()), Expr::Int(16)],
+                position: 0,
+            }
+        );
+        assert_eq!(
+            parse("{1, 2,}").unwrap(),
+            Expr::Set(vec![Expr::Int(1), Expr::Int(2)])
+        );
+        assert_eq!(parse("{}").unwrap(), Expr::Set(vec![]));
+        assert_eq!(parse("1_000").unwrap(), Expr::Int(1000));
+        assert_eq!(
+            parse("-true").unwrap(),
+            Expr::Negate {
+                operand: Box::new(Expr::Bool(true)),
+                position: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |source: &str| parse(source).unwrap_err();
+        assert_eq!(
+            error("factors(12"),
+            ParseError {
+                position: 10,
+                kind: ParseErrorKind::Expected {
+                    expected: "`,` or `)`",
+                    found: "the end of the expression".to_owned()
+                }
+            }
+        );
+        assert_eq!(error("factors 12").position, 8);
+        assert_eq!(
+            error("1 + $").kind,
+            ParseErrorKind::UnexpectedCharacter('$')
+        );
+        assert_eq!(error("\"abc").kind, ParseErrorKind::UnterminatedString);
+        assert_eq!(
+            error("99999999999999999999").kind,
+            ParseErrorKind::InvalidNumber("99999999999999999999".to_owned())
+        );
+        assert_eq!(error("1 < 2 < 3").position, 6);
+        assert_eq!(
+            error("1 = 2").kind,
+            ParseErrorKind::UnexpectedCharacter('=')
+        );
+        assert_eq!(
+            error("(1 + 2) 3").to_string(),
+            "expected an operator or the end of the expression, but found 3"
+        );
+    }
+
+    #[test]
+    fn test_deep_nesting() {
+        let nested = |depth: usize| format!("{}1{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(parse(&nested(MAX_DEPTH)).unwrap(), Expr::Int(1));
+        assert_eq!(
+            parse(&nested(MAX_DEPTH + 1)).unwrap_err(),
+            ParseError {
+                position: MAX_DEPTH + 1,
+                kind: ParseErrorKind::TooDeeplyNested
+            }
+        );
+        for source in [
+            nested(50_000),
+            format!("{}1", "-".repeat(50_000)),
+            format!("2{}", "^2".repeat(50_000)),
+            format!("{}1{}", "{".repeat(50_000), "}".repeat(50_000)),
+            format!("{}1{}", "len(".repeat(50_000), ")".repeat(50_000)),
+            vec!["1"; 50_000].join(" + "),
+        ] {
+            assert_eq!(
+                parse(&source).unwrap_err().kind,
+                ParseErrorKind::TooDeeplyNested
+            );
+        }
+    }
+}
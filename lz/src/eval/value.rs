@@ -0,0 +1,114 @@
+//! The values `lz eval` expressions evaluate to.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The type of a [`Value`], for type errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Type {
+    Bool,
+    Int,
+    Str,
+    Tuple,
+    Set,
+    Map,
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Type::Bool => "bool",
+            Type::Int => "int",
+            Type::Str => "string",
+            Type::Tuple => "tuple",
+            Type::Set => "set",
+            Type::Map => "map",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A value. Sets and maps are ordered, so they always print the same way.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Value {
+    Bool(bool),
+    Int(i64),
+    Str(String),
+    /// A fixed group of values, such as a factor pair `(2, 6)`.
+    Tuple(Vec<Value>),
+    Set(BTreeSet<Value>),
+    /// A map, such as a prime factorization from each prime to its exponent.
+    Map(BTreeMap<Value, Value>),
+}
+
+impl Value {
+    /// Returns the value's type.
+    pub fn type_of(&self) -> Type {
+        match self {
+            Value::Bool(_) => Type::Bool,
+            Value::Int(_) => Type::Int,
+            Value::Str(_) => Type::Str,
+            Value::Tuple(_) => Type::Tuple,
+            Value::Set(_) => Type::Set,
+            Value::Map(_) => Type::Map,
+        }
+    }
+
+    /// Returns a set of integers.
+    pub fn int_set(numbers: impl IntoIterator<Item = impl Into<i64>>) -> Value {
+        Value::Set(numbers.into_iter().map(|n| Value::Int(n.into())).collect())
+    }
+
+    /// Writes the value inside a collection, where strings are quoted.
+    fn fmt_nested(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Str(s) => write!(f, "{s:?}"),
+            _ => write!(f, "{self}"),
+        }
+    }
+}
+
+/// Writes each of `items` with `write`, separated by commas.
+fn comma_separated<T>(
+    f: &mut fmt::Formatter<'_>,
+    items: impl IntoIterator<Item = T>,
+    mut write: impl FnMut(&mut fmt::Formatter<'_>, T) -> fmt::Result,
+) -> fmt::Result {
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write(f, item)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Value {
+    /// Writes sets as `{1, 2, 3}`, tuples as `(1, 12)` and maps as `{2: 3, 3: 2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Int(n) => write!(f, "{n}"),
+            Value::Str(s) => write!(f, "{s}"),
+            Value::Tuple(items) => {
+                write!(f, "(")?;
+                comma_separated(f, items, |f, item| item.fmt_nested(f))?;
+                write!(f, ")")
+            }
+            Value::Set(items) => {
+                write!(f, "{{")?;
+                comma_separated(f, items, |f, item| item.fmt_nested(f))?;
+                write!(f, "}}")
+            }
+            Value::Map(entries) => {
+                write!(f, "{{")?;
+                comma_separated(f, entries, |f, (key, value)| {
+                    key.fmt_nested(f)?;
+                    write!(f, ": ")?;
+                    value.fmt_nested(f)
+                })?;
+                write!(f, "}}")
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use clap::Command;
+use clap_complete::Shell;
+use clap_mangen::Man;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Writes the completion script for `shell` covering every subject and function in `cmd`.
+pub fn write_completions(mut cmd: Command, shell: Shell, out: &mut dyn Write) {
+    let name = cmd.get_name().to_owned();
+    clap_complete::generate(shell, &mut cmd, name, out);
+}
+
+/// Finds the command at `path` below `cmd`, e.g. `["prealgebra", "factors"]`, with its
+/// display name set to the man page name such as `lz-prealgebra-factors`.
+fn find_command(cmd: Command, path: &[String]) -> Result<Command, String> {
+    let mut cmd = cmd.disable_help_subcommand(true);
+    cmd.build();
+    let mut found = cmd;
+    for name in path {
+        let subcommand = found
+            .find_subcommand(name)
+            .ok_or_else(|| format!("{} has no subcommand named {name:?}", found.get_name()))?
+            .clone();
+        found = subcommand;
+    }
+    Ok(found)
+}
+
+/// Writes the man page for the command at `path` below `cmd`, or for `cmd` itself if `path` is
+/// empty.
+pub fn write_man_page(cmd: Command, path: &[String], out: &mut dyn Write) -> Result<(), String> {
+    let found = find_command(cmd, path)?;
+    Man::new(found).render(out).map_err(|e| e.to_string())
+}
+
+/// Writes a man page for `cmd` and every subject and function below it into `out_dir`.
+pub fn write_man_pages(cmd: Command, out_dir: &Path) -> io::Result<()> {
+    std::fs::create_dir_all(out_dir)?;
+    clap_mangen::generate_to(cmd, out_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cli;
+    use clap::{CommandFactory, ValueEnum};
+
+    fn completions(shell: Shell) -> String {
+        let mut out = Vec::new();
+        write_completions(Cli::command(), shell, &mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_completions_for_each_shell() {
+        for &shell in Shell::value_variants() {
+            let script = completions(shell);
+            for name in [
+                "prealgebra",
+                "precalculus",
+                "dsa",
+                "bioinformatics",
+                "completions",
+            ] {
+                assert!(script.contains(name), "{shell} completions lack {name}");
+            }
+            // Functions and their flags are completed too, not just subjects.
+            for name in ["factor-pairs", "convert", "reverse-complement"] {
+                assert!(script.contains(name), "{shell} completions lack {name}");
+            }
+            let raw_flag = match shell {
+                Shell::Fish => "-l raw",
+                _ => "--raw",
+            };
+            assert!(script.contains(raw_flag), "{shell} completions lack --raw");
+        }
+    }
+
+    #[test]
+    fn test_completions_use_shell_syntax() {
+        assert!(completions(Shell::Bash).contains("complete -F _lz"));
+        assert!(completions(Shell::Zsh).starts_with("#compdef lz"));
+        assert!(completions(Shell::Fish).contains("complete -c lz"));
+        assert!(completions(Shell::PowerShell).contains("Register-ArgumentCompleter"));
+        assert!(completions(Shell::Elvish).contains("set edit:completion:arg-completer[lz]"));
+    }
+
+    #[test]
+    fn test_man_pages() {
+        let page = |path: &[&str]| {
+            let path: Vec<String> = path.iter().map(|s| s.to_string()).collect();
+            let mut out = Vec::new();
+            write_man_page(Cli::command(), &path, &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        let top = page(&[]);
+        assert!(top.starts_with(".ie \\n(.g .ds Aq"));
+        assert!(top.contains(".TH lz 1"));
+        assert!(top.contains("prealgebra"));
+        let function = page(&["prealgebra", "factors"]);
+        assert!(function.contains(".TH lz-prealgebra-factors 1"));
+        assert!(function.contains("The positive integer to find factors for"));
+
+        let mut out = Vec::new();
+        let error = write_man_page(Cli::command(), &["algebra".to_owned()], &mut out);
+        assert_eq!(
+            error,
+            Err("lz has no subcommand named \"algebra\"".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_man_pages_for_every_command() {
+        let dir = std::env::temp_dir().join(format!("lz-man-{}", std::process::id()));
+        write_man_pages(Cli::command(), &dir).unwrap();
+        let mut expected = vec!["lz.1".to_owned()];
+        for subject in Cli::command().get_subcommands() {
+            expected.push(format!("lz-{}.1", subject.get_name()));
+            for function in subject.get_subcommands() {
+                expected.push(format!(
+                    "lz-{}-{}.1",
+                    subject.get_name(),
+                    function.get_name()
+                ));
+            }
+        }
+        for name in &expected {
+            assert!(dir.join(name).is_file(), "missing {name}");
+        }
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
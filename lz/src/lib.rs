@@ -0,0 +1,211 @@
+//! # lz
+//!
+//! A command-line interface for various math/tech subjects. Based on the [ladderz](https://github.com/rzmk/ladderz) library.
+//!
+//! # Installation
+//!
+//! To install the command-line interface, run the following command in your terminal:
+//!
+//! ```bash
+//! cargo install --git https://github.com/rzmk/ladderz --branch main
+//! ```
+//!
+//! # Example
+//!
+//! ```bash
+//! lz prealgebra is-factor 3 12
+//! ```
+//!
+//! ```console
+//! 3 is a factor of 12.
+//! ```
+//!
+//! You may view the help menu for a subject and a function by running the command with the `-h` or `--help` flag:
+//!
+//! ```bash
+//! lz prealgebra is-factor -h
+//! ```
+//!
+//! Learn more on [GitHub](https://github.com/rzmk/ladderz).
+
+// External modules
+use clap::{Parser, Subcommand};
+use clap_complete::Shell;
+use std::path::PathBuf;
+
+// Local modules
+pub mod output;
+use output::Format;
+pub mod registry;
+use registry::Invocation;
+pub mod prealgebra;
+pub mod precalculus;
+use precalculus::Precalculus;
+pub mod bioinformatics;
+pub mod dsa;
+use bioinformatics::Bioinformatics;
+pub mod practice;
+use practice::Practice;
+pub mod quiz;
+use quiz::Quiz;
+pub mod worksheet;
+use worksheet::Worksheet;
+pub mod eval;
+pub mod generate;
+
+#[derive(Parser)]
+#[command(
+    author = "Mueez Khan",
+    about = "Run various functions from a range of math/tech subjects on the command line.",
+    subcommand_value_name = "SUBJECT",
+    arg_required_else_help(true)
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub subject: Option<Subjects>,
+}
+
+/// The subjects that can be used.
+#[derive(Subcommand)]
+#[command(arg_required_else_help(true))]
+pub enum Subjects {
+    /// The subjects in the registry, such as `prealgebra` and `dsa`.
+    #[command(flatten)]
+    Registered(Invocation),
+    /// Functions, trigonometry, triangles, logarithms and series
+    Precalculus {
+        /// The function (command) to run.
+        #[command(subcommand)]
+        function: Option<Precalculus>,
+    },
+    /// DNA, RNA and protein sequences, motifs and alignment
+    Bioinformatics {
+        /// The function (command) to run.
+        #[command(subcommand)]
+        function: Option<Bioinformatics>,
+    },
+    /// Generates practice problems with answers for a subject.
+    Practice {
+        /// The subject to practice.
+        #[command(subcommand)]
+        subject: Option<Practice>,
+    },
+    /// Quizzes you interactively on a subject, with hints, and saves your progress.
+    Quiz {
+        /// The subject to be quizzed on, or `progress` to see your mastery.
+        #[command(subcommand)]
+        subject: Option<Quiz>,
+    },
+    /// Generates a printable worksheet of practice problems and a separate answer key.
+    ///
+    /// The worksheet is written as Markdown, HTML or LaTeX source (which can be compiled into a
+    /// PDF), and the same `--seed` always gives the same worksheet.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz worksheet --topics factors,primes --count 20 --seed 7 --out sheet.md
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// Wrote the worksheet to sheet.md and its answer key to sheet-answers.md.
+    /// ```
+    Worksheet(Worksheet),
+    /// Evaluates an expression that combines pre-algebra functions, arithmetic and set operations.
+    ///
+    /// Every `ladderz::prealgebra` function can be called, with or without its `get_` prefix.
+    /// Sets support `&` (intersection), `|` (union) and `-` (difference), and `sum`, `len`, `max`
+    /// and `min` aggregate them. Run `lz eval --functions` to list every function.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz eval "sum(keys(prime_factorization(lcm(12, 18))))"
+    /// lz eval "factors(12) & factors(18)"
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// sum(keys(prime_factorization(lcm(12, 18)))) = 5
+    /// factors(12) & factors(18) = {1, 2, 3, 6}
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 5
+    /// {1, 2, 3, 6}
+    /// ```
+    Eval {
+        /// The expression to evaluate, e.g. `len(factors(360))`.
+        #[arg(required_unless_present = "functions")]
+        expression: Option<String>,
+        /// List the functions that can be called instead.
+        #[arg(long, conflicts_with = "expression")]
+        functions: bool,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Runs a command from each line of a file, such as `prealgebra factors 12`.
+    ///
+    /// Each line is a subject, a function and its arguments, and quotes keep arguments with
+    /// spaces together. Blank lines and lines starting with `#` are skipped. A failed line is
+    /// reported and the rest still run.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// printf 'prealgebra is-prime 7\ndsa stack "push 3; pop"\n' | lz batch --format json
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// {"command":"prealgebra is-prime 7","result":true}
+    /// {"command":"dsa stack \"push 3; pop\"","result":"[]"}
+    /// ```
+    Batch {
+        /// The file of commands to run, or standard input if not given.
+        file: Option<PathBuf>,
+        /// The format to print each result in, unless its line has `--format` or `-r`. In JSON,
+        /// such a line's result is a string in its own format.
+        #[arg(long, value_enum, default_value_t = Format::Prose)]
+        format: Format,
+    },
+    /// Generates a shell completion script for lz.
+    ///
+    /// ## Example
+    ///
+    /// ```bash
+    /// lz completions bash > ~/.local/share/bash-completion/completions/lz
+    /// ```
+    Completions {
+        /// The shell to generate completions for.
+        shell: Shell,
+    },
+    /// Generates a man page for lz or one of its subjects and functions.
+    ///
+    /// ## Example
+    ///
+    /// ```bash
+    /// lz man prealgebra factors | man -l -
+    /// lz man --out-dir man/
+    /// ```
+    Man {
+        /// The subject and function to generate the man page for, e.g. `prealgebra factors`.
+        command: Vec<String>,
+        /// Write a man page for lz and every subject and function into this directory instead.
+        #[arg(long, conflicts_with = "command")]
+        out_dir: Option<PathBuf>,
+    },
+}
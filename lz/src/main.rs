@@ -1,76 +1,69 @@
-//! # lz
-//!
-//! A command-line interface for various math/tech subjects. Based on the [ladderz](https://github.com/rzmk/ladderz) library.
-//!
-//! # Installation
-//!
-//! To install the command-line interface, run the following command in your terminal:
-//!
-//! ```bash
-//! cargo install --git https://github.com/rzmk/ladderz --branch main
-//! ```
-//!
-//! # Example
-//!
-//! ```bash
-//! lz prealgebra is-factor 3 12
-//! ```
-//!
-//! ```console
-//! 3 is a factor of 12.
-//! ```
-//!
-//! You may view the help menu for a subject and a function by running the command with the `-h` or `--help` flag:
-//!
-//! ```bash
-//! lz prealgebra is-factor -h
-//! ```
-//!
-//! Learn more on [GitHub](https://github.com/rzmk/ladderz).
+//! The `lz` command, which parses its arguments into a [`lz::Cli`] and runs the chosen function.
 
-// External modules
-use clap::{Parser, Subcommand};
-
-// Local modules
-pub mod prealgebra;
-use prealgebra::{match_prealgebra, Prealgebra};
-pub mod dsa;
-use dsa::{match_dsa, Dsa};
-
-#[derive(Parser)]
-#[command(
-    author = "Mueez Khan",
-    about = "Run various functions from a range of math/tech subjects on the command line.",
-    subcommand_value_name = "SUBJECT",
-    arg_required_else_help(true)
-)]
-struct Cli {
-    #[command(subcommand)]
-    subject: Option<Subjects>,
-}
-
-/// The subjects that can be used.
-#[derive(Subcommand)]
-#[command(arg_required_else_help(true))]
-enum Subjects {
-    Prealgebra {
-        /// The function (command) to run.
-        #[command(subcommand)]
-        function: Option<Prealgebra>,
-    },
-    Dsa {
-        #[command(subcommand)]
-        function: Option<Dsa>,
-    },
-}
+use clap::{CommandFactory, Parser};
+use lz::bioinformatics::match_bioinformatics;
+use lz::eval::match_eval;
+use lz::generate;
+use lz::practice::match_practice;
+use lz::precalculus::match_precalculus;
+use lz::quiz::match_quiz;
+use lz::registry::run_batch;
+use lz::worksheet::match_worksheet;
+use lz::{Cli, Subjects};
 
 fn main() {
     let cli: Cli = Cli::parse();
 
     // Match the subject to run the correct function.
     match cli.subject {
-        Some(Subjects::Prealgebra { function }) => match_prealgebra(function),
-        Some(Subjects::Dsa { function }) => match_dsa(function),
+        Some(Subjects::Registered(invocation)) => match invocation.run() {
+            Ok(output) => println!("{output}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        },
+        Some(Subjects::Precalculus { function }) => match_precalculus(function),
+        Some(Subjects::Bioinformatics { function }) => match_bioinformatics(function),
+        Some(Subjects::Practice { subject }) => match_practice(subject),
+        Some(Subjects::Quiz { subject }) => match_quiz(subject),
+        Some(Subjects::Worksheet(worksheet)) => match_worksheet(worksheet),
+        Some(Subjects::Eval {
+            expression,
+            functions,
+            raw,
+        }) => match_eval(expression, functions, raw),
+        Some(Subjects::Batch { file, format }) => {
+            let (stdout, stderr) = (&mut std::io::stdout(), &mut std::io::stderr());
+            let result = match file {
+                Some(file) => std::fs::File::open(&file)
+                    .and_then(|f| run_batch(std::io::BufReader::new(f), format, stdout, stderr)),
+                None => run_batch(std::io::stdin().lock(), format, stdout, stderr),
+            };
+            match result {
+                Ok(0) => {}
+                Ok(_) => std::process::exit(1),
+                Err(e) => {
+                    eprintln!("Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Some(Subjects::Completions { shell }) => {
+            generate::write_completions(Cli::command(), shell, &mut std::io::stdout())
+        }
+        Some(Subjects::Man { command, out_dir }) => {
+            let result = match out_dir {
+                Some(out_dir) => {
+                    generate::write_man_pages(Cli::command(), &out_dir).map_err(|e| e.to_string())
+                }
+                None => generate::write_man_page(Cli::command(), &command, &mut std::io::stdout()),
+            };
+            if let Err(e) = result {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
         None => println!("Please provide a subject to use."),
     }
 }
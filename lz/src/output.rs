@@ -0,0 +1,593 @@
+//! The result model that registry functions return, rendered once to each output [`Format`].
+
+mod latex;
+mod markdown;
+mod mathml;
+mod table;
+
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt;
+
+/// How the result of a function is printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A sentence describing the result.
+    #[default]
+    Prose,
+    /// The result alone, as with `-r` or `--raw`.
+    Raw,
+    /// The result as JSON.
+    Json,
+    /// The result as an aligned table, such as a row for each factor pair.
+    Table,
+    /// A sentence with the result in LaTeX math mode.
+    Latex,
+    /// A sentence with the result in Markdown.
+    Markdown,
+    /// A sentence with the result in MathML, for pasting into web pages.
+    Mathml,
+}
+
+/// The result of a function.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LzOutput {
+    /// No result, such as when no two numbers sum to a target.
+    None,
+    Bool(bool),
+    Int(i128),
+    /// Text, which is quoted inside collections and sentences, e.g. `"marc"`.
+    Text(String),
+    /// Text shown exactly as written, such as `FF` in base 16 or a drawing of a tree.
+    Literal(String),
+    /// A fixed group of values, such as a factor pair `(2, 6)`.
+    Tuple(Vec<LzOutput>),
+    Sequence(Vec<LzOutput>),
+    Set(BTreeSet<LzOutput>),
+    /// Keys and their values, in order.
+    Map(Vec<(LzOutput, LzOutput)>),
+    /// A product of prime powers, e.g. `[(2, 2), (3, 1)]` for 12.
+    Factorization(Vec<(u64, u32)>),
+    /// Pairs of factors whose product is the same number, e.g. `[(1, 12), (2, 6), (3, 4)]`.
+    FactorPairs(Vec<(u64, u64)>),
+    /// Rows of values under named columns.
+    Table {
+        headers: Vec<String>,
+        rows: Vec<Vec<LzOutput>>,
+    },
+    /// A grid of values with a label for each row and column, such as a DP table.
+    Matrix {
+        row_labels: Vec<String>,
+        column_labels: Vec<String>,
+        cells: Vec<Vec<LzOutput>>,
+    },
+}
+
+impl LzOutput {
+    /// Returns the prime factorization with each prime mapped to its exponent, in order.
+    pub fn factorization(factors: &HashMap<u32, u32>) -> LzOutput {
+        let mut factors: Vec<(u64, u32)> = factors
+            .iter()
+            .map(|(&prime, &exponent)| (u64::from(prime), exponent))
+            .collect();
+        factors.sort();
+        LzOutput::Factorization(factors)
+    }
+
+    /// Returns the factor pairs in order of their smaller factor.
+    pub fn factor_pairs(pairs: &HashSet<(u32, u32)>) -> LzOutput {
+        let mut pairs: Vec<(u64, u64)> = pairs
+            .iter()
+            .map(|&(a, b)| (u64::from(a), u64::from(b)))
+            .collect();
+        pairs.sort();
+        LzOutput::FactorPairs(pairs)
+    }
+
+    /// Returns whether the value is drawn over several lines, like a table.
+    fn is_block(&self) -> bool {
+        match self {
+            LzOutput::Table { .. } | LzOutput::Matrix { .. } => true,
+            LzOutput::Literal(s) => s.contains('\n'),
+            _ => false,
+        }
+    }
+
+    /// Returns the value as JSON.
+    pub fn to_json(&self) -> Value {
+        match self {
+            LzOutput::None => Value::Null,
+            LzOutput::Bool(b) => json!(b),
+            LzOutput::Int(n) => i64::try_from(*n)
+                .map(Value::from)
+                .or_else(|_| u64::try_from(*n).map(Value::from))
+                .unwrap_or_else(|_| Value::String(n.to_string())),
+            LzOutput::Text(s) | LzOutput::Literal(s) => json!(s),
+            LzOutput::Tuple(items) | LzOutput::Sequence(items) => {
+                Value::Array(items.iter().map(LzOutput::to_json).collect())
+            }
+            LzOutput::Set(items) => Value::Array(items.iter().map(LzOutput::to_json).collect()),
+            LzOutput::Map(entries) if entries.iter().all(|(key, _)| key.is_scalar()) => {
+                let object: Map<String, Value> = entries
+                    .iter()
+                    .map(|(key, value)| (key.to_string(), value.to_json()))
+                    .collect();
+                Value::Object(object)
+            }
+            LzOutput::Map(entries) => entries
+                .iter()
+                .map(|(key, value)| json!([key.to_json(), value.to_json()]))
+                .collect(),
+            LzOutput::Factorization(factors) => factors
+                .iter()
+                .map(|(prime, exponent)| (prime.to_string(), json!(exponent)))
+                .collect::<Map<String, Value>>()
+                .into(),
+            LzOutput::FactorPairs(pairs) => json!(pairs),
+            LzOutput::Table { headers, rows } => rows
+                .iter()
+                .map(|row| {
+                    let object: Map<String, Value> = headers
+                        .iter()
+                        .zip(row)
+                        .map(|(header, cell)| (header.clone(), cell.to_json()))
+                        .collect();
+                    Value::Object(object)
+                })
+                .collect(),
+            LzOutput::Matrix {
+                row_labels,
+                column_labels,
+                cells,
+            } => json!({
+                "rows": row_labels,
+                "columns": column_labels,
+                "cells": cells
+                    .iter()
+                    .map(|row| row.iter().map(LzOutput::to_json).collect())
+                    .collect::<Vec<Value>>(),
+            }),
+        }
+    }
+
+    fn is_scalar(&self) -> bool {
+        matches!(
+            self,
+            LzOutput::Bool(_) | LzOutput::Int(_) | LzOutput::Text(_) | LzOutput::Literal(_)
+        )
+    }
+
+    /// Writes the value inside a collection or sentence, where text is quoted.
+    fn fmt_nested(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LzOutput::Text(s) => write!(f, "{s:?}"),
+            _ => write!(f, "{self}"),
+        }
+    }
+
+    /// Returns the value as written inside a collection or sentence.
+    fn nested(&self) -> String {
+        struct Nested<'a>(&'a LzOutput);
+        impl fmt::Display for Nested<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt_nested(f)
+            }
+        }
+        Nested(self).to_string()
+    }
+
+    /// Renders the value alone in `format`.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Prose | Format::Raw => self.to_string(),
+            Format::Json => self.to_json().to_string(),
+            Format::Table => table::pretty(self),
+            Format::Latex => latex::display(self),
+            Format::Markdown => markdown::value(self),
+            Format::Mathml => mathml::display(self),
+        }
+    }
+}
+
+/// Writes each of `items` with `write`, separated by commas.
+fn comma_separated<T>(
+    f: &mut fmt::Formatter<'_>,
+    items: impl IntoIterator<Item = T>,
+    mut write: impl FnMut(&mut fmt::Formatter<'_>, T) -> fmt::Result,
+) -> fmt::Result {
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write(f, item)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for LzOutput {
+    /// Writes the raw output: sets as `{1, 2, 3}`, tuples as `(1, 12)`, sequences as `[1, 2]`,
+    /// maps and factorizations as `{2: 2, 3: 1}` and tables as aligned grids.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LzOutput::None => write!(f, "None"),
+            LzOutput::Bool(b) => write!(f, "{b}"),
+            LzOutput::Int(n) => write!(f, "{n}"),
+            LzOutput::Text(s) | LzOutput::Literal(s) => write!(f, "{s}"),
+            LzOutput::Tuple(items) => {
+                write!(f, "(")?;
+                comma_separated(f, items, |f, item| item.fmt_nested(f))?;
+                write!(f, ")")
+            }
+            LzOutput::Sequence(items) => {
+                write!(f, "[")?;
+                comma_separated(f, items, |f, item| item.fmt_nested(f))?;
+                write!(f, "]")
+            }
+            LzOutput::Set(items) => {
+                write!(f, "{{")?;
+                comma_separated(f, items, |f, item| item.fmt_nested(f))?;
+                write!(f, "}}")
+            }
+            LzOutput::Map(entries) => {
+                write!(f, "{{")?;
+                // Keys that are text are field names, so they aren't quoted.
+                comma_separated(f, entries, |f, (key, value)| {
+                    write!(f, "{key}: ")?;
+                    value.fmt_nested(f)
+                })?;
+                write!(f, "}}")
+            }
+            LzOutput::Factorization(factors) => {
+                write!(f, "{{")?;
+                comma_separated(f, factors, |f, (prime, exponent)| {
+                    write!(f, "{prime}: {exponent}")
+                })?;
+                write!(f, "}}")
+            }
+            LzOutput::FactorPairs(pairs) => {
+                write!(f, "{{")?;
+                comma_separated(f, pairs, |f, (a, b)| write!(f, "({a}, {b})"))?;
+                write!(f, "}}")
+            }
+            LzOutput::Table { .. } | LzOutput::Matrix { .. } => {
+                write!(f, "{}", table::grid(self).unwrap_or_default())
+            }
+        }
+    }
+}
+
+/// Converts a result into an [`LzOutput`].
+pub trait ToOutput {
+    fn to_output(&self) -> LzOutput;
+}
+
+impl ToOutput for LzOutput {
+    fn to_output(&self) -> LzOutput {
+        self.clone()
+    }
+}
+
+impl ToOutput for bool {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Bool(*self)
+    }
+}
+
+impl ToOutput for u32 {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Int((*self).into())
+    }
+}
+
+impl ToOutput for u64 {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Int((*self).into())
+    }
+}
+
+impl ToOutput for usize {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Int(*self as i128)
+    }
+}
+
+impl ToOutput for i128 {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Int(*self)
+    }
+}
+
+impl ToOutput for i32 {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Int((*self).into())
+    }
+}
+
+impl ToOutput for str {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Text(self.to_owned())
+    }
+}
+
+impl ToOutput for String {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Text(self.clone())
+    }
+}
+
+impl<T: ToOutput + ?Sized> ToOutput for &T {
+    fn to_output(&self) -> LzOutput {
+        (**self).to_output()
+    }
+}
+
+impl<T: ToOutput> ToOutput for Option<T> {
+    fn to_output(&self) -> LzOutput {
+        match self {
+            Some(value) => value.to_output(),
+            None => LzOutput::None,
+        }
+    }
+}
+
+impl<A: ToOutput, B: ToOutput> ToOutput for (A, B) {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Tuple(vec![self.0.to_output(), self.1.to_output()])
+    }
+}
+
+impl<T: ToOutput> ToOutput for [T] {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Sequence(self.iter().map(ToOutput::to_output).collect())
+    }
+}
+
+impl<T: ToOutput, const N: usize> ToOutput for [T; N] {
+    fn to_output(&self) -> LzOutput {
+        self.as_slice().to_output()
+    }
+}
+
+impl<T: ToOutput> ToOutput for Vec<T> {
+    fn to_output(&self) -> LzOutput {
+        self.as_slice().to_output()
+    }
+}
+
+impl<T: ToOutput> ToOutput for HashSet<T> {
+    fn to_output(&self) -> LzOutput {
+        LzOutput::Set(self.iter().map(ToOutput::to_output).collect())
+    }
+}
+
+impl<K: ToOutput, V: ToOutput> ToOutput for HashMap<K, V> {
+    fn to_output(&self) -> LzOutput {
+        let mut entries: Vec<(LzOutput, LzOutput)> = self
+            .iter()
+            .map(|(key, value)| (key.to_output(), value.to_output()))
+            .collect();
+        entries.sort();
+        LzOutput::Map(entries)
+    }
+}
+
+/// A piece of a [`Sentence`].
+#[derive(Clone, Debug, PartialEq)]
+enum Piece {
+    Text(String),
+    Value(LzOutput),
+}
+
+/// A sentence describing a result, whose values are written in each format's own style.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sentence {
+    pieces: Vec<Piece>,
+}
+
+impl Sentence {
+    /// Fills each `{}` in `template` with the next of `values`, e.g. `"{} is prime."`.
+    pub fn new(template: &str, values: impl IntoIterator<Item = LzOutput>) -> Sentence {
+        let mut values = values.into_iter();
+        let mut pieces = Vec::new();
+        for (i, text) in template.split("{}").enumerate() {
+            if i > 0 {
+                let value = values.next().expect("a value for each {} in the template");
+                pieces.push(Piece::Value(value));
+            }
+            if !text.is_empty() {
+                pieces.push(Piece::Text(text.to_owned()));
+            }
+        }
+        Sentence { pieces }
+    }
+
+    /// Adds `lines` before the sentence as they are written, such as the steps of a replayed
+    /// script.
+    pub fn after_lines(mut self, lines: &[String]) -> Sentence {
+        if !lines.is_empty() {
+            let lines = LzOutput::Literal(lines.join("\n"));
+            self.pieces
+                .splice(0..0, [Piece::Value(lines), Piece::Text("\n".to_owned())]);
+        }
+        self
+    }
+
+    /// Renders the sentence in `format`, which is one of the sentence formats.
+    pub fn render(&self, format: Format) -> String {
+        self.pieces
+            .iter()
+            .map(|piece| match (piece, format) {
+                (Piece::Text(text), Format::Latex) => latex::text(text),
+                (Piece::Text(text), Format::Mathml) => mathml::escape(text),
+                (Piece::Text(text), _) => text.clone(),
+                (Piece::Value(value), Format::Latex) => latex::inline(value),
+                (Piece::Value(value), Format::Markdown) => markdown::inline(value),
+                (Piece::Value(value), Format::Mathml) => mathml::inline(value),
+                (Piece::Value(value), _) => value.nested(),
+            })
+            .collect()
+    }
+}
+
+/// A function's result, the sentence describing it and any details, such as a DP table.
+#[derive(Clone, Debug)]
+pub struct Rendered {
+    pub sentence: Sentence,
+    pub result: LzOutput,
+    pub details: Option<LzOutput>,
+}
+
+impl Rendered {
+    /// Returns the result as JSON, with the details alongside it if there are any.
+    pub fn to_json(&self) -> Value {
+        match &self.details {
+            Some(details) => {
+                json!({ "result": self.result.to_json(), "details": details.to_json() })
+            }
+            None => self.result.to_json(),
+        }
+    }
+
+    /// Returns the result written in `format`, followed by the details after a blank line.
+    pub fn format(&self, format: Format) -> String {
+        let main = match format {
+            Format::Json => return self.to_json().to_string(),
+            Format::Prose | Format::Latex | Format::Markdown | Format::Mathml => {
+                self.sentence.render(format)
+            }
+            Format::Raw | Format::Table => self.result.render(format),
+        };
+        match &self.details {
+            Some(details) => format!("{main}\n\n{}", details.render(format)),
+            None => main,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn factor_pairs() -> LzOutput {
+        HashSet::from([(1u32, 12u32), (2, 6), (3, 4)]).to_output()
+    }
+
+    fn dp_table() -> LzOutput {
+        LzOutput::Matrix {
+            row_labels: vec!["no coins".to_owned(), "+ coin 2".to_owned()],
+            column_labels: vec!["0".to_owned(), "1".to_owned(), "2".to_owned()],
+            cells: vec![
+                vec![LzOutput::Int(0), LzOutput::None, LzOutput::None],
+                vec![LzOutput::Int(0), LzOutput::None, LzOutput::Int(1)],
+            ],
+        }
+    }
+
+    #[test]
+    fn test_raw() {
+        assert_eq!(factor_pairs().to_string(), "{(1, 12), (2, 6), (3, 4)}");
+        let factorization = LzOutput::factorization(&HashMap::from([(3, 1), (2, 2)]));
+        assert_eq!(factorization.to_string(), "{2: 2, 3: 1}");
+        let words = vec!["ab".to_owned(), "ba".to_owned()].to_output();
+        assert_eq!(words.to_string(), "[\"ab\", \"ba\"]");
+        assert_eq!("FF".to_output().to_string(), "FF");
+        assert_eq!(None::<(usize, usize)>.to_output().to_string(), "None");
+        let record = LzOutput::Map(vec![("sum".to_output(), LzOutput::Int(6))]);
+        assert_eq!(record.to_string(), "{sum: 6}");
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(factor_pairs().render(Format::Json), "[[1,12],[2,6],[3,4]]");
+        let factorization = LzOutput::Factorization(vec![(2, 2), (3, 1)]);
+        assert_eq!(factorization.render(Format::Json), "{\"2\":2,\"3\":1}");
+        assert_eq!(
+            dp_table().render(Format::Json),
+            "{\"cells\":[[0,null,null],[0,null,1]],\"columns\":[\"0\",\"1\",\"2\"],\
+             \"rows\":[\"no coins\",\"+ coin 2\"]}"
+        );
+        assert_eq!(LzOutput::Int(u64::MAX.into()).to_json(), json!(u64::MAX));
+    }
+
+    #[test]
+    fn test_sentence() {
+        let sentence = Sentence::new(
+            "The factor pairs of {} are {}.",
+            [12u32.to_output(), factor_pairs()],
+        );
+        let rendered = Rendered {
+            sentence,
+            result: factor_pairs(),
+            details: None,
+        };
+        assert_eq!(
+            rendered.format(Format::Prose),
+            "The factor pairs of 12 are {(1, 12), (2, 6), (3, 4)}."
+        );
+        assert_eq!(rendered.format(Format::Raw), "{(1, 12), (2, 6), (3, 4)}");
+        assert_eq!(
+            rendered.format(Format::Markdown),
+            "The factor pairs of `12` are `{(1, 12), (2, 6), (3, 4)}`."
+        );
+        let quoted = Sentence::new(
+            "{} is an anagram of {}.",
+            ["marc".to_output(), "cram".to_output()],
+        );
+        assert_eq!(
+            quoted.render(Format::Prose),
+            "\"marc\" is an anagram of \"cram\"."
+        );
+        let steps = Sentence::new("The stack is {}.", [LzOutput::Literal("[]".to_owned())])
+            .after_lines(&["push 3".to_owned(), "pop => 3".to_owned()]);
+        assert_eq!(
+            steps.render(Format::Prose),
+            "push 3\npop => 3\nThe stack is []."
+        );
+    }
+
+    #[test]
+    fn test_verdicts() {
+        let prime = Sentence::new("{} is prime.", [7u32.to_output()]);
+        assert_eq!(prime.render(Format::Latex), "$7$ is prime.");
+        assert_eq!(
+            prime.render(Format::Mathml),
+            "<math><mn>7</mn></math> is prime."
+        );
+        let composite = Sentence::new(
+            "{} is composite & {} = {}.",
+            [
+                12u32.to_output(),
+                12u32.to_output(),
+                LzOutput::factorization(&HashMap::from([(2, 2), (3, 1)])),
+            ],
+        );
+        assert_eq!(
+            composite.render(Format::Latex),
+            "$12$ is composite \\& $12$ = $2^{2} \\cdot 3$."
+        );
+        assert_eq!(
+            composite.render(Format::Mathml),
+            "<math><mn>12</mn></math> is composite &amp; <math><mn>12</mn></math> = \
+             <math><mrow><msup><mn>2</mn><mn>2</mn></msup><mo>⋅</mo><mn>3</mn></mrow></math>."
+        );
+    }
+
+    #[test]
+    fn test_details() {
+        let rendered = Rendered {
+            sentence: Sentence::new("There is {} way.", [LzOutput::Int(1)]),
+            result: LzOutput::Int(1),
+            details: Some(dp_table()),
+        };
+        assert_eq!(
+            rendered.format(Format::Raw),
+            "1\n\n          0  1  2\nno coins  0  -  -\n+ coin 2  0  -  1"
+        );
+        assert_eq!(
+            rendered.format(Format::Json),
+            "{\"details\":{\"cells\":[[0,null,null],[0,null,1]],\"columns\":[\"0\",\"1\",\"2\"],\
+             \"rows\":[\"no coins\",\"+ coin 2\"]},\"result\":1}"
+        );
+    }
+}
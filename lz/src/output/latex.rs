@@ -0,0 +1,221 @@
+//! LaTeX for results, e.g. `2^{3} \cdot 3^{2} \cdot 5` for the prime factorization of 360.
+
+use super::LzOutput;
+
+/// Escapes `s` for LaTeX text mode.
+pub(super) fn text(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '{' | '}' | '#' | '$' | '%' | '&' | '_' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Writes each of `items` in math mode, separated by commas.
+fn list<'a>(items: impl IntoIterator<Item = &'a LzOutput>) -> String {
+    items
+        .into_iter()
+        .map(math)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Writes a table cell in math mode, where text isn't quoted and a missing value is `-`.
+fn cell(value: &LzOutput) -> String {
+    match value {
+        LzOutput::None => "-".to_owned(),
+        LzOutput::Text(s) => format!("\\text{{{}}}", text(s)),
+        _ => math(value),
+    }
+}
+
+/// Writes an `array` environment with a line under the header row.
+fn array(spec: &str, header: Vec<String>, rows: Vec<Vec<String>>) -> String {
+    let mut lines = vec![format!("\\begin{{array}}{{{spec}}}")];
+    lines.push(format!("{} \\\\", header.join(" & ")));
+    lines.push("\\hline".to_owned());
+    lines.extend(rows.iter().map(|row| format!("{} \\\\", row.join(" & "))));
+    lines.push("\\end{array}".to_owned());
+    lines.join("\n")
+}
+
+/// Writes the value in math mode.
+pub(super) fn math(value: &LzOutput) -> String {
+    match value {
+        LzOutput::None => "\\text{None}".to_owned(),
+        LzOutput::Bool(b) => format!("\\text{{{b}}}"),
+        LzOutput::Int(n) => n.to_string(),
+        LzOutput::Text(s) => format!("\\text{{``{}''}}", text(s)),
+        LzOutput::Literal(s) => format!("\\texttt{{{}}}", text(s)),
+        LzOutput::Tuple(items) => format!("({})", list(items)),
+        LzOutput::Sequence(items) => format!("[{}]", list(items)),
+        LzOutput::Set(items) if items.is_empty() => "\\emptyset".to_owned(),
+        LzOutput::Set(items) => format!("\\{{{}\\}}", list(items)),
+        LzOutput::Map(entries) => {
+            let entries: Vec<String> = entries
+                .iter()
+                .map(|(key, value)| format!("{} \\mapsto {}", math(key), math(value)))
+                .collect();
+            format!("\\{{{}\\}}", entries.join(", "))
+        }
+        LzOutput::Factorization(factors) if factors.is_empty() => "1".to_owned(),
+        LzOutput::Factorization(factors) => factors
+            .iter()
+            .map(|&(prime, exponent)| match exponent {
+                1 => prime.to_string(),
+                _ => format!("{prime}^{{{exponent}}}"),
+            })
+            .collect::<Vec<String>>()
+            .join(" \\cdot "),
+        LzOutput::FactorPairs(pairs) if pairs.is_empty() => "\\emptyset".to_owned(),
+        LzOutput::FactorPairs(pairs) => {
+            let rows: Vec<String> = pairs
+                .iter()
+                .map(|(a, b)| format!("{a} & \\times & {b} \\\\"))
+                .collect();
+            format!(
+                "\\begin{{array}}{{rcl}}\n{}\n\\end{{array}}",
+                rows.join("\n")
+            )
+        }
+        LzOutput::Table { headers, rows } => {
+            let columns = rows.iter().map(Vec::len).chain([headers.len()]).max();
+            let spec: String = (0..columns.unwrap_or(0))
+                .map(|i| match super::table::is_numeric(rows, i) {
+                    true => 'r',
+                    false => 'l',
+                })
+                .collect();
+            let header = headers
+                .iter()
+                .map(|header| format!("\\text{{{}}}", text(header)))
+                .collect();
+            let rows = rows
+                .iter()
+                .map(|row| row.iter().map(cell).collect())
+                .collect();
+            array(&spec, header, rows)
+        }
+        LzOutput::Matrix {
+            row_labels,
+            column_labels,
+            cells,
+        } => {
+            let spec = format!("l|{}", "r".repeat(column_labels.len()));
+            let header = std::iter::once(String::new())
+                .chain(
+                    column_labels
+                        .iter()
+                        .map(|label| format!("\\text{{{}}}", text(label))),
+                )
+                .collect();
+            let rows = row_labels
+                .iter()
+                .zip(cells)
+                .map(|(label, row)| {
+                    std::iter::once(format!("\\text{{{}}}", text(label)))
+                        .chain(row.iter().map(cell))
+                        .collect()
+                })
+                .collect();
+            array(&spec, header, rows)
+        }
+    }
+}
+
+/// Writes the value on its own: a drawing in a `verbatim` environment and anything else as
+/// display math.
+pub(super) fn display(value: &LzOutput) -> String {
+    match value {
+        LzOutput::Literal(s) if value.is_block() => {
+            format!("\\begin{{verbatim}}\n{s}\n\\end{{verbatim}}")
+        }
+        _ => format!("\\[\n{}\n\\]", math(value)),
+    }
+}
+
+/// Writes the value inside a sentence, as inline math unless it's drawn over several lines or
+/// is a set of factor pairs, which are lined up in a display.
+pub(super) fn inline(value: &LzOutput) -> String {
+    match value {
+        _ if value.is_block() => display(value),
+        LzOutput::FactorPairs(pairs) if !pairs.is_empty() => display(value),
+        _ => format!("${}$", math(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::ToOutput;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_math() {
+        let factorization = LzOutput::Factorization(vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(math(&factorization), "2^{3} \\cdot 3^{2} \\cdot 5");
+        assert_eq!(math(&LzOutput::Factorization(vec![])), "1");
+        let pairs = HashSet::from([(1u32, 4u32), (2, 2)]).to_output();
+        assert_eq!(math(&pairs), "\\{(1, 4), (2, 2)\\}");
+        assert_eq!(math(&HashSet::<u32>::new().to_output()), "\\emptyset");
+        assert_eq!(math(&"50%_off".to_output()), "\\text{``50\\%\\_off''}");
+        assert_eq!(math(&LzOutput::Bool(false)), "\\text{false}");
+    }
+
+    #[test]
+    fn test_factor_pairs() {
+        let pairs = LzOutput::factor_pairs(&HashSet::from([(3, 4), (1, 12), (2, 6)]));
+        assert_eq!(
+            inline(&pairs),
+            "\\[\n\\begin{array}{rcl}\n1 & \\times & 12 \\\\\n2 & \\times & 6 \\\\\n\
+             3 & \\times & 4 \\\\\n\\end{array}\n\\]"
+        );
+        assert_eq!(inline(&LzOutput::FactorPairs(vec![])), "$\\emptyset$");
+    }
+
+    #[test]
+    fn test_tables() {
+        let table = LzOutput::Table {
+            headers: vec!["Rank".to_owned(), "Suffix".to_owned()],
+            rows: vec![vec![LzOutput::Int(0), "a".to_output()]],
+        };
+        assert_eq!(
+            inline(&table),
+            "\\[\n\\begin{array}{rl}\n\\text{Rank} & \\text{Suffix} \\\\\n\\hline\n\
+             0 & \\text{a} \\\\\n\\end{array}\n\\]"
+        );
+        let matrix = LzOutput::Matrix {
+            row_labels: vec!["no items".to_owned()],
+            column_labels: vec!["0".to_owned(), "1".to_owned()],
+            cells: vec![vec![LzOutput::Int(0), LzOutput::None]],
+        };
+        assert_eq!(
+            math(&matrix),
+            "\\begin{array}{l|rr}\n & \\text{0} & \\text{1} \\\\\n\\hline\n\
+             \\text{no items} & 0 & - \\\\\n\\end{array}"
+        );
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            text("a\\b {c} ^~"),
+            "a\\textbackslash{}b \\{c\\} \\textasciicircum{}\\textasciitilde{}"
+        );
+        assert_eq!(inline(&LzOutput::Int(12)), "$12$");
+        let drawing = LzOutput::Literal("1\n└── 2".to_owned());
+        assert_eq!(
+            inline(&drawing),
+            "\\begin{verbatim}\n1\n└── 2\n\\end{verbatim}"
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Markdown for results: code spans for values, pipe tables for tables and matrices and code
+//! blocks for drawings.
+
+use super::LzOutput;
+
+/// Writes `s` as a code span, with enough backticks around it to hold any inside it.
+fn code(s: &str) -> String {
+    let fence = if s.contains('`') { "``" } else { "`" };
+    format!("{fence}{s}{fence}")
+}
+
+/// Writes a pipe table, right-aligning the columns marked in `right`.
+fn pipe_table(header: Vec<String>, rows: Vec<Vec<String>>, right: Vec<bool>) -> String {
+    let line = |cells: &[String]| {
+        let cells: Vec<String> = cells.iter().map(|cell| cell.replace('|', "\\|")).collect();
+        format!("| {} |", cells.join(" | "))
+    };
+    let rule: Vec<String> = right
+        .iter()
+        .map(|&right| match right {
+            true => "---:".to_owned(),
+            false => "---".to_owned(),
+        })
+        .collect();
+    let mut lines = vec![line(&header), format!("| {} |", rule.join(" | "))];
+    lines.extend(rows.iter().map(|row| line(row)));
+    lines.join("\n")
+}
+
+/// Writes a table cell, where a missing value is `-`.
+fn cell(value: &LzOutput) -> String {
+    match value {
+        LzOutput::None => "-".to_owned(),
+        _ => value.to_string(),
+    }
+}
+
+/// Writes the value on its own: a pipe table, a code block or a code span.
+pub(super) fn value(value: &LzOutput) -> String {
+    match value {
+        LzOutput::Table { headers, rows } => {
+            let columns = rows.iter().map(Vec::len).chain([headers.len()]).max();
+            let right = (0..columns.unwrap_or(0))
+                .map(|i| super::table::is_numeric(rows, i))
+                .collect();
+            let rows = rows
+                .iter()
+                .map(|row| row.iter().map(cell).collect())
+                .collect();
+            pipe_table(headers.clone(), rows, right)
+        }
+        LzOutput::Matrix {
+            row_labels,
+            column_labels,
+            cells,
+        } => {
+            let header = std::iter::once(String::new())
+                .chain(column_labels.iter().cloned())
+                .collect();
+            let rows = row_labels
+                .iter()
+                .zip(cells)
+                .map(|(label, row)| {
+                    std::iter::once(label.clone())
+                        .chain(row.iter().map(cell))
+                        .collect()
+                })
+                .collect();
+            let right = (0..=column_labels.len()).map(|i| i > 0).collect();
+            pipe_table(header, rows, right)
+        }
+        LzOutput::Literal(s) if value.is_block() => format!("```\n{s}\n```"),
+        _ => inline(value),
+    }
+}
+
+/// Writes the value inside a sentence, as a code span unless it's drawn over several lines.
+pub(super) fn inline(value: &LzOutput) -> String {
+    match value {
+        _ if value.is_block() => self::value(value),
+        LzOutput::Factorization(factors) if !factors.is_empty() => factors
+            .iter()
+            .map(|&(prime, exponent)| match exponent {
+                1 => prime.to_string(),
+                _ => format!("{prime}<sup>{exponent}</sup>"),
+            })
+            .collect::<Vec<String>>()
+            .join(" × "),
+        _ => code(&value.nested()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::ToOutput;
+
+    #[test]
+    fn test_inline() {
+        assert_eq!(inline(&LzOutput::Int(12)), "`12`");
+        assert_eq!(inline(&"a`b".to_output()), "``\"a`b\"``");
+        let factorization = LzOutput::Factorization(vec![(2, 2), (3, 1)]);
+        assert_eq!(inline(&factorization), "2<sup>2</sup> × 3");
+        let drawing = LzOutput::Literal("1\n└── 2".to_owned());
+        assert_eq!(inline(&drawing), "```\n1\n└── 2\n```");
+    }
+
+    #[test]
+    fn test_tables() {
+        let table = LzOutput::Table {
+            headers: vec!["Rank".to_owned(), "Suffix".to_owned()],
+            rows: vec![vec![LzOutput::Int(0), "a|b".to_output()]],
+        };
+        assert_eq!(
+            value(&table),
+            "| Rank | Suffix |\n| ---: | --- |\n| 0 | a\\|b |"
+        );
+        let matrix = LzOutput::Matrix {
+            row_labels: vec!["no items".to_owned()],
+            column_labels: vec!["0".to_owned(), "1".to_owned()],
+            cells: vec![vec![LzOutput::Int(0), LzOutput::None]],
+        };
+        assert_eq!(
+            value(&matrix),
+            "|  | 0 | 1 |\n| --- | ---: | ---: |\n| no items | 0 | - |"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! MathML for results, e.g. `<msup><mn>2</mn><mn>3</mn></msup>` for the 2³ in a prime
+//! factorization.
+
+use super::LzOutput;
+
+/// Escapes `s` for HTML and MathML text.
+pub(super) fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn mo(operator: &str) -> String {
+    format!("<mo>{}</mo>", escape(operator))
+}
+
+fn mtext(s: &str) -> String {
+    format!("<mtext>{}</mtext>", escape(s))
+}
+
+fn mn(n: impl ToString) -> String {
+    let n = n.to_string();
+    match n.strip_prefix('-') {
+        Some(magnitude) => format!("<mrow><mo>-</mo><mn>{magnitude}</mn></mrow>"),
+        None => format!("<mn>{n}</mn>"),
+    }
+}
+
+/// Writes `items` separated by commas between an opening and closing bracket.
+fn fenced(open: &str, items: impl IntoIterator<Item = String>, close: &str) -> String {
+    let items: Vec<String> = items.into_iter().collect();
+    format!(
+        "<mrow>{}{}{}</mrow>",
+        mo(open),
+        items.join(&mo(",")),
+        mo(close)
+    )
+}
+
+/// Writes a table cell, where text isn't quoted and a missing value is `-`.
+fn cell(value: &LzOutput) -> String {
+    match value {
+        LzOutput::None => mo("-"),
+        LzOutput::Text(s) => mtext(s),
+        _ => math(value),
+    }
+}
+
+/// Writes an `mtable` with a line under the header row, if there is one.
+fn mtable(align: &[&str], header: Option<Vec<String>>, rows: Vec<Vec<String>>) -> String {
+    let line = |cells: Vec<String>| {
+        let cells: String = cells
+            .iter()
+            .map(|cell| format!("<mtd>{cell}</mtd>"))
+            .collect();
+        format!("<mtr>{cells}</mtr>")
+    };
+    // The last of `rowlines` repeats for the rest of the rows.
+    let lines = match header {
+        Some(_) => "solid none",
+        None => "none",
+    };
+    let rows: String = header.into_iter().chain(rows).map(line).collect();
+    format!(
+        "<mtable columnalign=\"{}\" rowlines=\"{lines}\">{rows}</mtable>",
+        align.join(" ")
+    )
+}
+
+/// Writes the value as MathML elements.
+pub(super) fn math(value: &LzOutput) -> String {
+    match value {
+        LzOutput::None => mtext("None"),
+        LzOutput::Bool(b) => mtext(&b.to_string()),
+        LzOutput::Int(n) => mn(n),
+        LzOutput::Text(s) => mtext(&format!("“{s}”")),
+        LzOutput::Literal(s) => format!("<mtext mathvariant=\"monospace\">{}</mtext>", escape(s)),
+        LzOutput::Tuple(items) => fenced("(", items.iter().map(math), ")"),
+        LzOutput::Sequence(items) => fenced("[", items.iter().map(math), "]"),
+        LzOutput::Set(items) if items.is_empty() => "<mi>∅</mi>".to_owned(),
+        LzOutput::Set(items) => fenced("{", items.iter().map(math), "}"),
+        LzOutput::Map(entries) => fenced(
+            "{",
+            entries.iter().map(|(key, value)| {
+                format!("<mrow>{}{}{}</mrow>", math(key), mo("↦"), math(value))
+            }),
+            "}",
+        ),
+        LzOutput::Factorization(factors) if factors.is_empty() => mn(1),
+        LzOutput::Factorization(factors) => {
+            let powers: Vec<String> = factors
+                .iter()
+                .map(|&(prime, exponent)| match exponent {
+                    1 => mn(prime),
+                    _ => format!("<msup>{}{}</msup>", mn(prime), mn(exponent)),
+                })
+                .collect();
+            format!("<mrow>{}</mrow>", powers.join(&mo("⋅")))
+        }
+        LzOutput::FactorPairs(pairs) if pairs.is_empty() => "<mi>∅</mi>".to_owned(),
+        LzOutput::FactorPairs(pairs) => {
+            let rows = pairs
+                .iter()
+                .map(|&(a, b)| vec![mn(a), mo("×"), mn(b)])
+                .collect();
+            mtable(&["right", "center", "left"], None, rows)
+        }
+        LzOutput::Table { headers, rows } => {
+            let columns = rows.iter().map(Vec::len).chain([headers.len()]).max();
+            let align: Vec<&str> = (0..columns.unwrap_or(0))
+                .map(|i| match super::table::is_numeric(rows, i) {
+                    true => "right",
+                    false => "left",
+                })
+                .collect();
+            let header = headers.iter().map(|header| mtext(header)).collect();
+            let rows = rows
+                .iter()
+                .map(|row| row.iter().map(cell).collect())
+                .collect();
+            mtable(&align, Some(header), rows)
+        }
+        LzOutput::Matrix {
+            row_labels,
+            column_labels,
+            cells,
+        } => {
+            let align: Vec<&str> = std::iter::once("left")
+                .chain(column_labels.iter().map(|_| "right"))
+                .collect();
+            let header = std::iter::once(String::new())
+                .chain(column_labels.iter().map(|label| mtext(label)))
+                .collect();
+            let rows = row_labels
+                .iter()
+                .zip(cells)
+                .map(|(label, row)| {
+                    std::iter::once(mtext(label))
+                        .chain(row.iter().map(cell))
+                        .collect()
+                })
+                .collect();
+            mtable(&align, Some(header), rows)
+        }
+    }
+}
+
+/// Writes the value on its own: a drawing in a `pre` element and anything else as a block
+/// `math` element.
+pub(super) fn display(value: &LzOutput) -> String {
+    match value {
+        LzOutput::Literal(s) if value.is_block() => format!("<pre>{}</pre>", escape(s)),
+        _ => format!("<math display=\"block\">{}</math>", math(value)),
+    }
+}
+
+/// Writes the value inside a sentence, as an inline `math` element unless it's drawn over
+/// several lines or is a set of factor pairs, which are lined up in a block.
+pub(super) fn inline(value: &LzOutput) -> String {
+    match value {
+        _ if value.is_block() => display(value),
+        LzOutput::FactorPairs(pairs) if !pairs.is_empty() => display(value),
+        _ => format!("<math>{}</math>", math(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::ToOutput;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_math() {
+        let factorization = LzOutput::factorization(&HashMap::from([(2, 2), (3, 1)]));
+        assert_eq!(
+            math(&factorization),
+            "<mrow><msup><mn>2</mn><mn>2</mn></msup><mo>⋅</mo><mn>3</mn></mrow>"
+        );
+        assert_eq!(math(&LzOutput::Factorization(vec![])), "<mn>1</mn>");
+        assert_eq!(
+            math(&HashSet::from([1u32, 2]).to_output()),
+            "<mrow><mo>{</mo><mn>1</mn><mo>,</mo><mn>2</mn><mo>}</mo></mrow>"
+        );
+        assert_eq!(math(&HashSet::<u32>::new().to_output()), "<mi>∅</mi>");
+        assert_eq!(math(&(-3).to_output()), "<mrow><mo>-</mo><mn>3</mn></mrow>");
+        assert_eq!(math(&"a<b".to_output()), "<mtext>“a&lt;b”</mtext>");
+    }
+
+    #[test]
+    fn test_factor_pairs() {
+        let pairs = LzOutput::factor_pairs(&HashSet::from([(3, 4), (1, 12), (2, 6)]));
+        assert_eq!(
+            inline(&pairs),
+            "<math display=\"block\"><mtable columnalign=\"right center left\" rowlines=\"none\">\
+             <mtr><mtd><mn>1</mn></mtd><mtd><mo>×</mo></mtd><mtd><mn>12</mn></mtd></mtr>\
+             <mtr><mtd><mn>2</mn></mtd><mtd><mo>×</mo></mtd><mtd><mn>6</mn></mtd></mtr>\
+             <mtr><mtd><mn>3</mn></mtd><mtd><mo>×</mo></mtd><mtd><mn>4</mn></mtd></mtr>\
+             </mtable></math>"
+        );
+        assert_eq!(
+            inline(&LzOutput::FactorPairs(vec![])),
+            "<math><mi>∅</mi></math>"
+        );
+    }
+
+    #[test]
+    fn test_tables() {
+        let matrix = LzOutput::Matrix {
+            row_labels: vec!["no items".to_owned()],
+            column_labels: vec!["0".to_owned(), "1".to_owned()],
+            cells: vec![vec![LzOutput::Int(0), LzOutput::None]],
+        };
+        assert_eq!(
+            math(&matrix),
+            "<mtable columnalign=\"left right right\" rowlines=\"solid none\">\
+             <mtr><mtd></mtd><mtd><mtext>0</mtext></mtd><mtd><mtext>1</mtext></mtd></mtr>\
+             <mtr><mtd><mtext>no items</mtext></mtd><mtd><mn>0</mn></mtd><mtd><mo>-</mo></mtd></mtr>\
+             </mtable>"
+        );
+        let drawing = LzOutput::Literal("1\n└── 2".to_owned());
+        assert_eq!(inline(&drawing), "<pre>1\n└── 2</pre>");
+    }
+}
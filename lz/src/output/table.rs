@@ -0,0 +1,177 @@
+//! Aligned grids for tables and matrices, and for `--format table`.
+
+use super::LzOutput;
+
+/// Returns the text of a cell, where a missing value is `-`.
+fn cell(value: &LzOutput) -> String {
+    match value {
+        LzOutput::None => "-".to_owned(),
+        _ => value.to_string(),
+    }
+}
+
+/// Returns whether every cell in column `i` is a number or missing, so the column is
+/// right-aligned.
+pub(super) fn is_numeric(rows: &[Vec<LzOutput>], i: usize) -> bool {
+    rows.iter()
+        .all(|row| matches!(row.get(i), Some(LzOutput::Int(_) | LzOutput::None) | None))
+}
+
+/// Writes `rows` under `headers` (unless there are none) in columns separated by two spaces.
+fn align(headers: &[String], rows: &[Vec<String>], right: &[bool]) -> String {
+    let header = (!headers.is_empty()).then_some(headers);
+    let lines: Vec<&[String]> = header
+        .into_iter()
+        .chain(rows.iter().map(Vec::as_slice))
+        .collect();
+    let mut widths = vec![0; right.len()];
+    for line in &lines {
+        for (width, text) in widths.iter_mut().zip(line.iter()) {
+            *width = (*width).max(text.chars().count());
+        }
+    }
+    lines
+        .iter()
+        .map(|line| {
+            let cells: Vec<String> = line
+                .iter()
+                .zip(widths.iter().zip(right))
+                .map(|(text, (&width, &right))| match right {
+                    true => format!("{text:>width$}"),
+                    false => format!("{text:<width$}"),
+                })
+                .collect();
+            cells.join("  ").trim_end().to_owned()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns the grid of a table or matrix, with numbers right-aligned.
+pub(super) fn grid(value: &LzOutput) -> Option<String> {
+    match value {
+        LzOutput::Table { headers, rows } => {
+            let columns = rows.iter().map(Vec::len).chain([headers.len()]).max();
+            let right: Vec<bool> = (0..columns.unwrap_or(0))
+                .map(|i| is_numeric(rows, i))
+                .collect();
+            let rows: Vec<Vec<String>> = rows
+                .iter()
+                .map(|row| row.iter().map(cell).collect())
+                .collect();
+            Some(align(headers, &rows, &right))
+        }
+        LzOutput::Matrix {
+            row_labels,
+            column_labels,
+            cells,
+        } => {
+            let headers: Vec<String> = std::iter::once(String::new())
+                .chain(column_labels.iter().cloned())
+                .collect();
+            let rows: Vec<Vec<String>> = row_labels
+                .iter()
+                .zip(cells)
+                .map(|(label, row)| {
+                    std::iter::once(label.clone())
+                        .chain(row.iter().map(cell))
+                        .collect()
+                })
+                .collect();
+            let right: Vec<bool> = (0..headers.len()).map(|i| i > 0).collect();
+            Some(align(&headers, &rows, &right))
+        }
+        _ => None,
+    }
+}
+
+/// Splits an item of a collection into the cells of its row.
+fn row(item: &LzOutput) -> Vec<LzOutput> {
+    match item {
+        LzOutput::Tuple(items) | LzOutput::Sequence(items) => items.clone(),
+        _ => vec![item.clone()],
+    }
+}
+
+/// Writes the value as an aligned table, with a row for each item of a collection and a column
+/// for each part of an item. Single values are written as they are.
+pub(super) fn pretty(value: &LzOutput) -> String {
+    let (headers, rows): (&[&str], Vec<Vec<LzOutput>>) = match value {
+        LzOutput::Table { .. } | LzOutput::Matrix { .. } => return grid(value).unwrap_or_default(),
+        LzOutput::Map(entries) => (
+            &[],
+            entries
+                .iter()
+                .map(|(key, value)| vec![key.clone(), value.clone()])
+                .collect(),
+        ),
+        LzOutput::Factorization(factors) => (
+            &["Prime", "Exponent"],
+            factors
+                .iter()
+                .map(|&(prime, exponent)| {
+                    vec![LzOutput::Int(prime.into()), LzOutput::Int(exponent.into())]
+                })
+                .collect(),
+        ),
+        LzOutput::FactorPairs(pairs) => (
+            &[],
+            pairs
+                .iter()
+                .map(|&(a, b)| vec![LzOutput::Int(a.into()), LzOutput::Int(b.into())])
+                .collect(),
+        ),
+        LzOutput::Set(items) => (&[], items.iter().map(row).collect()),
+        LzOutput::Sequence(items) => (&[], items.iter().map(row).collect()),
+        _ => return value.to_string(),
+    };
+    let table = LzOutput::Table {
+        headers: headers.iter().map(|header| header.to_string()).collect(),
+        rows,
+    };
+    grid(&table).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::ToOutput;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_grid_matches_dp_table() {
+        let dp = ladderz::dsa::rod_cutting_table(&[1, 5, 8, 9], 4);
+        let matrix = LzOutput::Matrix {
+            row_labels: dp.row_labels.clone(),
+            column_labels: dp.column_labels.clone(),
+            cells: dp
+                .cells
+                .iter()
+                .map(|row| row.iter().map(ToOutput::to_output).collect())
+                .collect(),
+        };
+        assert_eq!(grid(&matrix).unwrap(), dp.to_string());
+    }
+
+    #[test]
+    fn test_pretty() {
+        let pairs = HashSet::from([(1u32, 12u32), (2, 6), (3, 4)]).to_output();
+        assert_eq!(pretty(&pairs), "1  12\n2   6\n3   4");
+        let factorization = LzOutput::Factorization(vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(
+            pretty(&factorization),
+            "Prime  Exponent\n    2         3\n    3         2\n    5         1"
+        );
+        let words = vec!["ab", "c"].to_output();
+        assert_eq!(pretty(&words), "ab\nc");
+        assert_eq!(pretty(&LzOutput::Bool(true)), "true");
+        let table = LzOutput::Table {
+            headers: vec!["Rank".to_owned(), "Suffix".to_owned()],
+            rows: vec![
+                vec![LzOutput::Int(0), "a".to_output()],
+                vec![LzOutput::Int(10), "ana".to_output()],
+            ],
+        };
+        assert_eq!(pretty(&table), "Rank  Suffix\n   0  a\n  10  ana");
+    }
+}
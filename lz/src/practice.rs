@@ -0,0 +1,106 @@
+use clap::Subcommand;
+use ladderz::practice::prealgebra::{generate_problems, Concept};
+use ladderz::practice::Difficulty;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Subcommand)]
+#[command(arg_required_else_help(true))]
+pub enum Practice {
+    /// Generates pre-algebra practice problems, such as finding factors or checking primes.
+    ///
+    /// With `--answers`, the answers are printed after the problems, or after a tab on each line
+    /// with `--raw`.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz practice prealgebra --count 3 --seed 42 --difficulty medium
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// Pre-algebra practice (seed 42):
+    ///
+    /// 1. [medium] Is 119 prime?
+    /// 2. [medium] Find the prime factorization of 60.
+    /// 3. [medium] Find all multiples of 20 from 97 to 157.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// Is 119 prime?
+    /// Find the prime factorization of 60.
+    /// Find all multiples of 20 from 97 to 157.
+    /// ```
+    Prealgebra {
+        /// The number of problems to generate.
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
+        /// The seed to generate problems from, so the same seed gives the same problems. Defaults
+        /// to a random seed, which is printed.
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// The difficulty of every problem: easy, medium or hard. Defaults to a mix.
+        #[arg(short, long)]
+        difficulty: Option<Difficulty>,
+        /// Only generate problems for a concept, e.g. `is-prime`. May be repeated.
+        #[arg(long = "concept", value_name = "CONCEPT")]
+        concepts: Vec<Concept>,
+        /// Whether or not to print the answers.
+        #[arg(short, long)]
+        answers: bool,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+}
+
+/// Returns a seed that changes on every run.
+pub(crate) fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+pub fn match_practice(subject: Option<Practice>) {
+    match subject {
+        Some(Practice::Prealgebra {
+            count,
+            seed,
+            difficulty,
+            concepts,
+            answers,
+            raw,
+        }) => {
+            let seed = seed.unwrap_or_else(random_seed);
+            let problems = generate_problems(count, seed, difficulty, &concepts);
+            if raw {
+                for problem in problems {
+                    match answers {
+                        true => println!("{problem}\t{}", problem.answer()),
+                        false => println!("{problem}"),
+                    }
+                }
+                return;
+            }
+            // Pad the numbers so the problems line up.
+            let width = count.to_string().len();
+            println!("Pre-algebra practice (seed {seed}):\n");
+            for (i, problem) in problems.iter().enumerate() {
+                println!("{:>width$}. [{}] {problem}", i + 1, problem.difficulty);
+            }
+            if answers {
+                println!("\nAnswers:\n");
+                for (i, problem) in problems.iter().enumerate() {
+                    println!("{:>width$}. {}", i + 1, problem.answer());
+                }
+            }
+        }
+        None => println!("Please provide a subject to practice."),
+    }
+}
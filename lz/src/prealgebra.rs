@@ -1,349 +1,1064 @@
-use clap::Subcommand;
-
-#[derive(Subcommand)]
-#[command(arg_required_else_help(true))]
-pub enum Prealgebra {
-    /// Finds all factor pairs for a positive integer.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra factor-pairs 12
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// The factor pairs of 12 are {(1, 12), (2, 6), (3, 4)}.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// {(1, 12), (2, 6), (3, 4)}
-    /// ```
-    FactorPairs {
-        /// The positive integer to find factor pairs for.
-        n: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Finds all factors for a positive integer.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra factors 12
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// The factors of 12 are {1, 2, 3, 4, 6, 12}.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// {1, 2, 3, 4, 6, 12}
-    /// ```
-    Factors {
-        /// The positive integer to find factors for.
-        n: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Finds all multiples of a positive integer in a given range.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra multiples-in-range 3 10
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// The multiples of 3 in the range [1, 10] are {3, 6, 9}.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// {3, 6, 9}
-    /// ```
-    MultiplesInRange {
-        /// The positive integer to find multiples for.
-        n: u32,
-        /// The lower bound of the range to find multiples in.
-        lower_bound: u32,
-        /// The upper bound of the range to find multiples in.
-        upper_bound: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Finds all primes in a given range.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra primes-in-range 1 10
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// The primes in the range [1, 10] are {2, 3, 7, 5}.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// {2, 3, 7, 5}
-    /// ```
-    PrimesInRange {
-        /// The lower bound of the range to find primes in.
-        lower_bound: u32,
-        /// The upper bound of the range to find primes in.
-        upper_bound: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Finds the prime factorization of a positive integer.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra prime-factorization 12
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// The prime factorization of 12 is {3: 1, 2: 2}.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// {3: 1, 2: 2}
-    /// ```
-    PrimeFactorization {
-        /// The positive integer to find the prime factorization of.
-        n: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Determines if a positive integer is composite.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra is-composite 12
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// 12 is composite.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// true
-    /// ```
-    IsComposite {
-        /// The positive integer to determine if it is composite.
-        n: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Determines if a positive integer is prime.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra is-prime 12
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// 12 is not prime.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// false
-    /// ```
-    IsPrime {
-        /// The positive integer to determine if it is prime.
-        n: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Determines if a positive integer is a factor of another positive integer.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra is-factor 3 12
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// 3 is a factor of 12.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// true
-    /// ```
-    IsFactor {
-        /// The positive integer to determine if it is a factor.
-        n: u32,
-        /// The positive integer to determine if it is a multiple.
-        m: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-    /// Determines if a positive integer is a multiple of another positive integer.
-    ///
-    /// ## Example
-    ///
-    /// ### Input
-    ///
-    /// ```bash
-    /// lz prealgebra is-multiple 12 3
-    /// ```
-    ///
-    /// ### Output
-    ///
-    /// ```bash
-    /// 12 is a multiple of 3.
-    /// ```
-    ///
-    /// ## Raw Output (use `-r` or `--raw`)
-    ///
-    /// ```bash
-    /// true
-    /// ```
-    IsMultiple {
-        /// The positive integer to determine if it is a multiple.
-        n: u32,
-        /// The positive integer to determine if it is a factor.
-        m: u32,
-        /// Whether or not to return the raw output.
-        #[arg(short = 'r', long)]
-        raw: bool,
-    },
-}
-
-pub fn match_prealgebra(function: Option<Prealgebra>) {
-    use ladderz::prealgebra::*;
-    match function {
-        Some(Prealgebra::FactorPairs { n, raw }) => match raw {
-            true => println!("{:?}", get_factor_pairs(n)),
-            false => println!("The factor pairs of {} are {:?}.", n, get_factor_pairs(n)),
-        },
-        Some(Prealgebra::Factors { n, raw }) => match raw {
-            true => println!("{:?}", get_factors(n)),
-            false => println!("The factors of {} are {:?}.", n, get_factors(n)),
-        },
-        Some(Prealgebra::MultiplesInRange {
-            n,
-            lower_bound,
-            upper_bound,
-            raw,
-        }) => match raw {
-            true => println!("{:?}", get_multiples_in_range(n, lower_bound, upper_bound)),
-            false => println!(
-                "The multiples of {} in the range [{}, {}] are {:?}.",
-                n,
-                lower_bound,
-                upper_bound,
-                get_multiples_in_range(n, lower_bound, upper_bound)
-            ),
-        },
-        Some(Prealgebra::PrimesInRange {
-            lower_bound,
-            upper_bound,
-            raw,
-        }) => match raw {
-            true => println!("{:?}", get_primes_in_range(lower_bound, upper_bound)),
-            false => println!(
-                "The primes in the range [{}, {}] are {:?}.",
-                lower_bound,
-                upper_bound,
-                get_primes_in_range(lower_bound, upper_bound)
-            ),
-        },
-        Some(Prealgebra::PrimeFactorization { n, raw }) => match raw {
-            true => println!("{:?}", get_prime_factorization(n)),
-            false => println!(
-                "The prime factorization of {} is {:?}.",
-                n,
-                get_prime_factorization(n)
-            ),
-        },
-        Some(Prealgebra::IsComposite { n, raw }) => match raw {
-            true => println!("{:?}", is_composite(n)),
-            false => println!(
-                "{} is {}composite.",
-                n,
-                if is_composite(n) { "" } else { "not " }
-            ),
-        },
-        Some(Prealgebra::IsPrime { n, raw }) => match raw {
-            true => println!("{:?}", is_prime(n)),
-            false => println!("{} is {}prime.", n, if is_prime(n) { "" } else { "not " }),
-        },
-        Some(Prealgebra::IsFactor { n, m, raw }) => match raw {
-            true => println!("{:?}", is_factor(n, m)),
-            false => println!(
-                "{} is {}a factor of {}.",
-                n,
-                if is_factor(n, m) { "" } else { "not " },
-                m
-            ),
+use crate::output::{LzOutput, Sentence, ToOutput};
+use crate::registry::{Entry, Function, Subject};
+use clap::Args;
+use clap::ValueEnum;
+use ladderz::prealgebra::{
+    fraction_to_base, from_balanced_ternary, from_base, from_roman, from_twos_complement,
+    get_factor_pairs, get_factors, get_prime_factorization, is_composite, is_factor, is_multiple,
+    is_prime, parse_int_literal, to_balanced_ternary, to_base, to_roman, to_scientific_notation,
+    to_twos_complement, BaseError,
+};
+// Large ranges are split across threads with the `parallel` feature, with the same results.
+#[cfg(feature = "parallel")]
+use ladderz::prealgebra::parallel::{get_multiples_in_range, get_primes_in_range};
+#[cfg(not(feature = "parallel"))]
+use ladderz::prealgebra::{get_multiples_in_range, get_primes_in_range};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::PathBuf;
+
+mod svg;
+mod visualize;
+
+/// The `lz prealgebra` subject.
+pub const SUBJECT: Subject = Subject {
+    name: "prealgebra",
+    about: "Factors, multiples, primes and conversions between bases",
+    functions: &[
+        Entry::of::<FactorPairs>(),
+        Entry::of::<Factors>(),
+        Entry::of::<MultiplesInRange>(),
+        Entry::of::<PrimesInRange>(),
+        Entry::of::<PrimeFactorization>(),
+        Entry::of::<IsComposite>(),
+        Entry::of::<IsPrime>(),
+        Entry::of::<IsFactor>(),
+        Entry::of::<IsMultiple>(),
+        Entry::of::<Convert>(),
+    ],
+};
+
+/// Parses a nonnegative integer argument, which may be written in hexadecimal, octal or binary
+/// with a `0x`, `0o` or `0b` prefix.
+fn parse_u32_literal(s: &str) -> Result<u32, String> {
+    let n = parse_int_literal(s).map_err(|e| e.to_string())?;
+    u32::try_from(n).map_err(|_| format!("{n} is not an integer from 0 to {}", u32::MAX))
+}
+
+/// Where to save a drawing of a result as an image.
+#[derive(Args)]
+pub struct ImageArgs {
+    /// Write a drawing of the result to an SVG file.
+    #[arg(long, value_name = "FILE")]
+    svg: Option<PathBuf>,
+    /// Write a drawing of the result to a PNG file.
+    #[arg(long, value_name = "FILE")]
+    png: Option<PathBuf>,
+}
+
+impl ImageArgs {
+    /// Writes the SVG from `draw` to each requested file, only drawing it if one was requested.
+    fn write(&self, draw: impl FnOnce() -> String) -> Result<(), String> {
+        if self.svg.is_none() && self.png.is_none() {
+            return Ok(());
+        }
+        let drawing = draw();
+        let write = |path: &PathBuf, contents: &[u8]| {
+            std::fs::write(path, contents)
+                .map_err(|e| format!("couldn't write {}: {e}", path.display()))
+        };
+        if let Some(path) = &self.svg {
+            write(path, drawing.as_bytes())?;
+        }
+        if let Some(path) = &self.png {
+            write(path, &svg::to_png(&drawing)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// A way of writing a number that `lz prealgebra convert` converts between.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Representation {
+    Base(u32),
+    Roman,
+    BalancedTernary,
+    Scientific,
+    TwosComplement,
+}
+
+impl fmt::Display for Representation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Representation::Base(base) => write!(f, "base {base}"),
+            Representation::Roman => write!(f, "Roman numerals"),
+            Representation::BalancedTernary => write!(f, "balanced ternary"),
+            Representation::Scientific => write!(f, "scientific notation"),
+            Representation::TwosComplement => write!(f, "two's complement"),
+        }
+    }
+}
+
+fn parse_representation(s: &str) -> Result<Representation, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "bin" | "binary" => Ok(Representation::Base(2)),
+        "oct" | "octal" => Ok(Representation::Base(8)),
+        "dec" | "decimal" => Ok(Representation::Base(10)),
+        "hex" | "hexadecimal" => Ok(Representation::Base(16)),
+        "roman" => Ok(Representation::Roman),
+        "balanced-ternary" => Ok(Representation::BalancedTernary),
+        "scientific" => Ok(Representation::Scientific),
+        "twos-complement" => Ok(Representation::TwosComplement),
+        other => match other.parse::<u32>() {
+            Ok(base) if (2..=36).contains(&base) => Ok(Representation::Base(base)),
+            _ => Err(format!(
+                "expected a base from 2 to 36, bin, oct, dec, hex, roman, balanced-ternary, \
+                 scientific or twos-complement, but found {s:?}"
+            )),
         },
-        Some(Prealgebra::IsMultiple { n, m, raw }) => match raw {
-            true => println!("{:?}", is_multiple(n, m)),
-            false => println!(
-                "{} is {}a multiple of {}.",
-                n,
-                if is_multiple(n, m) { "" } else { "not " },
-                m
+    }
+}
+
+/// A number read by `lz prealgebra convert`.
+enum Number {
+    Integer(i64),
+    /// A fraction `numerator / denominator`, which may be negative.
+    Fraction(bool, u64, u64),
+    Real(f64),
+}
+
+impl Number {
+    fn to_f64(&self) -> f64 {
+        match *self {
+            Number::Integer(n) => n as f64,
+            Number::Fraction(negative, numerator, denominator) => {
+                let value = numerator as f64 / denominator as f64;
+                if negative {
+                    -value
+                } else {
+                    value
+                }
+            }
+            Number::Real(x) => x,
+        }
+    }
+
+    fn to_integer(&self) -> Result<i64, String> {
+        match *self {
+            Number::Integer(n) => Ok(n),
+            Number::Fraction(negative, numerator, denominator) if numerator % denominator == 0 => {
+                let n = i64::try_from(numerator / denominator)
+                    .map_err(|_| BaseError::Overflow.to_string())?;
+                Ok(if negative { -n } else { n })
+            }
+            Number::Real(x) if x.fract() == 0.0 && x.abs() < i64::MAX as f64 => Ok(x as i64),
+            Number::Real(x) if x.fract() == 0.0 => Err(BaseError::Overflow.to_string()),
+            _ => Err("expected an integer".to_owned()),
+        }
+    }
+}
+
+/// Reads a base 10 fraction like `1/6` or decimal like `-0.375` as a fraction.
+fn parse_fraction(s: &str) -> Result<Number, String> {
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let parse = |digits: &str| {
+        digits
+            .parse::<u64>()
+            .map_err(|e| format!("{digits:?} is not a valid number: {e}"))
+    };
+    let (numerator, denominator) = match (rest.split_once('/'), rest.split_once('.')) {
+        (Some((numerator, denominator)), _) => (parse(numerator)?, parse(denominator)?),
+        (None, Some((integer, fraction))) => {
+            let denominator = u32::try_from(fraction.len())
+                .ok()
+                .and_then(|places| 10u64.checked_pow(places))
+                .ok_or_else(|| BaseError::Overflow.to_string())?;
+            let integer = if integer.is_empty() {
+                0
+            } else {
+                parse(integer)?
+            };
+            let fraction = if fraction.is_empty() {
+                0
+            } else {
+                parse(fraction)?
+            };
+            let numerator = integer
+                .checked_mul(denominator)
+                .and_then(|n| n.checked_add(fraction))
+                .ok_or_else(|| BaseError::Overflow.to_string())?;
+            (numerator, denominator)
+        }
+        (None, None) => unreachable!("only called for fractions and decimals"),
+    };
+    if denominator == 0 {
+        return Err(BaseError::ZeroDenominator.to_string());
+    }
+    Ok(Number::Fraction(negative, numerator, denominator))
+}
+
+/// Reads a number in scientific notation like `6.02e23` or `1.5 × 10^-3`, as an exact fraction
+/// when its numerator and denominator fit in 64 bits and as a float otherwise.
+fn parse_scientific(value: &str) -> Result<Number, String> {
+    let normalized = value
+        .replace(['×', ' '], "")
+        .replace("10^", "e")
+        .replace("*e", "e");
+    let x = normalized
+        .parse::<f64>()
+        .ok()
+        // Rules out `inf` and `NaN`, which f64 parses but aren't written with digits.
+        .filter(|_| normalized.contains(|c: char| c.is_ascii_digit()))
+        .ok_or_else(|| format!("{value:?} is not a number in scientific notation"))?;
+    if x.is_infinite() {
+        return Err(format!(
+            "{value:?} is out of range for a 64-bit float, whose magnitude is at most about 1.8e308"
+        ));
+    }
+    let exact = || {
+        let (mantissa, exponent) = match normalized.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().ok()?),
+            None => (normalized.as_str(), 0),
+        };
+        let mantissa = mantissa.strip_prefix('+').unwrap_or(mantissa);
+        // A trailing point makes an integer mantissa a decimal with no fractional digits.
+        let mantissa = match mantissa.contains('.') {
+            true => parse_fraction(mantissa),
+            false => parse_fraction(&format!("{mantissa}.")),
+        };
+        let Ok(Number::Fraction(negative, numerator, denominator)) = mantissa else {
+            return None;
+        };
+        let scale = 10u64.checked_pow(exponent.unsigned_abs())?;
+        match exponent >= 0 {
+            true => Some(Number::Fraction(
+                negative,
+                numerator.checked_mul(scale)?,
+                denominator,
+            )),
+            false => Some(Number::Fraction(
+                negative,
+                numerator,
+                denominator.checked_mul(scale)?,
+            )),
+        }
+    };
+    Ok(exact().unwrap_or(Number::Real(x)))
+}
+
+/// Returns the representation `value` is read in: the base of a `0x`, `0o` or `0b` prefix when
+/// `from` is the default base 10, and `from` otherwise.
+fn source_representation(value: &str, from: Representation) -> Representation {
+    let digits = value.trim();
+    let digits = digits.strip_prefix('-').unwrap_or(digits);
+    match (
+        from,
+        digits.get(..2).map(str::to_ascii_lowercase).as_deref(),
+    ) {
+        (Representation::Base(10), Some("0x")) => Representation::Base(16),
+        (Representation::Base(10), Some("0o")) => Representation::Base(8),
+        (Representation::Base(10), Some("0b")) => Representation::Base(2),
+        _ => from,
+    }
+}
+
+fn read_number(value: &str, from: Representation) -> Result<Number, String> {
+    let integer =
+        |result: Result<i64, BaseError>| result.map(Number::Integer).map_err(|e| e.to_string());
+    match from {
+        Representation::Base(10) if value.contains(['/', '.']) => parse_fraction(value),
+        Representation::Base(10) => integer(parse_int_literal(value)),
+        Representation::Base(base) => integer(from_base(value, base)),
+        Representation::Roman => integer(from_roman(value).map(i64::from)),
+        Representation::BalancedTernary => integer(from_balanced_ternary(value)),
+        Representation::TwosComplement => integer(from_twos_complement(value)),
+        Representation::Scientific => parse_scientific(value),
+    }
+}
+
+fn write_number(
+    number: &Number,
+    to: Representation,
+    bits: u32,
+    digits: u32,
+) -> Result<String, String> {
+    match (to, number) {
+        (Representation::Base(base), Number::Fraction(negative, numerator, denominator)) => {
+            let expansion =
+                fraction_to_base(*numerator, *denominator, base).map_err(|e| e.to_string())?;
+            let sign = if *negative && *numerator != 0 {
+                "-"
+            } else {
+                ""
+            };
+            Ok(format!("{sign}{expansion}"))
+        }
+        (Representation::Scientific, _) => {
+            Ok(to_scientific_notation(number.to_f64(), digits).to_string())
+        }
+        (Representation::Base(base), _) => {
+            to_base(number.to_integer()?, base).map_err(|e| e.to_string())
+        }
+        (Representation::Roman, _) => {
+            let n = number.to_integer()?;
+            match u32::try_from(n) {
+                Ok(n) => to_roman(n).map_err(|e| e.to_string()),
+                Err(_) => Err(format!(
+                    "{n} can't be written in Roman numerals, which range from 1 to 3999"
+                )),
+            }
+        }
+        (Representation::BalancedTernary, _) => Ok(to_balanced_ternary(number.to_integer()?)),
+        (Representation::TwosComplement, _) => {
+            to_twos_complement(number.to_integer()?, bits).map_err(|e| e.to_string())
+        }
+    }
+}
+
+/// Finds all factor pairs for a positive integer.
+///
+/// Use `--visualize` to also draw each factor pair as a rectangle of squares.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra factor-pairs 12
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The factor pairs of 12 are {(1, 12), (2, 6), (3, 4)}.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// {(1, 12), (2, 6), (3, 4)}
+/// ```
+///
+/// ## Visualization (use `--visualize`)
+///
+/// ```bash
+/// lz prealgebra factor-pairs 12 --visualize
+/// ```
+///
+/// ```bash
+/// The factor pairs of 12 are {(1, 12), (2, 6), (3, 4)}.
+///
+/// 1 × 12
+/// ■■■■■■■■■■■■
+///
+/// 2 × 6
+/// ■■■■■■
+/// ■■■■■■
+///
+/// 3 × 4
+/// ■■■■
+/// ■■■■
+/// ■■■■
+/// ```
+#[derive(Args)]
+pub struct FactorPairs {
+    /// The positive integer to find factor pairs for.
+    #[arg(value_parser = parse_u32_literal)]
+    n: u32,
+    /// Whether or not to draw each factor pair as a rectangle of squares.
+    #[arg(long)]
+    visualize: bool,
+}
+
+impl Function for FactorPairs {
+    const NAME: &'static str = "factor-pairs";
+    type Output = LzOutput;
+
+    fn run(&self) -> Result<LzOutput, String> {
+        Ok(LzOutput::factor_pairs(&get_factor_pairs(self.n)))
+    }
+
+    fn prose(&self, output: &LzOutput) -> Sentence {
+        Sentence::new(
+            "The factor pairs of {} are {}.",
+            [self.n.to_output(), output.clone()],
+        )
+    }
+
+    fn details(&self, _output: &LzOutput) -> Option<LzOutput> {
+        self.visualize
+            .then(|| LzOutput::Literal(visualize::factor_rectangles(&get_factor_pairs(self.n))))
+    }
+}
+
+/// Finds all factors for a positive integer.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra factors 12
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The factors of 12 are {1, 2, 3, 4, 6, 12}.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// {1, 2, 3, 4, 6, 12}
+/// ```
+#[derive(Args)]
+pub struct Factors {
+    /// The positive integer to find factors for.
+    #[arg(value_parser = parse_u32_literal)]
+    n: u32,
+}
+
+impl Function for Factors {
+    const NAME: &'static str = "factors";
+    type Output = HashSet<u32>;
+
+    fn run(&self) -> Result<HashSet<u32>, String> {
+        Ok(get_factors(self.n))
+    }
+
+    fn prose(&self, output: &HashSet<u32>) -> Sentence {
+        Sentence::new(
+            "The factors of {} are {}.",
+            [self.n.to_output(), output.to_output()],
+        )
+    }
+}
+
+/// Finds all multiples of a positive integer in a given range.
+///
+/// Use `--visualize` to also draw the range as a number line with a `●` on each multiple, or
+/// `--svg FILE` or `--png FILE` to save the number line as an image.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra multiples-in-range 3 1 10
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The multiples of 3 in the range [1, 10] are {3, 6, 9}.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// {3, 6, 9}
+/// ```
+///
+/// ## Visualization (use `--visualize`)
+///
+/// ```bash
+/// lz prealgebra multiples-in-range 3 1 10 --visualize
+/// ```
+///
+/// ```bash
+/// The multiples of 3 in the range [1, 10] are {3, 6, 9}.
+///
+/// ───┼───┼───●───┼───┼───●───┼───┼───●───┼─
+///    1   2   3   4   5   6   7   8   9  10
+/// ```
+#[derive(Args)]
+pub struct MultiplesInRange {
+    /// The positive integer to find multiples for.
+    #[arg(value_parser = parse_u32_literal)]
+    n: u32,
+    /// The lower bound of the range to find multiples in.
+    #[arg(value_parser = parse_u32_literal)]
+    lower_bound: u32,
+    /// The upper bound of the range to find multiples in.
+    #[arg(value_parser = parse_u32_literal)]
+    upper_bound: u32,
+    /// Whether or not to draw the range as a number line with the multiples marked.
+    #[arg(long)]
+    visualize: bool,
+    #[command(flatten)]
+    image: ImageArgs,
+}
+
+impl Function for MultiplesInRange {
+    const NAME: &'static str = "multiples-in-range";
+    type Output = HashSet<u32>;
+
+    fn run(&self) -> Result<HashSet<u32>, String> {
+        let (lower, upper) = (self.lower_bound, self.upper_bound);
+        let multiples = get_multiples_in_range(self.n, lower, upper);
+        self.image
+            .write(|| svg::number_line(lower, upper, &multiples))?;
+        Ok(multiples)
+    }
+
+    fn prose(&self, output: &HashSet<u32>) -> Sentence {
+        Sentence::new(
+            "The multiples of {} in the range [{}, {}] are {}.",
+            [
+                self.n.to_output(),
+                self.lower_bound.to_output(),
+                self.upper_bound.to_output(),
+                output.to_output(),
+            ],
+        )
+    }
+
+    fn details(&self, output: &HashSet<u32>) -> Option<LzOutput> {
+        self.visualize.then(|| {
+            let line = visualize::number_line(self.lower_bound, self.upper_bound, output);
+            LzOutput::Literal(line)
+        })
+    }
+}
+
+/// Finds all primes in a given range.
+///
+/// Use `--visualize` to also draw the range as a sieve grid with rows of ten, or
+/// `--visualize spiral` to draw it as an Ulam spiral with a `●` on each prime. `--svg FILE` and
+/// `--png FILE` save the same drawing as an image.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra primes-in-range 1 10
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The primes in the range [1, 10] are {2, 3, 5, 7}.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// {2, 3, 5, 7}
+/// ```
+///
+/// ## Visualization (use `--visualize`)
+///
+/// ```bash
+/// lz prealgebra primes-in-range 1 25 --visualize spiral
+/// ```
+///
+/// ```bash
+/// The primes in the range [1, 25] are {2, 3, 5, 7, 11, 13, 17, 19, 23}.
+///
+/// ● · · · ●
+/// · ● · ● ·
+/// ● · · ● ●
+/// · ● · · ·
+/// · · ● · ·
+/// ```
+#[derive(Args)]
+pub struct PrimesInRange {
+    /// The lower bound of the range to find primes in.
+    #[arg(value_parser = parse_u32_literal)]
+    lower_bound: u32,
+    /// The upper bound of the range to find primes in.
+    #[arg(value_parser = parse_u32_literal)]
+    upper_bound: u32,
+    /// Draw the range as a sieve grid with rows of ten, or as an Ulam spiral.
+    #[arg(long, value_enum, value_name = "LAYOUT", num_args = 0..=1, default_missing_value = "grid")]
+    visualize: Option<PrimeLayout>,
+    // Images are drawn in the `--visualize` layout, or as a sieve grid by default.
+    #[command(flatten)]
+    image: ImageArgs,
+}
+
+/// How `lz prealgebra primes-in-range --visualize` lays out the range.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum PrimeLayout {
+    /// Rows of ten with the primes written out and the rest crossed out.
+    Grid,
+    /// A spiral out from the lower bound with the primes marked.
+    Spiral,
+}
+
+impl Function for PrimesInRange {
+    const NAME: &'static str = "primes-in-range";
+    type Output = HashSet<u32>;
+
+    fn run(&self) -> Result<HashSet<u32>, String> {
+        let (lower, upper) = (self.lower_bound, self.upper_bound);
+        let primes = get_primes_in_range(lower, upper);
+        self.image.write(|| match self.visualize {
+            Some(PrimeLayout::Spiral) => svg::ulam_spiral(lower, upper, &primes),
+            _ => svg::sieve_grid(lower, upper, &primes),
+        })?;
+        Ok(primes)
+    }
+
+    fn prose(&self, output: &HashSet<u32>) -> Sentence {
+        Sentence::new(
+            "The primes in the range [{}, {}] are {}.",
+            [
+                self.lower_bound.to_output(),
+                self.upper_bound.to_output(),
+                output.to_output(),
+            ],
+        )
+    }
+
+    fn details(&self, output: &HashSet<u32>) -> Option<LzOutput> {
+        let (lower, upper) = (self.lower_bound, self.upper_bound);
+        let drawing = match self.visualize? {
+            PrimeLayout::Grid => visualize::sieve_grid(lower, upper, output),
+            PrimeLayout::Spiral => visualize::ulam_spiral(lower, upper, output),
+        };
+        Some(LzOutput::Literal(drawing))
+    }
+}
+
+/// Finds the prime factorization of a positive integer.
+///
+/// Use `--visualize` to also draw a factor tree that splits off the smallest prime at each step,
+/// or `--svg FILE` or `--png FILE` to save the factor tree as an image.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra prime-factorization 12
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// The prime factorization of 12 is {2: 2, 3: 1}.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// {2: 2, 3: 1}
+/// ```
+///
+/// ## Visualization (use `--visualize`)
+///
+/// ```bash
+/// lz prealgebra prime-factorization 12 --visualize
+/// ```
+///
+/// ```bash
+/// The prime factorization of 12 is {2: 2, 3: 1}.
+///
+/// 12
+/// ├── 2
+/// └── 6
+///     ├── 2
+///     └── 3
+/// ```
+#[derive(Args)]
+pub struct PrimeFactorization {
+    /// The positive integer to find the prime factorization of.
+    #[arg(value_parser = parse_u32_literal)]
+    n: u32,
+    /// Whether or not to draw a factor tree that splits off the smallest prime at each step.
+    #[arg(long)]
+    visualize: bool,
+    #[command(flatten)]
+    image: ImageArgs,
+}
+
+impl Function for PrimeFactorization {
+    const NAME: &'static str = "prime-factorization";
+    type Output = LzOutput;
+
+    fn run(&self) -> Result<LzOutput, String> {
+        let factorization = get_prime_factorization(self.n);
+        self.image
+            .write(|| svg::factor_tree(self.n, &factorization))?;
+        Ok(LzOutput::factorization(&factorization))
+    }
+
+    fn prose(&self, output: &LzOutput) -> Sentence {
+        Sentence::new(
+            "The prime factorization of {} is {}.",
+            [self.n.to_output(), output.clone()],
+        )
+    }
+
+    fn details(&self, _output: &LzOutput) -> Option<LzOutput> {
+        self.visualize.then(|| {
+            let tree = visualize::factor_tree(self.n, &get_prime_factorization(self.n));
+            LzOutput::Literal(tree)
+        })
+    }
+}
+
+/// Determines if a positive integer is composite.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra is-composite 12
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// 12 is composite.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// true
+/// ```
+#[derive(Args)]
+pub struct IsComposite {
+    /// The positive integer to determine if it is composite.
+    #[arg(value_parser = parse_u32_literal)]
+    n: u32,
+}
+
+impl Function for IsComposite {
+    const NAME: &'static str = "is-composite";
+    type Output = bool;
+
+    fn run(&self) -> Result<bool, String> {
+        Ok(is_composite(self.n))
+    }
+
+    fn prose(&self, output: &bool) -> Sentence {
+        let template = match output {
+            true => "{} is composite.",
+            false => "{} is not composite.",
+        };
+        Sentence::new(template, [self.n.to_output()])
+    }
+}
+
+/// Determines if a positive integer is prime.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra is-prime 12
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// 12 is not prime.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// false
+/// ```
+#[derive(Args)]
+pub struct IsPrime {
+    /// The positive integer to determine if it is prime.
+    #[arg(value_parser = parse_u32_literal)]
+    n: u32,
+}
+
+impl Function for IsPrime {
+    const NAME: &'static str = "is-prime";
+    type Output = bool;
+
+    fn run(&self) -> Result<bool, String> {
+        Ok(is_prime(self.n))
+    }
+
+    fn prose(&self, output: &bool) -> Sentence {
+        let template = match output {
+            true => "{} is prime.",
+            false => "{} is not prime.",
+        };
+        Sentence::new(template, [self.n.to_output()])
+    }
+}
+
+/// Determines if a positive integer is a factor of another positive integer.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra is-factor 3 12
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// 3 is a factor of 12.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// true
+/// ```
+#[derive(Args)]
+pub struct IsFactor {
+    /// The positive integer to determine if it is a factor.
+    #[arg(value_parser = parse_u32_literal)]
+    n: u32,
+    /// The positive integer to determine if it is a multiple.
+    #[arg(value_parser = parse_u32_literal)]
+    m: u32,
+}
+
+impl Function for IsFactor {
+    const NAME: &'static str = "is-factor";
+    type Output = bool;
+
+    fn run(&self) -> Result<bool, String> {
+        Ok(is_factor(self.n, self.m))
+    }
+
+    fn prose(&self, output: &bool) -> Sentence {
+        let template = match output {
+            true => "{} is a factor of {}.",
+            false => "{} is not a factor of {}.",
+        };
+        Sentence::new(template, [self.n.to_output(), self.m.to_output()])
+    }
+}
+
+/// Determines if a positive integer is a multiple of another positive integer.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra is-multiple 12 3
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// 12 is a multiple of 3.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// true
+/// ```
+#[derive(Args)]
+pub struct IsMultiple {
+    /// The positive integer to determine if it is a multiple.
+    #[arg(value_parser = parse_u32_literal)]
+    n: u32,
+    /// The positive integer to determine if it is a factor.
+    #[arg(value_parser = parse_u32_literal)]
+    m: u32,
+}
+
+impl Function for IsMultiple {
+    const NAME: &'static str = "is-multiple";
+    type Output = bool;
+
+    fn run(&self) -> Result<bool, String> {
+        Ok(is_multiple(self.n, self.m))
+    }
+
+    fn prose(&self, output: &bool) -> Sentence {
+        let template = match output {
+            true => "{} is a multiple of {}.",
+            false => "{} is not a multiple of {}.",
+        };
+        Sentence::new(template, [self.n.to_output(), self.m.to_output()])
+    }
+}
+
+/// Converts a number between bases and other representations.
+///
+/// `--from` and `--to` each take a base from 2 to 36 (or `bin`, `oct`, `dec`, `hex`), `roman`,
+/// `balanced-ternary`, `scientific` or `twos-complement`. Base 10 input may also be a literal
+/// with a `0x`, `0o` or `0b` prefix, read in that base, a fraction like `1/6` or a decimal like
+/// `0.1`, whose repeating digits are shown in parentheses. Scientific notation input like
+/// `6.02e2` or `1.5e-3` converts to any representation, but only an integer converts to
+/// `roman`, `balanced-ternary` or `twos-complement`, and a number needing more than 64 bits,
+/// like `6.02e23`, only converts to `scientific`. A fraction whose expansion runs past 10000
+/// digits, or scientific notation beyond the range of a 64-bit float, is an error.
+///
+/// ## Example
+///
+/// ### Input
+///
+/// ```bash
+/// lz prealgebra convert 255 --to 16
+/// ```
+///
+/// ### Output
+///
+/// ```bash
+/// 255 in base 10 is FF in base 16.
+/// ```
+///
+/// ## Raw Output (use `-r` or `--raw`)
+///
+/// ```bash
+/// FF
+/// ```
+#[derive(Args)]
+pub struct Convert {
+    /// The number to convert.
+    #[arg(allow_hyphen_values = true)]
+    value: String,
+    /// The representation the number is written in.
+    #[arg(long, default_value = "10", value_parser = parse_representation)]
+    from: Representation,
+    /// The representation to convert the number to.
+    #[arg(long, value_parser = parse_representation)]
+    to: Representation,
+    /// The width in bits of two's complement numbers.
+    #[arg(long, default_value_t = 8)]
+    bits: u32,
+    /// The number of significant digits in scientific notation.
+    #[arg(long, default_value_t = 3)]
+    digits: u32,
+}
+
+impl Function for Convert {
+    const NAME: &'static str = "convert";
+    type Output = LzOutput;
+
+    fn run(&self) -> Result<LzOutput, String> {
+        read_number(&self.value, self.from)
+            .and_then(|number| write_number(&number, self.to, self.bits, self.digits))
+            .map(LzOutput::Literal)
+    }
+
+    fn prose(&self, output: &LzOutput) -> Sentence {
+        let describe = |representation: Representation, bits: usize| match representation {
+            Representation::TwosComplement => format!("{bits}-bit {representation}"),
+            _ => representation.to_string(),
+        };
+        let template = format!(
+            "{{}} in {} is {{}} in {}.",
+            describe(
+                source_representation(&self.value, self.from),
+                self.value.chars().count()
             ),
-        },
-        None => println!("Please provide a function to use."),
+            describe(self.to, self.bits as usize)
+        );
+        Sentence::new(
+            &template,
+            [LzOutput::Literal(self.value.clone()), output.clone()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Format;
+
+    fn convert(value: &str, from: &str, to: &str) -> Convert {
+        Convert {
+            value: value.to_owned(),
+            from: parse_representation(from).unwrap(),
+            to: parse_representation(to).unwrap(),
+            bits: 8,
+            digits: 3,
+        }
+    }
+
+    fn prose(function: &Convert) -> String {
+        let output = function.run().unwrap();
+        function.prose(&output).render(Format::Prose)
+    }
+
+    #[test]
+    fn test_convert_prose_names_the_base_of_a_prefix() {
+        assert_eq!(
+            prose(&convert("0xff", "10", "roman")),
+            "0xff in base 16 is CCLV in Roman numerals."
+        );
+        assert_eq!(
+            prose(&convert("-0B101", "10", "10")),
+            "-0B101 in base 2 is -5 in base 10."
+        );
+        assert_eq!(
+            prose(&convert("0o17", "dec", "hex")),
+            "0o17 in base 8 is F in base 16."
+        );
+        assert_eq!(
+            prose(&convert("255", "10", "16")),
+            "255 in base 10 is FF in base 16."
+        );
+        // Without the default base 10, `0b1` is a base 16 number whose digits happen to be 0, B
+        // and 1.
+        assert_eq!(
+            prose(&convert("0b1", "16", "10")),
+            "0b1 in base 16 is 177 in base 10."
+        );
+    }
+
+    #[test]
+    fn test_convert_scientific_notation_and_long_fractions() {
+        assert_eq!(
+            convert("6.02e2", "scientific", "10").run(),
+            Ok(LzOutput::Literal("602".to_owned()))
+        );
+        assert_eq!(
+            convert("-1.5e-3", "scientific", "10").run(),
+            Ok(LzOutput::Literal("-0.0015".to_owned()))
+        );
+        assert_eq!(
+            convert("6.02e23", "scientific", "10").run(),
+            Err("the number is too large".to_owned())
+        );
+        assert_eq!(
+            convert("6.02e23", "scientific", "scientific").run(),
+            Ok(LzOutput::Literal("6.02 × 10^23".to_owned()))
+        );
+        assert_eq!(
+            convert("1/1000000007", "10", "10").run(),
+            Err(
+                "1/1000000007 has more than 10000 digits after the point in base 10 before it \
+                 repeats"
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn test_convert_scientific_out_of_range() {
+        for value in ["1.5e400", "-1.5e400", "1 × 10^309"] {
+            assert_eq!(
+                convert(value, "scientific", "10").run(),
+                Err(format!(
+                    "{value:?} is out of range for a 64-bit float, whose magnitude is at most \
+                     about 1.8e308"
+                ))
+            );
+        }
+        for value in ["inf", "NaN", "e5", "1.5e"] {
+            assert_eq!(
+                convert(value, "scientific", "10").run(),
+                Err(format!("{value:?} is not a number in scientific notation"))
+            );
+        }
     }
 }
@@ -0,0 +1,279 @@
+//! SVG drawings of pre-algebra results for `--svg`, and PNGs rasterized from them for `--png`.
+
+use super::visualize::{factor_steps, grid_rows, spiral};
+use resvg::{tiny_skia, usvg};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// The fill of a prime or highlighted number.
+const HIGHLIGHT: &str = "#2563eb";
+/// The fill of every other number.
+const MUTED: &str = "#e5e7eb";
+/// The color of lines and labels.
+const INK: &str = "#1f2937";
+/// Labels are drawn in DejaVu Sans where it's installed, which most Linux systems have.
+const FONT: &str = "DejaVu Sans, Arial, sans-serif";
+/// How many numbers a row of a number line holds before wrapping.
+const NUMBERS_PER_LINE: usize = 20;
+
+/// An SVG document that shapes are added to in order.
+struct Svg {
+    width: u32,
+    height: u32,
+    body: String,
+}
+
+impl Svg {
+    fn new(width: u32, height: u32) -> Svg {
+        Svg {
+            width,
+            height,
+            body: String::new(),
+        }
+    }
+
+    fn line(&mut self, (x1, y1): (u32, u32), (x2, y2): (u32, u32)) {
+        let _ = writeln!(
+            self.body,
+            r#"  <line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="{INK}" stroke-width="2"/>"#
+        );
+    }
+
+    fn circle(&mut self, (cx, cy): (u32, u32), r: u32, fill: &str) {
+        let _ = writeln!(
+            self.body,
+            r#"  <circle cx="{cx}" cy="{cy}" r="{r}" fill="{fill}"/>"#
+        );
+    }
+
+    fn rect(&mut self, (x, y): (u32, u32), size: u32, fill: &str) {
+        let _ = writeln!(
+            self.body,
+            r#"  <rect x="{x}" y="{y}" width="{size}" height="{size}" rx="3" fill="{fill}"/>"#
+        );
+    }
+
+    /// Adds `label` centered on `(x, y)`.
+    fn text(&mut self, (x, y): (u32, u32), label: impl std::fmt::Display, fill: &str) {
+        let _ = writeln!(
+            self.body,
+            r#"  <text x="{x}" y="{y}" fill="{fill}" text-anchor="middle" dominant-baseline="central">{label}</text>"#
+        );
+    }
+
+    fn finish(self) -> String {
+        let Svg {
+            width,
+            height,
+            body,
+        } = self;
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\" font-family=\"{FONT}\" font-size=\"14\">\n\
+             <rect width=\"{width}\" height=\"{height}\" fill=\"white\"/>\n{body}</svg>\n"
+        )
+    }
+}
+
+/// Draws a prime factor tree that splits off the smallest prime at each step, with each prime
+/// on the left and what's left to factor on the right.
+pub fn factor_tree(n: u32, factorization: &HashMap<u32, u32>) -> String {
+    let steps = factor_steps(n, factorization);
+    let (dx, dy, r, margin) = (40, 64, 22, 8);
+    let depth = steps.len() as u32;
+    // The first prime hangs one step left of the root, and each step moves one to the right.
+    let left = if steps.is_empty() { 0 } else { dx };
+    let mut svg = Svg::new(
+        2 * (r + margin) + left + dx * depth,
+        2 * (r + margin) + dy * depth,
+    );
+    let mut parent = (r + margin + left, r + margin);
+    // Lines go first so the circles are drawn over their ends.
+    for i in 0..depth {
+        let (x, y) = (parent.0 + dx * i, parent.1 + dy * i);
+        svg.line((x, y), (x - dx, y + dy));
+        svg.line((x, y), (x + dx, y + dy));
+    }
+    let fill = if steps.is_empty() && n > 1 {
+        HIGHLIGHT
+    } else {
+        MUTED
+    };
+    svg.circle(parent, r, fill);
+    svg.text(parent, n, if fill == HIGHLIGHT { "white" } else { INK });
+    for (i, &(prime, rest)) in steps.iter().enumerate() {
+        let (x, y) = (parent.0, parent.1 + dy);
+        svg.circle((x - dx, y), r, HIGHLIGHT);
+        svg.text((x - dx, y), prime, "white");
+        // The last number left is the largest prime.
+        let last = i == steps.len() - 1;
+        svg.circle((x + dx, y), r, if last { HIGHLIGHT } else { MUTED });
+        svg.text((x + dx, y), rest, if last { "white" } else { INK });
+        parent = (x + dx, y);
+    }
+    svg.finish()
+}
+
+/// Draws a number line over `[lower, upper]` with a dot on each number in `highlighted`,
+/// wrapping onto more lines for long ranges.
+pub fn number_line(lower: u32, upper: u32, highlighted: &HashSet<u32>) -> String {
+    let numbers: Vec<u32> = if lower <= upper {
+        (lower..=upper).collect()
+    } else {
+        Vec::new()
+    };
+    let cell = 12 + 9 * upper.to_string().len() as u32;
+    let (margin, row_height) = (20, 64);
+    let columns = numbers.len().clamp(1, NUMBERS_PER_LINE) as u32;
+    let rows = numbers.len().div_ceil(NUMBERS_PER_LINE).max(1) as u32;
+    let mut svg = Svg::new(2 * margin + cell * columns, 2 * margin + row_height * rows);
+    for (row, chunk) in numbers.chunks(NUMBERS_PER_LINE).enumerate() {
+        let y = margin + 16 + row_height * row as u32;
+        let end = margin + cell * chunk.len() as u32;
+        svg.line((margin, y), (end, y));
+        for (column, &n) in chunk.iter().enumerate() {
+            let x = margin + cell / 2 + cell * column as u32;
+            match highlighted.contains(&n) {
+                true => svg.circle((x, y), 7, HIGHLIGHT),
+                false => svg.line((x, y - 6), (x, y + 6)),
+            }
+            svg.text((x, y + 24), n, INK);
+        }
+    }
+    svg.finish()
+}
+
+/// Draws the numbers in `[lower, upper]` in rows of ten like the sieve of Eratosthenes, with
+/// each of `primes` highlighted.
+pub fn sieve_grid(lower: u32, upper: u32, primes: &HashSet<u32>) -> String {
+    let rows = grid_rows(lower, upper);
+    let (margin, size, gap) = (12, 40, 4);
+    let width = 2 * margin + 10 * (size + gap) - gap;
+    let height = 2 * margin + (size + gap) * rows.len() as u32;
+    let mut svg = Svg::new(width, height.saturating_sub(gap).max(2 * margin));
+    for (row, numbers) in rows.iter().enumerate() {
+        for (column, n) in numbers.iter().enumerate() {
+            let Some(n) = *n else { continue };
+            let (x, y) = (
+                margin + (size + gap) * column as u32,
+                margin + (size + gap) * row as u32,
+            );
+            let prime = primes.contains(&n);
+            svg.rect((x, y), size, if prime { HIGHLIGHT } else { MUTED });
+            let center = (x + size / 2, y + size / 2);
+            svg.text(center, n, if prime { "white" } else { INK });
+        }
+    }
+    svg.finish()
+}
+
+/// Draws an Ulam spiral that starts at `lower` in the middle and winds anticlockwise out to
+/// `upper`, with a dot on each of `primes`.
+pub fn ulam_spiral(lower: u32, upper: u32, primes: &HashSet<u32>) -> String {
+    let spiral = spiral(lower, upper);
+    let (margin, size) = (8, 12);
+    let mut svg = Svg::new(
+        2 * margin + size * spiral.columns as u32,
+        2 * margin + size * spiral.rows as u32,
+    );
+    for (n, x, y) in spiral.cells {
+        let center = (
+            margin + size * x as u32 + size / 2,
+            margin + size * y as u32 + size / 2,
+        );
+        match primes.contains(&n) {
+            true => svg.circle(center, size / 2 - 1, HIGHLIGHT),
+            false => svg.circle(center, 2, MUTED),
+        }
+    }
+    svg.finish()
+}
+
+/// Rasterizes an SVG drawing to a PNG at twice its size, so it stays sharp on high-density
+/// screens. Labels are drawn with the fonts installed on the system.
+pub fn to_png(svg: &str) -> Result<Vec<u8>, String> {
+    let mut options = usvg::Options::default();
+    options.fontdb_mut().load_system_fonts();
+    let tree = usvg::Tree::from_str(svg, &options).map_err(|e| e.to_string())?;
+    let size = tree
+        .size()
+        .to_int_size()
+        .scale_by(2.0)
+        .ok_or("the drawing is too big")?;
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or("the drawing is too big to rasterize")?;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_scale(2.0, 2.0),
+        &mut pixmap.as_mut(),
+    );
+    pixmap.encode_png().map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ladderz::prealgebra::{
+        get_multiples_in_range, get_prime_factorization, get_primes_in_range,
+    };
+    use std::path::PathBuf;
+
+    /// Checks `svg` against `golden/<name>`, or rewrites the file when `LZ_UPDATE_GOLDEN` is set.
+    fn assert_golden(name: &str, svg: &str) {
+        let path: PathBuf = [
+            env!("CARGO_MANIFEST_DIR"),
+            "src",
+            "prealgebra",
+            "golden",
+            name,
+        ]
+        .iter()
+        .collect();
+        if std::env::var_os("LZ_UPDATE_GOLDEN").is_some() {
+            std::fs::write(&path, svg).unwrap();
+        }
+        let golden = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(svg, golden, "{name} differs from its golden file");
+    }
+
+    #[test]
+    fn test_factor_tree() {
+        let svg = factor_tree(360, &get_prime_factorization(360));
+        assert_golden("factor_tree_360.svg", &svg);
+        assert_golden(
+            "factor_tree_7.svg",
+            &factor_tree(7, &get_prime_factorization(7)),
+        );
+    }
+
+    #[test]
+    fn test_number_line() {
+        let svg = number_line(1, 30, &get_multiples_in_range(3, 1, 30));
+        assert_golden("number_line_3_1_30.svg", &svg);
+    }
+
+    #[test]
+    fn test_sieve_grid() {
+        let svg = sieve_grid(1, 50, &get_primes_in_range(1, 50));
+        assert_golden("sieve_grid_1_50.svg", &svg);
+    }
+
+    #[test]
+    fn test_ulam_spiral() {
+        let svg = ulam_spiral(1, 100, &get_primes_in_range(1, 100));
+        assert_golden("ulam_spiral_1_100.svg", &svg);
+    }
+
+    #[test]
+    fn test_to_png() {
+        let svg = ulam_spiral(1, 9, &get_primes_in_range(1, 9));
+        let png = to_png(&svg).unwrap();
+        let pixmap = tiny_skia::Pixmap::decode_png(&png).unwrap();
+        // 3 cells of 12 pixels and two margins of 8, doubled.
+        assert_eq!((pixmap.width(), pixmap.height()), (104, 104));
+        // 2 is a prime right of the middle, and 1 in the middle isn't.
+        let color = |x: u32, y: u32| pixmap.pixel(2 * x, 2 * y).unwrap();
+        assert_eq!((color(38, 26).red(), color(38, 26).blue()), (0x25, 0xeb));
+        assert_eq!(color(26, 30).red(), 0xff);
+    }
+}
@@ -0,0 +1,257 @@
+//! Unicode drawings of pre-algebra results for `--visualize`.
+
+use std::collections::{HashMap, HashSet};
+
+/// The widest line a drawing is wrapped or scaled to fit in.
+const MAX_WIDTH: usize = 80;
+
+/// The most rows of a factor-pair rectangle that are drawn.
+const MAX_RECTANGLE_HEIGHT: u32 = 20;
+
+/// Returns the width of the widest number in `[lower, upper]`.
+fn label_width(lower: u32, upper: u32) -> usize {
+    lower.to_string().len().max(upper.to_string().len())
+}
+
+/// Returns each step of splitting off the smallest prime from `n`, as the prime and what's left.
+/// The last prime is what's left after dividing out the others, so it isn't split off.
+pub(super) fn factor_steps(n: u32, factorization: &HashMap<u32, u32>) -> Vec<(u32, u32)> {
+    let mut primes: Vec<u32> = factorization
+        .iter()
+        .flat_map(|(&prime, &exponent)| std::iter::repeat_n(prime, exponent as usize))
+        .collect();
+    primes.sort();
+    primes.pop();
+    let mut rest = n;
+    primes
+        .into_iter()
+        .map(|prime| {
+            rest /= prime;
+            (prime, rest)
+        })
+        .collect()
+}
+
+/// Draws a prime factor tree that splits off the smallest prime at each step, e.g.
+/// `12\n├── 2\n└── 6\n    ├── 2\n    └── 3`.
+pub fn factor_tree(n: u32, factorization: &HashMap<u32, u32>) -> String {
+    let mut lines = vec![n.to_string()];
+    let mut prefix = String::new();
+    for (prime, rest) in factor_steps(n, factorization) {
+        lines.push(format!("{prefix}├── {prime}"));
+        lines.push(format!("{prefix}└── {rest}"));
+        prefix.push_str("    ");
+    }
+    lines.join("\n")
+}
+
+/// Draws a number line over `[lower, upper]` with a `●` on each number in `highlighted`,
+/// wrapping onto more lines for long ranges.
+pub fn number_line(lower: u32, upper: u32, highlighted: &HashSet<u32>) -> String {
+    if lower > upper {
+        return String::new();
+    }
+    let cell = label_width(lower, upper) + 2;
+    // Each line ends with one more `─` past the last number.
+    let per_line = ((MAX_WIDTH - 1) / cell).max(1);
+    let numbers: Vec<u32> = (lower..=upper).collect();
+    numbers
+        .chunks(per_line)
+        .map(|chunk| {
+            let line: String = chunk
+                .iter()
+                .map(|n| {
+                    let mark = if highlighted.contains(n) {
+                        '●'
+                    } else {
+                        '┼'
+                    };
+                    format!("{}{mark}", "─".repeat(cell - 1))
+                })
+                .collect();
+            let labels: String = chunk.iter().map(|n| format!("{n:>cell$}")).collect();
+            format!("{line}─\n{}", labels.trim_end())
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+/// Returns the numbers in `[lower, upper]` in rows of ten starting at 1, 11, 21 and so on, so
+/// each column holds the numbers with the same last digit. Places before `lower` are `None`.
+pub(super) fn grid_rows(lower: u32, upper: u32) -> Vec<Vec<Option<u32>>> {
+    if lower > upper {
+        return Vec::new();
+    }
+    let first = lower.saturating_sub((lower + 9) % 10);
+    let numbers: Vec<Option<u32>> = (first..=upper).map(|n| (n >= lower).then_some(n)).collect();
+    numbers.chunks(10).map(<[Option<u32>]>::to_vec).collect()
+}
+
+/// Draws the numbers in `[lower, upper]` in rows of ten like the sieve of Eratosthenes, where
+/// each of `primes` is written out and the rest are crossed out with `·`.
+pub fn sieve_grid(lower: u32, upper: u32, primes: &HashSet<u32>) -> String {
+    let width = label_width(lower, upper);
+    grid_rows(lower, upper)
+        .iter()
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|&n| match n {
+                    None => " ".repeat(width),
+                    Some(n) if primes.contains(&n) => format!("{n:>width$}"),
+                    Some(_) => format!("{:>width$}", "·"),
+                })
+                .collect();
+            cells.join(" ").trim_end().to_owned()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// The place of each number in an Ulam spiral, and the number of columns and rows it spans.
+pub(super) struct Spiral {
+    /// Each number with its column and row, counted from the top left.
+    pub cells: Vec<(u32, usize, usize)>,
+    pub columns: usize,
+    pub rows: usize,
+}
+
+/// Lays out an Ulam spiral that starts at `lower` in the middle and winds anticlockwise out to
+/// `upper`.
+pub(super) fn spiral(lower: u32, upper: u32) -> Spiral {
+    let mut cells = Vec::new();
+    if lower <= upper {
+        // Walk right 1, up 1, left 2, down 2, right 3 and so on, turning after each leg.
+        let directions = [(1, 0), (0, -1), (-1, 0), (0, 1)];
+        let (mut x, mut y) = (0i64, 0i64);
+        let (mut leg, mut turn, mut n) = (1, 0, lower);
+        'walk: loop {
+            for _ in 0..2 {
+                let (dx, dy) = directions[turn % 4];
+                for _ in 0..leg {
+                    cells.push((n, x, y));
+                    if n == upper {
+                        break 'walk;
+                    }
+                    n += 1;
+                    x += dx;
+                    y += dy;
+                }
+                turn += 1;
+            }
+            leg += 1;
+        }
+    }
+    let min_x = cells.iter().map(|&(_, x, _)| x).min().unwrap_or(0);
+    let max_x = cells.iter().map(|&(_, x, _)| x).max().unwrap_or(-1);
+    let min_y = cells.iter().map(|&(_, _, y)| y).min().unwrap_or(0);
+    let max_y = cells.iter().map(|&(_, _, y)| y).max().unwrap_or(-1);
+    Spiral {
+        cells: cells
+            .into_iter()
+            .map(|(n, x, y)| (n, (x - min_x) as usize, (y - min_y) as usize))
+            .collect(),
+        columns: (max_x - min_x + 1) as usize,
+        rows: (max_y - min_y + 1) as usize,
+    }
+}
+
+/// Draws an Ulam spiral that starts at `lower` in the middle and winds anticlockwise out to
+/// `upper`, with a `●` on each of `primes` and a `·` on every other number.
+pub fn ulam_spiral(lower: u32, upper: u32, primes: &HashSet<u32>) -> String {
+    let spiral = spiral(lower, upper);
+    let mut grid = vec![vec![' '; spiral.columns]; spiral.rows];
+    for (n, x, y) in spiral.cells {
+        grid[y][x] = if primes.contains(&n) { '●' } else { '·' };
+    }
+    grid.iter()
+        .map(|row| {
+            let row: Vec<String> = row.iter().map(char::to_string).collect();
+            row.join(" ").trim_end().to_owned()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Draws each factor pair `(a, b)` as a rectangle of `a` rows of `b` squares, in order of the
+/// smaller factor. Rectangles too big for the terminal are named but not drawn.
+pub fn factor_rectangles(pairs: &HashSet<(u32, u32)>) -> String {
+    let mut pairs: Vec<&(u32, u32)> = pairs.iter().collect();
+    pairs.sort();
+    pairs
+        .into_iter()
+        .map(|&(rows, columns)| {
+            if columns as usize > MAX_WIDTH || rows > MAX_RECTANGLE_HEIGHT {
+                return format!("{rows} × {columns} (too big to draw)");
+            }
+            let row = "■".repeat(columns as usize);
+            let rectangle = vec![row; rows as usize].join("\n");
+            format!("{rows} × {columns}\n{rectangle}")
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ladderz::prealgebra::{
+        get_factor_pairs, get_multiples_in_range, get_prime_factorization, get_primes_in_range,
+    };
+
+    #[test]
+    fn test_factor_tree() {
+        assert_eq!(
+            factor_tree(12, &get_prime_factorization(12)),
+            "12\n├── 2\n└── 6\n    ├── 2\n    └── 3"
+        );
+        assert_eq!(factor_tree(7, &get_prime_factorization(7)), "7");
+        assert_eq!(factor_tree(1, &get_prime_factorization(1)), "1");
+    }
+
+    #[test]
+    fn test_number_line() {
+        let multiples = get_multiples_in_range(3, 1, 10);
+        assert_eq!(
+            number_line(1, 10, &multiples),
+            "───┼───┼───●───┼───┼───●───┼───┼───●───┼─\n   1   2   3   4   5   6   7   8   9  10"
+        );
+        let wrapped = number_line(1, 30, &HashSet::new());
+        assert_eq!(wrapped.split("\n\n").count(), 2);
+        assert!(wrapped
+            .lines()
+            .all(|line| line.chars().count() <= MAX_WIDTH));
+        assert_eq!(number_line(5, 1, &HashSet::new()), "");
+    }
+
+    #[test]
+    fn test_sieve_grid() {
+        let primes = get_primes_in_range(1, 20);
+        assert_eq!(
+            sieve_grid(1, 20, &primes),
+            " ·  2  3  ·  5  ·  7  ·  ·  ·\n11  · 13  ·  ·  · 17  · 19  ·"
+        );
+        assert_eq!(
+            sieve_grid(15, 21, &get_primes_in_range(15, 21)),
+            "             ·  · 17  · 19  ·\n ·"
+        );
+    }
+
+    #[test]
+    fn test_ulam_spiral() {
+        let primes = get_primes_in_range(1, 9);
+        assert_eq!(ulam_spiral(1, 9, &primes), "● · ●\n· · ●\n● · ·");
+        assert_eq!(ulam_spiral(2, 2, &primes), "●");
+    }
+
+    #[test]
+    fn test_factor_rectangles() {
+        assert_eq!(
+            factor_rectangles(&get_factor_pairs(6)),
+            "1 × 6\n■■■■■■\n\n2 × 3\n■■■\n■■■"
+        );
+        let drawing = factor_rectangles(&get_factor_pairs(162));
+        assert!(drawing
+            .starts_with("1 × 162 (too big to draw)\n\n2 × 81 (too big to draw)\n\n3 × 54\n■■■"));
+    }
+}
@@ -0,0 +1,720 @@
+use clap::{Subcommand, ValueEnum};
+use ladderz::precalculus::Expression;
+
+/// Parses an expression that doesn't depend on `x`, such as `pi/6` or `sqrt(2)`.
+fn parse_constant_expression(s: &str) -> Result<Expression, String> {
+    let expression: Expression = s.parse().map_err(|e| format!("{e}"))?;
+    if !expression.is_constant() {
+        return Err("expected a number, but the expression depends on x".to_owned());
+    }
+    Ok(expression)
+}
+
+/// Parses a number that may be written as a constant expression, such as `pi/6` or `sqrt(2)`.
+fn parse_constant(s: &str) -> Result<f64, String> {
+    parse_constant_expression(s).map(|expression| expression.evaluate(0.0))
+}
+
+/// An angle for `lz precalculus trig`.
+#[derive(Clone, Copy)]
+pub struct Angle {
+    value: f64,
+    /// Whether the angle is written with `pi`, like `pi/3`, which makes it an angle in radians.
+    has_pi: bool,
+}
+
+/// Returns `true` if `expression` uses the constant `pi`.
+fn has_pi(expression: &Expression) -> bool {
+    match expression {
+        Expression::Pi => true,
+        Expression::Negate(e) | Expression::Call(_, e) => has_pi(e),
+        Expression::Binary(_, left, right) => has_pi(left) || has_pi(right),
+        _ => false,
+    }
+}
+
+/// Parses an angle that may be written as a constant expression, such as `60` or `pi/3`.
+fn parse_angle(s: &str) -> Result<Angle, String> {
+    let expression = parse_constant_expression(s)?;
+    Ok(Angle {
+        value: expression.evaluate(0.0),
+        has_pi: has_pi(&expression),
+    })
+}
+
+/// Parses a function of `x`, such as `2x + 1`.
+fn parse_expression(s: &str) -> Result<Expression, String> {
+    s.parse().map_err(|e| format!("{e}"))
+}
+
+/// The trigonometric function to evaluate.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Trig {
+    Sin,
+    Cos,
+    Tan,
+    Csc,
+    Sec,
+    Cot,
+}
+
+/// Which sides and angles are given, in the order they are passed.
+#[derive(Clone, Copy, ValueEnum)]
+pub enum TriangleCase {
+    /// Three sides: a b c.
+    Sss,
+    /// Two sides and the angle between them: b A c.
+    Sas,
+    /// Two angles and the side between them: A c B.
+    Asa,
+    /// Two angles and the side opposite the first: A B a.
+    Aas,
+    /// Two sides and the angle opposite the first: a b A (the ambiguous case).
+    Ssa,
+}
+
+#[derive(Subcommand)]
+#[command(arg_required_else_help(true))]
+pub enum Precalculus {
+    /// Composes two functions of x into f(g(x)), optionally evaluating it at a point.
+    ///
+    /// Functions may use numbers, `x`, `pi`, `e`, `+ - * / ^`, parentheses, and `sin cos tan asin
+    /// acos atan sqrt abs exp ln log`.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus compose "x^2" "x + 1" --at 2
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// (f ∘ g)(x) = (x + 1)^2, and (f ∘ g)(2) = 9.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// (x + 1)^2
+    /// 9
+    /// ```
+    Compose {
+        /// The outer function f(x).
+        #[arg(value_parser = parse_expression)]
+        f: Expression,
+        /// The inner function g(x).
+        #[arg(value_parser = parse_expression)]
+        g: Expression,
+        /// A value of x to evaluate the composition at.
+        #[arg(long, allow_hyphen_values = true, value_parser = parse_constant)]
+        at: Option<f64>,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Checks whether two functions of x are inverses by sampling an interval.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus is-inverse "2x + 1" "(x - 1)/2"
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// 2*x + 1 and (x - 1)/2 are inverses on [-10, 10] (201 of 201 samples compared).
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// true
+    /// ```
+    IsInverse {
+        /// The first function f(x).
+        #[arg(value_parser = parse_expression)]
+        f: Expression,
+        /// The second function g(x).
+        #[arg(value_parser = parse_expression)]
+        g: Expression,
+        /// The start of the interval to sample.
+        #[arg(long, default_value_t = -10.0, allow_hyphen_values = true, value_parser = parse_constant)]
+        from: f64,
+        /// The end of the interval to sample.
+        #[arg(long, default_value_t = 10.0, allow_hyphen_values = true, value_parser = parse_constant)]
+        to: f64,
+        /// The number of evenly spaced samples.
+        #[arg(long, default_value_t = 201)]
+        samples: usize,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Converts an angle from degrees to radians.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus to-radians 30
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// 30° is π/6 ≈ 0.5236 radians.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 0.5235987755982988
+    /// ```
+    ToRadians {
+        /// The angle in degrees.
+        #[arg(allow_hyphen_values = true)]
+        degrees: f64,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Converts an angle from radians, which may be written like `pi/6`, to degrees.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus to-degrees pi/6
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// pi/6 radians is 30°.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 30
+    /// ```
+    ToDegrees {
+        /// The angle in radians.
+        #[arg(allow_hyphen_values = true)]
+        radians: String,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Evaluates a trigonometric function, with the exact value for multiples of 30° and 45°.
+    ///
+    /// The angle is in degrees, unless `--radians` is given or it's written with `pi`, so
+    /// `sin pi/3` is the same as `sin 60`.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus trig sin 60
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// sin(60°) = √3/2 ≈ 0.866.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 0.8660254037844386
+    /// ```
+    Trig {
+        /// The function to evaluate.
+        #[arg(value_enum)]
+        function: Trig,
+        /// The angle, in degrees unless `--radians` is given. An angle written with `pi`, like
+        /// `pi/4`, is always in radians.
+        #[arg(allow_hyphen_values = true, value_parser = parse_angle)]
+        angle: Angle,
+        /// Whether the angle is in radians.
+        #[arg(long)]
+        radians: bool,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Solves a triangle with the laws of sines and cosines. Angles are in degrees.
+    ///
+    /// Side a is opposite angle A, b opposite B, and c opposite C.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus solve-triangle ssa 6 8 30
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// There are 2 triangles:
+    /// a = 6, b = 8, c = 11.4003, A = 30°, B = 41.8103°, C = 108.1897°
+    /// a = 6, b = 8, c = 2.4561, A = 30°, B = 138.1897°, C = 11.8103°
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// [Triangle { a: 6.0, b: 8.0, c: 11.40033918527509, alpha: 30.0, beta: 41.810314895778596, gamma: 108.18968510422141 }, Triangle { a: 6.0, b: 8.0, c: 2.4560672752759283, alpha: 30.0, beta: 138.1896851042214, gamma: 11.810314895778589 }]
+    /// ```
+    SolveTriangle {
+        /// Which sides and angles are given.
+        #[arg(value_enum)]
+        case: TriangleCase,
+        /// The first given value.
+        first: f64,
+        /// The second given value.
+        second: f64,
+        /// The third given value.
+        third: f64,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Finds the logarithm of a positive number, by default the natural logarithm.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus log 8 --base 2
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// log_2(8) = 3.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 3
+    /// ```
+    Log {
+        /// The positive number to find the logarithm of.
+        #[arg(allow_hyphen_values = true, value_parser = parse_constant)]
+        x: f64,
+        /// The base of the logarithm.
+        #[arg(short, long, value_parser = parse_constant)]
+        base: Option<f64>,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Evaluates both sides of the logarithm identities for a base and two positive numbers.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus log-identities 2 8 4
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// product rule: log_2(8·4) = log_2(8) + log_2(4) (5 = 5)
+    /// quotient rule: log_2(8/4) = log_2(8) - log_2(4) (1 = 1)
+    /// power rule: log_2(8^4) = 4·log_2(8) (12 = 12)
+    /// change of base: log_2(8) = ln(8)/ln(2) (3 = 3)
+    /// inverse: 2^(log_2(8)) = 8 (8 = 8)
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// [(5.0, 5.0), (1.0, 1.0), (12.0, 12.0), (3.0, 3.0), (8.0, 8.0)]
+    /// ```
+    LogIdentities {
+        /// The base of the logarithm.
+        base: f64,
+        /// The first positive number.
+        x: f64,
+        /// The second positive number.
+        y: f64,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Evaluates both sides of the exponent identities for a base and two exponents.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus exponent-identities 2 3 2
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// product rule: 2^3·2^2 = 2^(3+2) (32 = 32)
+    /// quotient rule: 2^3/2^2 = 2^(3-2) (2 = 2)
+    /// power rule: (2^3)^2 = 2^(3·2) (64 = 64)
+    /// negative exponent: 2^-3 = 1/2^3 (0.125 = 0.125)
+    /// zero exponent: 2^0 = 1 (1 = 1)
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// [(32.0, 32.0), (2.0, 2.0), (64.0, 64.0), (0.125, 0.125), (1.0, 1.0)]
+    /// ```
+    ExponentIdentities {
+        /// The base.
+        #[arg(allow_hyphen_values = true)]
+        base: f64,
+        /// The first exponent.
+        #[arg(allow_hyphen_values = true)]
+        m: f64,
+        /// The second exponent.
+        #[arg(allow_hyphen_values = true)]
+        n: f64,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Lists the first n terms of an arithmetic sequence and their sum.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus arithmetic-series 3 4 5
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The first 5 terms are [3.0, 7.0, 11.0, 15.0, 19.0], which sum to 55.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 55
+    /// ```
+    ArithmeticSeries {
+        /// The first term.
+        #[arg(allow_hyphen_values = true)]
+        first: f64,
+        /// The difference between consecutive terms.
+        #[arg(allow_hyphen_values = true)]
+        difference: f64,
+        /// The number of terms.
+        n: u32,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+    /// Lists the first n terms of a geometric sequence, their sum, and the infinite sum if it
+    /// converges.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz precalculus geometric-series 1 0.5 4
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// The first 4 terms are [1.0, 0.5, 0.25, 0.125], which sum to 1.875.
+    /// The infinite series converges to 2.
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// ```bash
+    /// 1.875
+    /// ```
+    GeometricSeries {
+        /// The first term.
+        #[arg(allow_hyphen_values = true)]
+        first: f64,
+        /// The ratio between consecutive terms.
+        #[arg(allow_hyphen_values = true)]
+        ratio: f64,
+        /// The number of terms.
+        n: u32,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+}
+
+pub fn match_precalculus(function: Option<Precalculus>) {
+    use ladderz::precalculus::*;
+    match function {
+        Some(Precalculus::Compose { f, g, at, raw }) => {
+            let composition = f.compose(&g);
+            let value = at.map(|x| (x, composition.evaluate(x)));
+            match (raw, value) {
+                (true, None) => println!("{composition}"),
+                (true, Some((_, y))) => println!("{composition}\n{y}"),
+                (false, None) => println!("(f ∘ g)(x) = {composition}."),
+                (false, Some((x, y))) => println!(
+                    "(f ∘ g)(x) = {composition}, and (f ∘ g)({x}) = {}.",
+                    round_to(y, 10)
+                ),
+            }
+        }
+        Some(Precalculus::IsInverse {
+            f,
+            g,
+            from,
+            to,
+            samples,
+            raw,
+        }) => {
+            let domain = sample_domain(from, to, samples);
+            let comparison = compare_inverses(|x| f.evaluate(x), |x| g.evaluate(x), &domain, 1e-9);
+            match (raw, comparison) {
+                (_, InverseComparison::Undetermined) => {
+                    eprintln!(
+                        "Error: neither g(f(x)) nor f(g(x)) is defined at any of the {samples} \
+                         samples in [{from}, {to}]"
+                    );
+                    std::process::exit(1);
+                }
+                (true, comparison) => println!(
+                    "{:?}",
+                    matches!(comparison, InverseComparison::Inverses { .. })
+                ),
+                (false, InverseComparison::Inverses { compared }) => println!(
+                    "{f} and {g} are inverses on [{from}, {to}] ({compared} of {samples} samples \
+                     compared)."
+                ),
+                (false, InverseComparison::Counterexample(x)) => println!(
+                    "{f} and {g} are not inverses: at x = {x}, g(f(x)) = {} and f(g(x)) = {}.",
+                    round_to(g.evaluate(f.evaluate(x)), 10),
+                    round_to(f.evaluate(g.evaluate(x)), 10)
+                ),
+            }
+        }
+        Some(Precalculus::ToRadians { degrees, raw }) => {
+            let radians = degrees_to_radians(degrees);
+            match raw {
+                true => println!("{radians:?}"),
+                false if degrees.fract() == 0.0 => {
+                    let fraction = match degrees_to_pi_fraction(degrees as i64) {
+                        (0, _) => "0".to_owned(),
+                        (n, 1) => {
+                            format!("{}π", if n == 1 { String::new() } else { n.to_string() })
+                        }
+                        (1, d) => format!("π/{d}"),
+                        (-1, d) => format!("-π/{d}"),
+                        (n, d) => format!("{n}π/{d}"),
+                    };
+                    println!(
+                        "{degrees}° is {fraction} ≈ {} radians.",
+                        round_to(radians, 4)
+                    )
+                }
+                false => println!("{degrees}° is ≈ {} radians.", round_to(radians, 4)),
+            }
+        }
+        Some(Precalculus::ToDegrees { radians, raw }) => {
+            let value = parse_constant(&radians).unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
+            let degrees = round_to(radians_to_degrees(value), 10);
+            match raw {
+                true => println!("{degrees}"),
+                false => println!("{radians} radians is {degrees}°."),
+            }
+        }
+        Some(Precalculus::Trig {
+            function,
+            angle,
+            radians,
+            raw,
+        }) => {
+            let function = match function {
+                Trig::Sin => TrigFunction::Sin,
+                Trig::Cos => TrigFunction::Cos,
+                Trig::Tan => TrigFunction::Tan,
+                Trig::Csc => TrigFunction::Csc,
+                Trig::Sec => TrigFunction::Sec,
+                Trig::Cot => TrigFunction::Cot,
+            };
+            let radians = radians || angle.has_pi;
+            let angle = angle.value;
+            let degrees = match radians {
+                true => round_to(radians_to_degrees(angle), 10),
+                false => angle,
+            };
+            let exact = match degrees.fract() == 0.0 {
+                true => function.exact_value(degrees as i64),
+                false => None,
+            };
+            let label = match radians {
+                true => format!("{function}({})", round_to(angle, 4)),
+                false => format!("{function}({degrees}°)"),
+            };
+            let value = match exact {
+                Some(UnitCircleValue::Exact(exact)) => exact.to_f64(),
+                Some(UnitCircleValue::Undefined) => f64::NAN,
+                None => function.evaluate(degrees_to_radians(degrees)),
+            };
+            match (raw, exact) {
+                (true, Some(UnitCircleValue::Undefined)) => println!("undefined"),
+                (true, _) => println!("{value:?}"),
+                (false, Some(UnitCircleValue::Undefined)) => println!("{label} is undefined."),
+                (false, Some(UnitCircleValue::Exact(exact)))
+                    if exact.to_string() == round_to(value, 4).to_string() =>
+                {
+                    println!("{label} = {exact}.")
+                }
+                (false, Some(UnitCircleValue::Exact(exact))) => {
+                    println!("{label} = {exact} ≈ {}.", round_to(value, 4))
+                }
+                (false, None) => println!("{label} ≈ {}.", round_to(value, 4)),
+            }
+        }
+        Some(Precalculus::SolveTriangle {
+            case,
+            first,
+            second,
+            third,
+            raw,
+        }) => {
+            let (a, b, c) = (first, second, third);
+            let triangles = match case {
+                TriangleCase::Sss => solve_sss(a, b, c).map(|t| vec![t]),
+                TriangleCase::Sas => solve_sas(a, b, c).map(|t| vec![t]),
+                TriangleCase::Asa => solve_asa(a, b, c).map(|t| vec![t]),
+                TriangleCase::Aas => solve_aas(a, b, c).map(|t| vec![t]),
+                TriangleCase::Ssa => solve_ssa(a, b, c),
+            };
+            let triangles = triangles.unwrap_or_else(|e| {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            });
+            match (raw, triangles.len()) {
+                (true, _) => println!("{triangles:?}"),
+                (false, 0) => println!("No triangle has these measurements."),
+                (false, 1) => println!("The triangle is {}.", triangles[0]),
+                (false, n) => {
+                    println!("There are {n} triangles:");
+                    for triangle in triangles {
+                        println!("{triangle}");
+                    }
+                }
+            }
+        }
+        Some(Precalculus::Log { x, base, raw }) => {
+            let (result, label) = match base {
+                Some(base) => (log(base, x), format!("log_{base}({x})")),
+                None => (log(std::f64::consts::E, x), format!("ln({x})")),
+            };
+            match (raw, result) {
+                (true, Some(result)) => println!("{result}"),
+                (false, Some(result)) => println!("{label} = {}.", round_to(result, 10)),
+                (_, None) => {
+                    eprintln!("Error: {label} is undefined; x must be positive and the base must be positive and not 1.");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Some(Precalculus::LogIdentities { base, x, y, raw }) => {
+            let Some(identities) = log_identities(base, x, y) else {
+                eprintln!(
+                    "Error: x, y and x^y must be positive and the base must be positive and not 1."
+                );
+                std::process::exit(1);
+            };
+            print_identities(&identities, raw);
+        }
+        Some(Precalculus::ExponentIdentities { base, m, n, raw }) => {
+            print_identities(&exponent_identities(base, m, n), raw)
+        }
+        Some(Precalculus::ArithmeticSeries {
+            first,
+            difference,
+            n,
+            raw,
+        }) => {
+            let sum = arithmetic_sum(first, difference, n);
+            match raw {
+                true => println!("{sum}"),
+                false => println!(
+                    "The first {n} terms are {:?}, which sum to {}.",
+                    arithmetic_sequence(first, difference, n),
+                    round_to(sum, 10)
+                ),
+            }
+        }
+        Some(Precalculus::GeometricSeries {
+            first,
+            ratio,
+            n,
+            raw,
+        }) => {
+            let sum = geometric_sum(first, ratio, n);
+            match raw {
+                true => println!("{sum}"),
+                false => {
+                    println!(
+                        "The first {n} terms are {:?}, which sum to {}.",
+                        geometric_sequence(first, ratio, n),
+                        round_to(sum, 10)
+                    );
+                    match geometric_series_sum(first, ratio) {
+                        Some(total) => {
+                            println!("The infinite series converges to {}.", round_to(total, 10))
+                        }
+                        None => println!("The infinite series diverges because |r| >= 1."),
+                    }
+                }
+            }
+        }
+        None => println!("Please provide a function to use."),
+    }
+}
+
+fn print_identities(identities: &[ladderz::precalculus::IdentityCheck], raw: bool) {
+    match raw {
+        true => println!(
+            "{:?}",
+            identities
+                .iter()
+                .map(|i| (i.left, i.right))
+                .collect::<Vec<_>>()
+        ),
+        false => {
+            for identity in identities {
+                println!("{identity}");
+            }
+        }
+    }
+}
@@ -0,0 +1,581 @@
+use clap::Subcommand;
+use ladderz::practice::prealgebra::{generate_problems, Concept, Problem};
+use ladderz::practice::Difficulty;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Subcommand)]
+#[command(arg_required_else_help(true))]
+pub enum Quiz {
+    /// Quizzes you on pre-algebra problems, such as finding factors or checking primes, one at a
+    /// time.
+    ///
+    /// Answer in any natural format, such as `1,2,3,4,6,12`, `yes` or `2^2 * 3`. Type `hint` to
+    /// see the next step of the solution, `skip` to see the answer, or `quit` to stop. Your
+    /// progress is saved, so you can see your mastery of each function with `lz quiz progress`.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz quiz prealgebra --count 1 --seed 6 --concept prime-factorization --difficulty easy
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// Pre-algebra quiz (seed 6). Type an answer, or `hint`, `skip` or `quit`.
+    ///
+    /// Question 1 of 1 [easy]: Find the prime factorization of 12.
+    /// > hint
+    /// Hint: Divide 12 by its smallest prime factor: 12 ÷ 2 = 6.
+    /// > 2^2 * 3
+    /// Correct!
+    ///
+    /// Score: 1 of 1 correct, 0 on the first try without hints.
+    ///
+    /// get_prime_factorization: 1 of 1 correct (100%), practicing
+    /// ```
+    Prealgebra {
+        /// The number of questions to ask.
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
+        /// The seed to generate questions from, so the same seed gives the same questions.
+        /// Defaults to a random seed, which is printed.
+        #[arg(short, long)]
+        seed: Option<u64>,
+        /// The difficulty of every question: easy, medium or hard. Defaults to a mix.
+        #[arg(short, long)]
+        difficulty: Option<Difficulty>,
+        /// Only ask questions for a concept, e.g. `is-prime`. May be repeated.
+        #[arg(long = "concept", value_name = "CONCEPT")]
+        concepts: Vec<Concept>,
+        /// The file to save progress to. Defaults to `ladderz/progress.json` in your data
+        /// directory.
+        #[arg(long, value_name = "PATH")]
+        progress_file: Option<PathBuf>,
+    },
+    /// Shows your mastery of each function from past quizzes.
+    ///
+    /// ## Example
+    ///
+    /// ### Input
+    ///
+    /// ```bash
+    /// lz quiz progress
+    /// ```
+    ///
+    /// ### Output
+    ///
+    /// ```bash
+    /// Progress over 2 quizzes:
+    ///
+    /// prealgebra
+    ///   get_factors: 3 of 4 correct (75%), practicing
+    ///   get_prime_factorization: 5 of 5 correct (100%), mastered
+    /// ```
+    ///
+    /// ## Raw Output (use `-r` or `--raw`)
+    ///
+    /// The saved progress file, as JSON.
+    Progress {
+        /// The file progress was saved to. Defaults to `ladderz/progress.json` in your data
+        /// directory.
+        #[arg(long, value_name = "PATH")]
+        progress_file: Option<PathBuf>,
+        /// Whether or not to return the raw output.
+        #[arg(short = 'r', long)]
+        raw: bool,
+    },
+}
+
+/// The number of wrong answers allowed before a question's answer is shown.
+const ATTEMPTS: u32 = 3;
+
+/// How well a learner knows one function, from every quiz question about it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Mastery {
+    /// The number of questions asked.
+    pub attempted: u32,
+    /// The number of questions answered correctly, even after hints or wrong answers.
+    pub correct: u32,
+    /// The number of questions answered correctly on the first try without hints.
+    pub first_try: u32,
+    /// The number of latest questions in a row answered correctly on the first try without hints.
+    pub streak: u32,
+}
+
+impl Mastery {
+    /// Records a question's result.
+    fn record(&mut self, result: QuestionResult) {
+        self.attempted += 1;
+        if result.correct {
+            self.correct += 1;
+        }
+        match result.first_try() {
+            true => {
+                self.first_try += 1;
+                self.streak += 1;
+            }
+            false => self.streak = 0,
+        }
+    }
+
+    /// Returns `mastered` after at least 3 first tries in a row with 80% correct overall,
+    /// `practicing` with at least 50% correct, and `learning` otherwise.
+    pub fn level(&self) -> &'static str {
+        let correct = self.correct as u64 * 100;
+        let attempted = self.attempted as u64;
+        if self.streak >= 3 && correct >= attempted * 80 {
+            "mastered"
+        } else if correct >= attempted * 50 {
+            "practicing"
+        } else {
+            "learning"
+        }
+    }
+}
+
+impl std::fmt::Display for Mastery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let percent = (self.correct * 100)
+            .checked_div(self.attempted)
+            .unwrap_or(0);
+        write!(
+            f,
+            "{} of {} correct ({percent}%), {}",
+            self.correct,
+            self.attempted,
+            self.level()
+        )
+    }
+}
+
+/// A learner's progress across quizzes, saved as JSON.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Progress {
+    /// The number of quizzes taken.
+    pub quizzes: u32,
+    /// The mastery of each function by subject, e.g. `prealgebra` then `get_prime_factorization`.
+    pub subjects: BTreeMap<String, BTreeMap<String, Mastery>>,
+}
+
+impl Progress {
+    /// Returns the default progress file, `ladderz/progress.json` in the user's data directory.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("ladderz").join("progress.json"))
+    }
+
+    /// Loads progress from `path`, or returns empty progress if the file doesn't exist yet.
+    pub fn load(path: &Path) -> io::Result<Progress> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} isn't a valid progress file: {e}", path.display()),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Progress::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Saves progress to `path`, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_json() + "\n")
+    }
+
+    /// Returns the progress as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("progress always serializes")
+    }
+
+    /// Returns the mastery of `function` in `subject`, if it has been quizzed.
+    pub fn mastery(&self, subject: &str, function: &str) -> Option<&Mastery> {
+        self.subjects.get(subject)?.get(function)
+    }
+
+    fn record(&mut self, subject: &str, function: &str, result: QuestionResult) {
+        self.subjects
+            .entry(subject.to_owned())
+            .or_default()
+            .entry(function.to_owned())
+            .or_default()
+            .record(result);
+    }
+}
+
+/// How a question in a quiz went.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct QuestionResult {
+    correct: bool,
+    wrong_answers: u32,
+    hints: usize,
+}
+
+impl QuestionResult {
+    /// Whether the question was answered correctly on the first try without hints.
+    fn first_try(&self) -> bool {
+        self.correct && self.wrong_answers == 0 && self.hints == 0
+    }
+}
+
+/// The score of a quiz.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Score {
+    pub asked: usize,
+    pub correct: usize,
+    pub first_try: usize,
+}
+
+impl std::fmt::Display for Score {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} correct, {} on the first try without hints.",
+            self.correct, self.asked, self.first_try
+        )
+    }
+}
+
+/// Asks one question until it's answered, skipped or out of attempts. Returns `None` if the
+/// learner quits or the input ends.
+fn ask(
+    problem: &Problem,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<Option<QuestionResult>> {
+    let steps = problem.steps();
+    // The last step states the answer, so it's never given as a hint.
+    let hints = &steps[..steps.len() - 1];
+    let mut result = QuestionResult {
+        correct: false,
+        wrong_answers: 0,
+        hints: 0,
+    };
+    let mut line = String::new();
+    loop {
+        write!(output, "> ")?;
+        output.flush()?;
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(output)?;
+            return Ok(None);
+        }
+        match line.trim().to_ascii_lowercase().as_str() {
+            "" => continue,
+            "quit" | "q" => return Ok(None),
+            "hint" | "h" | "?" => match hints.get(result.hints) {
+                Some(hint) => {
+                    writeln!(output, "Hint: {hint}")?;
+                    result.hints += 1;
+                }
+                None => writeln!(output, "No more hints. Type `skip` to see the answer.")?,
+            },
+            "skip" | "s" => {
+                write_solution(problem, &steps, output)?;
+                return Ok(Some(result));
+            }
+            _ => match problem.check(&line) {
+                Ok(true) => {
+                    writeln!(output, "Correct!")?;
+                    result.correct = true;
+                    return Ok(Some(result));
+                }
+                Ok(false) => {
+                    result.wrong_answers += 1;
+                    if result.wrong_answers == ATTEMPTS {
+                        write!(output, "Not quite. ")?;
+                        write_solution(problem, &steps, output)?;
+                        return Ok(Some(result));
+                    }
+                    match hints.get(result.hints) {
+                        Some(hint) => {
+                            writeln!(output, "Not quite. Hint: {hint}")?;
+                            result.hints += 1;
+                        }
+                        None => writeln!(output, "Not quite. Try again.")?,
+                    }
+                }
+                Err(e) => writeln!(output, "Couldn't read that answer: {e}. Try again.")?,
+            },
+        }
+    }
+}
+
+fn write_solution(problem: &Problem, steps: &[String], output: &mut impl Write) -> io::Result<()> {
+    writeln!(output, "The answer is {}.", problem.answer())?;
+    for step in steps {
+        writeln!(output, "  {step}")?;
+    }
+    Ok(())
+}
+
+/// Quizzes the learner on `problems` in `subject`, reading answers from `input`, and records each
+/// answered question in `progress`.
+pub fn run_quiz(
+    subject: &str,
+    problems: &[Problem],
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    progress: &mut Progress,
+) -> io::Result<Score> {
+    let mut score = Score::default();
+    let mut functions = Vec::new();
+    for (i, problem) in problems.iter().enumerate() {
+        writeln!(
+            output,
+            "\nQuestion {} of {} [{}]: {problem}",
+            i + 1,
+            problems.len(),
+            problem.difficulty
+        )?;
+        let Some(result) = ask(problem, input, output)? else {
+            break;
+        };
+        score.asked += 1;
+        score.correct += result.correct as usize;
+        score.first_try += result.first_try() as usize;
+        let function = problem.concept().function_name();
+        progress.record(subject, function, result);
+        if !functions.contains(&function) {
+            functions.push(function);
+        }
+    }
+    if score.asked > 0 {
+        progress.quizzes += 1;
+    }
+    writeln!(output, "\nScore: {score}")?;
+    if !functions.is_empty() {
+        writeln!(output)?;
+        functions.sort_unstable();
+        for function in functions {
+            let mastery = progress.mastery(subject, function).unwrap();
+            writeln!(output, "{function}: {mastery}")?;
+        }
+    }
+    Ok(score)
+}
+
+/// Returns `path`, or the default progress file.
+fn progress_path(path: Option<PathBuf>) -> Result<PathBuf, String> {
+    path.or_else(Progress::default_path).ok_or_else(|| {
+        "couldn't find your data directory, so please pass --progress-file".to_owned()
+    })
+}
+
+/// Returns a seed that changes on every run.
+fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or_default()
+}
+
+fn quiz_prealgebra(
+    count: usize,
+    seed: Option<u64>,
+    difficulty: Option<Difficulty>,
+    concepts: &[Concept],
+    progress_file: Option<PathBuf>,
+) -> Result<(), String> {
+    let path = progress_path(progress_file)?;
+    let mut progress = Progress::load(&path).map_err(|e| e.to_string())?;
+    let seed = seed.unwrap_or_else(random_seed);
+    let problems = generate_problems(count, seed, difficulty, concepts);
+    println!("Pre-algebra quiz (seed {seed}). Type an answer, or `hint`, `skip` or `quit`.");
+    run_quiz(
+        "prealgebra",
+        &problems,
+        &mut io::stdin().lock(),
+        &mut io::stdout(),
+        &mut progress,
+    )
+    .map_err(|e| e.to_string())?;
+    progress
+        .save(&path)
+        .map_err(|e| format!("couldn't save progress to {}: {e}", path.display()))
+}
+
+fn show_progress(progress_file: Option<PathBuf>, raw: bool) -> Result<(), String> {
+    let progress = Progress::load(&progress_path(progress_file)?).map_err(|e| e.to_string())?;
+    if raw {
+        println!("{}", progress.to_json());
+    } else if progress.quizzes == 0 {
+        println!("No quizzes taken yet. Start one with `lz quiz prealgebra`.");
+    } else {
+        let plural = if progress.quizzes == 1 { "" } else { "zes" };
+        println!("Progress over {} quiz{plural}:", progress.quizzes);
+        for (subject, functions) in &progress.subjects {
+            println!("\n{subject}");
+            for (function, mastery) in functions {
+                println!("  {function}: {mastery}");
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn match_quiz(subject: Option<Quiz>) {
+    let result = match subject {
+        Some(Quiz::Prealgebra {
+            count,
+            seed,
+            difficulty,
+            concepts,
+            progress_file,
+        }) => quiz_prealgebra(count, seed, difficulty, &concepts, progress_file),
+        Some(Quiz::Progress { progress_file, raw }) => show_progress(progress_file, raw),
+        None => {
+            println!("Please provide a subject to be quizzed on.");
+            Ok(())
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ladderz::practice::prealgebra::Question;
+
+    /// Runs a quiz with scripted answers, returning the score and everything written.
+    fn quiz(problems: &[Problem], answers: &str, progress: &mut Progress) -> (Score, String) {
+        let mut output = Vec::new();
+        let score = run_quiz(
+            "prealgebra",
+            problems,
+            &mut answers.as_bytes(),
+            &mut output,
+            progress,
+        )
+        .unwrap();
+        (score, String::from_utf8(output).unwrap())
+    }
+
+    fn factorization(n: u32) -> Problem {
+        Problem::new(Question::PrimeFactorization { n }, Difficulty::Easy)
+    }
+
+    #[test]
+    fn test_natural_answers_and_hints() {
+        let problems = [
+            factorization(12),
+            Problem::new(Question::Factors { n: 12 }, Difficulty::Easy),
+        ];
+        let mut progress = Progress::default();
+        let (score, output) = quiz(&problems, "hint\n2^2 * 3\n1,2,3,4,6,12\n", &mut progress);
+        assert_eq!(
+            score,
+            Score {
+                asked: 2,
+                correct: 2,
+                first_try: 1
+            }
+        );
+        assert!(output.contains("Hint: Divide 12 by its smallest prime factor: 12 ÷ 2 = 6.\n"));
+        assert!(output.contains("Score: 2 of 2 correct, 1 on the first try without hints."));
+        assert!(output.contains("get_factors: 1 of 1 correct (100%), practicing"));
+        assert_eq!(progress.quizzes, 1);
+        let mastery = progress
+            .mastery("prealgebra", "get_prime_factorization")
+            .unwrap();
+        assert_eq!(
+            (mastery.correct, mastery.first_try, mastery.streak),
+            (1, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_wrong_answers_show_hints_then_solution() {
+        let mut progress = Progress::default();
+        let (score, output) = quiz(&[factorization(8)], "two\n2^2\n2 * 4\n\n8\n", &mut progress);
+        assert_eq!(score.correct, 0);
+        assert!(output.contains("Couldn't read that answer"));
+        assert!(output.contains("Not quite. Hint: Divide 8 by its smallest prime factor"));
+        assert!(output.contains("Not quite. The answer is 2^3.\n"));
+        // The quiz is over, so the last answer isn't read.
+        assert!(!output.contains("Correct!"));
+        assert_eq!(
+            progress.mastery("prealgebra", "get_prime_factorization"),
+            Some(&Mastery {
+                attempted: 1,
+                correct: 0,
+                first_try: 0,
+                streak: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_quit_and_end_of_input() {
+        let problems = [factorization(4), factorization(6), factorization(9)];
+        let mut progress = Progress::default();
+        let (score, _) = quiz(&problems, "2 2\nskip\nquit\n", &mut progress);
+        assert_eq!(score.asked, 2);
+        assert_eq!(score.correct, 1);
+
+        let (score, output) = quiz(&problems, "", &mut progress);
+        assert_eq!(score.asked, 0);
+        assert!(output.contains("Score: 0 of 0 correct"));
+        // A quiz without answers isn't counted.
+        assert_eq!(progress.quizzes, 1);
+    }
+
+    #[test]
+    fn test_mastery_levels() {
+        let mut mastery = Mastery::default();
+        let first_try = QuestionResult {
+            correct: true,
+            wrong_answers: 0,
+            hints: 0,
+        };
+        let wrong = QuestionResult {
+            correct: false,
+            wrong_answers: ATTEMPTS,
+            hints: 2,
+        };
+        mastery.record(wrong);
+        assert_eq!(mastery.level(), "learning");
+        mastery.record(first_try);
+        assert_eq!(mastery.level(), "practicing");
+        mastery.record(first_try);
+        mastery.record(first_try);
+        assert_eq!(mastery.level(), "practicing");
+        mastery.record(first_try);
+        assert_eq!(mastery.to_string(), "4 of 5 correct (80%), mastered");
+        mastery.record(wrong);
+        assert_eq!(mastery.level(), "practicing");
+    }
+
+    #[test]
+    fn test_progress_saves_and_loads() {
+        let dir = std::env::temp_dir().join(format!("lz-quiz-test-{}", std::process::id()));
+        let path = dir.join("nested").join("progress.json");
+        assert_eq!(Progress::load(&path).unwrap(), Progress::default());
+
+        let mut progress = Progress::default();
+        quiz(&[factorization(10)], "2 5\n", &mut progress);
+        progress.save(&path).unwrap();
+        let loaded = Progress::load(&path).unwrap();
+        assert_eq!(loaded, progress);
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains(r#""get_prime_factorization": {"#));
+
+        fs::write(&path, "not json").unwrap();
+        assert!(Progress::load(&path).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
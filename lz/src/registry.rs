@@ -0,0 +1,481 @@
+//! The registry of subjects whose functions are declared once as [`Function`]s.
+//!
+//! Each function's arguments, result type and formatters live on one type, and the registry
+//! builds its command, help text, `--raw` and `--format` flags and `lz batch` support from it.
+
+use crate::output::{Format, LzOutput, Rendered, Sentence, ToOutput};
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Args, Command, FromArgMatches};
+use std::io::{self, BufRead, Write};
+
+/// A function of a subject, such as `lz prealgebra factors`.
+///
+/// The arguments are the fields of the implementing type, and its doc comment is the help text.
+/// Its result is rendered to every [`Format`] from the [`LzOutput`] it converts to.
+pub trait Function: Args + FromArgMatches {
+    /// The name of the function's command, e.g. `factor-pairs`.
+    const NAME: &'static str;
+    /// The result of the function.
+    type Output: ToOutput;
+
+    /// Runs the function on its arguments.
+    fn run(&self) -> Result<Self::Output, String>;
+
+    /// Describes the result in a sentence.
+    fn prose(&self, output: &Self::Output) -> Sentence;
+
+    /// Extra output shown after the result, such as a DP table.
+    fn details(&self, _output: &Self::Output) -> Option<LzOutput> {
+        None
+    }
+}
+
+/// A [`Function`] in a subject's registry, with its type erased.
+pub struct Entry {
+    pub name: &'static str,
+    command: fn() -> Command,
+    call: fn(&ArgMatches) -> Result<Rendered, String>,
+}
+
+impl Entry {
+    pub const fn of<F: Function>() -> Entry {
+        Entry {
+            name: F::NAME,
+            command: command::<F>,
+            call: call::<F>,
+        }
+    }
+
+    /// Returns the function's command, including the `--raw` and `--format` flags.
+    pub fn command(&self) -> Command {
+        (self.command)()
+            .arg(
+                Arg::new("raw")
+                    .short('r')
+                    .long("raw")
+                    .action(ArgAction::SetTrue)
+                    .help("Whether or not to return the raw output"),
+            )
+            .arg(
+                Arg::new("format")
+                    .long("format")
+                    .value_name("FORMAT")
+                    .value_parser(value_parser!(Format))
+                    .conflicts_with("raw")
+                    .help("The format to print the result in"),
+            )
+    }
+
+    /// Runs the function on the arguments in `matches`.
+    pub fn call(&self, matches: &ArgMatches) -> Result<Rendered, String> {
+        (self.call)(matches)
+    }
+}
+
+fn command<F: Function>() -> Command {
+    F::augment_args(Command::new(F::NAME))
+}
+
+fn call<F: Function>(matches: &ArgMatches) -> Result<Rendered, String> {
+    let function = F::from_arg_matches(matches).map_err(|e| e.to_string())?;
+    let output = function.run()?;
+    Ok(Rendered {
+        sentence: function.prose(&output),
+        result: output.to_output(),
+        details: function.details(&output),
+    })
+}
+
+/// A subject and the functions it declares.
+pub struct Subject {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub functions: &'static [Entry],
+}
+
+impl Subject {
+    /// Returns the subject's command, with a subcommand for each of its functions.
+    pub fn command(&self) -> Command {
+        Command::new(self.name)
+            .about(self.about)
+            .subcommand_value_name("FUNCTION")
+            .arg_required_else_help(true)
+            .subcommands(self.functions.iter().map(Entry::command))
+    }
+
+    /// Finds the function named `name`.
+    pub fn function(&self, name: &str) -> Option<&'static Entry> {
+        self.functions.iter().find(|entry| entry.name == name)
+    }
+}
+
+/// Every subject in the registry.
+pub const SUBJECTS: &[Subject] = &[crate::prealgebra::SUBJECT, crate::dsa::SUBJECT];
+
+/// Finds the subject named `name`.
+pub fn subject(name: &str) -> Option<&'static Subject> {
+    SUBJECTS.iter().find(|subject| subject.name == name)
+}
+
+/// Returns the format requested by `--format` or `-r` in `matches`, or `default`.
+fn requested_format(matches: &ArgMatches, default: Format) -> Format {
+    match matches.get_one::<Format>("format") {
+        Some(&format) => format,
+        None if matches.get_flag("raw") => Format::Raw,
+        None => default,
+    }
+}
+
+/// A parsed command for a function in the registry, e.g. `lz prealgebra factors 12`.
+pub struct Invocation {
+    function: Option<(&'static Entry, ArgMatches)>,
+}
+
+impl Invocation {
+    /// Finds the subject and function of the subcommand in `matches`.
+    fn from_subcommand(name: &str, matches: &ArgMatches) -> Result<Self, clap::Error> {
+        let subject =
+            subject(name).ok_or_else(|| clap::Error::new(ErrorKind::InvalidSubcommand))?;
+        let function = match matches.subcommand() {
+            Some((name, matches)) => {
+                let entry = subject
+                    .function(name)
+                    .ok_or_else(|| clap::Error::new(ErrorKind::InvalidSubcommand))?;
+                Some((entry, matches.clone()))
+            }
+            None => None,
+        };
+        Ok(Invocation { function })
+    }
+
+    /// Runs the function, returning its result in the format requested by its flags.
+    pub fn run(&self) -> Result<String, String> {
+        let Some((entry, matches)) = &self.function else {
+            return Ok("Please provide a function to use.".to_owned());
+        };
+        let rendered = entry.call(matches)?;
+        Ok(rendered.format(requested_format(matches, Format::Prose)))
+    }
+}
+
+impl FromArgMatches for Invocation {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        match matches.subcommand() {
+            Some((name, matches)) => Invocation::from_subcommand(name, matches),
+            None => Err(clap::Error::new(ErrorKind::MissingSubcommand)),
+        }
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Invocation::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl clap::Subcommand for Invocation {
+    fn augment_subcommands(cmd: Command) -> Command {
+        cmd.subcommands(SUBJECTS.iter().map(Subject::command))
+    }
+
+    fn augment_subcommands_for_update(cmd: Command) -> Command {
+        Invocation::augment_subcommands(cmd)
+    }
+
+    fn has_subcommand(name: &str) -> bool {
+        subject(name).is_some()
+    }
+}
+
+/// Splits a batch line into words on whitespace, keeping text in single or double quotes
+/// together, e.g. `dsa stack "push 3; pop"` into `["dsa", "stack", "push 3; pop"]`.
+pub fn split_words(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    for c in line.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => word.get_or_insert_with(String::new).push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                word.get_or_insert_with(String::new);
+            }
+            (None, c) if c.is_whitespace() => words.extend(word.take()),
+            (None, c) => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    if let Some(q) = quote {
+        return Err(format!("unterminated {q} quote"));
+    }
+    words.extend(word);
+    Ok(words)
+}
+
+/// Runs the command on a batch line, such as `prealgebra factors 12`.
+fn run_line(line: &str, default: Format) -> Result<(Rendered, Format), String> {
+    let words = split_words(line)?;
+    let name = words.first().map(String::as_str).unwrap_or_default();
+    let subject = subject(name).ok_or_else(|| {
+        let names: Vec<&str> = SUBJECTS.iter().map(|subject| subject.name).collect();
+        format!(
+            "expected a subject ({}), but found {name:?}",
+            names.join(", ")
+        )
+    })?;
+    let matches = subject
+        .command()
+        .try_get_matches_from(&words)
+        .map_err(|e| {
+            let message = e.to_string();
+            let first = message.lines().next().unwrap_or_default();
+            first.trim_start_matches("error: ").to_owned()
+        })?;
+    let Some((name, matches)) = matches.subcommand() else {
+        return Err(format!("expected a function of {}", subject.name));
+    };
+    let entry = subject
+        .function(name)
+        .expect("clap only matches known functions");
+    Ok((entry.call(matches)?, requested_format(matches, default)))
+}
+
+/// Runs each line of `input` as a command such as `prealgebra factors 12` and writes the
+/// results to `out` in `format`, unless a line asks for another. Blank lines and lines
+/// starting with `#` are skipped.
+///
+/// In JSON, each line is written as an object with its `command` and either its `result` or its
+/// `error`, where a line that asks for another format has its result rendered in that format as
+/// a string. Otherwise errors are written to `errors`. Returns the number of lines that failed.
+///
+/// With the `parallel` feature, all of `input` is read first and its lines are run on several
+/// threads, but the results are still written in the order of the lines.
+pub fn run_batch(
+    input: impl BufRead,
+    format: Format,
+    out: &mut impl Write,
+    errors: &mut impl Write,
+) -> io::Result<usize> {
+    let mut failures = 0;
+    let commands = input.lines().enumerate().filter_map(|(number, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => return Some(Err(e)),
+        };
+        let command = line.trim();
+        let skipped = command.is_empty() || command.starts_with('#');
+        (!skipped).then(|| Ok((number, command.to_owned())))
+    });
+    #[cfg(feature = "parallel")]
+    let commands = {
+        use rayon::prelude::*;
+        let commands: Vec<(usize, String)> = commands.collect::<io::Result<_>>()?;
+        let results: Vec<_> = commands
+            .par_iter()
+            .map(|(_, command)| run_line(command, format))
+            .collect();
+        commands.into_iter().zip(results).map(io::Result::Ok)
+    };
+    #[cfg(not(feature = "parallel"))]
+    let commands = commands.map(|command| {
+        command.map(|(number, command)| {
+            let result = run_line(&command, format);
+            ((number, command), result)
+        })
+    });
+    for command in commands {
+        let ((number, command), result) = command?;
+        failures += usize::from(result.is_err());
+        match (format, result) {
+            (Format::Json, Ok((rendered, line_format))) => {
+                let result = match line_format {
+                    Format::Json => rendered.to_json(),
+                    line_format => rendered.format(line_format).into(),
+                };
+                let object = serde_json::json!({ "command": command, "result": result });
+                writeln!(out, "{object}")?;
+            }
+            (Format::Json, Err(e)) => {
+                let object = serde_json::json!({ "command": command, "error": e });
+                writeln!(out, "{object}")?;
+            }
+            (_, Ok((rendered, format))) => writeln!(out, "{}", rendered.format(format))?,
+            (_, Err(e)) => writeln!(errors, "Error on line {}: {e}", number + 1)?,
+        }
+    }
+    Ok(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use ladderz::prealgebra::is_prime;
+    use proptest::prelude::*;
+
+    fn batch(input: &str, format: Format) -> (String, String, usize) {
+        let (mut out, mut errors) = (Vec::new(), Vec::new());
+        let failures = run_batch(input.as_bytes(), format, &mut out, &mut errors).unwrap();
+        (
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(errors).unwrap(),
+            failures,
+        )
+    }
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(
+            split_words("dsa stack \"push 3; pop\"  -r").unwrap(),
+            ["dsa", "stack", "push 3; pop", "-r"]
+        );
+        assert_eq!(
+            split_words("dsa is-anagram '' ''").unwrap(),
+            ["dsa", "is-anagram", "", ""]
+        );
+        assert_eq!(split_words("   ").unwrap(), Vec::<String>::new());
+        assert_eq!(
+            split_words("dsa stack \"push 3"),
+            Err("unterminated \" quote".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_every_function_has_a_command() {
+        for subject in SUBJECTS {
+            let mut command = subject.command();
+            command.build();
+            for entry in subject.functions {
+                let function = command.find_subcommand(entry.name).unwrap();
+                assert!(function.get_about().is_some(), "{} has no help", entry.name);
+                for flag in ["raw", "format"] {
+                    assert!(function.get_arguments().any(|arg| arg.get_id() == flag));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_batch_formats() {
+        let input = "# Comment\nprealgebra is-prime 7\n\ndsa two-sum-sorted 1,2,3 5\n";
+        let (out, errors, failures) = batch(input, Format::Prose);
+        assert_eq!(
+            out,
+            "7 is prime.\nThe pair of indices of the two numbers that sum to 5 is: (1, 2).\n"
+        );
+        assert_eq!((errors.as_str(), failures), ("", 0));
+
+        let (out, _, _) = batch(input, Format::Raw);
+        assert_eq!(out, "true\n(1, 2)\n");
+
+        let (out, _, _) = batch(input, Format::Latex);
+        assert_eq!(
+            out,
+            "$7$ is prime.\nThe pair of indices of the two numbers that sum to $5$ is: $(1, 2)$.\n"
+        );
+
+        let (out, _, _) = batch(input, Format::Json);
+        assert_eq!(
+            out,
+            "{\"command\":\"prealgebra is-prime 7\",\"result\":true}\n\
+             {\"command\":\"dsa two-sum-sorted 1,2,3 5\",\"result\":[1,2]}\n"
+        );
+    }
+
+    #[test]
+    fn test_batch_line_flags_override_format() {
+        let (out, _, _) = batch(
+            "prealgebra factors 1 -r\nprealgebra is-prime 4",
+            Format::Prose,
+        );
+        assert_eq!(out, "{1}\n4 is not prime.\n");
+        let (out, _, _) = batch("prealgebra is-prime 4 --format json", Format::Prose);
+        assert_eq!(out, "false\n");
+        let (out, _, _) = batch(
+            "prealgebra factors 4 -r\nprealgebra is-prime 4 --format prose",
+            Format::Json,
+        );
+        assert_eq!(
+            out,
+            "{\"command\":\"prealgebra factors 4 -r\",\"result\":\"{1, 2, 4}\"}\n\
+             {\"command\":\"prealgebra is-prime 4 --format prose\",\"result\":\"4 is not prime.\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_batch_keeps_line_order() {
+        let input: String = (1..=200)
+            .map(|n| format!("prealgebra is-prime {n} -r\n"))
+            .collect();
+        let (out, _, failures) = batch(&input, Format::Prose);
+        let expected: String = (1..=200).map(|n| format!("{}\n", is_prime(n))).collect();
+        assert_eq!((out, failures), (expected, 0));
+    }
+
+    #[test]
+    fn test_batch_errors() {
+        let input =
+            "algebra solve x\nprealgebra is-prime\ndsa stack \"frob 3\"\nprealgebra is-prime 2";
+        let (out, errors, failures) = batch(input, Format::Prose);
+        assert_eq!(out, "2 is prime.\n");
+        assert_eq!(failures, 3);
+        let lines: Vec<&str> = errors.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Error on line 1: expected a subject (prealgebra, dsa), but found \"algebra\""
+        );
+        assert!(lines[1].starts_with("Error on line 2: the following required arguments"));
+        assert!(lines[2].starts_with("Error on line 3: "));
+
+        let (out, errors, failures) = batch("dsa two-sum 1,x 5", Format::Json);
+        assert_eq!(errors, "");
+        assert_eq!(failures, 1);
+        assert!(out.starts_with("{\"command\":\"dsa two-sum 1,x 5\",\"error\":"));
+    }
+
+    /// Returns a strategy for the words of a command line, mostly names `lz` knows so parsing
+    /// gets past the subject, mixed with arbitrary text.
+    fn words() -> impl Strategy<Value = Vec<String>> {
+        let known = prop::sample::select(vec![
+            "prealgebra",
+            "dsa",
+            "factors",
+            "is-prime",
+            "primes-in-range",
+            "two-sum",
+            "stack",
+            "batch",
+            "eval",
+            "worksheet",
+            "--format",
+            "json",
+            "-r",
+            "--raw",
+            "--visualize",
+            "-h",
+            "--",
+            "12",
+            "-3",
+            "1,2,3",
+            "4294967296",
+        ]);
+        let word = prop_oneof![3 => known.prop_map(str::to_owned), 1 => any::<String>()];
+        prop::collection::vec(word, 0..8)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_parsing_arguments_never_panics(words in words()) {
+            let args = std::iter::once("lz".to_owned()).chain(words);
+            if let Err(e) = crate::Cli::try_parse_from(args) {
+                // Rendering the error runs clap's suggestions and usage formatting too.
+                let _ = e.to_string();
+            }
+        }
+
+        #[test]
+        fn prop_split_words_keeps_quoted_words(words in prop::collection::vec("[^\"]*", 0..6)) {
+            let line: Vec<String> = words.iter().map(|word| format!("\"{word}\"")).collect();
+            prop_assert_eq!(split_words(&line.join(" ")).unwrap(), words);
+        }
+    }
+}
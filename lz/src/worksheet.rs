@@ -0,0 +1,392 @@
+use crate::output::{Format, LzOutput, Sentence, ToOutput};
+use crate::practice::random_seed;
+use clap::{Args, ValueEnum};
+use ladderz::practice::prealgebra::{generate_problems, Concept, Problem, Question};
+use ladderz::practice::Difficulty;
+use ladderz::prealgebra::{
+    get_factor_pairs, get_factors, get_multiples_in_range, get_prime_factorization,
+    get_primes_in_range, is_composite, is_factor, is_multiple, is_prime,
+};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The subjects that worksheets can be made for.
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum WorksheetSubject {
+    #[default]
+    Prealgebra,
+}
+
+/// A group of concepts to put on a worksheet, or a single concept such as `is-prime`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Topic {
+    /// Factor pairs, factors and whether a number is a factor of another.
+    Factors,
+    /// Whether a number is a multiple of another and the multiples in a range.
+    Multiples,
+    /// Whether a number is prime or composite, the primes in a range and prime factorizations.
+    Primes,
+    Concept(Concept),
+}
+
+impl Topic {
+    /// Returns the concepts the topic covers.
+    fn concepts(&self) -> Vec<Concept> {
+        match self {
+            Topic::Factors => vec![Concept::FactorPairs, Concept::Factors, Concept::IsFactor],
+            Topic::Multiples => vec![Concept::IsMultiple, Concept::MultiplesInRange],
+            Topic::Primes => vec![
+                Concept::IsPrime,
+                Concept::IsComposite,
+                Concept::PrimesInRange,
+                Concept::PrimeFactorization,
+            ],
+            Topic::Concept(concept) => vec![*concept],
+        }
+    }
+}
+
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Topic::Factors => write!(f, "factors"),
+            Topic::Multiples => write!(f, "multiples"),
+            Topic::Primes => write!(f, "primes"),
+            Topic::Concept(concept) => write!(f, "{concept}"),
+        }
+    }
+}
+
+impl FromStr for Topic {
+    type Err = String;
+
+    /// Parses `factors`, `multiples`, `primes` or the name of a concept, e.g. `is-prime`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "factors" => Ok(Topic::Factors),
+            "multiples" => Ok(Topic::Multiples),
+            "primes" => Ok(Topic::Primes),
+            _ => s.parse().map(Topic::Concept).map_err(|_| {
+                let names: Vec<&str> = Concept::ALL.iter().map(Concept::name).collect();
+                format!(
+                    "unknown topic {s:?}, expected factors, multiples, primes or one of {}",
+                    names.join(", ")
+                )
+            }),
+        }
+    }
+}
+
+/// The kind of document a worksheet is written as.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum SheetFormat {
+    Markdown,
+    Html,
+    /// LaTeX source, to be compiled into a PDF with a tool such as `pdflatex`.
+    Latex,
+}
+
+impl SheetFormat {
+    /// Returns the format for a file's extension: `.md`, `.html` or `.tex`.
+    fn from_path(path: &Path) -> Option<SheetFormat> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "md" | "markdown" => Some(SheetFormat::Markdown),
+            "html" | "htm" => Some(SheetFormat::Html),
+            "tex" => Some(SheetFormat::Latex),
+            _ => None,
+        }
+    }
+
+    /// Returns the output format that answers are rendered in.
+    fn answer_format(&self) -> Format {
+        match self {
+            SheetFormat::Markdown => Format::Markdown,
+            // Answers are written as MathML, which browsers draw without any scripts.
+            SheetFormat::Html => Format::Mathml,
+            SheetFormat::Latex => Format::Latex,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct Worksheet {
+    /// The subject to make the worksheet for.
+    #[arg(long, value_enum, default_value_t = WorksheetSubject::Prealgebra)]
+    subject: WorksheetSubject,
+    /// The topics to practice, separated by commas: `factors`, `multiples`, `primes` or concepts
+    /// such as `is-prime`. Defaults to every topic.
+    #[arg(long, value_delimiter = ',', value_name = "TOPICS")]
+    topics: Vec<Topic>,
+    /// The number of problems to generate.
+    #[arg(short, long, default_value_t = 20)]
+    count: usize,
+    /// The seed to generate problems from, so the same seed gives the same worksheet. Defaults
+    /// to a random seed, which is printed on the worksheet.
+    #[arg(short, long)]
+    seed: Option<u64>,
+    /// The difficulty of every problem: easy, medium or hard. Defaults to a mix.
+    #[arg(short, long)]
+    difficulty: Option<Difficulty>,
+    /// The file to write the worksheet to. The answer key is written next to it with `-answers`
+    /// added to its name, e.g. `sheet-answers.md`. Both are printed if not given.
+    #[arg(short, long, value_name = "FILE")]
+    out: Option<PathBuf>,
+    /// The document format. Defaults to the format of the `--out` file's extension (`.md`,
+    /// `.html` or `.tex`), or Markdown.
+    #[arg(long, value_enum)]
+    format: Option<SheetFormat>,
+}
+
+/// Returns the answer to a problem, computed with the functions in [`ladderz::prealgebra`].
+fn answer(problem: &Problem) -> Sentence {
+    let verdict = |answer: bool| Sentence::new(if answer { "Yes" } else { "No" }, []);
+    let value = match problem.question {
+        Question::FactorPairs { n } => LzOutput::factor_pairs(&get_factor_pairs(n)),
+        Question::Factors { n } => get_factors(n).to_output(),
+        Question::IsFactor { x, y } => return verdict(is_factor(x, y)),
+        Question::IsMultiple { x, y } => return verdict(is_multiple(x, y)),
+        Question::MultiplesInRange { n, start, end } => {
+            get_multiples_in_range(n, start, end).to_output()
+        }
+        Question::IsPrime { n } => return verdict(is_prime(n)),
+        Question::IsComposite { n } => return verdict(is_composite(n)),
+        Question::PrimesInRange { start, end } => get_primes_in_range(start, end).to_output(),
+        Question::PrimeFactorization { n } => LzOutput::factorization(&get_prime_factorization(n)),
+    };
+    Sentence::new("{}", [value])
+}
+
+/// A worksheet's problems, with what's printed at the top of both documents.
+struct Sheet {
+    title: String,
+    about: String,
+    problems: Vec<Problem>,
+}
+
+impl Sheet {
+    /// Writes the problems, or the answer key, as a document in `format`.
+    fn document(&self, format: SheetFormat, answers: bool) -> String {
+        let title = match answers {
+            true => format!("{}: answer key", self.title),
+            false => self.title.clone(),
+        };
+        let items: Vec<String> = self
+            .problems
+            .iter()
+            .map(|problem| match answers {
+                true => answer(problem).render(format.answer_format()),
+                false => Sentence::new(&problem.prompt(), []).render(format.answer_format()),
+            })
+            .collect();
+        match format {
+            SheetFormat::Markdown => markdown(&title, &self.about, &items),
+            SheetFormat::Html => html(&title, &self.about, &items, answers),
+            SheetFormat::Latex => latex(&title, &self.about, &items, answers),
+        }
+    }
+}
+
+fn markdown(title: &str, about: &str, items: &[String]) -> String {
+    let items: Vec<String> = items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            // Lines after the first, like a table of factor pairs, are indented into the item.
+            let item = item.replace('\n', "\n   ");
+            format!("{}. {item}", i + 1)
+        })
+        .collect();
+    format!("# {title}\n\n{about}\n\n{}\n", items.join("\n"))
+}
+
+fn html(title: &str, about: &str, items: &[String], answers: bool) -> String {
+    // Problems get room to be worked out underneath.
+    let spacing = if answers { "0.5em" } else { "4em" };
+    let items: String = items
+        .iter()
+        .map(|item| format!("  <li>{item}</li>\n"))
+        .collect();
+    let title = Sentence::new(title, []).render(Format::Mathml);
+    let about = Sentence::new(about, []).render(Format::Mathml);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>body {{ font-family: sans-serif; }} li {{ margin-bottom: {spacing}; }}</style>\n\
+         </head>\n<body>\n<h1>{title}</h1>\n<p>{about}</p>\n<ol>\n{items}</ol>\n</body>\n</html>\n"
+    )
+}
+
+fn latex(title: &str, about: &str, items: &[String], answers: bool) -> String {
+    let spacing = if answers { "" } else { "\n  \\vspace{4em}" };
+    let items: String = items
+        .iter()
+        .map(|item| format!("  \\item {item}{spacing}\n"))
+        .collect();
+    let title = Sentence::new(title, []).render(Format::Latex);
+    let about = Sentence::new(about, []).render(Format::Latex);
+    format!(
+        "\\documentclass{{article}}\n\\usepackage{{amsmath}}\n\\begin{{document}}\n\n\
+         \\section*{{{title}}}\n{about}\n\n\\begin{{enumerate}}\n{items}\\end{{enumerate}}\n\n\
+         \\end{{document}}\n"
+    )
+}
+
+/// Returns the path of the answer key for a worksheet at `path`, e.g. `sheet-answers.md` for
+/// `sheet.md`.
+fn answers_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{stem}-answers.{}", extension.to_string_lossy()),
+        None => format!("{stem}-answers"),
+    };
+    path.with_file_name(name)
+}
+
+/// Generates the worksheet and its answer key, then writes them to files or prints them.
+fn make_worksheet(worksheet: Worksheet) -> Result<(), String> {
+    let format = match (worksheet.format, &worksheet.out) {
+        (Some(format), _) => format,
+        (None, Some(out)) => SheetFormat::from_path(out).ok_or_else(|| {
+            format!(
+                "can't tell the format of {} from its extension, so use --format",
+                out.display()
+            )
+        })?,
+        (None, None) => SheetFormat::Markdown,
+    };
+    let seed = worksheet.seed.unwrap_or_else(random_seed);
+    let mut concepts: Vec<Concept> = Vec::new();
+    for concept in worksheet.topics.iter().flat_map(Topic::concepts) {
+        if !concepts.contains(&concept) {
+            concepts.push(concept);
+        }
+    }
+    let topics: Vec<String> = worksheet.topics.iter().map(Topic::to_string).collect();
+    let topics = match topics.is_empty() {
+        true => "all topics".to_owned(),
+        false => topics.join(", "),
+    };
+    let sheet = match worksheet.subject {
+        WorksheetSubject::Prealgebra => Sheet {
+            title: "Pre-algebra worksheet".to_owned(),
+            about: format!("Topics: {topics}. Seed: {seed}."),
+            problems: generate_problems(worksheet.count, seed, worksheet.difficulty, &concepts),
+        },
+    };
+    let (problems, answers) = (sheet.document(format, false), sheet.document(format, true));
+    match worksheet.out {
+        Some(out) => {
+            let answers_out = answers_path(&out);
+            for (path, contents) in [(&out, problems), (&answers_out, answers)] {
+                std::fs::write(path, contents)
+                    .map_err(|e| format!("couldn't write {}: {e}", path.display()))?;
+            }
+            println!(
+                "Wrote the worksheet to {} and its answer key to {}.",
+                out.display(),
+                answers_out.display()
+            );
+        }
+        None => print!("{problems}\n{answers}"),
+    }
+    Ok(())
+}
+
+pub fn match_worksheet(worksheet: Worksheet) {
+    if let Err(e) = make_worksheet(worksheet) {
+        eprintln!("Error: {e}");
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sheet(count: usize, seed: u64, concepts: &[Concept]) -> Sheet {
+        Sheet {
+            title: "Pre-algebra worksheet".to_owned(),
+            about: format!("Seed: {seed}."),
+            problems: generate_problems(count, seed, Some(Difficulty::Easy), concepts),
+        }
+    }
+
+    #[test]
+    fn test_topics() {
+        assert_eq!("primes".parse::<Topic>(), Ok(Topic::Primes));
+        assert_eq!(
+            "is-prime".parse::<Topic>(),
+            Ok(Topic::Concept(Concept::IsPrime))
+        );
+        assert!("fractions".parse::<Topic>().is_err());
+        assert_eq!(Topic::Factors.concepts().len(), 3);
+    }
+
+    #[test]
+    fn test_answers() {
+        let problem = Problem::new(Question::PrimeFactorization { n: 12 }, Difficulty::Easy);
+        assert_eq!(answer(&problem).render(Format::Latex), "$2^{2} \\cdot 3$");
+        let problem = Problem::new(Question::Factors { n: 6 }, Difficulty::Easy);
+        assert_eq!(answer(&problem).render(Format::Markdown), "`{1, 2, 3, 6}`");
+        let problem = Problem::new(Question::IsPrime { n: 9 }, Difficulty::Easy);
+        assert_eq!(answer(&problem).render(Format::Mathml), "No");
+    }
+
+    #[test]
+    fn test_documents() {
+        let sheet = Sheet {
+            title: "Pre-algebra worksheet".to_owned(),
+            about: "Seed: 7.".to_owned(),
+            problems: vec![
+                Problem::new(Question::Factors { n: 6 }, Difficulty::Easy),
+                Problem::new(Question::FactorPairs { n: 6 }, Difficulty::Easy),
+            ],
+        };
+        assert_eq!(
+            sheet.document(SheetFormat::Markdown, false),
+            "# Pre-algebra worksheet\n\nSeed: 7.\n\n1. Find all factors of 6.\n\
+             2. Find all factor pairs of 6.\n"
+        );
+        assert_eq!(
+            sheet.document(SheetFormat::Markdown, true),
+            "# Pre-algebra worksheet: answer key\n\nSeed: 7.\n\n1. `{1, 2, 3, 6}`\n\
+             2. `{(1, 6), (2, 3)}`\n"
+        );
+        let latex = sheet.document(SheetFormat::Latex, true);
+        assert!(latex.contains("\\item $\\{1, 2, 3, 6\\}$\n"));
+        assert!(latex.contains("\\item \\[\n\\begin{array}{rcl}\n1 & \\times & 6 \\\\\n"));
+        let html = sheet.document(SheetFormat::Html, false);
+        assert!(html.contains("<li>Find all factors of 6.</li>"));
+    }
+
+    #[test]
+    fn test_seed_reproduces_worksheet() {
+        let concepts = Topic::Primes.concepts();
+        let a = sheet(20, 7, &concepts);
+        let b = sheet(20, 7, &concepts);
+        assert_eq!(
+            a.document(SheetFormat::Latex, true),
+            b.document(SheetFormat::Latex, true)
+        );
+        assert!(a
+            .problems
+            .iter()
+            .all(|problem| concepts.contains(&problem.concept())));
+        let c = sheet(20, 8, &concepts);
+        assert_ne!(a.problems, c.problems);
+    }
+
+    #[test]
+    fn test_paths() {
+        assert_eq!(
+            answers_path(Path::new("out/sheet.md")),
+            Path::new("out/sheet-answers.md")
+        );
+        assert_eq!(
+            SheetFormat::from_path(Path::new("sheet.TEX")),
+            Some(SheetFormat::Latex)
+        );
+        assert_eq!(SheetFormat::from_path(Path::new("sheet.pdf")), None);
+    }
+}